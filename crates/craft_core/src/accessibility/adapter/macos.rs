@@ -0,0 +1,46 @@
+use accesskit::{ActionHandler, ActivationHandler, DeactivationHandler, TreeUpdate};
+use accesskit_macos::SubclassingAdapter;
+use craft_logging::warn;
+use winit::event::WindowEvent;
+use winit::raw_window_handle::{HasWindowHandle, RawWindowHandle};
+use winit::window::Window;
+
+pub(super) struct Adapter {
+    /// None when the window is not an AppKit view, like on iOS, which has no accessibility support yet.
+    adapter: Option<SubclassingAdapter>,
+}
+
+impl Adapter {
+    pub(super) fn new(
+        window: &dyn Window,
+        activation_handler: impl 'static + ActivationHandler,
+        action_handler: impl 'static + ActionHandler,
+        _deactivation_handler: impl 'static + DeactivationHandler,
+    ) -> Self {
+        let view = match window.window_handle().map(|handle| handle.as_raw()) {
+            Ok(RawWindowHandle::AppKit(handle)) => handle.ns_view.as_ptr(),
+            handle => {
+                warn!("Accessibility is not supported for the window handle {:?}.", handle);
+                return Self { adapter: None };
+            }
+        };
+
+        // SAFETY: The view belongs to the window, which outlives the adapter.
+        let adapter = unsafe { SubclassingAdapter::new(view, activation_handler, action_handler) };
+        Self { adapter: Some(adapter) }
+    }
+
+    pub(super) fn update_if_active(&mut self, updater: impl FnOnce() -> TreeUpdate) {
+        if let Some(events) = self.adapter.as_mut().and_then(|adapter| adapter.update_if_active(updater)) {
+            events.raise();
+        }
+    }
+
+    pub(super) fn process_event(&mut self, _window: &dyn Window, event: &WindowEvent) {
+        if let (WindowEvent::Focused(is_focused), Some(adapter)) = (event, self.adapter.as_mut()) {
+            if let Some(events) = adapter.update_view_focus_state(*is_focused) {
+                events.raise();
+            }
+        }
+    }
+}
@@ -0,0 +1,111 @@
+//! Connects the accessibility tree to the accessibility API of the platform.
+//!
+//! The platform adapters are based on `accesskit_winit`, which does not support our winit fork.
+
+#[cfg(target_os = "linux")]
+#[path = "unix.rs"]
+mod platform;
+
+#[cfg(target_os = "windows")]
+#[path = "windows.rs"]
+mod platform;
+
+#[cfg(target_os = "macos")]
+#[path = "macos.rs"]
+mod platform;
+
+#[cfg(not(any(target_os = "linux", target_os = "windows", target_os = "macos")))]
+#[path = "null.rs"]
+mod platform;
+
+use crate::app_message::AppMessage;
+use crate::events::internal::InternalMessage;
+use crate::WindowId;
+use accesskit::{ActionHandler, ActionRequest, ActivationHandler, DeactivationHandler, NodeId, TreeUpdate};
+use std::sync::Arc;
+use tokio::sync::mpsc::Sender;
+use winit::event::WindowEvent;
+use winit::window::Window;
+
+/// Owns the platform adapter of a window. This must live on the main thread.
+pub(crate) struct AccessibilityAdapter {
+    adapter: platform::Adapter,
+    window_title: String,
+}
+
+impl AccessibilityAdapter {
+    /// Creates the adapter for `window`, which must not be visible yet.
+    ///
+    /// Activation changes and action requests are forwarded to the Craft event loop through `app_sender`.
+    pub(crate) fn new(
+        window_id: WindowId,
+        window: &Arc<dyn Window>,
+        window_title: &str,
+        app_sender: Sender<AppMessage>,
+    ) -> Self {
+        let handler = Handler {
+            window_id,
+            window: window.clone(),
+            app_sender,
+        };
+
+        Self {
+            adapter: platform::Adapter::new(window.as_ref(), handler.clone(), handler.clone(), handler),
+            window_title: window_title.to_string(),
+        }
+    }
+
+    /// Sends the accessibility tree of the last frame to the platform, unless no assistive technology is observing it.
+    pub(crate) fn update_if_active(&mut self, mut tree_update: TreeUpdate) {
+        // The window title is only known here, so it is added to the root node.
+        let root = tree_update.tree.as_ref().map(|tree| tree.root).unwrap_or(NodeId(0));
+        if let Some((_, node)) =
+            tree_update.nodes.iter_mut().find(|(node_id, node)| *node_id == root && node.label().is_none())
+        {
+            node.set_label(self.window_title.as_str());
+        }
+
+        self.adapter.update_if_active(|| tree_update);
+    }
+
+    pub(crate) fn process_event(&mut self, window: &dyn Window, event: &WindowEvent) {
+        self.adapter.process_event(window, event);
+    }
+}
+
+/// Handles the callbacks of the platform adapter, which may run on any thread.
+#[derive(Clone)]
+struct Handler {
+    window_id: WindowId,
+    window: Arc<dyn Window>,
+    app_sender: Sender<AppMessage>,
+}
+
+impl Handler {
+    fn send_message(&self, message: InternalMessage) {
+        // The callbacks may run while the winit thread is waiting on the Craft event loop, so they must not block.
+        if self.app_sender.try_send(AppMessage::new(0, message)).is_ok() {
+            self.window.request_redraw();
+        }
+    }
+}
+
+impl ActivationHandler for Handler {
+    fn request_initial_tree(&mut self) -> Option<TreeUpdate> {
+        // The tree is built with the next frame.
+        self.send_message(InternalMessage::AccessibilityActivated(self.window_id));
+        None
+    }
+}
+
+impl ActionHandler for Handler {
+    fn do_action(&mut self, request: ActionRequest) {
+        self.send_message(InternalMessage::AccessibilityAction(self.window_id, request));
+    }
+}
+
+impl DeactivationHandler for Handler {
+    fn deactivate_accessibility(&mut self) {
+        self.send_message(InternalMessage::AccessibilityDeactivated(self.window_id));
+    }
+}
@@ -0,0 +1,21 @@
+use accesskit::{ActionHandler, ActivationHandler, DeactivationHandler, TreeUpdate};
+use winit::event::WindowEvent;
+use winit::window::Window;
+
+/// Used on platforms without a supported accessibility API.
+pub(super) struct Adapter;
+
+impl Adapter {
+    pub(super) fn new(
+        _window: &dyn Window,
+        _activation_handler: impl 'static + ActivationHandler,
+        _action_handler: impl 'static + ActionHandler,
+        _deactivation_handler: impl 'static + DeactivationHandler,
+    ) -> Self {
+        Self
+    }
+
+    pub(super) fn update_if_active(&mut self, _updater: impl FnOnce() -> TreeUpdate) {}
+
+    pub(super) fn process_event(&mut self, _window: &dyn Window, _event: &WindowEvent) {}
+}
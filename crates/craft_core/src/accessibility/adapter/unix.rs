@@ -0,0 +1,50 @@
+use accesskit::{ActionHandler, ActivationHandler, DeactivationHandler, Rect, TreeUpdate};
+use accesskit_unix::Adapter as UnixAdapter;
+use winit::event::WindowEvent;
+use winit::window::Window;
+
+pub(super) struct Adapter {
+    adapter: UnixAdapter,
+}
+
+impl Adapter {
+    pub(super) fn new(
+        _window: &dyn Window,
+        activation_handler: impl 'static + ActivationHandler + Send,
+        action_handler: impl 'static + ActionHandler + Send,
+        deactivation_handler: impl 'static + DeactivationHandler + Send,
+    ) -> Self {
+        let adapter = UnixAdapter::new(activation_handler, action_handler, deactivation_handler);
+        Self { adapter }
+    }
+
+    pub(super) fn update_if_active(&mut self, updater: impl FnOnce() -> TreeUpdate) {
+        self.adapter.update_if_active(updater);
+    }
+
+    pub(super) fn process_event(&mut self, window: &dyn Window, event: &WindowEvent) {
+        match event {
+            WindowEvent::Moved(_) | WindowEvent::SurfaceResized(_) => {
+                let outer_position = window.outer_position().unwrap_or_default().cast::<f64>();
+                let outer_size = window.outer_size().cast::<f64>();
+                // The surface position is relative to the outer position.
+                let surface_position = window.surface_position().cast::<f64>();
+                let surface_size = window.surface_size().cast::<f64>();
+
+                let outer = Rect::from_origin_size(
+                    (outer_position.x, outer_position.y),
+                    (outer_size.width, outer_size.height),
+                );
+                let inner = Rect::from_origin_size(
+                    (outer_position.x + surface_position.x, outer_position.y + surface_position.y),
+                    (surface_size.width, surface_size.height),
+                );
+                self.adapter.set_root_window_bounds(outer, inner);
+            }
+            WindowEvent::Focused(is_focused) => {
+                self.adapter.update_window_focus_state(*is_focused);
+            }
+            _ => (),
+        }
+    }
+}
@@ -0,0 +1,39 @@
+use accesskit::{ActionHandler, ActivationHandler, DeactivationHandler, TreeUpdate};
+use accesskit_windows::{SubclassingAdapter, HWND};
+use craft_logging::warn;
+use winit::event::WindowEvent;
+use winit::raw_window_handle::{HasWindowHandle, RawWindowHandle};
+use winit::window::Window;
+
+pub(super) struct Adapter {
+    /// None when the window is not a Win32 window, like a WinRT core window, which has no accessibility support yet.
+    adapter: Option<SubclassingAdapter>,
+}
+
+impl Adapter {
+    pub(super) fn new(
+        window: &dyn Window,
+        activation_handler: impl 'static + ActivationHandler,
+        action_handler: impl 'static + ActionHandler + Send,
+        _deactivation_handler: impl 'static + DeactivationHandler,
+    ) -> Self {
+        let hwnd = match window.window_handle().map(|handle| handle.as_raw()) {
+            Ok(RawWindowHandle::Win32(handle)) => handle.hwnd.get() as *mut _,
+            handle => {
+                warn!("Accessibility is not supported for the window handle {:?}.", handle);
+                return Self { adapter: None };
+            }
+        };
+
+        let adapter = SubclassingAdapter::new(HWND(hwnd), activation_handler, action_handler);
+        Self { adapter: Some(adapter) }
+    }
+
+    pub(super) fn update_if_active(&mut self, updater: impl FnOnce() -> TreeUpdate) {
+        if let Some(events) = self.adapter.as_mut().and_then(|adapter| adapter.update_if_active(updater)) {
+            events.raise();
+        }
+    }
+
+    pub(super) fn process_event(&mut self, _window: &dyn Window, _event: &WindowEvent) {}
+}
@@ -1 +1,198 @@
+//! Exposes the element tree to assistive technologies, like screen readers, through [AccessKit](https://accesskit.dev).
+//!
+//! Every frame the laid out element tree is converted into an accessibility tree, where each element becomes a node
+//! with the element's component id as its node id. Action requests from assistive technologies are dispatched back
+//! into the element tree as events.
 
+pub(crate) mod adapter;
+#[cfg(test)]
+mod tests;
+
+pub use accesskit::{Action, ActionData, ActionRequest, Node, NodeId, Role, Toggled, Tree, TreeUpdate};
+
+use crate::components::{ComponentId, FocusRequest};
+use crate::elements::element::Element;
+use crate::elements::Text;
+use crate::events::event_dispatch::dispatch_event;
+use crate::events::focus::{apply_focus_request, is_focusable};
+use crate::events::{CraftMessage, EventDispatchType, Message, PointerButton};
+use crate::geometry::Point;
+use crate::reactive::element_state_store::ElementStateStore;
+use crate::resource_manager::ResourceManager;
+use crate::text::text_context::TextContext;
+use crate::{GlobalState, ReactiveTree, WindowContext};
+use accesskit::Rect;
+use std::sync::Arc;
+use winit::dpi::PhysicalPosition;
+use winit::event::{ButtonSource, ElementState, MouseButton};
+
+/// Builds a full accessibility tree from the laid out element tree.
+///
+/// The root element becomes the window node. Bounds are in physical pixels, relative to the window. When the focused
+/// element is no longer mounted, the window is focused instead.
+pub(crate) fn build_tree_update(
+    root: &dyn Element,
+    element_state: &ElementStateStore,
+    focus: Option<ComponentId>,
+) -> TreeUpdate {
+    let mut nodes: Vec<(NodeId, Node)> = Vec::new();
+    let root_id = build_node(root, None, element_state, &mut nodes);
+    nodes[0].1.set_role(Role::Window);
+
+    let focus = focus
+        .map(NodeId)
+        .filter(|focus| nodes.iter().any(|(node_id, _)| node_id == focus))
+        .unwrap_or(root_id);
+
+    TreeUpdate {
+        nodes,
+        tree: Some(Tree::new(root_id)),
+        focus,
+    }
+}
+
+/// Adds the node of `element` and the nodes of its children to `nodes` in pre-order.
+fn build_node(
+    element: &dyn Element,
+    parent: Option<(&dyn Element, usize)>,
+    element_state: &ElementStateStore,
+    nodes: &mut Vec<(NodeId, Node)>,
+) -> NodeId {
+    let element_data = element.element_data();
+    let node_id = NodeId(element.component_id());
+
+    let mut node = Node::new(element.default_accessibility_role());
+
+    let bounds = element_data.layout_item.computed_box_transformed.border_rectangle();
+    node.set_bounds(Rect {
+        x0: bounds.left() as f64,
+        y0: bounds.top() as f64,
+        x1: bounds.right() as f64,
+        y1: bounds.bottom() as f64,
+    });
+
+    if !element_data.style.visible() {
+        node.set_hidden();
+    }
+    if element_data.on_pointer_button.is_some() {
+        node.add_action(Action::Click);
+    }
+    if is_focusable(element) {
+        node.add_action(Action::Focus);
+    }
+
+    element.update_accessibility_node(&mut node, element_state);
+    if let Some((parent, index)) = parent {
+        parent.update_accessibility_child_node(index, &mut node, element_state);
+    }
+
+    // The user provided label and role take precedence over the ones set by the element.
+    if let Some(label) = &element_data.accessibility_label {
+        node.set_label(label.as_str());
+    }
+    if let Some(role) = element_data.accessibility_role {
+        node.set_role(role);
+    }
+
+    // Reserve the slot, so that parents come before their children.
+    let node_index = nodes.len();
+    nodes.push((node_id, Node::default()));
+
+    for (index, child) in element.accessibility_children(element_state).into_iter().enumerate() {
+        let child_id = build_node(child, Some((element, index)), element_state, nodes);
+        node.push_child(child_id);
+    }
+
+    nodes[node_index].1 = node;
+    node_id
+}
+
+/// Concatenates the text of every [`Text`] element in the subtree of `element`.
+pub(crate) fn text_content(element: &dyn Element, element_state: &ElementStateStore) -> String {
+    element
+        .pre_order_iter()
+        .filter_map(|element| element.as_any().downcast_ref::<Text>())
+        .filter_map(|text| text.text(element_state))
+        .collect::<Vec<&str>>()
+        .join(" ")
+}
+
+/// Dispatches an action requested by an assistive technology to its target element.
+///
+/// Clicks are sent as a left mouse button press and release in the center of the target, so they behave exactly like
+/// a pointer click. Every other action is sent to the target as a [`CraftMessage::AccessibilityAction`] and bubbles up
+/// from there.
+pub(crate) fn dispatch_action_request(
+    request: ActionRequest,
+    resource_manager: &mut Arc<ResourceManager>,
+    reactive_tree: &mut ReactiveTree,
+    global_state: &mut GlobalState,
+    text_context: &mut Option<TextContext>,
+    window_context: &mut WindowContext,
+) {
+    let target = request.target.0;
+
+    let bounds = {
+        let root = if let Some(root) = reactive_tree.element_tree.as_ref() {
+            root
+        } else {
+            return;
+        };
+
+        if let Some(element) = root.as_ref().pre_order_iter().find(|element| element.component_id() == target) {
+            element.element_data().layout_item.computed_box_transformed.border_rectangle()
+        } else {
+            return;
+        }
+    };
+
+    match request.action {
+        Action::Click => {
+            let center = Point::new(bounds.x + bounds.width / 2.0, bounds.y + bounds.height / 2.0);
+
+            for state in [ElementState::Pressed, ElementState::Released] {
+                let pointer_button = PointerButton::new(
+                    None,
+                    state,
+                    PhysicalPosition::new(center.x as f64, center.y as f64),
+                    ButtonSource::Mouse(MouseButton::Left),
+                    true,
+                );
+
+                dispatch_event(
+                    &Message::CraftMessage(CraftMessage::PointerButtonEvent(pointer_button)),
+                    EventDispatchType::Targeted(target),
+                    resource_manager,
+                    Some(center),
+                    reactive_tree,
+                    global_state,
+                    text_context,
+                    window_context,
+                    true,
+                );
+            }
+        }
+        action => {
+            let focus_request = match action {
+                Action::Focus => Some(FocusRequest::Element(Some(target))),
+                Action::Blur if reactive_tree.focus == Some(target) => Some(FocusRequest::Element(None)),
+                _ => None,
+            };
+            if let Some(focus_request) = focus_request {
+                apply_focus_request(focus_request, resource_manager, reactive_tree, global_state, text_context, window_context);
+            }
+
+            dispatch_event(
+                &Message::CraftMessage(CraftMessage::AccessibilityAction(request)),
+                EventDispatchType::Targeted(target),
+                resource_manager,
+                None,
+                reactive_tree,
+                global_state,
+                text_context,
+                window_context,
+                false,
+            );
+        }
+    }
+}
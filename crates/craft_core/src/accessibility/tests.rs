@@ -0,0 +1,98 @@
+use crate::accessibility::{Action, ActionData, ActionRequest, NodeId, Role, Toggled};
+use crate::components::Component;
+use crate::geometry::Size;
+use crate::testing::helpers::{accessibility_node, state, Form};
+use crate::testing::TestHarness;
+use std::collections::HashSet;
+
+fn accessibility_request(harness: &TestHarness, id: &str, action: Action, data: Option<ActionData>) -> ActionRequest {
+    ActionRequest {
+        action,
+        target: NodeId(harness.find_element_by_id(id).unwrap().component_id()),
+        data,
+    }
+}
+
+#[test]
+fn accessibility_tree_exposes_roles_labels_and_values() {
+    let harness = TestHarness::new(Form::component(), (), Size::new(400.0, 600.0));
+    let tree = harness.accessibility_tree();
+
+    // Every node is reachable from the root exactly once.
+    let root_id = tree.tree.as_ref().unwrap().root;
+    let mut reachable = HashSet::new();
+    let mut to_visit = vec![root_id];
+    while let Some(node_id) = to_visit.pop() {
+        assert!(reachable.insert(node_id));
+        let (_, node) = tree.nodes.iter().find(|(id, _)| *id == node_id).unwrap();
+        to_visit.extend(node.children());
+    }
+    assert_eq!(reachable.len(), tree.nodes.len());
+    assert_eq!(tree.nodes[0].1.role(), Role::Window);
+    assert_eq!(tree.focus, root_id);
+
+    let label = accessibility_node(&harness, &tree, "label");
+    assert_eq!((label.role(), label.label()), (Role::Label, Some("Name")));
+
+    let name = accessibility_node(&harness, &tree, "name");
+    assert_eq!((name.role(), name.value()), (Role::TextInput, Some("")));
+    assert!(name.supports_action(Action::Focus) && name.supports_action(Action::SetValue));
+
+    let subscribed = accessibility_node(&harness, &tree, "subscribed");
+    assert_eq!((subscribed.role(), subscribed.toggled()), (Role::Switch, Some(Toggled::False)));
+
+    let volume = accessibility_node(&harness, &tree, "volume");
+    assert_eq!(volume.role(), Role::Slider);
+    assert_eq!((volume.numeric_value(), volume.max_numeric_value()), (Some(0.0), Some(100.0)));
+
+    let submit = accessibility_node(&harness, &tree, "submit");
+    assert_eq!((submit.role(), submit.label()), (Role::Button, Some("Submit")));
+    assert!(submit.supports_action(Action::Click));
+    let bounds = harness.element_bounds("submit").unwrap();
+    let node_bounds = submit.bounds().unwrap();
+    assert_eq!((node_bounds.x0, node_bounds.y0), (bounds.x as f64, bounds.y as f64));
+    assert_eq!((node_bounds.width(), node_bounds.height()), (bounds.width as f64, bounds.height as f64));
+
+    let list = accessibility_node(&harness, &tree, "list");
+    assert_eq!((list.scroll_y(), list.scroll_y_max()), (Some(0.0), Some(150.0)));
+}
+
+#[test]
+fn accessibility_actions_are_dispatched_to_elements() {
+    let mut harness = TestHarness::new(Form::component(), (), Size::new(400.0, 600.0));
+
+    harness.accessibility_action(accessibility_request(&harness, "submit", Action::Click, None));
+    assert_eq!(state::<Form>(&harness).presses, 1);
+
+    harness.accessibility_action(accessibility_request(&harness, "subscribed", Action::Click, None));
+    assert!(state::<Form>(&harness).subscribed);
+    // Clicking a focusable element focuses it.
+    let tree = harness.accessibility_tree();
+    assert_eq!(accessibility_node(&harness, &tree, "subscribed").toggled(), Some(Toggled::True));
+    assert_eq!(tree.focus, NodeId(harness.find_element_by_id("subscribed").unwrap().component_id()));
+
+    harness.accessibility_action(accessibility_request(
+        &harness,
+        "volume",
+        Action::SetValue,
+        Some(ActionData::NumericValue(40.0)),
+    ));
+    harness.accessibility_action(accessibility_request(&harness, "volume", Action::Increment, None));
+    assert_eq!(state::<Form>(&harness).volume, 41.0);
+
+    harness.accessibility_action(accessibility_request(&harness, "name", Action::Focus, None));
+    harness.accessibility_action(accessibility_request(
+        &harness,
+        "name",
+        Action::SetValue,
+        Some(ActionData::Value("Ada".into())),
+    ));
+    assert_eq!(state::<Form>(&harness).name, "Ada");
+    let tree = harness.accessibility_tree();
+    assert_eq!(accessibility_node(&harness, &tree, "name").value(), Some("Ada"));
+    assert_eq!(tree.focus, NodeId(harness.find_element_by_id("name").unwrap().component_id()));
+
+    harness.accessibility_action(accessibility_request(&harness, "list", Action::ScrollDown, None));
+    let tree = harness.accessibility_tree();
+    assert_eq!(accessibility_node(&harness, &tree, "list").scroll_y(), Some(50.0));
+}
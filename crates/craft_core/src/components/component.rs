@@ -1,11 +1,11 @@
 use crate::components::props::Props;
 use crate::elements::element::ElementBoxed;
-use crate::events::{CraftMessage, KeyboardInput, Message, MouseWheel, PointerButton, PointerMoved};
+use crate::events::{Click, CraftMessage, Drag, FileDrag, KeyboardInput, Message, MouseWheel, PointerButton, PointerMoved};
 use crate::reactive::state_store::StateStoreItem;
-use crate::{GlobalState, WindowContext};
+use crate::{GlobalState, WindowContext, WindowId};
 
-use crate::components::update_result::Event;
-use crate::elements::{Container};
+use crate::components::update_result::{Event, EventPhase};
+use crate::elements::{Container, SortDirection};
 use std::any::{Any, TypeId};
 use std::ops::Deref;
 use winit::event::{Ime, Modifiers};
@@ -164,6 +164,20 @@ where
         let casted_state: &mut Self = state.downcast_mut::<Self>().unwrap();
         let props: &Self::Props = props.data.deref().downcast_ref().unwrap();
 
+        // In the capture phase, only the capture handler of the element runs. The event does not reach `update`, so
+        // components that override it only see the events that bubble.
+        if event.phase == EventPhase::Capturing {
+            let Message::CraftMessage(craft_message) = message else {
+                return;
+            };
+            if let Some(global_state_casted) = global_state.downcast_mut::<Self::GlobalState>() {
+                Self::on_capture(casted_state, global_state_casted, props, event, craft_message)
+            } else {
+                Self::on_capture(casted_state, &mut Self::GlobalState::default(), props, event, craft_message)
+            }
+            return;
+        }
+
         if let Some(global_state_casted) = global_state.downcast_mut::<Self::GlobalState>() {
             Self::update(casted_state, global_state_casted, props, event, message)
         } else {
@@ -192,6 +206,39 @@ where
                 CraftMessage::PointerMovedEvent(pointer_moved) => {
                     self.on_pointer_move(global_state, props, event, pointer_moved);
                 }
+                CraftMessage::PointerEnter(pointer_moved) => {
+                    self.on_pointer_enter(global_state, props, event, pointer_moved);
+                }
+                CraftMessage::PointerLeave(pointer_moved) => {
+                    self.on_pointer_leave(global_state, props, event, pointer_moved);
+                }
+                CraftMessage::Click(click) => {
+                    self.on_click(global_state, props, event, *click);
+                }
+                CraftMessage::DragStart(drag) => {
+                    self.on_drag_start(global_state, props, event, drag);
+                }
+                CraftMessage::DragOver(drag) => {
+                    self.on_drag_over(global_state, props, event, drag);
+                }
+                CraftMessage::DragLeave(drag) => {
+                    self.on_drag_leave(global_state, props, event, drag);
+                }
+                CraftMessage::Drop(drag) => {
+                    self.on_drop(global_state, props, event, drag);
+                }
+                CraftMessage::DragEnd(drag) => {
+                    self.on_drag_end(global_state, props, event, drag);
+                }
+                CraftMessage::FileHovered(file_drag) => {
+                    self.on_file_hovered(global_state, props, event, file_drag);
+                }
+                CraftMessage::FileHoverCancelled(file_drag) => {
+                    self.on_file_hover_cancelled(global_state, props, event, file_drag);
+                }
+                CraftMessage::FileDropped(file_drag) => {
+                    self.on_file_dropped(global_state, props, event, file_drag);
+                }
                 CraftMessage::MouseWheelEvent(mouse_wheel) => {
                     self.on_mouse_wheel(global_state, props, event, mouse_wheel);
                 }
@@ -204,6 +251,9 @@ where
                 CraftMessage::TextInputChanged(new_string) => {
                     self.on_text_input_changed(global_state, props, event, new_string);
                 }
+                CraftMessage::TextInputSubmitted(text) => {
+                    self.on_text_input_submitted(global_state, props, event, text);
+                }
                 CraftMessage::DropdownToggled(dropdown_toggled) => {
                     self.on_dropdown_toggled(global_state, props, event, *dropdown_toggled);
                 }
@@ -213,9 +263,46 @@ where
                 CraftMessage::SwitchToggled(switch_state) => {
                     self.on_switch_toggled(global_state, props, event, *switch_state);
                 }
+                CraftMessage::CheckboxToggled(checked) => {
+                    self.on_checkbox_toggled(global_state, props, event, *checked);
+                }
+                CraftMessage::RadioSelected(value) => {
+                    self.on_radio_selected(global_state, props, event, value);
+                }
                 CraftMessage::SliderValueChanged(slider_value) => {
                     self.on_slider_value_changed(global_state, props, event, *slider_value);
                 }
+                CraftMessage::TableSorted(column, direction) => {
+                    self.on_table_sorted(global_state, props, event, *column, *direction);
+                }
+                CraftMessage::TableColumnResized(column, width) => {
+                    self.on_table_column_resized(global_state, props, event, *column, *width);
+                }
+                CraftMessage::TableSelectionChanged(row, column) => {
+                    self.on_table_selection_changed(global_state, props, event, *row, *column);
+                }
+                CraftMessage::MenuItemSelected(id) => {
+                    self.on_menu_item_selected(global_state, props, event, id);
+                }
+                CraftMessage::TabChanged(index) => {
+                    self.on_tab_changed(global_state, props, event, *index);
+                }
+                CraftMessage::TabClosed(index) => {
+                    self.on_tab_closed(global_state, props, event, *index);
+                }
+                CraftMessage::DialogClosed => {
+                    self.on_dialog_closed(global_state, props, event);
+                }
+                CraftMessage::Focus => {
+                    self.on_focus(global_state, props, event);
+                }
+                CraftMessage::Blur => {
+                    self.on_blur(global_state, props, event);
+                }
+                CraftMessage::WindowClosed(window) => {
+                    self.on_window_closed(global_state, props, event, *window);
+                }
+                CraftMessage::AccessibilityAction(_) => {}
                 CraftMessage::ElementMessage(_) => {}
             },
             crate::events::Message::UserMessage(user_message) => {
@@ -259,6 +346,90 @@ where
         }
     }
     
+    fn on_pointer_enter(&mut self, global_state: &mut Self::GlobalState, _props: &Self::Props, event: &mut Event, pointer_moved: &PointerMoved) {
+        let on_pointer_enter = event.current_target.and_then(|element| element.element_data().on_pointer_enter.as_ref());
+        if let Some(on_pointer_enter) = on_pointer_enter {
+            on_pointer_enter(self, global_state, event, pointer_moved);
+        }
+    }
+
+    fn on_pointer_leave(&mut self, global_state: &mut Self::GlobalState, _props: &Self::Props, event: &mut Event, pointer_moved: &PointerMoved) {
+        let on_pointer_leave = event.current_target.and_then(|element| element.element_data().on_pointer_leave.as_ref());
+        if let Some(on_pointer_leave) = on_pointer_leave {
+            on_pointer_leave(self, global_state, event, pointer_moved);
+        }
+    }
+
+    fn on_click(&mut self, global_state: &mut Self::GlobalState, _props: &Self::Props, event: &mut Event, click: Click) {
+        let on_click = event.current_target.and_then(|element| element.element_data().on_click.as_ref());
+        if let Some(on_click) = on_click {
+            on_click(self, global_state, event, click);
+        }
+    }
+
+    fn on_drag_start(&mut self, global_state: &mut Self::GlobalState, _props: &Self::Props, event: &mut Event, drag: &Drag) {
+        let on_drag_start = event.current_target.and_then(|element| element.element_data().on_drag_start.as_ref());
+        if let Some(on_drag_start) = on_drag_start {
+            on_drag_start(self, global_state, event, drag);
+        }
+    }
+
+    fn on_drag_over(&mut self, global_state: &mut Self::GlobalState, _props: &Self::Props, event: &mut Event, drag: &Drag) {
+        let on_drag_over = event.current_target.and_then(|element| element.element_data().on_drag_over.as_ref());
+        if let Some(on_drag_over) = on_drag_over {
+            on_drag_over(self, global_state, event, drag);
+        }
+    }
+
+    fn on_drag_leave(&mut self, global_state: &mut Self::GlobalState, _props: &Self::Props, event: &mut Event, drag: &Drag) {
+        let on_drag_leave = event.current_target.and_then(|element| element.element_data().on_drag_leave.as_ref());
+        if let Some(on_drag_leave) = on_drag_leave {
+            on_drag_leave(self, global_state, event, drag);
+        }
+    }
+
+    fn on_drop(&mut self, global_state: &mut Self::GlobalState, _props: &Self::Props, event: &mut Event, drag: &Drag) {
+        let on_drop = event.current_target.and_then(|element| element.element_data().on_drop.as_ref());
+        if let Some(on_drop) = on_drop {
+            on_drop(self, global_state, event, drag);
+        }
+    }
+
+    fn on_drag_end(&mut self, global_state: &mut Self::GlobalState, _props: &Self::Props, event: &mut Event, drag: &Drag) {
+        let on_drag_end = event.current_target.and_then(|element| element.element_data().on_drag_end.as_ref());
+        if let Some(on_drag_end) = on_drag_end {
+            on_drag_end(self, global_state, event, drag);
+        }
+    }
+
+    fn on_file_hovered(&mut self, global_state: &mut Self::GlobalState, _props: &Self::Props, event: &mut Event, file_drag: &FileDrag) {
+        let on_file_hovered = event.current_target.and_then(|element| element.element_data().on_file_hovered.as_ref());
+        if let Some(on_file_hovered) = on_file_hovered {
+            on_file_hovered(self, global_state, event, file_drag);
+        }
+    }
+
+    fn on_file_hover_cancelled(&mut self, global_state: &mut Self::GlobalState, _props: &Self::Props, event: &mut Event, file_drag: &FileDrag) {
+        let on_file_hover_cancelled = event.current_target.and_then(|element| element.element_data().on_file_hover_cancelled.as_ref());
+        if let Some(on_file_hover_cancelled) = on_file_hover_cancelled {
+            on_file_hover_cancelled(self, global_state, event, file_drag);
+        }
+    }
+
+    fn on_file_dropped(&mut self, global_state: &mut Self::GlobalState, _props: &Self::Props, event: &mut Event, file_drag: &FileDrag) {
+        let on_file_dropped = event.current_target.and_then(|element| element.element_data().on_file_dropped.as_ref());
+        if let Some(on_file_dropped) = on_file_dropped {
+            on_file_dropped(self, global_state, event, file_drag);
+        }
+    }
+
+    fn on_capture(&mut self, global_state: &mut Self::GlobalState, _props: &Self::Props, event: &mut Event, message: &CraftMessage) {
+        let on_capture = event.current_target.and_then(|element| element.element_data().on_capture.as_ref());
+        if let Some(on_capture) = on_capture {
+            on_capture(self, global_state, event, message);
+        }
+    }
+
     fn on_user_message(&mut self, _global_state: &mut Self::GlobalState, _props: &Self::Props, _event: &mut Event, _user_message: &Self::Message) {}
 
     fn on_mouse_wheel(&mut self, global_state: &mut Self::GlobalState, _props: &Self::Props, event: &mut Event, mouse_wheel: &MouseWheel) {
@@ -293,6 +464,13 @@ where
         }
     }
 
+    fn on_text_input_submitted(&mut self, global_state: &mut Self::GlobalState, _props: &Self::Props, event: &mut Event, text: &str) {
+        let on_text_input_submitted = event.current_target.and_then(|element| element.element_data().on_text_input_submitted.as_ref());
+        if let Some(on_text_input_submitted) = on_text_input_submitted {
+            on_text_input_submitted(self, global_state, event, text);
+        }
+    }
+
     fn on_dropdown_toggled(&mut self, global_state: &mut Self::GlobalState, _props: &Self::Props, event: &mut Event, dropdown_toggled: bool) {
         if let Some(element) = event.current_target {
             if let Some(on_dropdown_toggled) = &element.element_data().on_dropdown_toggled {
@@ -317,6 +495,20 @@ where
         }
     }
 
+    fn on_checkbox_toggled(&mut self, global_state: &mut Self::GlobalState, _props: &Self::Props, event: &mut Event, checked: bool) {
+        let on_checkbox_toggled = event.current_target.and_then(|element| element.element_data().on_checkbox_toggled.as_ref());
+        if let Some(on_checkbox_toggled) = on_checkbox_toggled {
+            on_checkbox_toggled(self, global_state, event, checked);
+        }
+    }
+
+    fn on_radio_selected(&mut self, global_state: &mut Self::GlobalState, _props: &Self::Props, event: &mut Event, value: &str) {
+        let on_radio_selected = event.current_target.and_then(|element| element.element_data().on_radio_selected.as_ref());
+        if let Some(on_radio_selected) = on_radio_selected {
+            on_radio_selected(self, global_state, event, value);
+        }
+    }
+
     fn on_slider_value_changed(&mut self, global_state: &mut Self::GlobalState, _props: &Self::Props, event: &mut Event, slider_value: f64) {
         if let Some(element) = event.current_target {
             if let Some(on_slider_value_changed) = &element.element_data().on_slider_value_changed {
@@ -325,6 +517,70 @@ where
         }
     }
 
+    fn on_table_sorted(&mut self, global_state: &mut Self::GlobalState, _props: &Self::Props, event: &mut Event, column: usize, direction: SortDirection) {
+        let on_table_sorted = event.current_target.and_then(|element| element.element_data().on_table_sorted.as_ref());
+        if let Some(on_table_sorted) = on_table_sorted {
+            on_table_sorted(self, global_state, event, (column, direction));
+        }
+    }
+
+    fn on_table_column_resized(&mut self, global_state: &mut Self::GlobalState, _props: &Self::Props, event: &mut Event, column: usize, width: f32) {
+        let on_table_column_resized = event.current_target.and_then(|element| element.element_data().on_table_column_resized.as_ref());
+        if let Some(on_table_column_resized) = on_table_column_resized {
+            on_table_column_resized(self, global_state, event, (column, width));
+        }
+    }
+
+    fn on_table_selection_changed(&mut self, global_state: &mut Self::GlobalState, _props: &Self::Props, event: &mut Event, row: usize, column: Option<usize>) {
+        let on_table_selection_changed = event.current_target.and_then(|element| element.element_data().on_table_selection_changed.as_ref());
+        if let Some(on_table_selection_changed) = on_table_selection_changed {
+            on_table_selection_changed(self, global_state, event, (row, column));
+        }
+    }
+
+    fn on_menu_item_selected(&mut self, global_state: &mut Self::GlobalState, _props: &Self::Props, event: &mut Event, id: &str) {
+        let on_menu_item_selected = event.current_target.and_then(|element| element.element_data().on_menu_item_selected.as_ref());
+        if let Some(on_menu_item_selected) = on_menu_item_selected {
+            on_menu_item_selected(self, global_state, event, id);
+        }
+    }
+
+    fn on_tab_changed(&mut self, global_state: &mut Self::GlobalState, _props: &Self::Props, event: &mut Event, index: usize) {
+        let on_tab_changed = event.current_target.and_then(|element| element.element_data().on_tab_changed.as_ref());
+        if let Some(on_tab_changed) = on_tab_changed {
+            on_tab_changed(self, global_state, event, index);
+        }
+    }
+
+    fn on_tab_closed(&mut self, global_state: &mut Self::GlobalState, _props: &Self::Props, event: &mut Event, index: usize) {
+        let on_tab_closed = event.current_target.and_then(|element| element.element_data().on_tab_closed.as_ref());
+        if let Some(on_tab_closed) = on_tab_closed {
+            on_tab_closed(self, global_state, event, index);
+        }
+    }
+
+    fn on_dialog_closed(&mut self, global_state: &mut Self::GlobalState, _props: &Self::Props, event: &mut Event) {
+        let on_dialog_closed = event.current_target.and_then(|element| element.element_data().on_dialog_closed.as_ref());
+        if let Some(on_dialog_closed) = on_dialog_closed {
+            on_dialog_closed(self, global_state, event);
+        }
+    }
+
+    fn on_focus(&mut self, global_state: &mut Self::GlobalState, _props: &Self::Props, event: &mut Event) {
+        if let Some(on_focus) = event.current_target.and_then(|element| element.element_data().on_focus.as_ref()) {
+            on_focus(self, global_state, event);
+        }
+    }
+
+    fn on_blur(&mut self, global_state: &mut Self::GlobalState, _props: &Self::Props, event: &mut Event) {
+        if let Some(on_blur) = event.current_target.and_then(|element| element.element_data().on_blur.as_ref()) {
+            on_blur(self, global_state, event);
+        }
+    }
+
+    /// Called when the user closes a window that this component opened.
+    fn on_window_closed(&mut self, _global_state: &mut Self::GlobalState, _props: &Self::Props, _event: &mut Event, _window: WindowId) {}
+
     fn default_state() -> Box<StateStoreItem> {
         Box::<Self>::default()
     }
@@ -9,5 +9,7 @@ pub use component::ComponentSpecification;
 pub use props::Props;
 pub use update_result::PointerCapture;
 pub use update_result::Event;
+pub use update_result::EventPhase;
 pub use update_result::ImeAction;
+pub(crate) use update_result::FocusRequest;
 pub use crate::events::UserMessage;
\ No newline at end of file
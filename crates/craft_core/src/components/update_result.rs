@@ -1,3 +1,4 @@
+use crate::components::ComponentId;
 use crate::events::{CraftMessage, EventDispatchType, Message};
 use crate::{PinnedFutureAny, WindowContext};
 use std::any::Any;
@@ -12,24 +13,42 @@ pub enum PointerCapture {
     Unset,
 }
 
+/// The phase of the dispatch that an event is in.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum EventPhase {
+    /// The event goes from the root to the target, to the capture handlers of the target and its ancestors.
+    Capturing,
+    /// The event goes from the target to the root.
+    #[default]
+    Bubbling,
+}
+
 /// The result of an update.
 pub struct Event<'a> {
     /// Propagate craft_events to the next element. True by default.
+    ///
+    /// When it is set to false in the capture phase, the event does not reach the target and does not bubble either.
     pub propagate: bool,
     /// A future that will produce a message when complete. The message will be sent to the origin component.
     pub future: Option<PinnedFutureAny>,
     /// Prevent default event handlers from running when an craft_event is not explicitly handled.
     /// False by default.
     pub prevent_defaults: bool,
+    /// Stops the event in both phases and keeps the elements from handling it, see
+    /// [`Event::stop_immediate_propagation`]. False by default.
+    pub stop_immediate: bool,
     pub(crate) result_message: Option<CraftMessage>,
     /// Redirect future pointer events to this component. None by default.
     pub(crate) pointer_capture: PointerCapture,
     pub(crate) effects: Vec<(EventDispatchType, Message)>,
     pub(crate) ime: ImeAction,
+    pub(crate) focus: Option<FocusRequest>,
 
     pub target: Option<&'a dyn Element>,
     pub window: WindowContext,
-    pub current_target: Option<&'a dyn Element>
+    pub current_target: Option<&'a dyn Element>,
+    /// Whether the event is captured on the way to the target, or bubbles up from it.
+    pub phase: EventPhase,
 }
 
 #[derive(Debug, Clone, Copy, Default)]
@@ -40,6 +59,15 @@ pub enum ImeAction {
     Unset,
 }
 
+/// A focus change that is applied once the event has been handled.
+#[derive(Debug, Clone)]
+pub(crate) enum FocusRequest {
+    /// Focus the first element with the given user-defined id.
+    Id(String),
+    /// Focus the element with the given component id, or remove the focus if there is none.
+    Element(Option<ComponentId>),
+}
+
 impl<'a> Event<'a> {
     pub fn with_window_context(window: WindowContext) -> Self {
         Event {
@@ -79,13 +107,16 @@ impl<'a> Default for Event<'a> {
             propagate: true,
             future: None,
             prevent_defaults: false,
+            stop_immediate: false,
             result_message: None,
             pointer_capture: Default::default(),
             effects: Vec::new(),
             ime: ImeAction::None,
+            focus: None,
             target: None,
             current_target: None,
             window: WindowContext::new(),
+            phase: EventPhase::Bubbling,
         }
     }
 }
@@ -117,6 +148,13 @@ impl<'a> Event<'a> {
         self.propagate = false;
     }
 
+    /// Stops the event in both the capture and the bubbling phase, and keeps the elements from handling it, e.g. so
+    /// that a shortcut that is captured by an ancestor is not typed into the focused text input.
+    pub fn stop_immediate_propagation(&mut self) {
+        self.propagate = false;
+        self.stop_immediate = true;
+    }
+
     pub fn result_message(&mut self, message: CraftMessage) {
         self.result_message = Some(message);
     }
@@ -128,4 +166,14 @@ impl<'a> Event<'a> {
     pub fn add_effect(&mut self, event_dispatch_type: EventDispatchType, message: Message) {
         self.effects.push((event_dispatch_type, message));
     }
+
+    /// Moves the keyboard focus to the first element with the given `id` once the event has been handled.
+    pub fn request_focus(&mut self, id: &str) {
+        self.focus = Some(FocusRequest::Id(id.to_string()));
+    }
+
+    /// Removes the keyboard focus from the focused element once the event has been handled.
+    pub fn blur(&mut self) {
+        self.focus = Some(FocusRequest::Element(None));
+    }
 }
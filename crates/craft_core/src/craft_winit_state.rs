@@ -13,20 +13,21 @@ use crate::renderer::vello_cpu::VelloCpuRenderer;
 #[cfg(feature = "vello_hybrid_renderer")]
 use crate::renderer::vello_hybrid::VelloHybridRenderer;
 
+use crate::accessibility::adapter::AccessibilityAdapter;
 use crate::app_message::AppMessage;
 use crate::events::internal::InternalMessage;
-use crate::events::{KeyboardInput, MouseWheel, PointerButton, PointerMoved};
+use crate::events::{FileDragEvent, KeyboardInput, MouseWheel, PointerButton, PointerMoved};
 use crate::geometry::Size;
 use crate::renderer::blank_renderer::BlankRenderer;
 use crate::renderer::renderer::Renderer;
-use crate::{CraftOptions, CraftRuntime, RendererType, WAIT_TIME};
+use crate::{CraftOptions, CraftRuntime, RendererType, WindowId, WindowOptions, WAIT_TIME};
 use craft_logging::info;
 
 use winit::application::ApplicationHandler;
 use winit::event::{StartCause, WindowEvent};
 use winit::event_loop::{ActiveEventLoop, ControlFlow};
 use winit::window::WindowAttributes;
-use winit::window::{Window, WindowId};
+use winit::window::{Window, WindowId as WinitWindowId};
 
 #[cfg(target_arch = "wasm32")]
 use web_time as time;
@@ -36,6 +37,7 @@ use std::time;
 use tokio::sync::mpsc::Receiver;
 use tokio::sync::mpsc::Sender;
 
+use std::collections::HashMap;
 use std::future::Future;
 use std::pin::Pin;
 use std::sync::Arc;
@@ -56,96 +58,52 @@ pub(crate) struct CraftWinitState {
     request_redraw: bool,
     wait_cancelled: bool,
     close_requested: bool,
-    window: Option<Arc<dyn Window>>,
+    windows: HashMap<WinitWindowId, WinitWindow>,
+    /// The windows that the app asked to open or close, which can only be done with the event loop.
+    pending_window_messages: Vec<InternalMessage>,
     #[allow(dead_code)]
     winit_receiver: Receiver<AppMessage>,
     app_sender: Sender<AppMessage>,
     craft_options: CraftOptions,
 }
 
+/// A window that winit created for the app.
+struct WinitWindow {
+    id: WindowId,
+    window: Arc<dyn Window>,
+    accessibility_adapter: AccessibilityAdapter,
+}
+
 impl ApplicationHandler for CraftWinitState {
     fn new_events(&mut self, _event_loop: &dyn ActiveEventLoop, cause: StartCause) {
         self.wait_cancelled = matches!(cause, StartCause::WaitCancelled { .. })
     }
 
     fn can_create_surfaces(&mut self, event_loop: &dyn ActiveEventLoop) {
-        let mut window_attributes = WindowAttributes::default().with_title(self.craft_options.window_title.as_str());
-        
-        if let Some(window_size) = &self.craft_options.window_size {
-            window_attributes = window_attributes.with_surface_size(LogicalSize::new(window_size.width, window_size.height));
-        }
-        
-        #[cfg(target_arch = "wasm32")]
-        let window_attributes = {
-            let canvas = web_sys::window()
-                .unwrap()
-                .document()
-                .unwrap()
-                .get_element_by_id("canvas")
-                .unwrap()
-                .dyn_into::<web_sys::HtmlCanvasElement>()
-                .unwrap();
+        let window_options = WindowOptions::from(&self.craft_options);
+        self.create_window(event_loop, WindowId::PRIMARY, &window_options);
+    }
 
-            window_attributes.with_canvas(Some(canvas))
+    fn window_event(&mut self, _event_loop: &dyn ActiveEventLoop, winit_window_id: WinitWindowId, event: WindowEvent) {
+        let Some(winit_window) = self.windows.get_mut(&winit_window_id) else {
+            return;
         };
+        winit_window.accessibility_adapter.process_event(winit_window.window.as_ref(), &event);
+        let window_id = winit_window.id;
+        let window = winit_window.window.clone();
 
-        let window: Arc<dyn Window> =
-            Arc::from(event_loop.create_window(window_attributes).expect("Failed to create window."));
-        info!("Created window");
-
-        window.set_ime_allowed(true);
-
-        self.window = Some(window.clone());
-        info!("Creating renderer");
-        info!("Using {} renderer.", self.craft_options.renderer);
-
-        let renderer_type = self.craft_options.renderer;
-        let window_copy = window.clone();
-
-        let renderer_future: Pin<Box<dyn Future<Output = Box<dyn Renderer + Send>>>> = Box::pin(async move {
-            let renderer: Box<dyn Renderer + Send> = match renderer_type {
-                #[cfg(feature = "vello_renderer")]
-                RendererType::Vello => Box::new(VelloRenderer::new(window_copy).await),
-                #[cfg(feature = "vello_cpu_renderer")]
-                RendererType::VelloCPU => Box::new(VelloCpuRenderer::new(window_copy)),
-                #[cfg(feature = "vello_hybrid_renderer")]
-                RendererType::VelloHybrid => Box::new(VelloHybridRenderer::new(window_copy).await),
-                RendererType::Blank => Box::new(BlankRenderer),
-            };
-
-            renderer
-        });
-
-        #[cfg(target_arch = "wasm32")]
-        {
-            let tx = self.app_sender.clone();
-            wasm_bindgen_futures::spawn_local(async move {
-                let renderer = renderer_future.await;
-
-                info!("Created renderer");
-                tx.send(AppMessage::new(0, InternalMessage::Resume(window.clone(), Some(renderer))))
-                    .await
-                    .expect("Sending app message failed");
-                window.request_redraw();
-            });
-        }
-        #[cfg(not(target_arch = "wasm32"))]
-        {
-            let renderer = self.runtime.borrow_tokio_runtime().block_on(renderer_future);
-            info!("Created renderer");
-            self.send_message(InternalMessage::Resume(window, Some(renderer)), true);
-        }
-    }
-
-    fn window_event(&mut self, _event_loop: &dyn ActiveEventLoop, _window_id: WindowId, event: WindowEvent) {
         match event {
             WindowEvent::ScaleFactorChanged { .. } => {}
-            WindowEvent::CloseRequested => {
+            WindowEvent::CloseRequested if window_id == WindowId::PRIMARY => {
                 self.send_message(InternalMessage::Close, true);
                 self.close_requested = true;
             }
+            WindowEvent::CloseRequested => {
+                self.windows.remove(&winit_window_id);
+                self.send_message(InternalMessage::CloseWindow(window_id), false);
+            }
             WindowEvent::ModifiersChanged(modifiers) => {
-                self.send_message(InternalMessage::ModifiersChanged(modifiers), true);
+                self.send_message(InternalMessage::ModifiersChanged(window_id, modifiers), true);
             }
             WindowEvent::PointerButton {
                 device_id,
@@ -155,7 +113,7 @@ impl ApplicationHandler for CraftWinitState {
                 primary,
             } => {
                 let event = PointerButton::new(device_id, state, position, button, primary);
-                self.send_message(InternalMessage::PointerButton(event), false);
+                self.send_message(InternalMessage::PointerButton(window_id, event), false);
             }
             WindowEvent::PointerMoved {
                 device_id,
@@ -164,7 +122,7 @@ impl ApplicationHandler for CraftWinitState {
                 primary,
             } => {
                 self.send_message(
-                    InternalMessage::PointerMoved(PointerMoved::new(device_id, position, source, primary)),
+                    InternalMessage::PointerMoved(window_id, PointerMoved::new(device_id, position, source, primary)),
                     true,
                 );
             }
@@ -174,10 +132,10 @@ impl ApplicationHandler for CraftWinitState {
                 phase,
             } => {
                 let event = MouseWheel::new(device_id, delta, phase);
-                self.send_message(InternalMessage::MouseWheel(event), true);
+                self.send_message(InternalMessage::MouseWheel(window_id, event), true);
             }
             WindowEvent::SurfaceResized(new_size) => {
-                self.send_message(InternalMessage::Resize(new_size), true);
+                self.send_message(InternalMessage::Resize(window_id, new_size), true);
             }
             WindowEvent::KeyboardInput {
                 device_id,
@@ -185,21 +143,34 @@ impl ApplicationHandler for CraftWinitState {
                 is_synthetic,
             } => {
                 self.send_message(
-                    InternalMessage::KeyboardInput(KeyboardInput::new(device_id, event, is_synthetic)),
+                    InternalMessage::KeyboardInput(window_id, KeyboardInput::new(device_id, event, is_synthetic)),
                     true,
                 );
             }
             WindowEvent::Ime(ime) => {
-                self.send_message(InternalMessage::Ime(ime), true);
+                self.send_message(InternalMessage::Ime(window_id, ime), true);
+            }
+            WindowEvent::DragEntered { paths, position } => {
+                let event = FileDragEvent::Entered(paths, position.into());
+                self.send_message(InternalMessage::FileDrag(window_id, event), true);
+            }
+            WindowEvent::DragMoved { position } => {
+                self.send_message(InternalMessage::FileDrag(window_id, FileDragEvent::Moved(position.into())), true);
+            }
+            WindowEvent::DragDropped { paths, position } => {
+                let event = FileDragEvent::Dropped(paths, position.into());
+                self.send_message(InternalMessage::FileDrag(window_id, event), true);
+            }
+            WindowEvent::DragLeft { .. } => {
+                self.send_message(InternalMessage::FileDrag(window_id, FileDragEvent::Left), true);
             }
             WindowEvent::RedrawRequested => {
                 // We want to do any window operations within the main thread.
                 // On some operating systems, the window is not thread-safe.
-                let window = self.window.clone().unwrap();
                 let scale_factor = window.scale_factor();
                 let surface_size = Size::new(window.surface_size().width as f32, window.surface_size().height as f32);
 
-                self.send_message(InternalMessage::RequestRedraw(scale_factor, surface_size), true);
+                self.send_message(InternalMessage::RequestRedraw(window_id, scale_factor, surface_size), true);
                 window.pre_present_notify();
             }
             _ => (),
@@ -214,6 +185,12 @@ impl ApplicationHandler for CraftWinitState {
             //self.window.as_ref().unwrap().request_redraw();
         }
 
+        // Messages may be sent to the winit thread while it is not waiting on the app.
+        while let Ok(message) = self.winit_receiver.try_recv() {
+            self.handle_app_message(message.data);
+        }
+        self.create_and_close_windows(event_loop);
+
         if self.close_requested {
             info!("Exiting winit event loop");
 
@@ -249,13 +226,104 @@ impl CraftWinitState {
             request_redraw: false,
             wait_cancelled: false,
             close_requested: false,
-            window: None,
+            windows: HashMap::new(),
+            pending_window_messages: Vec::new(),
             winit_receiver,
             app_sender,
             craft_options,
         }
     }
 
+    /// Creates the window and the renderer of `window_id`, and hands them to the app.
+    fn create_window(&mut self, event_loop: &dyn ActiveEventLoop, window_id: WindowId, window_options: &WindowOptions) {
+        // The surfaces may be created again, e.g. when an Android app is resumed.
+        self.windows.retain(|_, window| window.id != window_id);
+
+        // The window stays hidden until the accessibility adapter is created.
+        let mut window_attributes = WindowAttributes::default()
+            .with_title(window_options.title.as_str())
+            .with_visible(false);
+        
+        if let Some(window_size) = &window_options.size {
+            window_attributes = window_attributes.with_surface_size(LogicalSize::new(window_size.width, window_size.height));
+        }
+        
+        // Only the primary window is drawn to the canvas of the page.
+        #[cfg(target_arch = "wasm32")]
+        let window_attributes = if window_id != WindowId::PRIMARY {
+            window_attributes
+        } else {
+            let canvas = web_sys::window()
+                .unwrap()
+                .document()
+                .unwrap()
+                .get_element_by_id("canvas")
+                .unwrap()
+                .dyn_into::<web_sys::HtmlCanvasElement>()
+                .unwrap();
+
+            window_attributes.with_canvas(Some(canvas))
+        };
+
+        let window: Arc<dyn Window> =
+            Arc::from(event_loop.create_window(window_attributes).expect("Failed to create window."));
+        info!("Created window");
+
+        window.set_ime_allowed(true);
+
+        let accessibility_adapter =
+            AccessibilityAdapter::new(window_id, &window, window_options.title.as_str(), self.app_sender.clone());
+        window.set_visible(true);
+
+        self.windows.insert(
+            window.id(),
+            WinitWindow {
+                id: window_id,
+                window: window.clone(),
+                accessibility_adapter,
+            },
+        );
+        info!("Creating renderer");
+        info!("Using {} renderer.", self.craft_options.renderer);
+
+        let renderer_type = self.craft_options.renderer;
+        let window_copy = window.clone();
+
+        let renderer_future: Pin<Box<dyn Future<Output = Box<dyn Renderer + Send>>>> = Box::pin(async move {
+            let renderer: Box<dyn Renderer + Send> = match renderer_type {
+                #[cfg(feature = "vello_renderer")]
+                RendererType::Vello => Box::new(VelloRenderer::new(window_copy).await),
+                #[cfg(feature = "vello_cpu_renderer")]
+                RendererType::VelloCPU => Box::new(VelloCpuRenderer::new(window_copy)),
+                #[cfg(feature = "vello_hybrid_renderer")]
+                RendererType::VelloHybrid => Box::new(VelloHybridRenderer::new(window_copy).await),
+                RendererType::Blank => Box::new(BlankRenderer),
+            };
+
+            renderer
+        });
+
+        #[cfg(target_arch = "wasm32")]
+        {
+            let tx = self.app_sender.clone();
+            wasm_bindgen_futures::spawn_local(async move {
+                let renderer = renderer_future.await;
+
+                info!("Created renderer");
+                tx.send(AppMessage::new(0, InternalMessage::Resume(window_id, window.clone(), Some(renderer))))
+                    .await
+                    .expect("Sending app message failed");
+                window.request_redraw();
+            });
+        }
+        #[cfg(not(target_arch = "wasm32"))]
+        {
+            let renderer = self.runtime.borrow_tokio_runtime().block_on(renderer_future);
+            info!("Created renderer");
+            self.send_message(InternalMessage::Resume(window_id, window, Some(renderer)), true);
+        }
+    }
+
     fn send_message(&mut self, message: InternalMessage, blocking: bool) {
         #[cfg(not(target_arch = "wasm32"))]
         let is_close_message = matches!(message, InternalMessage::Close);
@@ -267,6 +335,7 @@ impl CraftWinitState {
         };
         #[cfg(not(target_arch = "wasm32"))]
         {
+            let mut app_messages = Vec::new();
             self.runtime.borrow_tokio_runtime().block_on(async {
                 let result: Result<(), SendError<AppMessage>> = self.app_sender.send(app_message).await;
                 if !is_close_message {
@@ -274,16 +343,26 @@ impl CraftWinitState {
                 }
 
                 if blocking {
-                    if let Some(response) = self.winit_receiver.recv().await {
-                        if let InternalMessage::Confirmation = response.data {
-                            assert_eq!(response.id, self.id, "Expected response message with id {}", self.id);
-                        } else {
-                            panic!("Expected response message, but response was something else");
+                    while let Some(response) = self.winit_receiver.recv().await {
+                        match response.data {
+                            InternalMessage::Confirmation => {
+                                assert_eq!(response.id, self.id, "Expected response message with id {}", self.id);
+                                break;
+                            }
+                            // Sent while redrawing, before the redraw is confirmed.
+                            message @ (InternalMessage::AccessibilityTreeUpdate(..)
+                            | InternalMessage::CreateWindow(..)
+                            | InternalMessage::CloseWindow(..)) => app_messages.push(message),
+                            _ => panic!("Expected response message, but response was something else"),
                         }
                     }
                 }
             });
             self.id += 1;
+
+            for message in app_messages {
+                self.handle_app_message(message);
+            }
         }
         #[cfg(target_arch = "wasm32")]
         {
@@ -295,6 +374,43 @@ impl CraftWinitState {
         }
     }
 
+    /// Handles a message that the app sent to the winit thread.
+    ///
+    /// Windows can only be created with the event loop, so their messages are queued for
+    /// [`Self::create_and_close_windows`].
+    fn handle_app_message(&mut self, message: InternalMessage) {
+        match message {
+            InternalMessage::AccessibilityTreeUpdate(window_id, tree_update) => {
+                if let Some(window) = self.windows.values_mut().find(|window| window.id == window_id) {
+                    window.accessibility_adapter.update_if_active(tree_update);
+                }
+            }
+            InternalMessage::CreateWindow(..) | InternalMessage::CloseWindow(..) => {
+                self.pending_window_messages.push(message);
+            }
+            _ => {}
+        }
+    }
+
+    fn create_and_close_windows(&mut self, event_loop: &dyn ActiveEventLoop) {
+        while !self.pending_window_messages.is_empty() {
+            match self.pending_window_messages.remove(0) {
+                InternalMessage::CreateWindow(window_id, window_options) => {
+                    self.create_window(event_loop, window_id, &window_options);
+                }
+                // Closing the primary window closes the application.
+                InternalMessage::CloseWindow(WindowId::PRIMARY) => {
+                    self.send_message(InternalMessage::Close, true);
+                    self.close_requested = true;
+                }
+                InternalMessage::CloseWindow(window_id) => {
+                    self.windows.retain(|_, window| window.id != window_id);
+                }
+                _ => {}
+            }
+        }
+    }
+
     fn get_id(&self) -> u64 {
         #[cfg(target_arch = "wasm32")]
         {
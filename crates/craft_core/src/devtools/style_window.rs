@@ -143,13 +143,13 @@ pub(crate) fn styles_window_view(selected_element: Option<&dyn Element>) -> Comp
             styles_window = styles_window.push(field_row(
                 "Row Gap: ",
                 FIELD_NAME_COLOR,
-                style.gap()[0].to_string().as_str(),
+                style.gap()[1].to_string().as_str(),
                 FIELD_VALUE_COLOR,
             ));
             styles_window = styles_window.push(field_row(
                 "Column Gap: ",
                 FIELD_NAME_COLOR,
-                style.gap()[1].to_string().as_str(),
+                style.gap()[0].to_string().as_str(),
                 FIELD_VALUE_COLOR,
             ));
         }
@@ -342,6 +342,78 @@ pub(crate) fn styles_window_view(selected_element: Option<&dyn Element>) -> Comp
             ));
         }
 
+        // Grid Template Rows
+        if style.dirty_flags.contains(StyleFlags::GRID_TEMPLATE_ROWS) {
+            styles_window = styles_window.push(field_row(
+                "Grid Template Rows: ",
+                FIELD_NAME_COLOR,
+                style.grid_template_rows().to_string().as_str(),
+                FIELD_VALUE_COLOR,
+            ));
+        }
+
+        // Grid Template Columns
+        if style.dirty_flags.contains(StyleFlags::GRID_TEMPLATE_COLUMNS) {
+            styles_window = styles_window.push(field_row(
+                "Grid Template Columns: ",
+                FIELD_NAME_COLOR,
+                style.grid_template_columns().to_string().as_str(),
+                FIELD_VALUE_COLOR,
+            ));
+        }
+
+        // Grid Template Areas
+        if style.dirty_flags.contains(StyleFlags::GRID_TEMPLATE_AREAS) {
+            let template_areas = style.grid_template_areas();
+            let areas: Vec<&str> = template_areas.areas().iter().map(|area| area.name.as_str()).collect();
+            styles_window = styles_window.push(field_row(
+                "Grid Template Areas: ",
+                FIELD_NAME_COLOR,
+                areas.join(", ").as_str(),
+                FIELD_VALUE_COLOR,
+            ));
+        }
+
+        // Grid Auto Flow
+        if style.dirty_flags.contains(StyleFlags::GRID_AUTO_FLOW) {
+            styles_window = styles_window.push(field_row(
+                "Grid Auto Flow: ",
+                FIELD_NAME_COLOR,
+                format!("{:?}", style.grid_auto_flow()).as_str(),
+                FIELD_VALUE_COLOR,
+            ));
+        }
+
+        // Grid Row
+        if style.dirty_flags.contains(StyleFlags::GRID_ROW) {
+            styles_window = styles_window.push(field_row(
+                "Grid Row: ",
+                FIELD_NAME_COLOR,
+                format!("{:?}", style.grid_row()).as_str(),
+                FIELD_VALUE_COLOR,
+            ));
+        }
+
+        // Grid Column
+        if style.dirty_flags.contains(StyleFlags::GRID_COLUMN) {
+            styles_window = styles_window.push(field_row(
+                "Grid Column: ",
+                FIELD_NAME_COLOR,
+                format!("{:?}", style.grid_column()).as_str(),
+                FIELD_VALUE_COLOR,
+            ));
+        }
+
+        // Grid Area
+        if style.dirty_flags.contains(StyleFlags::GRID_AREA) && style.grid_area().is_some() {
+            styles_window = styles_window.push(field_row(
+                "Grid Area: ",
+                FIELD_NAME_COLOR,
+                style.grid_area().unwrap(),
+                FIELD_VALUE_COLOR,
+            ));
+        }
+
         // Color
         if style.dirty_flags.contains(StyleFlags::COLOR) {
             styles_window = styles_window.push(field_row(
@@ -362,6 +434,16 @@ pub(crate) fn styles_window_view(selected_element: Option<&dyn Element>) -> Comp
             ))
         }
 
+        // Background Gradient
+        if style.dirty_flags.contains(StyleFlags::BACKGROUND_GRADIENT) {
+            styles_window = styles_window.push(field_row(
+                "Background Gradient: ",
+                FIELD_NAME_COLOR,
+                format_option(style.background_gradient()).as_str(),
+                FIELD_VALUE_COLOR,
+            ))
+        }
+
         // Font Size
         if style.dirty_flags.contains(StyleFlags::FONT_SIZE) {
             styles_window = styles_window.push(field_row(
@@ -431,6 +513,46 @@ pub(crate) fn styles_window_view(selected_element: Option<&dyn Element>) -> Comp
                 FIELD_VALUE_COLOR,
             ));
         }
+
+        // Box Shadow
+        if style.dirty_flags.contains(StyleFlags::BOX_SHADOW) {
+            styles_window = styles_window.push(field_row(
+                "Box Shadow: ",
+                FIELD_NAME_COLOR,
+                format_option(style.box_shadow()).as_str(),
+                FIELD_VALUE_COLOR,
+            ));
+        }
+
+        // Opacity
+        if style.dirty_flags.contains(StyleFlags::OPACITY) {
+            styles_window = styles_window.push(field_row(
+                "Opacity: ",
+                FIELD_NAME_COLOR,
+                format!("{:?}", style.opacity()).as_str(),
+                FIELD_VALUE_COLOR,
+            ));
+        }
+
+        // Transform
+        if style.dirty_flags.contains(StyleFlags::TRANSFORM) {
+            styles_window = styles_window.push(field_row(
+                "Transform: ",
+                FIELD_NAME_COLOR,
+                format!("{:?}", style.transform().to_affine().as_coeffs()).as_str(),
+                FIELD_VALUE_COLOR,
+            ));
+        }
+
+        // Transform Origin
+        if style.dirty_flags.contains(StyleFlags::TRANSFORM_ORIGIN) {
+            styles_window = styles_window.push(field_row(
+                "Transform Origin: ",
+                FIELD_NAME_COLOR,
+                format!("{:?}", style.transform_origin()).as_str(),
+                FIELD_VALUE_COLOR,
+            ));
+        }
     }
 
     styles_window
@@ -1,50 +1,64 @@
 use crate::elements::element_states::ElementState;
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 use crate::elements::element_data::ElementData;
+use crate::events::PointerId;
+use crate::geometry::Point;
+use crate::reactive::animation::AnimationState;
 use crate::style::Style;
 
 #[derive(Debug, Default, Clone)]
 pub struct BaseElementState {
-    pub(crate) hovered: bool,
-    pub(crate) active: bool,
+    /// The pointers that hover the element.
+    pub(crate) hovered: HashSet<PointerId>,
+    /// The pointers that press the element.
+    pub(crate) active: HashSet<PointerId>,
+    pub(crate) focused: bool,
     #[allow(dead_code)]
     pub(crate) current_state: ElementState,
     /// Whether this element should receive pointer events regardless of hit testing.
     /// Useful for scroll thumbs.
-    pub(crate) pointer_capture: HashMap<i64, bool>,
+    pub(crate) pointer_capture: HashMap<PointerId, bool>,
+    /// While the element is dragged with a preview, the position of the pointer when the drag started.
+    pub(crate) drag_origin: Option<Point>,
+    /// While the element is dragged with a preview, the position of the pointer that drags it.
+    pub(crate) drag_position: Option<Point>,
+    pub(crate) animation: AnimationState,
 }
 
 impl<'a> BaseElementState {
     
     pub fn current_style(&self, element_data: &'a ElementData) -> &'a Style {
-        if self.active {
+        if !self.active.is_empty() {
             if let Some(pressed_style) = &element_data.pressed_style {
                 return pressed_style;
             }
         }
-        if self.hovered {
+        if !self.hovered.is_empty() {
             if let Some(hover_style) = &element_data.hover_style {
                 return hover_style;
             }
         }
+        if let Some(focused_style) = element_data.focused_style.as_ref().filter(|_| self.focused) {
+            return focused_style;
+        }
         &element_data.style
     }
 
     pub fn current_style_mut(&self, element_data: &'a mut ElementData) -> &'a mut Style {
-        if self.active {
+        if !self.active.is_empty() {
             if let Some(pressed_style) = &mut element_data.pressed_style {
                 return pressed_style;
             }
         }
-        if self.hovered {
+        if !self.hovered.is_empty() {
             if let Some(hover_style) = &mut element_data.hover_style {
                 return hover_style;
             }
         }
+        if let Some(focused_style) = element_data.focused_style.as_mut().filter(|_| self.focused) {
+            return focused_style;
+        }
         &mut element_data.style
     }
     
 }
-
-// HACK: Remove this and all usages when pointer capture per device works.
-pub(crate) const DUMMY_DEVICE_ID: i64 = -1;
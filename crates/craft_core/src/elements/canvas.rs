@@ -11,6 +11,7 @@ use crate::renderer::RenderCommand;
 use crate::style::Style;
 use crate::Color;
 use crate::generate_component_methods_no_children;
+use peniko::kurbo::Affine;
 use std::any::Any;
 use std::sync::Arc;
 use taffy::{NodeId, TaffyTree};
@@ -125,12 +126,43 @@ impl Element for Canvas {
                     );
                     renderer.push_layer(translated_rectangle);
                 }
+                RenderCommand::PushOpacityLayer(opacity) => {
+                    renderer.push_opacity_layer(*opacity);
+                }
                 RenderCommand::PopLayer => {
                     renderer.pop_layer();
                 }
+                RenderCommand::PushTransform(transform) => {
+                    // The transform is relative to the canvas, like the rest of the commands.
+                    let translation = Affine::translate((computed_x_transformed as f64, computed_y_transformed as f64));
+                    renderer.push_transform(translation * *transform * translation.inverse());
+                }
+                RenderCommand::PopTransform => {
+                    renderer.pop_transform();
+                }
                 RenderCommand::FillBezPath(path, brush) => {
                     renderer.fill_bez_path(path.clone(), brush.clone());
                 }
+                RenderCommand::DrawShadow(rectangle, radius, std_dev, color) => {
+                    let translated_rectangle = Rectangle::new(
+                        rectangle.x + computed_x_transformed,
+                        rectangle.y + computed_y_transformed,
+                        rectangle.width,
+                        rectangle.height,
+                    );
+                    renderer.draw_shadow(translated_rectangle, *radius, *std_dev, *color);
+                }
+                RenderCommand::DrawInsetShadow(clip, rectangle, radius, std_dev, color) => {
+                    let translated_rectangle = Rectangle::new(
+                        rectangle.x + computed_x_transformed,
+                        rectangle.y + computed_y_transformed,
+                        rectangle.width,
+                        rectangle.height,
+                    );
+                    let translation = Affine::translate((computed_x_transformed as f64, computed_y_transformed as f64));
+                    let translated_clip = translation * clip.clone();
+                    renderer.draw_inset_shadow(translated_clip, translated_rectangle, *radius, *std_dev, *color);
+                }
                 RenderCommand::DrawTinyVg(rectangle, resource_identifier, color) => {
                     renderer.draw_tiny_vg(*rectangle, resource_identifier.clone(), *color);
                 }
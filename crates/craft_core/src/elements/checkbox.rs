@@ -0,0 +1,388 @@
+use crate::accessibility::text_content;
+use crate::components::component::ComponentSpecification;
+use crate::components::Props;
+use crate::components::Event;
+use crate::elements::element::Element;
+use crate::elements::element_data::ElementData;
+use crate::elements::element_styles::ElementStyles;
+use crate::elements::thumb::Thumb;
+use crate::elements::Text;
+use crate::events::CraftMessage;
+use crate::geometry::{Point, Rectangle, TrblRectangle};
+use crate::layout::layout_context::LayoutContext;
+use crate::reactive::element_state_store::{ElementStateStore, ElementStateStoreItem};
+use crate::renderer::renderer::RenderList;
+use crate::renderer::Brush;
+use crate::style::{AlignItems, Display, Style, Unit};
+use crate::text::text_context::TextContext;
+use crate::{generate_component_methods, palette};
+use accesskit::{Action, Node, Role, Toggled};
+use peniko::kurbo::{self, Shape};
+use std::any::Any;
+use std::sync::Arc;
+use taffy::{NodeId, TaffyTree};
+use winit::keyboard::Key;
+use winit::window::Window;
+
+/// Whether a [`Checkbox`] is checked.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum CheckState {
+    #[default]
+    Unchecked,
+    Checked,
+    /// Neither checked nor unchecked, for example when some of the items that the checkbox stands for are checked.
+    /// Clicking an indeterminate checkbox checks it.
+    Indeterminate,
+}
+
+/// An element that can be checked and unchecked, with optional label children that toggle it when clicked.
+#[derive(Clone)]
+pub struct Checkbox {
+    pub element_data: ElementData,
+    /// When `state.checked` is None, use this as the default value.
+    default_checked: CheckState,
+    /// When set, the checkbox always shows this state, and the component updates it on `CheckboxToggled`.
+    checked: Option<CheckState>,
+    enabled: bool,
+
+    /// A pseudo element for the box, this is not stored in the user tree nor will it receive events.
+    indicator: Thumb,
+}
+
+#[derive(Clone, Default)]
+pub struct CheckboxState {
+    pub(crate) checked: Option<CheckState>,
+}
+
+impl Element for Checkbox {
+    fn element_data(&self) -> &ElementData {
+        &self.element_data
+    }
+
+    fn element_data_mut(&mut self) -> &mut ElementData {
+        &mut self.element_data
+    }
+
+    fn name(&self) -> &'static str {
+        "Checkbox"
+    }
+
+    fn draw(
+        &mut self,
+        renderer: &mut RenderList,
+        text_context: &mut TextContext,
+        taffy_tree: &mut TaffyTree<LayoutContext>,
+        _root_node: NodeId,
+        element_state: &mut ElementStateStore,
+        pointer: Option<Point>,
+        window: Option<Arc<dyn Window>>,
+    ) {
+        if !self.element_data.style.visible() {
+            return;
+        }
+        self.draw_borders(renderer, element_state);
+        self.indicator.draw(renderer);
+
+        let mark = self.indicator.layout_item.computed_box_transformed.padding_rectangle();
+        let color = Brush::Color(self.indicator.thumb_style.color());
+        match self.check_state(element_state) {
+            CheckState::Unchecked => {}
+            CheckState::Checked => renderer.fill_bez_path(check_mark_path(mark), color),
+            CheckState::Indeterminate => {
+                let height = mark.height * 0.14;
+                let bar = Rectangle::new(
+                    mark.x + mark.width * 0.22,
+                    mark.y + (mark.height - height) / 2.0,
+                    mark.width * 0.56,
+                    height,
+                );
+                renderer.fill_bez_path(bar.to_kurbo().to_path(0.1), color);
+            }
+        }
+
+        self.draw_children(renderer, text_context, taffy_tree, element_state, pointer, window);
+    }
+
+    fn compute_layout(
+        &mut self,
+        taffy_tree: &mut TaffyTree<LayoutContext>,
+        element_state: &mut ElementStateStore,
+        scale_factor: f64,
+    ) -> Option<NodeId> {
+        self.merge_default_style();
+        if !self.enabled {
+            self.element_data.style = Style::merge(&self.element_data.style, &default_disabled_style());
+            if let Some(disabled_style) = self.element_data.disabled_style.as_deref() {
+                self.element_data.style = Style::merge(&self.element_data.style, disabled_style);
+            }
+        }
+
+        let check_state = self.check_state(element_state);
+        let indicator_node =
+            self.indicator.compute_layout(taffy_tree, scale_factor, check_state != CheckState::Unchecked, false);
+        self.element_data.layout_item.push_child(&Some(indicator_node));
+
+        for child in &mut self.element_data.children {
+            let child_node = child.internal.compute_layout(taffy_tree, element_state, scale_factor);
+            self.element_data.layout_item.push_child(&child_node);
+        }
+
+        self.element_data.style.scale(scale_factor);
+        let style: taffy::Style = self.element_data.style.to_taffy_style();
+
+        self.element_data.layout_item.build_tree(taffy_tree, style)
+    }
+
+    fn finalize_layout(
+        &mut self,
+        taffy_tree: &mut TaffyTree<LayoutContext>,
+        root_node: NodeId,
+        position: Point,
+        z_index: &mut u32,
+        transform: glam::Mat4,
+        element_state: &mut ElementStateStore,
+        pointer: Option<Point>,
+        text_context: &mut TextContext,
+        clip_bounds: Option<Rectangle>,
+    ) {
+        let result = taffy_tree.layout(root_node).unwrap();
+        self.resolve_box(position, transform, result, z_index);
+        self.resolve_clip(clip_bounds);
+        self.finalize_borders(element_state);
+
+        let position = self.element_data.layout_item.computed_box.position;
+        self.indicator.finalize_layout(
+            taffy_tree,
+            position,
+            z_index,
+            transform,
+            element_state,
+            pointer,
+            text_context,
+            clip_bounds,
+        );
+
+        for child in self.element_data.children.iter_mut() {
+            let taffy_child_node_id = child.internal.element_data().layout_item.taffy_node_id;
+            if taffy_child_node_id.is_none() {
+                continue;
+            }
+
+            child.internal.finalize_layout(
+                taffy_tree,
+                taffy_child_node_id.unwrap(),
+                position,
+                z_index,
+                transform,
+                element_state,
+                pointer,
+                text_context,
+                clip_bounds,
+            );
+        }
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn on_event(
+        &self,
+        message: &CraftMessage,
+        element_state: &mut ElementStateStore,
+        _text_context: &mut TextContext,
+        should_style: bool,
+        event: &mut Event,
+    ) {
+        self.on_style_event(message, element_state, should_style, event);
+        if !self.enabled {
+            return;
+        }
+
+        let toggle = match message {
+            CraftMessage::KeyboardInputEvent(keyboard_input) => {
+                keyboard_input.event.state.is_pressed()
+                    && keyboard_input.event.logical_key == Key::Character(" ".into())
+            }
+            CraftMessage::AccessibilityAction(request) => request.action == Action::Click,
+            message => message.clicked(),
+        };
+        if !toggle {
+            return;
+        }
+
+        // Clicking an unchecked or an indeterminate checkbox checks it.
+        let checked = self.check_state(element_state) != CheckState::Checked;
+        if self.checked.is_none() {
+            let base_state = self.get_base_state_mut(element_state);
+            let state = base_state.data.as_mut().downcast_mut::<CheckboxState>().unwrap();
+            state.checked = Some(if checked { CheckState::Checked } else { CheckState::Unchecked });
+        }
+
+        event.result_message(CraftMessage::CheckboxToggled(checked));
+        event.prevent_defaults();
+        event.prevent_propagate();
+    }
+
+    fn initialize_state(&mut self, _scaling_factor: f64) -> ElementStateStoreItem {
+        ElementStateStoreItem {
+            base: Default::default(),
+            data: Box::new(CheckboxState::default()),
+        }
+    }
+
+    fn default_style(&self) -> Style {
+        let mut style = Style::default();
+
+        *style.display_mut() = Display::Flex;
+        *style.align_items_mut() = Some(AlignItems::Center);
+        *style.gap_mut() = [Unit::Px(6.0), Unit::Px(6.0)];
+
+        style
+    }
+
+    fn default_accessibility_role(&self) -> Role {
+        Role::CheckBox
+    }
+
+    fn update_accessibility_node(&self, node: &mut Node, element_state: &ElementStateStore) {
+        let toggled = match self.check_state(element_state) {
+            CheckState::Unchecked => Toggled::False,
+            CheckState::Checked => Toggled::True,
+            CheckState::Indeterminate => Toggled::Mixed,
+        };
+        node.set_toggled(toggled);
+        let label = text_content(self, element_state);
+        if !label.is_empty() {
+            node.set_label(label);
+        }
+        if self.enabled {
+            node.add_action(Action::Click);
+        } else {
+            node.set_disabled();
+        }
+    }
+
+    fn is_focusable(&self) -> bool {
+        self.enabled
+    }
+}
+
+impl Default for Checkbox {
+    fn default() -> Self {
+        Self::new(16.0)
+    }
+}
+
+impl Checkbox {
+    /// Creates a checkbox whose box is `size` pixels wide and high.
+    pub fn new(size: f32) -> Checkbox {
+        Checkbox {
+            element_data: Default::default(),
+            default_checked: CheckState::Unchecked,
+            checked: None,
+            enabled: true,
+            indicator: Thumb {
+                layout_item: Default::default(),
+                thumb_style: default_indicator_style(size * 0.2),
+                toggled_thumb_style: default_checked_indicator_style(),
+                size,
+            },
+        }
+    }
+
+    pub fn default_checked(mut self, default_checked: CheckState) -> Self {
+        self.default_checked = default_checked;
+        self
+    }
+
+    /// Controls the state of the checkbox. Clicking it only emits `CheckboxToggled`, and the state changes when the
+    /// component passes the new state.
+    pub fn checked(mut self, checked: CheckState) -> Self {
+        self.checked = Some(checked);
+        self
+    }
+
+    /// Whether the checkbox can be focused and toggled. A disabled checkbox uses the disabled style.
+    pub fn enabled(mut self, enabled: bool) -> Self {
+        self.enabled = enabled;
+        self
+    }
+
+    /// Adds a text label after the box, which toggles the checkbox when clicked.
+    pub fn label(self, label: &str) -> Self {
+        self.push(Text::new(label).disable_selection())
+    }
+
+    /// Sets the style of the box. The color is used for the check mark.
+    pub fn indicator_style(mut self, indicator_style: Style) -> Self {
+        self.indicator.thumb_style(Style::merge(&self.indicator.thumb_style, &indicator_style));
+        self
+    }
+
+    /// Sets the style of the box when the checkbox is checked or indeterminate. This style will get merged with the
+    /// indicator style.
+    pub fn checked_style(mut self, checked_style: Style) -> Self {
+        self.indicator.toggled_thumb_style(Style::merge(&self.indicator.toggled_thumb_style, &checked_style));
+        self
+    }
+
+    fn check_state(&self, element_state: &ElementStateStore) -> CheckState {
+        let state = self.get_state(element_state);
+        self.checked.or(state.checked).unwrap_or(self.default_checked)
+    }
+
+    #[allow(dead_code)]
+    fn get_state<'a>(&self, element_state: &'a ElementStateStore) -> &'a CheckboxState {
+        element_state.storage.get(&self.element_data.component_id).unwrap().data.as_ref().downcast_ref().unwrap()
+    }
+
+    generate_component_methods!();
+}
+
+impl ElementStyles for Checkbox {
+    fn styles_mut(&mut self) -> &mut Style {
+        self.element_data.current_style_mut()
+    }
+}
+
+/// The style of the box of an unchecked checkbox or radio button.
+pub(crate) fn default_indicator_style(border_radius: f32) -> Style {
+    let mut style = Style::default();
+    let border_color = palette::css::GRAY;
+    *style.border_width_mut() = TrblRectangle::new_all(Unit::Px(1.0));
+    *style.border_color_mut() = TrblRectangle::new_all(border_color);
+    *style.border_radius_mut() = [(border_radius, border_radius); 4];
+    *style.color_mut() = palette::css::WHITE;
+    *style.flex_shrink_mut() = 0.0;
+    style
+}
+
+/// The style of the box of a checked checkbox or a selected radio button.
+pub(crate) fn default_checked_indicator_style() -> Style {
+    let mut style = Style::default();
+    *style.background_mut() = palette::css::DODGER_BLUE;
+    *style.border_color_mut() = TrblRectangle::new_all(palette::css::DODGER_BLUE);
+    style
+}
+
+/// Dims disabled checkboxes and radio buttons, with their labels.
+pub(crate) fn default_disabled_style() -> Style {
+    let mut style = Style::default();
+    *style.opacity_mut() = 0.5;
+    style
+}
+
+/// A check mark that fills the rectangle.
+fn check_mark_path(rectangle: Rectangle) -> kurbo::BezPath {
+    let point = |x: f32, y: f32| {
+        kurbo::Point::new((rectangle.x + rectangle.width * x) as f64, (rectangle.y + rectangle.height * y) as f64)
+    };
+    let mut path = kurbo::BezPath::new();
+    path.move_to(point(0.2, 0.52));
+    path.line_to(point(0.42, 0.72));
+    path.line_to(point(0.8, 0.3));
+
+    let stroke = kurbo::Stroke::new((rectangle.width * 0.14) as f64);
+    kurbo::stroke(path, &stroke, &kurbo::StrokeOpts::default(), 0.1)
+}
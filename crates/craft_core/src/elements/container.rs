@@ -11,6 +11,7 @@ use crate::geometry::{Point, Rectangle, Size};
 use crate::reactive::element_state_store::{ElementStateStore, ElementStateStoreItem};
 use crate::style::Style;
 use crate::{generate_component_methods};
+use accesskit::Node;
 use std::any::Any;
 use std::sync::Arc;
 use taffy::{NodeId, TaffyTree};
@@ -81,6 +82,16 @@ impl Element for Container {
         for child in &mut self.element_data.children {
             let child_node =
                 child.internal.compute_layout(taffy_tree, element_state, scale_factor);
+
+            // Taffy does not know about named grid areas, so we place the children in their area's lines instead.
+            let area_lines = child.internal.style().grid_area().and_then(|name| self.element_data.style.grid_area_lines(name));
+            if let (Some(child_node), Some((grid_row, grid_column))) = (child_node, area_lines) {
+                let mut child_style = taffy_tree.style(child_node).unwrap().clone();
+                child_style.grid_row = grid_row;
+                child_style.grid_column = grid_column;
+                taffy_tree.set_style(child_node, child_style).unwrap();
+            }
+
             self.element_data.layout_item.push_child(&child_node);
         }
 
@@ -177,6 +188,10 @@ impl Element for Container {
             data: Box::new(ContainerState::default()),
         }
     }
+
+    fn update_accessibility_node(&self, node: &mut Node, element_state: &ElementStateStore) {
+        self.get_state(element_state).scroll_state.update_accessibility_node(&self.element_data, node);
+    }
 }
 
 impl Container {
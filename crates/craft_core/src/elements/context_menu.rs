@@ -0,0 +1,284 @@
+use crate::components::component::ComponentSpecification;
+use crate::components::{ComponentId, Event, FocusRequest, Props};
+use crate::elements::element::Element;
+use crate::elements::element_data::ElementData;
+use crate::elements::element_styles::ElementStyles;
+use crate::elements::menu::{
+    activate, hover, is_press_outside, item_path_at, menu_element, navigate, MenuItem, MenuKeyAction, MenuState,
+};
+use crate::events::focus::is_focusable;
+use crate::events::CraftMessage;
+use crate::geometry::{Point, Rectangle};
+use crate::layout::layout_context::LayoutContext;
+use crate::reactive::element_state_store::{ElementStateStore, ElementStateStoreItem};
+use crate::renderer::renderer::RenderList;
+use crate::style::{Style, Unit};
+use crate::text::text_context::TextContext;
+use crate::{generate_component_methods, WindowContext};
+use std::any::Any;
+use std::sync::Arc;
+use taffy::{NodeId, TaffyTree};
+use winit::event::{ElementState, MouseButton};
+use winit::keyboard::{Key, NamedKey};
+use winit::window::Window;
+
+/// An element that opens a menu of [`MenuItem`]s where its children, the target, are clicked with the right mouse
+/// button.
+///
+/// Choosing an item emits `MenuItemSelected` with the id of the item. Submenus open when their item is hovered. The
+/// arrow keys move through the menus, Enter chooses the highlighted item and Escape closes the innermost menu. Clicking
+/// outside of the menu closes it. While the target has the focus, the context menu key opens the menu at the top left
+/// corner of the target.
+///
+/// When the context menu has an id, every item with an id gets the element id `{id}-{item id}`.
+#[derive(Clone, Default)]
+pub struct ContextMenu {
+    pub element_data: ElementData,
+    items: Vec<MenuItem>,
+    /// Whether the menu is open. The open menu is the last child, and the context menu takes the focus to receive the
+    /// key presses.
+    is_open: bool,
+}
+
+impl Element for ContextMenu {
+    fn element_data(&self) -> &ElementData {
+        &self.element_data
+    }
+
+    fn element_data_mut(&mut self) -> &mut ElementData {
+        &mut self.element_data
+    }
+
+    fn name(&self) -> &'static str {
+        "ContextMenu"
+    }
+
+    fn draw(
+        &mut self,
+        renderer: &mut RenderList,
+        text_context: &mut TextContext,
+        taffy_tree: &mut TaffyTree<LayoutContext>,
+        _root_node: NodeId,
+        element_state: &mut ElementStateStore,
+        pointer: Option<Point>,
+        window: Option<Arc<dyn Window>>,
+    ) {
+        if !self.element_data.style.visible() {
+            return;
+        }
+        self.draw_borders(renderer, element_state);
+        self.draw_children(renderer, text_context, taffy_tree, element_state, pointer, window);
+    }
+
+    fn compute_layout(
+        &mut self,
+        taffy_tree: &mut TaffyTree<LayoutContext>,
+        element_state: &mut ElementStateStore,
+        scale_factor: f64,
+    ) -> Option<NodeId> {
+        self.merge_default_style();
+
+        for child in &mut self.element_data.children {
+            let child_node = child.internal.compute_layout(taffy_tree, element_state, scale_factor);
+            self.element_data.layout_item.push_child(&child_node);
+        }
+
+        self.element_data.style.scale(scale_factor);
+        let style: taffy::Style = self.element_data.style.to_taffy_style();
+
+        self.element_data.layout_item.build_tree(taffy_tree, style)
+    }
+
+    fn finalize_layout(
+        &mut self,
+        taffy_tree: &mut TaffyTree<LayoutContext>,
+        root_node: NodeId,
+        position: Point,
+        z_index: &mut u32,
+        transform: glam::Mat4,
+        element_state: &mut ElementStateStore,
+        pointer: Option<Point>,
+        text_context: &mut TextContext,
+        clip_bounds: Option<Rectangle>,
+    ) {
+        let result = taffy_tree.layout(root_node).unwrap();
+        self.resolve_box(position, transform, result, z_index);
+        self.resolve_clip(clip_bounds);
+        self.finalize_borders(element_state);
+
+        for child in self.element_data.children.iter_mut() {
+            let taffy_child_node_id = child.internal.element_data().layout_item.taffy_node_id;
+            if taffy_child_node_id.is_none() {
+                continue;
+            }
+
+            child.internal.finalize_layout(
+                taffy_tree,
+                taffy_child_node_id.unwrap(),
+                self.element_data.layout_item.computed_box.position,
+                z_index,
+                transform,
+                element_state,
+                pointer,
+                text_context,
+                self.element_data.layout_item.clip_bounds,
+            );
+        }
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn on_event(
+        &self,
+        message: &CraftMessage,
+        element_state: &mut ElementStateStore,
+        _text_context: &mut TextContext,
+        should_style: bool,
+        event: &mut Event,
+    ) {
+        self.on_style_event(message, element_state, should_style, event);
+
+        let origin = self.computed_box_transformed().border_rectangle();
+        let menu = self.menu();
+        let state = self.get_state_mut(element_state);
+        let was_open = state.is_open;
+        // Where the focus goes when the menu closes.
+        let mut return_focus = None;
+
+        match message {
+            CraftMessage::PointerButtonEvent(pointer_button) => {
+                let position = pointer_button.position;
+                if let Some(path) = menu.and_then(|menu| item_path_at(menu, position)) {
+                    let chosen = if pointer_button.clicked() { activate(&self.items, state, &path) } else { None };
+                    if let Some(id) = chosen {
+                        event.result_message(CraftMessage::MenuItemSelected(id));
+                    }
+                    event.prevent_propagate();
+                } else if pointer_button.state == ElementState::Pressed {
+                    if pointer_button.button.mouse_button() == MouseButton::Right {
+                        state.open(&self.items, false);
+                        state.anchor = Point::new(position.x - origin.x, position.y - origin.y);
+                        event.prevent_propagate();
+                    } else {
+                        state.close();
+                        return_focus = Some(position);
+                    }
+                }
+            }
+            CraftMessage::PointerMovedEvent(pointer_moved) => {
+                if let Some(path) = menu.and_then(|menu| item_path_at(menu, pointer_moved.position)) {
+                    hover(&self.items, state, &path);
+                }
+            }
+            CraftMessage::KeyboardInputEvent(_) if state.is_open => match navigate(&self.items, state, message) {
+                MenuKeyAction::Ignored | MenuKeyAction::Sideways(_) => {}
+                MenuKeyAction::Selected(id) => {
+                    event.result_message(CraftMessage::MenuItemSelected(id));
+                    event.prevent_defaults();
+                }
+                MenuKeyAction::Navigated | MenuKeyAction::Closed => event.prevent_defaults(),
+            },
+            CraftMessage::KeyboardInputEvent(keyboard_input) => {
+                let is_context_menu_key = keyboard_input.event.state.is_pressed()
+                    && keyboard_input.event.logical_key == Key::Named(NamedKey::ContextMenu);
+                if is_context_menu_key {
+                    state.open(&self.items, true);
+                    state.anchor = Point::default();
+                    event.prevent_defaults();
+                }
+            }
+            _ => {}
+        }
+
+        if !was_open && state.is_open {
+            event.focus = Some(FocusRequest::Element(Some(self.component_id())));
+        } else if was_open && !state.is_open {
+            event.focus = Some(FocusRequest::Element(self.target_focus(return_focus)));
+        }
+    }
+
+    fn on_global_event(&self, message: &CraftMessage, element_state: &mut ElementStateStore, is_target: bool) {
+        if is_press_outside(message, is_target) {
+            self.get_state_mut(element_state).close();
+        }
+    }
+
+    fn initialize_state(&mut self, _scaling_factor: f64) -> ElementStateStoreItem {
+        ElementStateStoreItem {
+            base: Default::default(),
+            data: Box::new(MenuState::default()),
+        }
+    }
+
+    fn materialize_children(
+        &mut self,
+        mut children: Vec<ComponentSpecification>,
+        element_state: &mut ElementStateStore,
+        _window: &WindowContext,
+        scaling_factor: f64,
+    ) -> Vec<ComponentSpecification> {
+        let state = self.get_state(element_state);
+        self.is_open = state.is_open;
+        if state.is_open {
+            let anchor = Point::new(state.anchor.x / scaling_factor as f32, state.anchor.y / scaling_factor as f32);
+            let menu = menu_element(&self.items, state, 0, self.element_data.id.as_deref())
+                .inset(Unit::Px(anchor.y), Unit::Auto, Unit::Auto, Unit::Px(anchor.x));
+            children.push(menu.component());
+        }
+
+        children
+    }
+
+    fn is_focusable(&self) -> bool {
+        self.is_open
+    }
+}
+
+impl ContextMenu {
+    pub fn new(items: Vec<MenuItem>) -> ContextMenu {
+        ContextMenu {
+            items,
+            ..Default::default()
+        }
+    }
+
+    /// The open menu.
+    fn menu(&self) -> Option<&dyn Element> {
+        self.children().last().copied().filter(|_| self.is_open)
+    }
+
+    /// Returns the focusable element of the target under `point`, or the first one when there is no point.
+    fn target_focus(&self, point: Option<Point>) -> Option<ComponentId> {
+        let mut target = self.children();
+        if self.is_open {
+            target.pop();
+        }
+
+        let mut focusable =
+            target.into_iter().flat_map(|child| child.pre_order_iter()).filter(|element| is_focusable(*element));
+        let element = match point {
+            Some(point) => focusable.filter(|element| element.in_bounds(point)).last(),
+            None => focusable.next(),
+        };
+        element.map(|element| element.component_id())
+    }
+
+    #[allow(dead_code)]
+    fn get_state<'a>(&self, element_state: &'a ElementStateStore) -> &'a MenuState {
+        element_state.storage.get(&self.element_data.component_id).unwrap().data.as_ref().downcast_ref().unwrap()
+    }
+
+    fn get_state_mut<'a>(&self, element_state: &'a mut ElementStateStore) -> &'a mut MenuState {
+        element_state.storage.get_mut(&self.element_data.component_id).unwrap().data.as_mut().downcast_mut().unwrap()
+    }
+
+    generate_component_methods!();
+}
+
+impl ElementStyles for ContextMenu {
+    fn styles_mut(&mut self) -> &mut Style {
+        self.element_data.current_style_mut()
+    }
+}
@@ -0,0 +1,281 @@
+use crate::components::component::ComponentSpecification;
+use crate::components::{Event, Props};
+use crate::elements::element::Element;
+use crate::elements::element_data::ElementData;
+use crate::elements::element_styles::ElementStyles;
+use crate::events::CraftMessage;
+use crate::geometry::{Point, Rectangle, Size, TrblRectangle};
+use crate::layout::layout_context::LayoutContext;
+use crate::reactive::element_state_store::{ElementStateStore, ElementStateStoreItem};
+use crate::renderer::renderer::RenderList;
+use crate::style::{Display, FlexDirection, Position, Style, Unit};
+use crate::text::text_context::TextContext;
+use crate::{generate_component_methods, WindowContext};
+use accesskit::{Node, Role};
+use peniko::Color;
+use std::any::Any;
+use std::sync::Arc;
+use taffy::{NodeId, TaffyTree};
+use winit::keyboard::{Key, NamedKey};
+use winit::window::Window;
+
+/// A modal dialog, which is shown in the center of the window over a backdrop that dims the rest of the window.
+///
+/// The dialog is open while it is in the tree. Pointer and keyboard events only reach the elements inside of the
+/// topmost open dialog, and Tab only moves the focus between them. Pressing Escape or clicking the backdrop emits
+/// `DialogClosed`, and the component has to remove the dialog.
+#[derive(Clone)]
+pub struct Dialog {
+    pub element_data: ElementData,
+    close_on_escape: bool,
+    close_on_backdrop_click: bool,
+    backdrop_color: Color,
+}
+
+#[derive(Clone, Copy, Default)]
+pub struct DialogState {
+    /// The size of the window in physical pixels, which the backdrop covers.
+    window_size: Size<f32>,
+}
+
+impl Default for Dialog {
+    fn default() -> Self {
+        Dialog {
+            element_data: Default::default(),
+            close_on_escape: true,
+            close_on_backdrop_click: true,
+            backdrop_color: Color::from_rgba8(0, 0, 0, 100),
+        }
+    }
+}
+
+impl Element for Dialog {
+    fn element_data(&self) -> &ElementData {
+        &self.element_data
+    }
+
+    fn element_data_mut(&mut self) -> &mut ElementData {
+        &mut self.element_data
+    }
+
+    /// The backdrop covers the whole window.
+    fn in_bounds(&self, _point: Point) -> bool {
+        true
+    }
+
+    fn name(&self) -> &'static str {
+        "Dialog"
+    }
+
+    fn draw(
+        &mut self,
+        renderer: &mut RenderList,
+        text_context: &mut TextContext,
+        taffy_tree: &mut TaffyTree<LayoutContext>,
+        _root_node: NodeId,
+        element_state: &mut ElementStateStore,
+        pointer: Option<Point>,
+        window: Option<Arc<dyn Window>>,
+    ) {
+        if !self.element_data.style.visible() {
+            return;
+        }
+        let window_size = self.get_state(element_state).window_size;
+
+        renderer.start_overlay();
+        renderer.draw_rect(Rectangle::new(0.0, 0.0, window_size.width, window_size.height), self.backdrop_color);
+        self.draw_borders(renderer, element_state);
+        self.draw_children(renderer, text_context, taffy_tree, element_state, pointer, window);
+        renderer.end_overlay();
+    }
+
+    fn compute_layout(
+        &mut self,
+        taffy_tree: &mut TaffyTree<LayoutContext>,
+        element_state: &mut ElementStateStore,
+        scale_factor: f64,
+    ) -> Option<NodeId> {
+        self.merge_default_style();
+
+        for child in self.element_data.children.iter_mut() {
+            let child_node = child.internal.compute_layout(taffy_tree, element_state, scale_factor);
+            self.element_data.layout_item.push_child(&child_node);
+        }
+
+        self.element_data.style.scale(scale_factor);
+        let style: taffy::Style = self.element_data.style.to_taffy_style();
+
+        self.element_data.layout_item.build_tree(taffy_tree, style)
+    }
+
+    fn finalize_layout(
+        &mut self,
+        taffy_tree: &mut TaffyTree<LayoutContext>,
+        root_node: NodeId,
+        _position: Point,
+        z_index: &mut u32,
+        _transform: glam::Mat4,
+        element_state: &mut ElementStateStore,
+        pointer: Option<Point>,
+        text_context: &mut TextContext,
+        _clip_bounds: Option<Rectangle>,
+    ) {
+        // The dialog is centered in the window, wherever it is in the tree and however its ancestors are scrolled.
+        let result = taffy_tree.layout(root_node).unwrap();
+        let window_size = self.get_state(element_state).window_size;
+        let center = Point::new(
+            ((window_size.width - result.size.width) / 2.0).max(0.0),
+            ((window_size.height - result.size.height) / 2.0).max(0.0),
+        );
+        let position = Point::new(center.x - result.location.x, center.y - result.location.y);
+        self.resolve_box(position, glam::Mat4::IDENTITY, result, z_index);
+        self.resolve_clip(None);
+        self.finalize_borders(element_state);
+
+        for child in self.element_data.children.iter_mut() {
+            let taffy_child_node_id = child.internal.element_data().layout_item.taffy_node_id;
+            if taffy_child_node_id.is_none() {
+                continue;
+            }
+
+            child.internal.finalize_layout(
+                taffy_tree,
+                taffy_child_node_id.unwrap(),
+                self.element_data.layout_item.computed_box.position,
+                z_index,
+                glam::Mat4::IDENTITY,
+                element_state,
+                pointer,
+                text_context,
+                None,
+            );
+        }
+    }
+
+    fn resolve_clip(&mut self, _clip_bounds: Option<Rectangle>) {
+        self.element_data.layout_item.clip_bounds = None;
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn on_event(
+        &self,
+        message: &CraftMessage,
+        element_state: &mut ElementStateStore,
+        _text_context: &mut TextContext,
+        should_style: bool,
+        event: &mut Event,
+    ) {
+        self.on_style_event(message, element_state, should_style, event);
+
+        let closes = match message {
+            CraftMessage::KeyboardInputEvent(keyboard_input) => {
+                self.close_on_escape
+                    && keyboard_input.event.state.is_pressed()
+                    && keyboard_input.event.logical_key == Key::Named(NamedKey::Escape)
+            }
+            CraftMessage::PointerButtonEvent(pointer_button) => {
+                let dialog = self.computed_box_transformed().border_rectangle();
+                self.close_on_backdrop_click && pointer_button.clicked() && !dialog.contains(&pointer_button.position)
+            }
+            _ => false,
+        };
+
+        if closes {
+            event.result_message(CraftMessage::DialogClosed);
+            event.prevent_defaults();
+        }
+    }
+
+    fn initialize_state(&mut self, _scaling_factor: f64) -> ElementStateStoreItem {
+        ElementStateStoreItem {
+            base: Default::default(),
+            data: Box::new(DialogState::default()),
+        }
+    }
+
+    fn materialize_children(
+        &mut self,
+        children: Vec<ComponentSpecification>,
+        element_state: &mut ElementStateStore,
+        window: &WindowContext,
+        scaling_factor: f64,
+    ) -> Vec<ComponentSpecification> {
+        let window_size = window.window_size;
+        self.get_state_mut(element_state).window_size =
+            Size::new(window_size.width * scaling_factor as f32, window_size.height * scaling_factor as f32);
+        children
+    }
+
+    fn default_style(&self) -> Style {
+        let mut style = Style::default();
+
+        *style.display_mut() = Display::Flex;
+        *style.flex_direction_mut() = FlexDirection::Column;
+        *style.position_mut() = Position::Absolute;
+        *style.gap_mut() = [Unit::Px(12.0), Unit::Px(12.0)];
+        *style.padding_mut() = TrblRectangle::new_all(Unit::Px(20.0));
+        *style.min_width_mut() = Unit::Px(240.0);
+        *style.background_mut() = Color::WHITE;
+        *style.border_radius_mut() = [(8.0, 8.0); 4];
+        *style.border_width_mut() = TrblRectangle::new_all(Unit::Px(1.0));
+        *style.border_color_mut() = TrblRectangle::new_all(Color::from_rgb8(200, 200, 200));
+
+        style
+    }
+
+    fn default_accessibility_role(&self) -> Role {
+        Role::Dialog
+    }
+
+    fn update_accessibility_node(&self, node: &mut Node, _element_state: &ElementStateStore) {
+        node.set_modal();
+    }
+
+    fn is_modal(&self) -> bool {
+        true
+    }
+}
+
+impl Dialog {
+    pub fn new() -> Dialog {
+        Dialog::default()
+    }
+
+    /// Whether pressing Escape emits `DialogClosed`. Defaults to true.
+    pub fn close_on_escape(mut self, close_on_escape: bool) -> Self {
+        self.close_on_escape = close_on_escape;
+        self
+    }
+
+    /// Whether clicking the backdrop emits `DialogClosed`. Defaults to true.
+    pub fn close_on_backdrop_click(mut self, close_on_backdrop_click: bool) -> Self {
+        self.close_on_backdrop_click = close_on_backdrop_click;
+        self
+    }
+
+    /// Sets the color that is drawn over the rest of the window. Defaults to a translucent black.
+    pub fn backdrop_color(mut self, backdrop_color: Color) -> Self {
+        self.backdrop_color = backdrop_color;
+        self
+    }
+
+    #[allow(dead_code)]
+    fn get_state<'a>(&self, element_state: &'a ElementStateStore) -> &'a DialogState {
+        element_state.storage.get(&self.element_data.component_id).unwrap().data.as_ref().downcast_ref().unwrap()
+    }
+
+    fn get_state_mut<'a>(&self, element_state: &'a mut ElementStateStore) -> &'a mut DialogState {
+        element_state.storage.get_mut(&self.element_data.component_id).unwrap().data.as_mut().downcast_mut().unwrap()
+    }
+
+    generate_component_methods!();
+}
+
+impl ElementStyles for Dialog {
+    fn styles_mut(&mut self) -> &mut Style {
+        self.element_data.current_style_mut()
+    }
+}
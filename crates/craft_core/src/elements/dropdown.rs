@@ -13,6 +13,8 @@ use crate::renderer::renderer::RenderList;
 use crate::style::{AlignItems, Display, FlexDirection, Style, Unit};
 use crate::generate_component_methods;
 use peniko::Color;
+use crate::accessibility::text_content;
+use accesskit::{Action, Node, Role};
 use std::any::Any;
 use std::sync::Arc;
 use taffy::{NodeId, Position, TaffyTree, TraversePartialTree};
@@ -313,6 +315,18 @@ impl Element for Dropdown {
                     event.result_message(CraftMessage::DropdownToggled(state.is_open));
                 }
             }
+            CraftMessage::AccessibilityAction(request) => {
+                let is_open = match request.action {
+                    Action::Expand => true,
+                    Action::Collapse => false,
+                    _ => return,
+                };
+
+                if state.is_open != is_open {
+                    state.is_open = is_open;
+                    event.result_message(CraftMessage::DropdownToggled(state.is_open));
+                }
+            }
             CraftMessage::KeyboardInputEvent(_) => {}
             _ => {}
         }
@@ -348,12 +362,48 @@ impl Element for Dropdown {
 
         default_style
     }
+
+    fn default_accessibility_role(&self) -> Role {
+        Role::ComboBox
+    }
+
+    fn update_accessibility_node(&self, node: &mut Node, element_state: &ElementStateStore) {
+        let state = self.get_state(element_state);
+        node.set_expanded(state.is_open);
+
+        let selected_item = state.selected_item.and_then(|selected_item| self.children().get(selected_item).copied());
+        if let Some(selected_item) = selected_item {
+            node.set_value(text_content(selected_item, element_state));
+        }
+
+        node.add_action(Action::Click);
+        node.add_action(if state.is_open { Action::Collapse } else { Action::Expand });
+    }
+
+    fn update_accessibility_child_node(&self, index: usize, node: &mut Node, element_state: &ElementStateStore) {
+        node.set_role(Role::ListBoxOption);
+        node.set_selected(self.get_state(element_state).selected_item == Some(index));
+        node.add_action(Action::Click);
+    }
+
+    /// The dropdown list items are only exposed while the list is open.
+    fn accessibility_children(&self, element_state: &ElementStateStore) -> Vec<&dyn Element> {
+        if self.get_state(element_state).is_open {
+            self.children()
+        } else {
+            Vec::new()
+        }
+    }
+
+    fn is_focusable(&self) -> bool {
+        true
+    }
 }
 
 impl Dropdown {
     /// Sets the style of a dropdown list.
     pub fn dropdown_list_style(mut self, style: &Style) -> Self {
-        self.pseudo_dropdown_list_element.element_data.style = *style;
+        self.pseudo_dropdown_list_element.element_data.style = style.clone();
         self
     }
 
@@ -0,0 +1,104 @@
+use std::collections::VecDeque;
+
+/// The number of edits that can be undone when no history depth is set.
+pub(crate) const DEFAULT_HISTORY_DEPTH: usize = 100;
+
+/// The text of an editor and its selection, as the byte indices of the anchor and the focus.
+#[derive(Clone, Debug, PartialEq)]
+pub(crate) struct EditSnapshot {
+    pub(crate) text: String,
+    pub(crate) selection: (usize, usize),
+}
+
+/// What an edit did. Consecutive edits of the same kind are undone together, except for [`EditKind::Other`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) enum EditKind {
+    Typing,
+    Deleting,
+    Other,
+}
+
+/// The edits of a text editor that can be undone and redone.
+#[derive(Clone, Debug)]
+pub(crate) struct EditHistory {
+    /// The snapshots from before each edit, oldest first.
+    undo_stack: VecDeque<EditSnapshot>,
+    /// The snapshots from before each undo, most recently undone last.
+    redo_stack: Vec<EditSnapshot>,
+    /// The number of edits that are kept. Zero disables the history.
+    depth: usize,
+    /// The kind of the last edit, as long as the next edit of the same kind can be merged into it.
+    last_edit: Option<EditKind>,
+}
+
+impl Default for EditHistory {
+    fn default() -> Self {
+        EditHistory::new(DEFAULT_HISTORY_DEPTH)
+    }
+}
+
+impl EditHistory {
+    pub(crate) fn new(depth: usize) -> Self {
+        EditHistory {
+            undo_stack: VecDeque::new(),
+            redo_stack: Vec::new(),
+            depth,
+            last_edit: None,
+        }
+    }
+
+    pub(crate) fn is_enabled(&self) -> bool {
+        self.depth > 0
+    }
+
+    pub(crate) fn set_depth(&mut self, depth: usize) {
+        self.depth = depth;
+        while self.undo_stack.len() > depth {
+            self.undo_stack.pop_front();
+        }
+        if depth == 0 {
+            self.redo_stack.clear();
+        }
+    }
+
+    /// Records an edit, given the snapshot from before it was made.
+    pub(crate) fn record(&mut self, before: EditSnapshot, kind: EditKind) {
+        if !self.is_enabled() {
+            return;
+        }
+
+        self.redo_stack.clear();
+        if kind != EditKind::Other && self.last_edit == Some(kind) {
+            return;
+        }
+
+        self.undo_stack.push_back(before);
+        if self.undo_stack.len() > self.depth {
+            self.undo_stack.pop_front();
+        }
+        self.last_edit = Some(kind);
+    }
+
+    /// Stops the next edit from being merged into the last one, for example because the cursor was moved.
+    pub(crate) fn break_coalescing(&mut self) {
+        self.last_edit = None;
+    }
+
+    /// Returns the snapshot to restore to undo the last edit, given the current snapshot.
+    pub(crate) fn undo(&mut self, current: EditSnapshot) -> Option<EditSnapshot> {
+        let snapshot = self.undo_stack.pop_back()?;
+        self.redo_stack.push(current);
+        self.last_edit = None;
+
+        Some(snapshot)
+    }
+
+    /// Returns the snapshot to restore to redo the last undone edit, given the current snapshot.
+    pub(crate) fn redo(&mut self, current: EditSnapshot) -> Option<EditSnapshot> {
+        let snapshot = self.redo_stack.pop()?;
+        self.undo_stack.push_back(current);
+        self.last_edit = None;
+
+        Some(snapshot)
+    }
+}
@@ -10,13 +10,24 @@ use crate::reactive::element_state_store::{ElementStateStore, ElementStateStoreI
 use crate::renderer::renderer::RenderList;
 use crate::style::Style;
 use crate::text::text_context::TextContext;
+use crate::WindowContext;
+use accesskit::{Node, Role};
 use std::any::Any;
 use std::mem;
 use std::sync::Arc;
+use peniko::kurbo::Affine;
 use taffy::{NodeId, Overflow, TaffyTree};
 use winit::event::MouseButton;
 use winit::window::Window;
 
+#[cfg(target_arch = "wasm32")]
+use web_time as time;
+#[cfg(not(target_arch = "wasm32"))]
+use std::time as time;
+
+/// The opacity of the copy of a dragged element that follows the pointer.
+const DRAG_PREVIEW_OPACITY: f32 = 0.6;
+
 #[derive(Clone)]
 pub struct ElementBoxed {
     pub internal: Box<dyn Element>,
@@ -44,6 +55,8 @@ pub trait Element: Any + StandardElementClone + Send + Sync {
 
     fn in_bounds(&self, point: Point) -> bool {
         let element_data = self.element_data();
+        // The boxes are laid out without the transforms, so the point is moved into their coordinates instead.
+        let point = element_data.layout_item.window_to_local(point);
         let rect = element_data.layout_item.computed_box_transformed.border_rectangle();
 
         if let Some(clip) = element_data.layout_item.clip_bounds {
@@ -120,6 +133,11 @@ pub trait Element: Any + StandardElementClone + Send + Sync {
         self.on_style_event(message, element_state, should_style, event);
     }
 
+    /// Called on every element once an event was dispatched, whether or not it reached the element, so that elements
+    /// can react to the events outside of them, like a press outside of an open menu. `is_target` tells whether the
+    /// element is the target of the event or one of its ancestors.
+    fn on_global_event(&self, _message: &CraftMessage, _element_state: &mut ElementStateStore, _is_target: bool) {}
+
     fn on_style_event(
         &self,
         message: &CraftMessage,
@@ -131,14 +149,14 @@ pub trait Element: Any + StandardElementClone + Send + Sync {
             let state = _element_state.storage.get_mut(&self.element_data().component_id).unwrap();
 
             match message {
-                CraftMessage::PointerMovedEvent(..) => {
-                    state.base.hovered = true;
+                CraftMessage::PointerMovedEvent(pointer_moved) => {
+                    state.base.hovered.insert(pointer_moved.pointer_id());
                 }
                 CraftMessage::PointerButtonEvent(pointer_button) => {
                     if pointer_button.button.mouse_button() == MouseButton::Left
                         && pointer_button.state == winit::event::ElementState::Pressed
                     {
-                        state.base.active = true;
+                        state.base.active.insert(pointer_button.pointer_id());
                     }
                 }
                 _ => {}
@@ -179,6 +197,19 @@ pub trait Element: Any + StandardElementClone + Send + Sync {
             if taffy_child_node_id.is_none() {
                 continue;
             }
+
+            let transform = child.internal.element_data().layout_item.transform;
+            if transform != Affine::IDENTITY {
+                renderer.push_transform(transform);
+            }
+            // The opacity applies to the child and its descendants as a whole, so they are drawn in their own layer.
+            let opacity = {
+                let base_state = child.internal.get_base_state(element_state);
+                base_state.base.current_style(child.internal.element_data()).opacity()
+            };
+            if opacity < 1.0 {
+                renderer.push_opacity_layer(opacity);
+            }
             child.internal.draw(
                 renderer,
                 text_context,
@@ -188,6 +219,33 @@ pub trait Element: Any + StandardElementClone + Send + Sync {
                 pointer,
                 window.clone(),
             );
+            if opacity < 1.0 {
+                renderer.pop_layer();
+            }
+            if transform != Affine::IDENTITY {
+                renderer.pop_transform();
+            }
+
+            // While the child is dragged with a preview, a translucent copy of it follows the pointer over the window.
+            let base_state = &child.internal.get_base_state(element_state).base;
+            if let (Some(origin), Some(position)) = (base_state.drag_origin, base_state.drag_position) {
+                let offset = Affine::translate(((position.x - origin.x) as f64, (position.y - origin.y) as f64));
+                renderer.start_overlay();
+                renderer.push_transform(offset * child.internal.element_data().layout_item.window_transform);
+                renderer.push_opacity_layer(DRAG_PREVIEW_OPACITY);
+                child.internal.draw(
+                    renderer,
+                    text_context,
+                    taffy_tree,
+                    taffy_child_node_id.unwrap(),
+                    element_state,
+                    pointer,
+                    window.clone(),
+                );
+                renderer.pop_layer();
+                renderer.pop_transform();
+                renderer.end_overlay();
+            }
         }
     }
 
@@ -219,6 +277,25 @@ pub trait Element: Any + StandardElementClone + Send + Sync {
         }
     }
 
+    /// Resolves the transforms of the element and its children once their boxes are laid out.
+    fn resolve_transform(&mut self, element_state: &ElementStateStore, parent_transform: Affine) {
+        let (transform, transform_origin) = {
+            let base_state = self.get_base_state(element_state);
+            let current_style = base_state.base.current_style(self.element_data());
+            (current_style.transform(), current_style.transform_origin())
+        };
+        self.element_data_mut().layout_item.resolve_transform(transform, transform_origin, parent_transform);
+
+        let window_transform = self.element_data().layout_item.window_transform;
+        for child in self.element_data_mut().children.iter_mut() {
+            // Skip non-visual elements.
+            if child.internal.taffy_node_id().is_none() {
+                continue;
+            }
+            child.internal.resolve_transform(element_state, window_transform);
+        }
+    }
+
     fn finalize_borders(&mut self, element_state: &ElementStateStore) {
         let base_state = self.get_base_state(element_state);
         let (has_border, border_radius, border_color) = {
@@ -258,8 +335,8 @@ pub trait Element: Any + StandardElementClone + Send + Sync {
         // The scroll track height is the height of the padding box.
         let scroll_track_height = client_height;
 
-        let max_scroll_y = (scroll_height - client_height).max(0.0);
-        element_data.layout_item.max_scroll_y = max_scroll_y;
+        element_data.layout_item.resolve_max_scroll_y();
+        let max_scroll_y = element_data.layout_item.max_scroll_y;
 
         let visible_y = client_height / scroll_height;
         let scroll_thumb_height = scroll_track_height * visible_y;
@@ -315,6 +392,34 @@ pub trait Element: Any + StandardElementClone + Send + Sync {
     /// Called on sequential renders to update any state that the element may have.
     fn update_state(&mut self, _element_state: &mut ElementStateStore, _reload_fonts: bool, _scaling_factor: f64) {}
 
+    /// Advances the timers of the element, like the hover delay of a `Tooltip`, to the time of the frame. This is
+    /// called once per frame, before the tree is laid out.
+    /// Returns whether a timer is still running, in which case another frame should be drawn.
+    fn advance_timers(&mut self, _element_state: &mut ElementStateStore, _now: time::Instant) -> bool {
+        false
+    }
+
+    /// Returns the children to diff against the old tree, so that an element can create its children on demand,
+    /// like the visible rows of a `VirtualList`. The state of the element is up-to-date when this is called.
+    fn materialize_children(
+        &mut self,
+        children: Vec<ComponentSpecification>,
+        _element_state: &mut ElementStateStore,
+        _window: &WindowContext,
+        _scaling_factor: f64,
+    ) -> Vec<ComponentSpecification> {
+        children
+    }
+
+    /// The number of keyed children whose state is kept after they are unmounted, so that they get it back when they
+    /// are mounted again. The state of the children that were unmounted the longest ago is removed first, and the
+    /// state of all of them is removed with the element.
+    ///
+    /// Keyed children of an element that retains them are only matched to old children by their key.
+    fn retained_children_limit(&self) -> usize {
+        0
+    }
+
     fn default_style(&self) -> Style {
         Style::default()
     }
@@ -340,6 +445,33 @@ pub trait Element: Any + StandardElementClone + Send + Sync {
     fn computed_border(&self) -> &ComputedBorderSpec {
         &self.element_data().layout_item.computed_border
     }
+
+    /// The role reported to assistive technologies, unless it is overridden with `accessibility_role`.
+    fn default_accessibility_role(&self) -> Role {
+        Role::GenericContainer
+    }
+
+    /// Adds the element specific properties, like the current value, and the supported actions to the accessibility node.
+    fn update_accessibility_node(&self, _node: &mut Node, _element_state: &ElementStateStore) {}
+
+    /// Adds the properties of a child that depend on this element, like whether a dropdown item is selected.
+    fn update_accessibility_child_node(&self, _index: usize, _node: &mut Node, _element_state: &ElementStateStore) {}
+
+    /// The children that are exposed to assistive technologies.
+    fn accessibility_children(&self, _element_state: &ElementStateStore) -> Vec<&dyn Element> {
+        self.children()
+    }
+
+    /// Whether the element can receive the focus.
+    fn is_focusable(&self) -> bool {
+        false
+    }
+
+    /// Whether the element is modal, like an open dialog. While a modal element is in the tree, the events stay inside
+    /// of the topmost one.
+    fn is_modal(&self) -> bool {
+        false
+    }
 }
 
 impl<T: Element> From<T> for ElementBoxed {
@@ -475,6 +607,110 @@ macro_rules! generate_component_methods_no_children {
             self
         }
 
+        #[allow(dead_code)]
+        /// Animates the changes of `properties`, for example between the normal and the hovered style.
+        ///
+        /// Only the properties in [`ANIMATABLE_PROPERTIES`] are interpolated, the others change immediately.
+        ///
+        /// [`ANIMATABLE_PROPERTIES`]: $crate::style::ANIMATABLE_PROPERTIES
+        pub fn transition(
+            mut self,
+            properties: $crate::style::StyleFlags,
+            duration: std::time::Duration,
+            easing: $crate::style::Easing,
+        ) -> Self {
+            self.element_data.transitions.push($crate::style::Transition::new(properties, duration, easing));
+            self
+        }
+
+        #[allow(dead_code)]
+        /// Plays a keyframe animation when the element is displayed.
+        pub fn animation(mut self, animation: $crate::style::Animation) -> Self {
+            self.element_data.animation = Some(animation);
+            self
+        }
+
+        #[allow(dead_code)]
+        /// Sets the label that assistive technologies, like screen readers, announce for the element.
+        pub fn accessibility_label(mut self, label: &str) -> Self {
+            self.element_data.accessibility_label = Some(label.to_string());
+            self
+        }
+
+        #[allow(dead_code)]
+        /// Overrides the role that assistive technologies report for the element.
+        pub fn accessibility_role(mut self, role: $crate::accessibility::Role) -> Self {
+            self.element_data.accessibility_role = Some(role);
+            self
+        }
+
+        #[allow(dead_code)]
+        /// Sets the position of the element in the tab order.
+        ///
+        /// Elements with a positive index are visited first, in ascending order, followed by the focusable elements
+        /// with an index of 0 or no index, in layout order. Any element with an index can receive the focus,
+        /// but a negative index removes it from the tab order.
+        pub fn tab_index(mut self, tab_index: i32) -> Self {
+            self.element_data.tab_index = Some(tab_index);
+            self
+        }
+
+        #[allow(dead_code)]
+        /// Lets the element be dragged with the pointer, carrying `payload` to the elements that it is dragged over
+        /// and dropped on.
+        pub fn draggable<T: Any + Send + Sync>(mut self, payload: T) -> Self {
+            self.element_data.drag_payload = Some(Props::new(payload));
+            self
+        }
+
+        #[allow(dead_code)]
+        /// Sets how far, in pixels, the pointer has to move while it is pressed before the element is dragged.
+        /// Defaults to 4 pixels.
+        pub fn drag_threshold(mut self, drag_threshold: f32) -> Self {
+            self.element_data.drag_threshold = Some(drag_threshold);
+            self
+        }
+
+        #[allow(dead_code)]
+        /// Whether a translucent copy of the element follows the pointer while it is dragged. Defaults to false.
+        pub fn drag_preview(mut self, drag_preview: bool) -> Self {
+            self.element_data.drag_preview = drag_preview;
+            self
+        }
+
+        #[allow(dead_code)]
+        /// Sets the capture handler of the element, which receives the events of the element and its descendants in
+        /// the capture phase, from the root to the target, before they bubble.
+        ///
+        /// Call `Event::prevent_propagate` or `Event::stop_immediate_propagation` to intercept an event, e.g. for a
+        /// global shortcut.
+        pub fn on_capture<State, GlobalState, Handler>(mut self, handler: Handler) -> Self
+        where
+            State: Any + Send + Sync + 'static,
+            GlobalState: Any + Send + Sync + Default + 'static,
+            Handler: Fn(&mut State, &mut GlobalState, &mut $crate::components::Event, &$crate::events::CraftMessage)
+                + Send
+                + Sync
+                + 'static,
+        {
+            use $crate::components::Event;
+            use $crate::elements::element_data::EventHandlerWithRef;
+            use $crate::events::CraftMessage;
+
+            let callback: EventHandlerWithRef<CraftMessage> = Arc::new(
+                move |state_any: &mut dyn Any,
+                      global_any: &mut dyn Any,
+                      event: &mut Event,
+                      message: &CraftMessage| {
+                    let state = state_any.downcast_mut::<State>().unwrap();
+                    let global = global_any.downcast_mut::<GlobalState>().unwrap();
+                    handler(state, global, event, message);
+                },
+            );
+            self.element_data_mut().on_capture = Some(callback);
+            self
+        }
+
         #[allow(dead_code)]
         /// Sets the on_pointer_button handler for the element.
         pub fn on_pointer_button<State, GlobalState, Handler>(mut self, handler: Handler) -> Self
@@ -583,6 +819,299 @@ macro_rules! generate_component_methods_no_children {
             self
         }
 
+        #[allow(dead_code)]
+        /// Sets the on_pointer_enter handler for the element.
+        pub fn on_pointer_enter<State, GlobalState, Handler>(mut self, handler: Handler) -> Self
+        where
+            State: Any + Send + Sync + 'static,
+            GlobalState: Any + Send + Sync + Default + 'static,
+            Handler: Fn(&mut State, &mut GlobalState, &mut $crate::components::Event, &$crate::events::PointerMoved)
+                + Send
+                + Sync
+                + 'static,
+        {
+            use $crate::components::Event;
+            use $crate::elements::element_data::EventHandlerWithRef;
+            use $crate::events::PointerMoved;
+
+            let callback: EventHandlerWithRef<PointerMoved> = Arc::new(
+                move |state_any: &mut dyn Any,
+                      global_any: &mut dyn Any,
+                      event: &mut Event,
+                      pointer_moved: &PointerMoved| {
+                    let state = state_any.downcast_mut::<State>().unwrap();
+                    let global = global_any.downcast_mut::<GlobalState>().unwrap();
+                    handler(state, global, event, pointer_moved);
+                },
+            );
+            self.element_data_mut().on_pointer_enter = Some(callback);
+            self
+        }
+
+        #[allow(dead_code)]
+        /// Sets the on_pointer_leave handler for the element.
+        pub fn on_pointer_leave<State, GlobalState, Handler>(mut self, handler: Handler) -> Self
+        where
+            State: Any + Send + Sync + 'static,
+            GlobalState: Any + Send + Sync + Default + 'static,
+            Handler: Fn(&mut State, &mut GlobalState, &mut $crate::components::Event, &$crate::events::PointerMoved)
+                + Send
+                + Sync
+                + 'static,
+        {
+            use $crate::components::Event;
+            use $crate::elements::element_data::EventHandlerWithRef;
+            use $crate::events::PointerMoved;
+
+            let callback: EventHandlerWithRef<PointerMoved> = Arc::new(
+                move |state_any: &mut dyn Any,
+                      global_any: &mut dyn Any,
+                      event: &mut Event,
+                      pointer_moved: &PointerMoved| {
+                    let state = state_any.downcast_mut::<State>().unwrap();
+                    let global = global_any.downcast_mut::<GlobalState>().unwrap();
+                    handler(state, global, event, pointer_moved);
+                },
+            );
+            self.element_data_mut().on_pointer_leave = Some(callback);
+            self
+        }
+
+        #[allow(dead_code)]
+        /// Sets the on_click handler for the element.
+        pub fn on_click<State, GlobalState, Handler>(mut self, handler: Handler) -> Self
+        where
+            State: Any + Send + Sync + 'static,
+            GlobalState: Any + Send + Sync + Default + 'static,
+            Handler: Fn(&mut State, &mut GlobalState, &mut $crate::components::Event, $crate::events::Click)
+                + Send
+                + Sync
+                + 'static,
+        {
+            use $crate::elements::element_data::EventHandlerCopy;
+            use $crate::events::Click;
+
+            let callback: EventHandlerCopy<Click> = Arc::new(move |state_any, global_any, event, click| {
+                let state = state_any.downcast_mut::<State>().unwrap();
+                let global = global_any.downcast_mut::<GlobalState>().unwrap();
+                handler(state, global, event, click);
+            });
+            self.element_data_mut().on_click = Some(callback);
+            self
+        }
+
+        #[allow(dead_code)]
+        /// Sets the on_drag_start handler for the element.
+        pub fn on_drag_start<State, GlobalState, Handler>(mut self, handler: Handler) -> Self
+        where
+            State: Any + Send + Sync + 'static,
+            GlobalState: Any + Send + Sync + Default + 'static,
+            Handler: Fn(&mut State, &mut GlobalState, &mut $crate::components::Event, &$crate::events::Drag)
+                + Send
+                + Sync
+                + 'static,
+        {
+            use $crate::components::Event;
+            use $crate::elements::element_data::EventHandlerWithRef;
+            use $crate::events::Drag;
+
+            let callback: EventHandlerWithRef<Drag> =
+                Arc::new(move |state_any: &mut dyn Any, global_any: &mut dyn Any, event: &mut Event, drag: &Drag| {
+                    let state = state_any.downcast_mut::<State>().unwrap();
+                    let global = global_any.downcast_mut::<GlobalState>().unwrap();
+                    handler(state, global, event, drag);
+                });
+            self.element_data_mut().on_drag_start = Some(callback);
+            self
+        }
+
+        #[allow(dead_code)]
+        /// Sets the on_drag_over handler for the element.
+        pub fn on_drag_over<State, GlobalState, Handler>(mut self, handler: Handler) -> Self
+        where
+            State: Any + Send + Sync + 'static,
+            GlobalState: Any + Send + Sync + Default + 'static,
+            Handler: Fn(&mut State, &mut GlobalState, &mut $crate::components::Event, &$crate::events::Drag)
+                + Send
+                + Sync
+                + 'static,
+        {
+            use $crate::components::Event;
+            use $crate::elements::element_data::EventHandlerWithRef;
+            use $crate::events::Drag;
+
+            let callback: EventHandlerWithRef<Drag> =
+                Arc::new(move |state_any: &mut dyn Any, global_any: &mut dyn Any, event: &mut Event, drag: &Drag| {
+                    let state = state_any.downcast_mut::<State>().unwrap();
+                    let global = global_any.downcast_mut::<GlobalState>().unwrap();
+                    handler(state, global, event, drag);
+                });
+            self.element_data_mut().on_drag_over = Some(callback);
+            self
+        }
+
+        #[allow(dead_code)]
+        /// Sets the on_drag_leave handler for the element.
+        pub fn on_drag_leave<State, GlobalState, Handler>(mut self, handler: Handler) -> Self
+        where
+            State: Any + Send + Sync + 'static,
+            GlobalState: Any + Send + Sync + Default + 'static,
+            Handler: Fn(&mut State, &mut GlobalState, &mut $crate::components::Event, &$crate::events::Drag)
+                + Send
+                + Sync
+                + 'static,
+        {
+            use $crate::components::Event;
+            use $crate::elements::element_data::EventHandlerWithRef;
+            use $crate::events::Drag;
+
+            let callback: EventHandlerWithRef<Drag> =
+                Arc::new(move |state_any: &mut dyn Any, global_any: &mut dyn Any, event: &mut Event, drag: &Drag| {
+                    let state = state_any.downcast_mut::<State>().unwrap();
+                    let global = global_any.downcast_mut::<GlobalState>().unwrap();
+                    handler(state, global, event, drag);
+                });
+            self.element_data_mut().on_drag_leave = Some(callback);
+            self
+        }
+
+        #[allow(dead_code)]
+        /// Sets the on_drop handler for the element.
+        pub fn on_drop<State, GlobalState, Handler>(mut self, handler: Handler) -> Self
+        where
+            State: Any + Send + Sync + 'static,
+            GlobalState: Any + Send + Sync + Default + 'static,
+            Handler: Fn(&mut State, &mut GlobalState, &mut $crate::components::Event, &$crate::events::Drag)
+                + Send
+                + Sync
+                + 'static,
+        {
+            use $crate::components::Event;
+            use $crate::elements::element_data::EventHandlerWithRef;
+            use $crate::events::Drag;
+
+            let callback: EventHandlerWithRef<Drag> =
+                Arc::new(move |state_any: &mut dyn Any, global_any: &mut dyn Any, event: &mut Event, drag: &Drag| {
+                    let state = state_any.downcast_mut::<State>().unwrap();
+                    let global = global_any.downcast_mut::<GlobalState>().unwrap();
+                    handler(state, global, event, drag);
+                });
+            self.element_data_mut().on_drop = Some(callback);
+            self
+        }
+
+        #[allow(dead_code)]
+        /// Sets the on_drag_end handler for the element.
+        pub fn on_drag_end<State, GlobalState, Handler>(mut self, handler: Handler) -> Self
+        where
+            State: Any + Send + Sync + 'static,
+            GlobalState: Any + Send + Sync + Default + 'static,
+            Handler: Fn(&mut State, &mut GlobalState, &mut $crate::components::Event, &$crate::events::Drag)
+                + Send
+                + Sync
+                + 'static,
+        {
+            use $crate::components::Event;
+            use $crate::elements::element_data::EventHandlerWithRef;
+            use $crate::events::Drag;
+
+            let callback: EventHandlerWithRef<Drag> =
+                Arc::new(move |state_any: &mut dyn Any, global_any: &mut dyn Any, event: &mut Event, drag: &Drag| {
+                    let state = state_any.downcast_mut::<State>().unwrap();
+                    let global = global_any.downcast_mut::<GlobalState>().unwrap();
+                    handler(state, global, event, drag);
+                });
+            self.element_data_mut().on_drag_end = Some(callback);
+            self
+        }
+
+        #[allow(dead_code)]
+        /// Sets the on_file_hovered handler for the element.
+        pub fn on_file_hovered<State, GlobalState, Handler>(mut self, handler: Handler) -> Self
+        where
+            State: Any + Send + Sync + 'static,
+            GlobalState: Any + Send + Sync + Default + 'static,
+            Handler: Fn(&mut State, &mut GlobalState, &mut $crate::components::Event, &$crate::events::FileDrag)
+                + Send
+                + Sync
+                + 'static,
+        {
+            use $crate::components::Event;
+            use $crate::elements::element_data::EventHandlerWithRef;
+            use $crate::events::FileDrag;
+
+            let callback: EventHandlerWithRef<FileDrag> = Arc::new(
+                move |state_any: &mut dyn Any,
+                      global_any: &mut dyn Any,
+                      event: &mut Event,
+                      file_drag: &FileDrag| {
+                    let state = state_any.downcast_mut::<State>().unwrap();
+                    let global = global_any.downcast_mut::<GlobalState>().unwrap();
+                    handler(state, global, event, file_drag);
+                },
+            );
+            self.element_data_mut().on_file_hovered = Some(callback);
+            self
+        }
+
+        #[allow(dead_code)]
+        /// Sets the on_file_hover_cancelled handler for the element.
+        pub fn on_file_hover_cancelled<State, GlobalState, Handler>(mut self, handler: Handler) -> Self
+        where
+            State: Any + Send + Sync + 'static,
+            GlobalState: Any + Send + Sync + Default + 'static,
+            Handler: Fn(&mut State, &mut GlobalState, &mut $crate::components::Event, &$crate::events::FileDrag)
+                + Send
+                + Sync
+                + 'static,
+        {
+            use $crate::components::Event;
+            use $crate::elements::element_data::EventHandlerWithRef;
+            use $crate::events::FileDrag;
+
+            let callback: EventHandlerWithRef<FileDrag> = Arc::new(
+                move |state_any: &mut dyn Any,
+                      global_any: &mut dyn Any,
+                      event: &mut Event,
+                      file_drag: &FileDrag| {
+                    let state = state_any.downcast_mut::<State>().unwrap();
+                    let global = global_any.downcast_mut::<GlobalState>().unwrap();
+                    handler(state, global, event, file_drag);
+                },
+            );
+            self.element_data_mut().on_file_hover_cancelled = Some(callback);
+            self
+        }
+
+        #[allow(dead_code)]
+        /// Sets the on_file_dropped handler for the element.
+        pub fn on_file_dropped<State, GlobalState, Handler>(mut self, handler: Handler) -> Self
+        where
+            State: Any + Send + Sync + 'static,
+            GlobalState: Any + Send + Sync + Default + 'static,
+            Handler: Fn(&mut State, &mut GlobalState, &mut $crate::components::Event, &$crate::events::FileDrag)
+                + Send
+                + Sync
+                + 'static,
+        {
+            use $crate::components::Event;
+            use $crate::elements::element_data::EventHandlerWithRef;
+            use $crate::events::FileDrag;
+
+            let callback: EventHandlerWithRef<FileDrag> = Arc::new(
+                move |state_any: &mut dyn Any,
+                      global_any: &mut dyn Any,
+                      event: &mut Event,
+                      file_drag: &FileDrag| {
+                    let state = state_any.downcast_mut::<State>().unwrap();
+                    let global = global_any.downcast_mut::<GlobalState>().unwrap();
+                    handler(state, global, event, file_drag);
+                },
+            );
+            self.element_data_mut().on_file_dropped = Some(callback);
+            self
+        }
+
         #[allow(dead_code)]
         /// Sets the on_mouse_wheel handler for the element.
         pub fn on_mouse_wheel<State, GlobalState, Handler>(mut self, handler: Handler) -> Self
@@ -677,6 +1206,25 @@ macro_rules! generate_component_methods_no_children {
             self
         }
 
+        #[allow(dead_code)]
+        /// Sets the on_text_input_submitted handler for the element.
+        pub fn on_text_input_submitted<State, GlobalState, Handler>(mut self, handler: Handler) -> Self
+        where
+            State: Any + Send + Sync + 'static,
+            GlobalState: Any + Send + Sync + Default + 'static,
+            Handler: Fn(&mut State, &mut GlobalState, &mut $crate::components::Event, &str) + Send + Sync + 'static,
+        {
+            use $crate::elements::element_data::EventHandlerWithRef;
+
+            let callback: EventHandlerWithRef<str> = Arc::new(move |state_any, global_any, event, text| {
+                let state = state_any.downcast_mut::<State>().unwrap();
+                let global = global_any.downcast_mut::<GlobalState>().unwrap();
+                handler(state, global, event, text);
+            });
+            self.element_data_mut().on_text_input_submitted = Some(callback);
+            self
+        }
+
         #[allow(dead_code)]
         /// Sets the on_dropdown_toggled handler for the element.
         pub fn on_dropdown_toggled<State, GlobalState, Handler>(mut self, handler: Handler) -> Self
@@ -734,6 +1282,44 @@ macro_rules! generate_component_methods_no_children {
             self
         }
 
+        #[allow(dead_code)]
+        /// Sets the on_checkbox_toggled handler for the element.
+        pub fn on_checkbox_toggled<State, GlobalState, Handler>(mut self, handler: Handler) -> Self
+        where
+            State: Any + Send + Sync + 'static,
+            GlobalState: Any + Send + Sync + Default + 'static,
+            Handler: Fn(&mut State, &mut GlobalState, &mut $crate::components::Event, bool) + Send + Sync + 'static,
+        {
+            use $crate::elements::element_data::EventHandlerCopy;
+
+            let callback: EventHandlerCopy<bool> = Arc::new(move |state_any, global_any, event, checked| {
+                let state = state_any.downcast_mut::<State>().unwrap();
+                let global = global_any.downcast_mut::<GlobalState>().unwrap();
+                handler(state, global, event, checked);
+            });
+            self.element_data_mut().on_checkbox_toggled = Some(callback);
+            self
+        }
+
+        #[allow(dead_code)]
+        /// Sets the on_radio_selected handler for the element.
+        pub fn on_radio_selected<State, GlobalState, Handler>(mut self, handler: Handler) -> Self
+        where
+            State: Any + Send + Sync + 'static,
+            GlobalState: Any + Send + Sync + Default + 'static,
+            Handler: Fn(&mut State, &mut GlobalState, &mut $crate::components::Event, &str) + Send + Sync + 'static,
+        {
+            use $crate::elements::element_data::EventHandlerWithRef;
+
+            let callback: EventHandlerWithRef<str> = Arc::new(move |state_any, global_any, event, value| {
+                let state = state_any.downcast_mut::<State>().unwrap();
+                let global = global_any.downcast_mut::<GlobalState>().unwrap();
+                handler(state, global, event, value);
+            });
+            self.element_data_mut().on_radio_selected = Some(callback);
+            self
+        }
+
         #[allow(dead_code)]
         /// Sets the on_slider_value_changed handler for the element.
         pub fn on_slider_value_changed<State, GlobalState, Handler>(mut self, handler: Handler) -> Self
@@ -752,6 +1338,191 @@ macro_rules! generate_component_methods_no_children {
             self.element_data_mut().on_slider_value_changed = Some(callback);
             self
         }
+
+        #[allow(dead_code)]
+        /// Sets the on_table_sorted handler for the element.
+        pub fn on_table_sorted<State, GlobalState, Handler>(mut self, handler: Handler) -> Self
+        where
+            State: Any + Send + Sync + 'static,
+            GlobalState: Any + Send + Sync + Default + 'static,
+            Handler: Fn(&mut State, &mut GlobalState, &mut $crate::components::Event, usize, $crate::elements::SortDirection)
+                + Send
+                + Sync
+                + 'static,
+        {
+            use $crate::elements::element_data::EventHandlerCopy;
+
+            let callback: EventHandlerCopy<(usize, $crate::elements::SortDirection)> =
+                Arc::new(move |state_any, global_any, event, (column, direction)| {
+                    let state = state_any.downcast_mut::<State>().unwrap();
+                    let global = global_any.downcast_mut::<GlobalState>().unwrap();
+                    handler(state, global, event, column, direction);
+                });
+            self.element_data_mut().on_table_sorted = Some(callback);
+            self
+        }
+
+        #[allow(dead_code)]
+        /// Sets the on_table_column_resized handler for the element.
+        pub fn on_table_column_resized<State, GlobalState, Handler>(mut self, handler: Handler) -> Self
+        where
+            State: Any + Send + Sync + 'static,
+            GlobalState: Any + Send + Sync + Default + 'static,
+            Handler: Fn(&mut State, &mut GlobalState, &mut $crate::components::Event, usize, f32) + Send + Sync + 'static,
+        {
+            use $crate::elements::element_data::EventHandlerCopy;
+
+            let callback: EventHandlerCopy<(usize, f32)> = Arc::new(move |state_any, global_any, event, (column, width)| {
+                let state = state_any.downcast_mut::<State>().unwrap();
+                let global = global_any.downcast_mut::<GlobalState>().unwrap();
+                handler(state, global, event, column, width);
+            });
+            self.element_data_mut().on_table_column_resized = Some(callback);
+            self
+        }
+
+        #[allow(dead_code)]
+        /// Sets the on_table_selection_changed handler for the element.
+        pub fn on_table_selection_changed<State, GlobalState, Handler>(mut self, handler: Handler) -> Self
+        where
+            State: Any + Send + Sync + 'static,
+            GlobalState: Any + Send + Sync + Default + 'static,
+            Handler: Fn(&mut State, &mut GlobalState, &mut $crate::components::Event, usize, Option<usize>)
+                + Send
+                + Sync
+                + 'static,
+        {
+            use $crate::elements::element_data::EventHandlerCopy;
+
+            let callback: EventHandlerCopy<(usize, Option<usize>)> =
+                Arc::new(move |state_any, global_any, event, (row, column)| {
+                    let state = state_any.downcast_mut::<State>().unwrap();
+                    let global = global_any.downcast_mut::<GlobalState>().unwrap();
+                    handler(state, global, event, row, column);
+                });
+            self.element_data_mut().on_table_selection_changed = Some(callback);
+            self
+        }
+
+        #[allow(dead_code)]
+        /// Sets the on_menu_item_selected handler for the element.
+        pub fn on_menu_item_selected<State, GlobalState, Handler>(mut self, handler: Handler) -> Self
+        where
+            State: Any + Send + Sync + 'static,
+            GlobalState: Any + Send + Sync + Default + 'static,
+            Handler: Fn(&mut State, &mut GlobalState, &mut $crate::components::Event, &str) + Send + Sync + 'static,
+        {
+            use $crate::elements::element_data::EventHandlerWithRef;
+
+            let callback: EventHandlerWithRef<str> = Arc::new(move |state_any, global_any, event, id| {
+                let state = state_any.downcast_mut::<State>().unwrap();
+                let global = global_any.downcast_mut::<GlobalState>().unwrap();
+                handler(state, global, event, id);
+            });
+            self.element_data_mut().on_menu_item_selected = Some(callback);
+            self
+        }
+
+        #[allow(dead_code)]
+        /// Sets the on_tab_changed handler for the element.
+        pub fn on_tab_changed<State, GlobalState, Handler>(mut self, handler: Handler) -> Self
+        where
+            State: Any + Send + Sync + 'static,
+            GlobalState: Any + Send + Sync + Default + 'static,
+            Handler: Fn(&mut State, &mut GlobalState, &mut $crate::components::Event, usize) + Send + Sync + 'static,
+        {
+            use $crate::elements::element_data::EventHandlerCopy;
+
+            let callback: EventHandlerCopy<usize> = Arc::new(move |state_any, global_any, event, value| {
+                let state = state_any.downcast_mut::<State>().unwrap();
+                let global = global_any.downcast_mut::<GlobalState>().unwrap();
+                handler(state, global, event, value);
+            });
+            self.element_data_mut().on_tab_changed = Some(callback);
+            self
+        }
+
+        #[allow(dead_code)]
+        /// Sets the on_tab_closed handler for the element.
+        pub fn on_tab_closed<State, GlobalState, Handler>(mut self, handler: Handler) -> Self
+        where
+            State: Any + Send + Sync + 'static,
+            GlobalState: Any + Send + Sync + Default + 'static,
+            Handler: Fn(&mut State, &mut GlobalState, &mut $crate::components::Event, usize) + Send + Sync + 'static,
+        {
+            use $crate::elements::element_data::EventHandlerCopy;
+
+            let callback: EventHandlerCopy<usize> = Arc::new(move |state_any, global_any, event, value| {
+                let state = state_any.downcast_mut::<State>().unwrap();
+                let global = global_any.downcast_mut::<GlobalState>().unwrap();
+                handler(state, global, event, value);
+            });
+            self.element_data_mut().on_tab_closed = Some(callback);
+            self
+        }
+
+        #[allow(dead_code)]
+        /// Sets the on_dialog_closed handler for the element.
+        pub fn on_dialog_closed<State, GlobalState, Handler>(mut self, handler: Handler) -> Self
+        where
+            State: Any + Send + Sync + 'static,
+            GlobalState: Any + Send + Sync + Default + 'static,
+            Handler: Fn(&mut State, &mut GlobalState, &mut $crate::components::Event) + Send + Sync + 'static,
+        {
+            use $crate::components::Event;
+            use $crate::elements::element_data::EventHandler;
+
+            let callback: EventHandler =
+                Arc::new(move |state_any: &mut dyn Any, global_any: &mut dyn Any, event: &mut Event| {
+                    let state = state_any.downcast_mut::<State>().unwrap();
+                    let global = global_any.downcast_mut::<GlobalState>().unwrap();
+                    handler(state, global, event);
+                });
+            self.element_data_mut().on_dialog_closed = Some(callback);
+            self
+        }
+
+        #[allow(dead_code)]
+        /// Sets the on_focus handler for the element.
+        pub fn on_focus<State, GlobalState, Handler>(mut self, handler: Handler) -> Self
+        where
+            State: Any + Send + Sync + 'static,
+            GlobalState: Any + Send + Sync + Default + 'static,
+            Handler: Fn(&mut State, &mut GlobalState, &mut $crate::components::Event) + Send + Sync + 'static,
+        {
+            use $crate::components::Event;
+            use $crate::elements::element_data::EventHandler;
+
+            let callback: EventHandler =
+                Arc::new(move |state_any: &mut dyn Any, global_any: &mut dyn Any, event: &mut Event| {
+                    let state = state_any.downcast_mut::<State>().unwrap();
+                    let global = global_any.downcast_mut::<GlobalState>().unwrap();
+                    handler(state, global, event);
+                });
+            self.element_data_mut().on_focus = Some(callback);
+            self
+        }
+
+        #[allow(dead_code)]
+        /// Sets the on_blur handler for the element.
+        pub fn on_blur<State, GlobalState, Handler>(mut self, handler: Handler) -> Self
+        where
+            State: Any + Send + Sync + 'static,
+            GlobalState: Any + Send + Sync + Default + 'static,
+            Handler: Fn(&mut State, &mut GlobalState, &mut $crate::components::Event) + Send + Sync + 'static,
+        {
+            use $crate::components::Event;
+            use $crate::elements::element_data::EventHandler;
+
+            let callback: EventHandler =
+                Arc::new(move |state_any: &mut dyn Any, global_any: &mut dyn Any, event: &mut Event| {
+                    let state = state_any.downcast_mut::<State>().unwrap();
+                    let global = global_any.downcast_mut::<GlobalState>().unwrap();
+                    handler(state, global, event);
+                });
+            self.element_data_mut().on_blur = Some(callback);
+            self
+        }
     };
 }
 
@@ -2,9 +2,11 @@ use crate::components::{ComponentId, ComponentSpecification};
 use crate::components::{Event, Props};
 use crate::elements::element::ElementBoxed;
 use crate::elements::element_states::ElementState;
-use crate::events::{KeyboardInput, MouseWheel, PointerButton, PointerMoved};
+use crate::elements::SortDirection;
+use crate::events::{Click, CraftMessage, Drag, FileDrag, KeyboardInput, MouseWheel, PointerButton, PointerMoved};
 use crate::layout::layout_item::LayoutItem;
-use crate::style::Style;
+use crate::style::{Animation, Style, Transition};
+use accesskit::Role;
 use std::any::Any;
 use std::sync::Arc;
 use winit::event::{Ime, Modifiers};
@@ -36,6 +38,12 @@ pub struct ElementData {
     /// The style of the element when it is focused.
     pub focused_style: Option<Box<Style>>,
 
+    /// Animates the changes of style properties, like a switch from the normal to the hovered style.
+    pub(crate) transitions: Vec<Transition>,
+
+    /// A keyframe animation that plays when the element is displayed.
+    pub(crate) animation: Option<Animation>,
+
     /// The children of the element.
     pub children: Vec<ElementBoxed>,
     
@@ -50,19 +58,57 @@ pub struct ElementData {
     pub(crate) key: Option<String>,
     pub(crate) props: Option<Props>,
 
+    /// The label announced by assistive technologies.
+    pub(crate) accessibility_label: Option<String>,
+    /// Overrides the default accessibility role of the element.
+    pub(crate) accessibility_role: Option<Role>,
+    /// The position of the element in the tab order, see `tab_index` for details.
+    pub(crate) tab_index: Option<i32>,
+    /// The payload of the element when it can be dragged, see `draggable`.
+    pub(crate) drag_payload: Option<Props>,
+    /// How far the pointer has to move while it is pressed before the element is dragged.
+    pub(crate) drag_threshold: Option<f32>,
+    /// Whether a translucent copy of the element follows the pointer while it is dragged.
+    pub(crate) drag_preview: bool,
+
+    /// Runs for the events of the element and its descendants before they reach the target, see `on_capture`.
+    pub(crate) on_capture: Option<EventHandlerWithRef<CraftMessage>>,
     pub(crate) on_pointer_button: Option<EventHandlerWithRef<PointerButton>>,
     pub(crate) on_initialized: Option<EventHandler>,
     pub(crate) on_keyboard_input: Option<EventHandlerWithRef<KeyboardInput>>,
     pub(crate) on_pointer_move: Option<EventHandlerWithRef<PointerMoved>>,
+    pub(crate) on_pointer_enter: Option<EventHandlerWithRef<PointerMoved>>,
+    pub(crate) on_pointer_leave: Option<EventHandlerWithRef<PointerMoved>>,
+    pub(crate) on_click: Option<EventHandlerCopy<Click>>,
+    pub(crate) on_drag_start: Option<EventHandlerWithRef<Drag>>,
+    pub(crate) on_drag_over: Option<EventHandlerWithRef<Drag>>,
+    pub(crate) on_drag_leave: Option<EventHandlerWithRef<Drag>>,
+    pub(crate) on_drop: Option<EventHandlerWithRef<Drag>>,
+    pub(crate) on_drag_end: Option<EventHandlerWithRef<Drag>>,
+    pub(crate) on_file_hovered: Option<EventHandlerWithRef<FileDrag>>,
+    pub(crate) on_file_hover_cancelled: Option<EventHandlerWithRef<FileDrag>>,
+    pub(crate) on_file_dropped: Option<EventHandlerWithRef<FileDrag>>,
     pub(crate) on_mouse_wheel: Option<EventHandlerWithRef<MouseWheel>>,
     pub(crate) on_modifiers_changed: Option<EventHandlerWithRef<Modifiers>>,
     pub(crate) on_ime: Option<EventHandlerWithRef<Ime>>,
     pub(crate) on_text_input_changed: Option<EventHandlerWithRef<str>>,
+    pub(crate) on_text_input_submitted: Option<EventHandlerWithRef<str>>,
 
     pub(crate) on_dropdown_toggled: Option<EventHandlerCopy<bool>>,
     pub(crate) on_dropdown_item_selected: Option<EventHandlerCopy<usize>>,
     pub(crate) on_switch_toggled: Option<EventHandlerCopy<bool>>,
+    pub(crate) on_checkbox_toggled: Option<EventHandlerCopy<bool>>,
+    pub(crate) on_radio_selected: Option<EventHandlerWithRef<str>>,
     pub(crate) on_slider_value_changed: Option<EventHandlerCopy<f64>>,
+    pub(crate) on_table_sorted: Option<EventHandlerCopy<(usize, SortDirection)>>,
+    pub(crate) on_table_column_resized: Option<EventHandlerCopy<(usize, f32)>>,
+    pub(crate) on_table_selection_changed: Option<EventHandlerCopy<(usize, Option<usize>)>>,
+    pub(crate) on_menu_item_selected: Option<EventHandlerWithRef<str>>,
+    pub(crate) on_tab_changed: Option<EventHandlerCopy<usize>>,
+    pub(crate) on_tab_closed: Option<EventHandlerCopy<usize>>,
+    pub(crate) on_dialog_closed: Option<EventHandler>,
+    pub(crate) on_focus: Option<EventHandler>,
+    pub(crate) on_blur: Option<EventHandler>,
 }
 
 impl ElementData {
@@ -77,7 +123,7 @@ impl ElementData {
                 if let Some(ref mut hover_style) = self.hover_style {
                     hover_style
                 } else {
-                    self.hover_style = Some(Box::new(self.style));
+                    self.hover_style = Some(Box::new(self.style.clone()));
                     self.hover_style.as_mut().unwrap()
                 }
             }
@@ -85,7 +131,7 @@ impl ElementData {
                 if let Some(ref mut pressed_style) = self.pressed_style {
                     pressed_style
                 } else {
-                    self.pressed_style = Some(Box::new(self.style));
+                    self.pressed_style = Some(Box::new(self.style.clone()));
                     self.pressed_style.as_mut().unwrap()
                 }
             }
@@ -93,7 +139,7 @@ impl ElementData {
                 if let Some(ref mut disabled_style) = self.disabled_style {
                     disabled_style
                 } else {
-                    self.disabled_style = Some(Box::new(self.style));
+                    self.disabled_style = Some(Box::new(self.style.clone()));
                     self.disabled_style.as_mut().unwrap()
                 }
             }
@@ -101,7 +147,7 @@ impl ElementData {
                 if let Some(ref mut focused_style) = self.focused_style {
                     focused_style
                 } else {
-                    self.focused_style = Some(Box::new(self.style));
+                    self.focused_style = Some(Box::new(self.style.clone()));
                     self.focused_style.as_mut().unwrap()
                 }
             }
@@ -1,9 +1,12 @@
 use crate::renderer::color::Color;
 use crate::style::{
-    AlignItems, Display, FlexDirection, FontStyle, JustifyContent, Overflow, Style, Unit, Weight, Wrap,
+    AlignItems, BoxShadow, Display, FlexDirection, FontStyle, Gradient, GridAreaName, GridAutoFlow, GridPlacement,
+    GridTemplate, GridTemplateAreas, JustifyContent, Overflow, Style, Transform, Unit, Weight, Wrap,
 };
 use taffy::Position;
 use crate::geometry::TrblRectangle;
+use craft_logging::warn;
+use std::fmt;
 
 pub trait ElementStyles
 where
@@ -16,6 +19,38 @@ where
         self
     }
 
+    /// Draws a gradient above the background color.
+    fn background_gradient(mut self, gradient: Gradient) -> Self {
+        *self.styles_mut().background_gradient_mut() = Some(gradient);
+        self
+    }
+
+    /// Draws a shadow around the element, or inside of it for inset shadows.
+    ///
+    /// The hybrid renderer cannot blur, so it approximates the blur with a few rounded rectangles that fade out.
+    fn box_shadow(mut self, box_shadow: BoxShadow) -> Self {
+        *self.styles_mut().box_shadow_mut() = Some(box_shadow);
+        self
+    }
+
+    /// Sets the opacity of the element and its children, between 0 (transparent) and 1 (opaque).
+    fn opacity(mut self, opacity: f32) -> Self {
+        *self.styles_mut().opacity_mut() = opacity.clamp(0.0, 1.0);
+        self
+    }
+
+    /// Transforms the element and its children when they are drawn. The layout is not affected.
+    fn transform(mut self, transform: Transform) -> Self {
+        *self.styles_mut().transform_mut() = transform;
+        self
+    }
+
+    /// Sets the point that the transform happens around. Defaults to the center of the element.
+    fn transform_origin<U: Into<Unit>>(mut self, x: U, y: U) -> Self {
+        *self.styles_mut().transform_origin_mut() = [x.into(), y.into()];
+        self
+    }
+
     fn margin<U: Into<Unit>>(mut self, top: U, right: U, bottom: U, left: U) -> Self {
         *self.styles_mut().margin_mut() = TrblRectangle::new(top.into(), right.into(), bottom.into(), left.into());
         self
@@ -106,6 +141,65 @@ where
         self
     }
 
+    /// Sets the rows of a grid container, like `"100px 1fr"` or `"repeat(3, minmax(50px, 1fr))"`.
+    ///
+    /// Invalid rows are logged and ignored.
+    fn grid_template_rows<T: TryInto<GridTemplate, Error: fmt::Display>>(mut self, rows: T) -> Self {
+        match rows.try_into() {
+            Ok(rows) => *self.styles_mut().grid_template_rows_mut() = rows,
+            Err(error) => warn!("Ignoring the grid template rows. {error}"),
+        }
+        self
+    }
+
+    /// Sets the columns of a grid container, like `"200px 1fr 1fr"`.
+    ///
+    /// Invalid columns are logged and ignored.
+    fn grid_template_columns<T: TryInto<GridTemplate, Error: fmt::Display>>(mut self, columns: T) -> Self {
+        match columns.try_into() {
+            Ok(columns) => *self.styles_mut().grid_template_columns_mut() = columns,
+            Err(error) => warn!("Ignoring the grid template columns. {error}"),
+        }
+        self
+    }
+
+    /// Names the areas of a grid container, one string per row, like `&["header header", "sidebar main"]`.
+    fn grid_template_areas(mut self, rows: &[&str]) -> Self {
+        *self.styles_mut().grid_template_areas_mut() = GridTemplateAreas::new(rows);
+        self
+    }
+
+    fn grid_auto_flow(mut self, grid_auto_flow: GridAutoFlow) -> Self {
+        *self.styles_mut().grid_auto_flow_mut() = grid_auto_flow;
+        self
+    }
+
+    fn grid_row(mut self, start: GridPlacement, end: GridPlacement) -> Self {
+        *self.styles_mut().grid_row_mut() = [start, end];
+        self
+    }
+
+    fn grid_column(mut self, start: GridPlacement, end: GridPlacement) -> Self {
+        *self.styles_mut().grid_column_mut() = [start, end];
+        self
+    }
+
+    /// Makes the grid item span `span` rows.
+    fn grid_row_span(self, span: u16) -> Self {
+        self.grid_row(GridPlacement::Auto, GridPlacement::Span(span))
+    }
+
+    /// Makes the grid item span `span` columns.
+    fn grid_column_span(self, span: u16) -> Self {
+        self.grid_column(GridPlacement::Auto, GridPlacement::Span(span))
+    }
+
+    /// Places the grid item in a named area of its grid container, see `grid_template_areas`.
+    fn grid_area(mut self, name: &str) -> Self {
+        *self.styles_mut().grid_area_mut() = GridAreaName::new(name);
+        self
+    }
+
     fn width<U: Into<Unit>>(mut self, width: U) -> Self {
         *self.styles_mut().width_mut() = width.into();
         self
@@ -202,12 +296,12 @@ where
     }
 
     fn row_gap<U: Into<Unit>>(mut self, row_gap: U) -> Self {
-        self.styles_mut().gap_mut()[0] = row_gap.into();
+        self.styles_mut().gap_mut()[1] = row_gap.into();
         self
     }
 
     fn column_gap<U: Into<Unit>>(mut self, column_gap: U) -> Self {
-        self.styles_mut().gap_mut()[1] = column_gap.into();
+        self.styles_mut().gap_mut()[0] = column_gap.into();
         self
     }
 
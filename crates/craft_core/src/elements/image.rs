@@ -10,6 +10,7 @@ use crate::renderer::renderer::RenderList;
 use crate::resource_manager::ResourceIdentifier;
 use crate::style::Style;
 use crate::generate_component_methods_no_children;
+use accesskit::Role;
 use std::any::Any;
 use std::sync::Arc;
 use taffy::{NodeId, TaffyTree};
@@ -109,6 +110,10 @@ impl Element for Image {
     fn as_any(&self) -> &dyn Any {
         self
     }
+
+    fn default_accessibility_role(&self) -> Role {
+        Role::Image
+    }
 }
 
 impl Image {
@@ -0,0 +1,194 @@
+use std::ops::Range;
+
+/// An insertion that is about to be made into a [`TextInput`](crate::elements::TextInput).
+#[derive(Clone, Debug)]
+pub struct TextInputEdit<'a> {
+    /// The text before the edit.
+    pub text: &'a str,
+    /// The byte range of `text` that is replaced.
+    pub range: Range<usize>,
+    /// The text that is inserted in place of `range`.
+    pub insert: &'a str,
+}
+
+/// The edit that an [`InputFilter`] makes in place of a [`TextInputEdit`].
+#[derive(Clone, Debug, PartialEq)]
+pub struct FilteredEdit {
+    /// The byte range of the text before the edit that is replaced.
+    pub range: Range<usize>,
+    /// The text that is inserted in place of `range`.
+    pub insert: String,
+    /// The byte offset in `insert` that the cursor is placed at.
+    pub cursor: usize,
+}
+
+/// Checks or transforms the text that is typed, pasted or committed by an input method into a
+/// [`TextInput`](crate::elements::TextInput), before it is inserted.
+pub trait InputFilter: Send + Sync {
+    /// Returns the text to insert instead of `edit.insert`, or `None` to reject the edit.
+    fn filter(&self, edit: &TextInputEdit) -> Option<String>;
+
+    /// Returns the edit to make instead of `edit`, or `None` to reject it. This is called for deletions too, which
+    /// insert an empty text.
+    ///
+    /// By default, the inserted text is checked by [`InputFilter::filter`] and deletions are made as they are. Filters
+    /// that change the text around the edit, like [`InputMask`], replace a larger range.
+    fn filter_edit(&self, edit: &TextInputEdit) -> Option<FilteredEdit> {
+        let insert = if edit.insert.is_empty() { String::new() } else { self.filter(edit)? };
+
+        Some(FilteredEdit {
+            range: edit.range.clone(),
+            cursor: insert.len(),
+            insert,
+        })
+    }
+}
+
+impl<F> InputFilter for F
+where
+    F: Fn(&TextInputEdit) -> Option<String> + Send + Sync,
+{
+    fn filter(&self, edit: &TextInputEdit) -> Option<String> {
+        self(edit)
+    }
+}
+
+/// Drops the characters that are not allowed from the inserted text, and rejects the edit if none are left.
+#[derive(Clone, Copy)]
+pub struct AllowedCharacters(fn(char) -> bool);
+
+impl AllowedCharacters {
+    pub fn new(is_allowed: fn(char) -> bool) -> Self {
+        AllowedCharacters(is_allowed)
+    }
+
+    /// Allows the ASCII digits 0-9.
+    pub fn digits() -> Self {
+        AllowedCharacters(|character| character.is_ascii_digit())
+    }
+}
+
+impl InputFilter for AllowedCharacters {
+    fn filter(&self, edit: &TextInputEdit) -> Option<String> {
+        let filtered: String = edit.insert.chars().filter(|character| (self.0)(*character)).collect();
+        if filtered.is_empty() && !edit.insert.is_empty() {
+            return None;
+        }
+
+        Some(filtered)
+    }
+}
+
+/// Formats the text to match a pattern, such as `(###) ###-####` for phone numbers.
+///
+/// In the pattern, `#` matches a digit, `A` a letter and `*` a letter or a digit. `\` makes the next character literal,
+/// and every other character is literal. Literals are inserted as the user types and characters that do not fit the
+/// pattern are dropped. Text that is inserted or deleted in the middle moves the characters after it to the next or
+/// previous slots, and the ones that no longer fit their slot are dropped.
+#[derive(Clone, Debug)]
+pub struct InputMask {
+    pattern: Vec<MaskSlot>,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum MaskSlot {
+    Digit,
+    Letter,
+    Alphanumeric,
+    Literal(char),
+}
+
+impl MaskSlot {
+    fn accepts(self, character: char) -> bool {
+        match self {
+            MaskSlot::Digit => character.is_ascii_digit(),
+            MaskSlot::Letter => character.is_alphabetic(),
+            MaskSlot::Alphanumeric => character.is_alphanumeric(),
+            MaskSlot::Literal(literal) => character == literal,
+        }
+    }
+}
+
+impl InputMask {
+    pub fn new(pattern: &str) -> Self {
+        let mut slots = Vec::new();
+        let mut characters = pattern.chars();
+        while let Some(character) = characters.next() {
+            slots.push(match character {
+                '#' => MaskSlot::Digit,
+                'A' => MaskSlot::Letter,
+                '*' => MaskSlot::Alphanumeric,
+                '\\' => MaskSlot::Literal(characters.next().unwrap_or('\\')),
+                literal => MaskSlot::Literal(literal),
+            });
+        }
+
+        InputMask { pattern: slots }
+    }
+
+    /// Fits `characters` into the slots from `slot` on. The literals are only kept once a character after them is
+    /// fitted.
+    fn format(&self, slot: usize, characters: impl Iterator<Item = char>) -> String {
+        let mut slots = self.pattern.iter().skip(slot).peekable();
+        let mut formatted = String::new();
+        let mut committed_length = 0;
+        'characters: for character in characters {
+            while let Some(MaskSlot::Literal(literal)) = slots.peek() {
+                formatted.push(*literal);
+                slots.next();
+                // The literal was typed by the user.
+                if *literal == character {
+                    committed_length = formatted.len();
+                    continue 'characters;
+                }
+            }
+
+            match slots.peek() {
+                Some(slot) if slot.accepts(character) => {
+                    formatted.push(character);
+                    slots.next();
+                    committed_length = formatted.len();
+                }
+                Some(_) => {}
+                None => break,
+            }
+        }
+        formatted.truncate(committed_length);
+
+        formatted
+    }
+}
+
+impl InputFilter for InputMask {
+    fn filter(&self, edit: &TextInputEdit) -> Option<String> {
+        // The text before the insertion already matches the pattern, so the cursor is at the slot of its character.
+        let slot = edit.text[..edit.range.start].chars().count();
+        let formatted = self.format(slot, edit.insert.chars());
+        if formatted.is_empty() && !edit.insert.is_empty() {
+            return None;
+        }
+
+        Some(formatted)
+    }
+
+    fn filter_edit(&self, edit: &TextInputEdit) -> Option<FilteredEdit> {
+        let insert = if edit.insert.is_empty() { String::new() } else { self.filter(edit)? };
+
+        // The characters after the edit that the user entered move into the slots after the inserted text, and the
+        // literals between them are inserted again.
+        let end_slot = edit.text[..edit.range.end].chars().count();
+        let entered = edit.text[edit.range.end..]
+            .chars()
+            .zip(self.pattern.iter().skip(end_slot))
+            .filter(|(_, slot)| !matches!(slot, MaskSlot::Literal(_)))
+            .map(|(character, _)| character);
+        let slot = edit.text[..edit.range.start].chars().count() + insert.chars().count();
+        let rest = self.format(slot, entered);
+
+        Some(FilteredEdit {
+            range: edit.range.start..edit.text.len(),
+            cursor: insert.len(),
+            insert: insert + &rest,
+        })
+    }
+}
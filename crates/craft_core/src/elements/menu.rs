@@ -0,0 +1,340 @@
+use crate::elements::element::Element;
+use crate::elements::element_styles::ElementStyles;
+use crate::elements::{Container, Overlay, Text, TinyVg};
+use crate::events::CraftMessage;
+use crate::geometry::Point;
+use crate::resource_manager::ResourceIdentifier;
+use crate::style::{AlignItems, Display, FlexDirection, Position, Unit};
+use accesskit::Role;
+use peniko::Color;
+use winit::keyboard::{Key, NamedKey};
+
+/// The background of the highlighted item.
+const HIGHLIGHT_COLOR: Color = Color::from_rgb8(204, 228, 255);
+
+/// The color of the shortcut hints, and of the labels of disabled items.
+const MUTED_COLOR: Color = Color::from_rgb8(140, 140, 140);
+
+/// The vertical padding of a menu, in logical pixels, which submenus are moved up by to line up with their item.
+const MENU_PADDING: f32 = 4.0;
+
+/// An item of a [`ContextMenu`](crate::elements::ContextMenu) or of a menu in a
+/// [`MenuBar`](crate::elements::MenuBar), or a separator between items.
+#[derive(Clone, Debug, Default)]
+pub struct MenuItem {
+    id: Option<String>,
+    label: String,
+    icon: Option<ResourceIdentifier>,
+    shortcut: Option<String>,
+    disabled: bool,
+    separator: bool,
+    submenu: Vec<MenuItem>,
+}
+
+impl MenuItem {
+    pub fn new(label: &str) -> Self {
+        MenuItem {
+            label: label.to_string(),
+            ..Default::default()
+        }
+    }
+
+    /// A line that separates groups of items. It can not be highlighted or chosen.
+    pub fn separator() -> Self {
+        MenuItem {
+            separator: true,
+            ..Default::default()
+        }
+    }
+
+    /// Sets the id that `MenuItemSelected` reports when the item is chosen. Defaults to the label.
+    pub fn id(mut self, id: &str) -> Self {
+        self.id = Some(id.to_string());
+        self
+    }
+
+    /// Shows a TinyVG icon in front of the label.
+    pub fn icon(mut self, icon: ResourceIdentifier) -> Self {
+        self.icon = Some(icon);
+        self
+    }
+
+    /// Shows a hint for the keyboard shortcut of the item, like `Ctrl+S`. The shortcut is not handled by the menu.
+    pub fn shortcut(mut self, shortcut: &str) -> Self {
+        self.shortcut = Some(shortcut.to_string());
+        self
+    }
+
+    /// Whether the item is shown, but can not be chosen.
+    pub fn disabled(mut self, disabled: bool) -> Self {
+        self.disabled = disabled;
+        self
+    }
+
+    /// Adds an item to the submenu of the item, which opens when the item is highlighted.
+    pub fn push(mut self, item: MenuItem) -> Self {
+        self.submenu.push(item);
+        self
+    }
+
+    /// Sets the items of the submenu of the item, which opens when the item is highlighted.
+    pub fn submenu(mut self, items: Vec<MenuItem>) -> Self {
+        self.submenu = items;
+        self
+    }
+
+    fn selected_id(&self) -> &str {
+        self.id.as_deref().unwrap_or(&self.label)
+    }
+
+    fn is_enabled(&self) -> bool {
+        !self.separator && !self.disabled
+    }
+
+    fn has_submenu(&self) -> bool {
+        !self.submenu.is_empty()
+    }
+}
+
+/// The open menus of a [`ContextMenu`](crate::elements::ContextMenu) or a [`MenuBar`](crate::elements::MenuBar).
+#[derive(Clone, Debug, Default)]
+pub struct MenuState {
+    pub(crate) is_open: bool,
+    /// The open menu of a menu bar.
+    pub(crate) menu: usize,
+    /// Where the context menu was opened, in physical pixels relative to the context menu element.
+    pub(crate) anchor: Point,
+    /// The highlighted item of every open menu, from the outermost menu to the innermost submenu.
+    pub(crate) highlighted: Vec<Option<usize>>,
+}
+
+impl MenuState {
+    /// Opens the outermost menu, with the first enabled item highlighted if `highlight_first` is set.
+    pub(crate) fn open(&mut self, items: &[MenuItem], highlight_first: bool) {
+        self.is_open = true;
+        self.highlighted = vec![if highlight_first { step(items, None, true) } else { None }];
+    }
+
+    /// Closes the menu and all of its submenus.
+    pub(crate) fn close(&mut self) {
+        self.is_open = false;
+        self.highlighted.clear();
+    }
+}
+
+/// What a key press did to a menu.
+pub(crate) enum MenuKeyAction {
+    /// The key is not used by menus.
+    Ignored,
+    /// The highlight moved, or a submenu was opened or closed.
+    Navigated,
+    /// The highlighted item was chosen, with its id. The menu is closed.
+    Selected(String),
+    /// Escape closed the outermost menu.
+    Closed,
+    /// ArrowLeft was pressed in the outermost menu, or ArrowRight on an item without a submenu. The boolean is whether
+    /// ArrowRight was pressed.
+    Sideways(bool),
+}
+
+/// Handles the keyboard navigation of the open menus of `state`.
+pub(crate) fn navigate(items: &[MenuItem], state: &mut MenuState, message: &CraftMessage) -> MenuKeyAction {
+    let CraftMessage::KeyboardInputEvent(keyboard_input) = message else {
+        return MenuKeyAction::Ignored;
+    };
+    if !keyboard_input.event.state.is_pressed() || state.highlighted.is_empty() {
+        return MenuKeyAction::Ignored;
+    }
+
+    let level = state.highlighted.len() - 1;
+    let menu = menu_items(items, &state.highlighted, level);
+    let highlighted = state.highlighted[level];
+    let item = highlighted.map(|index| &menu[index]);
+    let key = &keyboard_input.event.logical_key;
+    // Enter and Space choose the highlighted item.
+    let is_activation = match key {
+        Key::Named(NamedKey::Enter) => true,
+        Key::Character(character) => character == " ",
+        _ => false,
+    };
+    match key {
+        _ if is_activation => {
+            let Some(item) = item.filter(|item| item.is_enabled()) else {
+                return MenuKeyAction::Navigated;
+            };
+            if !item.has_submenu() {
+                let id = item.selected_id().to_string();
+                state.close();
+                return MenuKeyAction::Selected(id);
+            }
+            state.highlighted.push(step(&item.submenu, None, true));
+        }
+        Key::Named(NamedKey::ArrowDown) => state.highlighted[level] = step(menu, highlighted, true),
+        Key::Named(NamedKey::ArrowUp) => state.highlighted[level] = step(menu, highlighted, false),
+        Key::Named(NamedKey::Home) => state.highlighted[level] = step(menu, None, true),
+        Key::Named(NamedKey::End) => state.highlighted[level] = step(menu, None, false),
+        Key::Named(NamedKey::ArrowRight) => match item.filter(|item| item.is_enabled() && item.has_submenu()) {
+            Some(item) => state.highlighted.push(step(&item.submenu, None, true)),
+            None => return MenuKeyAction::Sideways(true),
+        },
+        Key::Named(NamedKey::ArrowLeft) if level == 0 => return MenuKeyAction::Sideways(false),
+        Key::Named(NamedKey::ArrowLeft) => {
+            state.highlighted.pop();
+        }
+        Key::Named(NamedKey::Escape) if level == 0 => {
+            state.close();
+            return MenuKeyAction::Closed;
+        }
+        Key::Named(NamedKey::Escape) => {
+            state.highlighted.pop();
+        }
+        _ => return MenuKeyAction::Ignored,
+    }
+
+    MenuKeyAction::Navigated
+}
+
+/// Returns whether `message` is a pointer button press outside of the menu element, which closes its open menus.
+pub(crate) fn is_press_outside(message: &CraftMessage, is_target: bool) -> bool {
+    let is_press =
+        matches!(message, CraftMessage::PointerButtonEvent(pointer_button) if pointer_button.state.is_pressed());
+    is_press && !is_target
+}
+
+/// Highlights the item at `path`, which is the index of the item in every menu from the outermost menu, and opens its
+/// submenu.
+pub(crate) fn hover(items: &[MenuItem], state: &mut MenuState, path: &[usize]) {
+    state.highlighted = path.iter().map(|index| Some(*index)).collect();
+    let item = item_at_path(items, path);
+    if !item.is_enabled() {
+        *state.highlighted.last_mut().unwrap() = None;
+    } else if item.has_submenu() {
+        state.highlighted.push(None);
+    }
+}
+
+/// Chooses the item at `path`, and returns its id. Items with a submenu open the submenu instead.
+pub(crate) fn activate(items: &[MenuItem], state: &mut MenuState, path: &[usize]) -> Option<String> {
+    let item = item_at_path(items, path);
+    if !item.is_enabled() || item.has_submenu() {
+        hover(items, state, path);
+        return None;
+    }
+
+    state.close();
+    Some(item.selected_id().to_string())
+}
+
+/// Returns the path of the item under `point` in `menu`, which was built by [`menu_element`], or in its open
+/// submenus.
+pub(crate) fn item_path_at(menu: &dyn Element, point: Point) -> Option<Vec<usize>> {
+    for (index, row) in menu.children().into_iter().enumerate() {
+        // The open submenu is the last child of its item, outside the bounds of the item.
+        let submenu = row.children().last().copied().filter(|child| child.as_any().is::<Overlay>());
+        if let Some(mut path) = submenu.and_then(|submenu| item_path_at(submenu, point)) {
+            path.insert(0, index);
+            return Some(path);
+        }
+        if row.in_bounds(point) {
+            return Some(vec![index]);
+        }
+    }
+
+    None
+}
+
+/// Builds the element of the menu at `level` of the open menus, with its open submenu.
+///
+/// When `id` is set, every item with an id gets the element id `{id}-{item id}`.
+pub(crate) fn menu_element(items: &[MenuItem], state: &MenuState, level: usize, id: Option<&str>) -> Overlay {
+    let highlighted = state.highlighted.get(level).copied().flatten();
+    let has_icons = items.iter().any(|item| item.icon.is_some());
+
+    let mut menu = Overlay::new()
+        .display(Display::Flex)
+        .flex_direction(FlexDirection::Column)
+        .position(Position::Absolute)
+        .min_width(Unit::Px(160.0))
+        .padding(Unit::Px(MENU_PADDING), Unit::Px(0.0), Unit::Px(MENU_PADDING), Unit::Px(0.0))
+        .background(Color::WHITE)
+        .border_width(Unit::Px(1.0), Unit::Px(1.0), Unit::Px(1.0), Unit::Px(1.0))
+        .border_color(Color::from_rgb8(200, 200, 200))
+        .border_radius(4.0, 4.0, 4.0, 4.0)
+        .accessibility_role(Role::Menu);
+
+    for (index, item) in items.iter().enumerate() {
+        if item.separator {
+            let separator = Container::new()
+                .height(Unit::Px(1.0))
+                .margin(Unit::Px(MENU_PADDING), Unit::Px(0.0), Unit::Px(MENU_PADDING), Unit::Px(0.0))
+                .background(Color::from_rgb8(220, 220, 220))
+                .accessibility_role(Role::Splitter);
+            menu = menu.push(separator);
+            continue;
+        }
+
+        let label_color = if item.disabled { MUTED_COLOR } else { Color::BLACK };
+        let mut row = Container::new()
+            .display(Display::Flex)
+            .align_items(AlignItems::Center)
+            .gap(Unit::Px(8.0))
+            .padding(Unit::Px(4.0), Unit::Px(12.0), Unit::Px(4.0), Unit::Px(8.0))
+            .accessibility_role(Role::MenuItem)
+            .accessibility_label(&item.label);
+        if let (Some(menu_id), Some(item_id)) = (id, &item.id) {
+            row = row.id(&format!("{menu_id}-{item_id}"));
+        }
+        if highlighted == Some(index) {
+            row = row.background(HIGHLIGHT_COLOR);
+        }
+
+        // The labels line up when any item has an icon.
+        if let Some(icon) = &item.icon {
+            row = row.push(TinyVg::new(icon.clone()).width(Unit::Px(16.0)).height(Unit::Px(16.0)));
+        } else if has_icons {
+            row = row.push(Container::new().width(Unit::Px(16.0)).height(Unit::Px(16.0)));
+        }
+        row = row.push(Text::new(&item.label).disable_selection().color(label_color).flex_grow(1.0));
+        if let Some(shortcut) = &item.shortcut {
+            row = row.push(Text::new(shortcut).disable_selection().color(MUTED_COLOR));
+        }
+        if item.has_submenu() {
+            row = row.push(Text::new("▸").disable_selection().color(label_color));
+
+            let is_open = highlighted == Some(index) && state.highlighted.len() > level + 1;
+            if is_open {
+                let submenu = menu_element(&item.submenu, state, level + 1, id)
+                    .inset(Unit::Px(-MENU_PADDING - 1.0), Unit::Auto, Unit::Auto, Unit::Percentage(100.0));
+                row = row.push(submenu);
+            }
+        }
+
+        menu = menu.push(row);
+    }
+
+    menu
+}
+
+/// Returns the items of the open menu at `level`.
+fn menu_items<'a>(items: &'a [MenuItem], highlighted: &[Option<usize>], level: usize) -> &'a [MenuItem] {
+    highlighted[..level].iter().fold(items, |menu, index| &menu[index.unwrap()].submenu)
+}
+
+fn item_at_path<'a>(items: &'a [MenuItem], path: &[usize]) -> &'a MenuItem {
+    let menu = path[..path.len() - 1].iter().fold(items, |menu, index| &menu[*index].submenu);
+    &menu[*path.last().unwrap()]
+}
+
+/// Returns the next enabled item after `current`, or before it when `forward` is not set. The highlight wraps around,
+/// and starts at the first or the last item when no item is highlighted.
+fn step(items: &[MenuItem], current: Option<usize>, forward: bool) -> Option<usize> {
+    let count = items.len();
+    (1..=count)
+        .map(|offset| match (current, forward) {
+            (Some(current), true) => (current + offset) % count,
+            (Some(current), false) => (current + count - offset) % count,
+            (None, true) => offset - 1,
+            (None, false) => count - offset,
+        })
+        .find(|index| items[*index].is_enabled())
+}
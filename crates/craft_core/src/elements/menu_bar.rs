@@ -0,0 +1,319 @@
+use crate::components::component::ComponentSpecification;
+use crate::components::{Event, Props};
+use crate::elements::element::Element;
+use crate::elements::element_data::ElementData;
+use crate::elements::element_styles::ElementStyles;
+use crate::elements::menu::{
+    activate, hover, is_press_outside, item_path_at, menu_element, navigate, MenuItem, MenuKeyAction, MenuState,
+};
+use crate::elements::{Container, Overlay, Text};
+use crate::events::CraftMessage;
+use crate::geometry::{Point, Rectangle, TrblRectangle};
+use crate::layout::layout_context::LayoutContext;
+use crate::reactive::element_state_store::{ElementStateStore, ElementStateStoreItem};
+use crate::renderer::renderer::RenderList;
+use crate::style::{AlignItems, Display, FlexDirection, Style, Unit};
+use crate::text::text_context::TextContext;
+use crate::{generate_component_methods_no_children, WindowContext};
+use accesskit::{Node, Role};
+use peniko::Color;
+use std::any::Any;
+use std::sync::Arc;
+use taffy::{NodeId, TaffyTree};
+use winit::event::ElementState;
+use winit::keyboard::{Key, NamedKey};
+use winit::window::Window;
+
+/// A bar with the titles of menus, like the application menu at the top of a window.
+///
+/// Clicking a title opens its menu below it, and while a menu is open, hovering another title opens that menu instead.
+/// The menus work like the menu of a [`ContextMenu`](crate::elements::ContextMenu), and ArrowLeft and ArrowRight move
+/// between them. While the menu bar has the focus, Enter, Space or ArrowDown open the menu that was last open.
+///
+/// When the menu bar has an id, the titles get the element id `{id}-menu-{index}`, and every item with an id gets the
+/// element id `{id}-{item id}`.
+#[derive(Clone, Default)]
+pub struct MenuBar {
+    pub element_data: ElementData,
+    /// The title and the items of every menu.
+    menus: Vec<(String, Vec<MenuItem>)>,
+}
+
+impl Element for MenuBar {
+    fn element_data(&self) -> &ElementData {
+        &self.element_data
+    }
+
+    fn element_data_mut(&mut self) -> &mut ElementData {
+        &mut self.element_data
+    }
+
+    fn name(&self) -> &'static str {
+        "MenuBar"
+    }
+
+    fn draw(
+        &mut self,
+        renderer: &mut RenderList,
+        text_context: &mut TextContext,
+        taffy_tree: &mut TaffyTree<LayoutContext>,
+        _root_node: NodeId,
+        element_state: &mut ElementStateStore,
+        pointer: Option<Point>,
+        window: Option<Arc<dyn Window>>,
+    ) {
+        if !self.element_data.style.visible() {
+            return;
+        }
+        self.draw_borders(renderer, element_state);
+        self.draw_children(renderer, text_context, taffy_tree, element_state, pointer, window);
+    }
+
+    fn compute_layout(
+        &mut self,
+        taffy_tree: &mut TaffyTree<LayoutContext>,
+        element_state: &mut ElementStateStore,
+        scale_factor: f64,
+    ) -> Option<NodeId> {
+        self.merge_default_style();
+
+        for child in &mut self.element_data.children {
+            let child_node = child.internal.compute_layout(taffy_tree, element_state, scale_factor);
+            self.element_data.layout_item.push_child(&child_node);
+        }
+
+        self.element_data.style.scale(scale_factor);
+        let style: taffy::Style = self.element_data.style.to_taffy_style();
+
+        self.element_data.layout_item.build_tree(taffy_tree, style)
+    }
+
+    fn finalize_layout(
+        &mut self,
+        taffy_tree: &mut TaffyTree<LayoutContext>,
+        root_node: NodeId,
+        position: Point,
+        z_index: &mut u32,
+        transform: glam::Mat4,
+        element_state: &mut ElementStateStore,
+        pointer: Option<Point>,
+        text_context: &mut TextContext,
+        clip_bounds: Option<Rectangle>,
+    ) {
+        let result = taffy_tree.layout(root_node).unwrap();
+        self.resolve_box(position, transform, result, z_index);
+        self.resolve_clip(clip_bounds);
+        self.finalize_borders(element_state);
+
+        for child in self.element_data.children.iter_mut() {
+            let taffy_child_node_id = child.internal.element_data().layout_item.taffy_node_id;
+            if taffy_child_node_id.is_none() {
+                continue;
+            }
+
+            child.internal.finalize_layout(
+                taffy_tree,
+                taffy_child_node_id.unwrap(),
+                self.element_data.layout_item.computed_box.position,
+                z_index,
+                transform,
+                element_state,
+                pointer,
+                text_context,
+                self.element_data.layout_item.clip_bounds,
+            );
+        }
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn on_event(
+        &self,
+        message: &CraftMessage,
+        element_state: &mut ElementStateStore,
+        _text_context: &mut TextContext,
+        should_style: bool,
+        event: &mut Event,
+    ) {
+        self.on_style_event(message, element_state, should_style, event);
+        if self.menus.is_empty() {
+            return;
+        }
+
+        let titles = self.children();
+        let title_at = |point: Point| titles.iter().position(|title| title.in_bounds(point));
+        let state = self.get_state_mut(element_state);
+        // The open menu is the last child of its title.
+        let menu = titles
+            .get(state.menu)
+            .and_then(|title| title.children().last().copied())
+            .filter(|menu| state.is_open && menu.as_any().is::<Overlay>());
+
+        match message {
+            CraftMessage::PointerButtonEvent(pointer_button) => {
+                let position = pointer_button.position;
+                if let Some(path) = menu.and_then(|menu| item_path_at(menu, position)) {
+                    let items = &self.menus[state.menu].1;
+                    let chosen = if pointer_button.clicked() { activate(items, state, &path) } else { None };
+                    if let Some(id) = chosen {
+                        event.result_message(CraftMessage::MenuItemSelected(id));
+                    }
+                    event.prevent_propagate();
+                } else if pointer_button.state == ElementState::Pressed {
+                    match title_at(position) {
+                        Some(index) if !state.is_open || state.menu != index => {
+                            state.menu = index;
+                            state.open(&self.menus[index].1, false);
+                        }
+                        _ => state.close(),
+                    }
+                }
+            }
+            CraftMessage::PointerMovedEvent(pointer_moved) => {
+                let position = pointer_moved.position;
+                if let Some(path) = menu.and_then(|menu| item_path_at(menu, position)) {
+                    hover(&self.menus[state.menu].1, state, &path);
+                } else if let Some(index) = title_at(position).filter(|index| state.is_open && *index != state.menu) {
+                    state.menu = index;
+                    state.open(&self.menus[index].1, false);
+                }
+            }
+            CraftMessage::KeyboardInputEvent(_) if state.is_open => {
+                match navigate(&self.menus[state.menu].1, state, message) {
+                    MenuKeyAction::Ignored => {}
+                    MenuKeyAction::Sideways(forward) => {
+                        let count = self.menus.len();
+                        state.menu = if forward { (state.menu + 1) % count } else { (state.menu + count - 1) % count };
+                        state.open(&self.menus[state.menu].1, true);
+                        event.prevent_defaults();
+                    }
+                    MenuKeyAction::Selected(id) => {
+                        event.result_message(CraftMessage::MenuItemSelected(id));
+                        event.prevent_defaults();
+                    }
+                    MenuKeyAction::Navigated | MenuKeyAction::Closed => event.prevent_defaults(),
+                }
+            }
+            CraftMessage::KeyboardInputEvent(keyboard_input) => {
+                let opens_menu = match &keyboard_input.event.logical_key {
+                    Key::Named(NamedKey::Enter | NamedKey::ArrowDown) => true,
+                    Key::Character(character) => character == " ",
+                    _ => false,
+                };
+                if opens_menu && keyboard_input.event.state.is_pressed() {
+                    state.open(&self.menus[state.menu].1, true);
+                    event.prevent_defaults();
+                }
+            }
+            _ => {}
+        }
+    }
+
+    fn on_global_event(&self, message: &CraftMessage, element_state: &mut ElementStateStore, is_target: bool) {
+        if is_press_outside(message, is_target) {
+            self.get_state_mut(element_state).close();
+        }
+    }
+
+    fn initialize_state(&mut self, _scaling_factor: f64) -> ElementStateStoreItem {
+        ElementStateStoreItem {
+            base: Default::default(),
+            data: Box::new(MenuState::default()),
+        }
+    }
+
+    fn materialize_children(
+        &mut self,
+        _children: Vec<ComponentSpecification>,
+        element_state: &mut ElementStateStore,
+        _window: &WindowContext,
+        _scaling_factor: f64,
+    ) -> Vec<ComponentSpecification> {
+        let menu_count = self.menus.len();
+        let state = self.get_state_mut(element_state);
+        // The open menu may have been removed.
+        if state.menu >= menu_count {
+            state.menu = 0;
+            state.close();
+        }
+        let state = state.clone();
+
+        let id = self.element_data.id.as_deref();
+        let mut titles = Vec::with_capacity(menu_count);
+        for (index, (title, items)) in self.menus.iter().enumerate() {
+            let is_open = state.is_open && state.menu == index;
+            let mut title_element = Container::new()
+                .padding(Unit::Px(4.0), Unit::Px(10.0), Unit::Px(4.0), Unit::Px(10.0))
+                .accessibility_label(title)
+                .push(Text::new(title).disable_selection());
+            if let Some(id) = id {
+                title_element = title_element.id(&format!("{id}-menu-{index}"));
+            }
+            if is_open {
+                let menu = menu_element(items, &state, 0, id)
+                    .inset(Unit::Percentage(100.0), Unit::Auto, Unit::Auto, Unit::Px(0.0));
+                title_element = title_element.background(Color::from_rgb8(204, 228, 255)).push(menu);
+            }
+            titles.push(title_element.component());
+        }
+
+        titles
+    }
+
+    fn default_style(&self) -> Style {
+        let mut style = Style::default();
+        *style.display_mut() = Display::Flex;
+        *style.flex_direction_mut() = FlexDirection::Row;
+        *style.align_items_mut() = Some(AlignItems::Center);
+        *style.background_mut() = Color::from_rgb8(240, 240, 240);
+        *style.border_width_mut() = TrblRectangle::new(Unit::Px(0.0), Unit::Px(0.0), Unit::Px(1.0), Unit::Px(0.0));
+        *style.border_color_mut() = TrblRectangle::new_all(Color::from_rgb8(200, 200, 200));
+
+        style
+    }
+
+    fn default_accessibility_role(&self) -> Role {
+        Role::MenuBar
+    }
+
+    fn update_accessibility_child_node(&self, index: usize, node: &mut Node, element_state: &ElementStateStore) {
+        let state = self.get_state(element_state);
+        node.set_role(Role::MenuItem);
+        node.set_expanded(state.is_open && state.menu == index);
+    }
+
+    fn is_focusable(&self) -> bool {
+        true
+    }
+}
+
+impl MenuBar {
+    pub fn new() -> MenuBar {
+        MenuBar::default()
+    }
+
+    /// Adds a menu with the given title and items to the end of the bar.
+    pub fn menu(mut self, title: &str, items: Vec<MenuItem>) -> Self {
+        self.menus.push((title.to_string(), items));
+        self
+    }
+
+    #[allow(dead_code)]
+    fn get_state<'a>(&self, element_state: &'a ElementStateStore) -> &'a MenuState {
+        element_state.storage.get(&self.element_data.component_id).unwrap().data.as_ref().downcast_ref().unwrap()
+    }
+
+    fn get_state_mut<'a>(&self, element_state: &'a mut ElementStateStore) -> &'a mut MenuState {
+        element_state.storage.get_mut(&self.element_data.component_id).unwrap().data.as_mut().downcast_mut().unwrap()
+    }
+
+    generate_component_methods_no_children!();
+}
+
+impl ElementStyles for MenuBar {
+    fn styles_mut(&mut self) -> &mut Style {
+        self.element_data.current_style_mut()
+    }
+}
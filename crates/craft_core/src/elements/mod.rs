@@ -1,13 +1,24 @@
+pub(crate) mod checkbox;
 pub(crate) mod container;
+pub(crate) mod context_menu;
+pub(crate) mod dialog;
 pub(crate) mod overlay;
+pub(crate) mod radio;
 pub(crate) mod dropdown;
 pub(crate) mod element;
 pub(crate) mod empty;
 pub(crate) mod image;
+pub(crate) mod input_filter;
+pub(crate) mod menu;
+pub(crate) mod menu_bar;
 pub(crate) mod slider;
 pub(crate) mod switch;
+pub(crate) mod table;
+pub(crate) mod tabs;
 pub(crate) mod text;
 pub(crate) mod tinyvg;
+pub(crate) mod tooltip;
+pub(crate) mod virtual_list;
 
 #[allow(clippy::module_inception)]
 pub(crate) mod text_input;
@@ -15,12 +26,15 @@ pub(crate) mod text_input;
 pub(crate) mod canvas;
 
 pub(crate) mod base_element_state;
+pub(crate) mod edit_history;
 pub(crate) mod element_data;
 mod element_pre_order_iterator;
 pub(crate) mod element_states;
 pub(crate) mod element_styles;
 pub(crate) mod font;
 mod scroll_state;
+#[cfg(test)]
+mod tests;
 mod thumb;
 
 
@@ -29,15 +43,26 @@ pub use crate::elements::element_data::ElementData;
 pub use crate::elements::element_states::ElementState;
 pub use crate::elements::element::ElementBoxed;
 pub use crate::elements::canvas::Canvas;
+pub use crate::elements::checkbox::{CheckState, Checkbox};
 pub use crate::elements::container::Container;
+pub use crate::elements::context_menu::ContextMenu;
+pub use crate::elements::dialog::Dialog;
 pub use crate::elements::overlay::Overlay;
+pub use crate::elements::radio::{Radio, RadioGroup};
 pub use crate::elements::dropdown::Dropdown;
 pub use crate::elements::element_styles::ElementStyles;
 pub use crate::elements::font::Font;
 pub use crate::elements::image::Image;
+pub use crate::elements::menu::MenuItem;
+pub use crate::elements::menu_bar::MenuBar;
+pub use crate::elements::input_filter::{AllowedCharacters, FilteredEdit, InputFilter, InputMask, TextInputEdit};
 pub use crate::elements::slider::Slider;
 pub use crate::elements::slider::SliderDirection;
 pub use crate::elements::switch::Switch;
+pub use crate::elements::table::{ColumnAlignment, SortDirection, Table, TableColumn, TableSelectionMode};
+pub use crate::elements::tabs::Tabs;
 pub use crate::elements::text::Text;
 pub use crate::elements::text_input::TextInput;
 pub use crate::elements::tinyvg::TinyVg;
+pub use crate::elements::tooltip::{Tooltip, TooltipPlacement};
+pub use crate::elements::virtual_list::VirtualList;
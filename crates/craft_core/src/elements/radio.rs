@@ -0,0 +1,581 @@
+use crate::accessibility::text_content;
+use crate::components::component::ComponentSpecification;
+use crate::components::{ComponentId, Event, FocusRequest, Props};
+use crate::elements::checkbox::{default_checked_indicator_style, default_disabled_style, default_indicator_style};
+use crate::elements::element::{resolve_clip_for_scrollable, Element};
+use crate::elements::element_data::ElementData;
+use crate::elements::element_styles::ElementStyles;
+use crate::elements::thumb::Thumb;
+use crate::elements::Text;
+use crate::events::CraftMessage;
+use crate::geometry::{Point, Rectangle};
+use crate::layout::layout_context::LayoutContext;
+use crate::reactive::element_state_store::{ElementStateStore, ElementStateStoreItem};
+use crate::renderer::renderer::RenderList;
+use crate::renderer::Brush;
+use crate::style::{AlignItems, Display, FlexDirection, Style, Unit};
+use crate::text::text_context::TextContext;
+use crate::generate_component_methods;
+use accesskit::{Action, Node, Role, Toggled};
+use peniko::kurbo::{self, Shape};
+use std::any::Any;
+use std::sync::Arc;
+use taffy::{NodeId, TaffyTree};
+use winit::keyboard::{Key, NamedKey};
+use winit::window::Window;
+
+/// An element that holds the [`Radio`] buttons of a group, of which at most one is selected.
+///
+/// The radio buttons can be nested in other elements of the group. They belong to the group that they name with
+/// [`Radio::group`], or to the closest group around them when they do not name one, so that a radio button can be
+/// nested in another group. Selecting one emits `RadioSelected` with its value from the group, and the arrow keys move
+/// the selection between the enabled radio buttons.
+#[derive(Clone, Default)]
+pub struct RadioGroup {
+    pub element_data: ElementData,
+    /// The name of the group, which labels it for assistive technologies and is named by its radio buttons.
+    name: String,
+    /// When `state.selected` is None, use this as the default value.
+    default_selected: Option<String>,
+    /// When set, the group always selects this value, and the component updates it on `RadioSelected`.
+    selected: Option<String>,
+}
+
+#[derive(Clone, Default)]
+pub struct RadioGroupState {
+    pub(crate) selected: Option<String>,
+    /// The value of the radio button that was activated while the event bubbles up to the group. It is cleared once
+    /// the event was dispatched, even when it did not reach the group.
+    pub(crate) activated: Option<String>,
+}
+
+/// A radio button in a [`RadioGroup`], with optional label children that select it when clicked.
+#[derive(Clone)]
+pub struct Radio {
+    pub element_data: ElementData,
+    value: String,
+    enabled: bool,
+    /// The name of the group that the radio button belongs to.
+    group: Option<String>,
+
+    /// A pseudo element for the circle, this is not stored in the user tree nor will it receive events.
+    indicator: Thumb,
+}
+
+#[derive(Clone, Default)]
+pub struct RadioState {
+    /// The group of the radio button, which is set when the group is laid out.
+    pub(crate) group: Option<ComponentId>,
+    pub(crate) selected: bool,
+}
+
+impl Element for RadioGroup {
+    fn element_data(&self) -> &ElementData {
+        &self.element_data
+    }
+
+    fn element_data_mut(&mut self) -> &mut ElementData {
+        &mut self.element_data
+    }
+
+    fn name(&self) -> &'static str {
+        "RadioGroup"
+    }
+
+    fn draw(
+        &mut self,
+        renderer: &mut RenderList,
+        text_context: &mut TextContext,
+        taffy_tree: &mut TaffyTree<LayoutContext>,
+        _root_node: NodeId,
+        element_state: &mut ElementStateStore,
+        pointer: Option<Point>,
+        window: Option<Arc<dyn Window>>,
+    ) {
+        if !self.element_data.style.visible() {
+            return;
+        }
+        self.draw_borders(renderer, element_state);
+        self.draw_children(renderer, text_context, taffy_tree, element_state, pointer, window);
+    }
+
+    fn compute_layout(
+        &mut self,
+        taffy_tree: &mut TaffyTree<LayoutContext>,
+        element_state: &mut ElementStateStore,
+        scale_factor: f64,
+    ) -> Option<NodeId> {
+        self.merge_default_style();
+
+        // Tell the radio buttons which group they belong to and whether they are selected, before they are laid out.
+        let group = self.element_data.component_id;
+        let selected = self.selected_value(element_state).map(str::to_string);
+        let radios: Vec<(ComponentId, bool)> =
+            self.radios().iter().map(|radio| (radio.component_id(), selected.as_ref() == Some(&radio.value))).collect();
+        for (radio, is_selected) in radios {
+            let state = element_state.storage.get_mut(&radio).unwrap().data.as_mut().downcast_mut::<RadioState>().unwrap();
+            state.group = Some(group);
+            state.selected = is_selected;
+        }
+
+        for child in &mut self.element_data.children {
+            let child_node = child.internal.compute_layout(taffy_tree, element_state, scale_factor);
+            self.element_data.layout_item.push_child(&child_node);
+        }
+
+        self.element_data.style.scale(scale_factor);
+        let style: taffy::Style = self.element_data.style.to_taffy_style();
+
+        self.element_data.layout_item.build_tree(taffy_tree, style)
+    }
+
+    fn finalize_layout(
+        &mut self,
+        taffy_tree: &mut TaffyTree<LayoutContext>,
+        root_node: NodeId,
+        position: Point,
+        z_index: &mut u32,
+        transform: glam::Mat4,
+        element_state: &mut ElementStateStore,
+        pointer: Option<Point>,
+        text_context: &mut TextContext,
+        clip_bounds: Option<Rectangle>,
+    ) {
+        let result = taffy_tree.layout(root_node).unwrap();
+        self.resolve_box(position, transform, result, z_index);
+        self.resolve_clip(clip_bounds);
+        self.finalize_borders(element_state);
+
+        for child in self.element_data.children.iter_mut() {
+            let taffy_child_node_id = child.internal.element_data().layout_item.taffy_node_id;
+            if taffy_child_node_id.is_none() {
+                continue;
+            }
+
+            child.internal.finalize_layout(
+                taffy_tree,
+                taffy_child_node_id.unwrap(),
+                self.element_data.layout_item.computed_box.position,
+                z_index,
+                transform,
+                element_state,
+                pointer,
+                text_context,
+                self.element_data.layout_item.clip_bounds,
+            );
+        }
+    }
+
+    fn resolve_clip(&mut self, clip_bounds: Option<Rectangle>) {
+        resolve_clip_for_scrollable(self, clip_bounds);
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn on_event(
+        &self,
+        message: &CraftMessage,
+        element_state: &mut ElementStateStore,
+        _text_context: &mut TextContext,
+        should_style: bool,
+        event: &mut Event,
+    ) {
+        self.on_style_event(message, element_state, should_style, event);
+
+        let mut activated = self.get_state_mut(element_state).activated.take();
+
+        // The arrow keys select the next or the previous enabled radio button, and move the focus to it.
+        if let CraftMessage::KeyboardInputEvent(keyboard_input) = message {
+            let step: isize = match &keyboard_input.event.logical_key {
+                Key::Named(NamedKey::ArrowDown | NamedKey::ArrowRight) => 1,
+                Key::Named(NamedKey::ArrowUp | NamedKey::ArrowLeft) => -1,
+                _ => 0,
+            };
+            let radios: Vec<&Radio> = self.radios().into_iter().filter(|radio| radio.enabled).collect();
+            let focused = radios.iter().position(|radio| radio.get_base_state(element_state).base.focused);
+            if let Some(focused) = focused.filter(|_| step != 0 && keyboard_input.event.state.is_pressed()) {
+                let next = radios[(focused as isize + step).rem_euclid(radios.len() as isize) as usize];
+                activated = Some(next.value.clone());
+                event.focus = Some(FocusRequest::Element(Some(next.component_id())));
+                event.prevent_defaults();
+            }
+        }
+
+        let Some(value) = activated else {
+            return;
+        };
+        if self.selected_value(element_state) != Some(value.as_str()) {
+            if self.selected.is_none() {
+                self.get_state_mut(element_state).selected = Some(value.clone());
+            }
+            event.result_message(CraftMessage::RadioSelected(value));
+        }
+        event.prevent_propagate();
+    }
+
+    fn on_global_event(&self, _message: &CraftMessage, element_state: &mut ElementStateStore, _is_target: bool) {
+        self.get_state_mut(element_state).activated = None;
+    }
+
+    fn initialize_state(&mut self, _scaling_factor: f64) -> ElementStateStoreItem {
+        ElementStateStoreItem {
+            base: Default::default(),
+            data: Box::new(RadioGroupState::default()),
+        }
+    }
+
+    fn default_style(&self) -> Style {
+        let mut style = Style::default();
+
+        *style.display_mut() = Display::Flex;
+        *style.flex_direction_mut() = FlexDirection::Column;
+        *style.gap_mut() = [Unit::Px(6.0), Unit::Px(6.0)];
+
+        style
+    }
+
+    fn default_accessibility_role(&self) -> Role {
+        Role::RadioGroup
+    }
+
+    fn update_accessibility_node(&self, node: &mut Node, _element_state: &ElementStateStore) {
+        node.set_label(self.name.as_str());
+    }
+}
+
+impl RadioGroup {
+    pub fn new(name: &str) -> RadioGroup {
+        RadioGroup {
+            name: name.to_string(),
+            ..Default::default()
+        }
+    }
+
+    pub fn default_selected(mut self, value: &str) -> Self {
+        self.default_selected = Some(value.to_string());
+        self
+    }
+
+    /// Controls the selected value of the group. Selecting a radio button only emits `RadioSelected`, and the
+    /// selection changes when the component passes the new value.
+    pub fn selected(mut self, value: &str) -> Self {
+        self.selected = Some(value.to_string());
+        self
+    }
+
+    fn selected_value<'a>(&'a self, element_state: &'a ElementStateStore) -> Option<&'a str> {
+        let state = self.get_state(element_state);
+        self.selected.as_deref().or(state.selected.as_deref()).or(self.default_selected.as_deref())
+    }
+
+    /// The radio buttons in the group, in tree order. Radio buttons in nested groups are only included when they name
+    /// this group.
+    fn radios(&self) -> Vec<&Radio> {
+        fn collect<'a>(element: &'a dyn Element, name: &str, is_closest: bool, radios: &mut Vec<&'a Radio>) {
+            for child in element.children() {
+                if let Some(radio) = child.as_any().downcast_ref::<Radio>() {
+                    if radio.group.as_ref().map_or(is_closest, |group| group == name) {
+                        radios.push(radio);
+                    }
+                } else {
+                    collect(child, name, is_closest && !child.as_any().is::<RadioGroup>(), radios);
+                }
+            }
+        }
+
+        let mut radios = Vec::new();
+        collect(self, &self.name, true, &mut radios);
+        radios
+    }
+
+    #[allow(dead_code)]
+    fn get_state<'a>(&self, element_state: &'a ElementStateStore) -> &'a RadioGroupState {
+        element_state.storage.get(&self.element_data.component_id).unwrap().data.as_ref().downcast_ref().unwrap()
+    }
+
+    fn get_state_mut<'a>(&self, element_state: &'a mut ElementStateStore) -> &'a mut RadioGroupState {
+        element_state.storage.get_mut(&self.element_data.component_id).unwrap().data.as_mut().downcast_mut().unwrap()
+    }
+
+    generate_component_methods!();
+}
+
+impl ElementStyles for RadioGroup {
+    fn styles_mut(&mut self) -> &mut Style {
+        self.element_data.current_style_mut()
+    }
+}
+
+impl Element for Radio {
+    fn element_data(&self) -> &ElementData {
+        &self.element_data
+    }
+
+    fn element_data_mut(&mut self) -> &mut ElementData {
+        &mut self.element_data
+    }
+
+    fn name(&self) -> &'static str {
+        "Radio"
+    }
+
+    fn draw(
+        &mut self,
+        renderer: &mut RenderList,
+        text_context: &mut TextContext,
+        taffy_tree: &mut TaffyTree<LayoutContext>,
+        _root_node: NodeId,
+        element_state: &mut ElementStateStore,
+        pointer: Option<Point>,
+        window: Option<Arc<dyn Window>>,
+    ) {
+        if !self.element_data.style.visible() {
+            return;
+        }
+        self.draw_borders(renderer, element_state);
+        self.indicator.draw(renderer);
+
+        if self.get_state(element_state).selected {
+            let circle = self.indicator.layout_item.computed_box_transformed.padding_rectangle();
+            let center = kurbo::Point::new(
+                (circle.x + circle.width / 2.0) as f64,
+                (circle.y + circle.height / 2.0) as f64,
+            );
+            let dot = kurbo::Circle::new(center, (circle.width * 0.2) as f64);
+            renderer.fill_bez_path(dot.to_path(0.1), Brush::Color(self.indicator.thumb_style.color()));
+        }
+
+        self.draw_children(renderer, text_context, taffy_tree, element_state, pointer, window);
+    }
+
+    fn compute_layout(
+        &mut self,
+        taffy_tree: &mut TaffyTree<LayoutContext>,
+        element_state: &mut ElementStateStore,
+        scale_factor: f64,
+    ) -> Option<NodeId> {
+        self.merge_default_style();
+        if !self.enabled {
+            self.element_data.style = Style::merge(&self.element_data.style, &default_disabled_style());
+            if let Some(disabled_style) = self.element_data.disabled_style.as_deref() {
+                self.element_data.style = Style::merge(&self.element_data.style, disabled_style);
+            }
+        }
+
+        let selected = self.get_state(element_state).selected;
+        let indicator_node = self.indicator.compute_layout(taffy_tree, scale_factor, selected, true);
+        self.element_data.layout_item.push_child(&Some(indicator_node));
+
+        for child in &mut self.element_data.children {
+            let child_node = child.internal.compute_layout(taffy_tree, element_state, scale_factor);
+            self.element_data.layout_item.push_child(&child_node);
+        }
+
+        self.element_data.style.scale(scale_factor);
+        let style: taffy::Style = self.element_data.style.to_taffy_style();
+
+        self.element_data.layout_item.build_tree(taffy_tree, style)
+    }
+
+    fn finalize_layout(
+        &mut self,
+        taffy_tree: &mut TaffyTree<LayoutContext>,
+        root_node: NodeId,
+        position: Point,
+        z_index: &mut u32,
+        transform: glam::Mat4,
+        element_state: &mut ElementStateStore,
+        pointer: Option<Point>,
+        text_context: &mut TextContext,
+        clip_bounds: Option<Rectangle>,
+    ) {
+        let result = taffy_tree.layout(root_node).unwrap();
+        self.resolve_box(position, transform, result, z_index);
+        self.resolve_clip(clip_bounds);
+        self.finalize_borders(element_state);
+
+        let position = self.element_data.layout_item.computed_box.position;
+        self.indicator.finalize_layout(
+            taffy_tree,
+            position,
+            z_index,
+            transform,
+            element_state,
+            pointer,
+            text_context,
+            clip_bounds,
+        );
+
+        for child in self.element_data.children.iter_mut() {
+            let taffy_child_node_id = child.internal.element_data().layout_item.taffy_node_id;
+            if taffy_child_node_id.is_none() {
+                continue;
+            }
+
+            child.internal.finalize_layout(
+                taffy_tree,
+                taffy_child_node_id.unwrap(),
+                position,
+                z_index,
+                transform,
+                element_state,
+                pointer,
+                text_context,
+                clip_bounds,
+            );
+        }
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn on_event(
+        &self,
+        message: &CraftMessage,
+        element_state: &mut ElementStateStore,
+        _text_context: &mut TextContext,
+        should_style: bool,
+        event: &mut Event,
+    ) {
+        self.on_style_event(message, element_state, should_style, event);
+        if !self.enabled {
+            return;
+        }
+
+        let activate = match message {
+            CraftMessage::KeyboardInputEvent(keyboard_input) => {
+                keyboard_input.event.state.is_pressed()
+                    && keyboard_input.event.logical_key == Key::Character(" ".into())
+            }
+            CraftMessage::AccessibilityAction(request) => request.action == Action::Click,
+            message => message.clicked(),
+        };
+        if !activate {
+            return;
+        }
+
+        // The group selects the radio button when the event reaches it.
+        let Some(group) = self.get_state(element_state).group else {
+            return;
+        };
+        if let Some(group_state) = element_state
+            .storage
+            .get_mut(&group)
+            .and_then(|group_state| group_state.data.as_mut().downcast_mut::<RadioGroupState>())
+        {
+            group_state.activated = Some(self.value.clone());
+        }
+    }
+
+    fn initialize_state(&mut self, _scaling_factor: f64) -> ElementStateStoreItem {
+        ElementStateStoreItem {
+            base: Default::default(),
+            data: Box::new(RadioState::default()),
+        }
+    }
+
+    fn default_style(&self) -> Style {
+        let mut style = Style::default();
+
+        *style.display_mut() = Display::Flex;
+        *style.align_items_mut() = Some(AlignItems::Center);
+        *style.gap_mut() = [Unit::Px(6.0), Unit::Px(6.0)];
+
+        style
+    }
+
+    fn default_accessibility_role(&self) -> Role {
+        Role::RadioButton
+    }
+
+    fn update_accessibility_node(&self, node: &mut Node, element_state: &ElementStateStore) {
+        let selected = self.get_state(element_state).selected;
+        node.set_toggled(if selected { Toggled::True } else { Toggled::False });
+        let label = text_content(self, element_state);
+        if !label.is_empty() {
+            node.set_label(label);
+        }
+        if self.enabled {
+            node.add_action(Action::Click);
+        } else {
+            node.set_disabled();
+        }
+    }
+
+    fn is_focusable(&self) -> bool {
+        self.enabled
+    }
+}
+
+impl Radio {
+    /// Creates a radio button that selects `value` in its group.
+    pub fn new(value: &str) -> Radio {
+        let size = 16.0;
+        Radio {
+            element_data: Default::default(),
+            value: value.to_string(),
+            enabled: true,
+            group: None,
+            indicator: Thumb {
+                layout_item: Default::default(),
+                thumb_style: default_indicator_style(size / 2.0),
+                toggled_thumb_style: default_checked_indicator_style(),
+                size,
+            },
+        }
+    }
+
+    /// Sets the diameter of the circle in pixels.
+    pub fn size(mut self, size: f32) -> Self {
+        self.indicator.size = size;
+        self.indicator.thumb_style = Style::merge(&self.indicator.thumb_style, &default_indicator_style(size / 2.0));
+        self
+    }
+
+    /// Whether the radio button can be focused and selected. A disabled radio button uses the disabled style.
+    pub fn enabled(mut self, enabled: bool) -> Self {
+        self.enabled = enabled;
+        self
+    }
+
+    /// Adds the radio button to the [`RadioGroup`] with this name, instead of the closest group around it. The group
+    /// has to be around the radio button.
+    pub fn group(mut self, name: &str) -> Self {
+        self.group = Some(name.to_string());
+        self
+    }
+
+    /// Adds a text label after the circle, which selects the radio button when clicked.
+    pub fn label(self, label: &str) -> Self {
+        self.push(Text::new(label).disable_selection())
+    }
+
+    /// Sets the style of the circle. The color is used for the dot.
+    pub fn indicator_style(mut self, indicator_style: Style) -> Self {
+        self.indicator.thumb_style(Style::merge(&self.indicator.thumb_style, &indicator_style));
+        self
+    }
+
+    /// Sets the style of the circle when the radio button is selected. This style will get merged with the indicator
+    /// style.
+    pub fn selected_style(mut self, selected_style: Style) -> Self {
+        self.indicator.toggled_thumb_style(Style::merge(&self.indicator.toggled_thumb_style, &selected_style));
+        self
+    }
+
+    #[allow(dead_code)]
+    fn get_state<'a>(&self, element_state: &'a ElementStateStore) -> &'a RadioState {
+        element_state.storage.get(&self.element_data.component_id).unwrap().data.as_ref().downcast_ref().unwrap()
+    }
+
+    generate_component_methods!();
+}
+
+impl ElementStyles for Radio {
+    fn styles_mut(&mut self) -> &mut Style {
+        self.element_data.current_style_mut()
+    }
+}
@@ -1,7 +1,8 @@
 use crate::components::Event;
-use crate::elements::base_element_state::{BaseElementState, DUMMY_DEVICE_ID};
+use crate::elements::base_element_state::BaseElementState;
 use crate::elements::element_data::ElementData;
 use crate::events::CraftMessage;
+use accesskit::{Action, Node};
 use winit::event::ElementState as WinitElementState;
 use winit::event::{ButtonSource, MouseButton, MouseScrollDelta, PointerSource};
 
@@ -59,8 +60,7 @@ impl ScrollState {
                             WinitElementState::Pressed => {
                                 if element.layout_item.computed_scroll_thumb.contains(&pointer_button.position) {
                                     self.scroll_click = Some((pointer_button.position.x, pointer_button.position.y));
-                                    // FIXME: Turn pointer capture on with the correct device id.
-                                    base_state.pointer_capture.insert(DUMMY_DEVICE_ID, true);
+                                    base_state.pointer_capture.insert(pointer_button.pointer_id(), true);
 
                                     event.prevent_propagate();
                                     event.prevent_defaults();
@@ -79,8 +79,7 @@ impl ScrollState {
                             WinitElementState::Released => {
                                 if self.scroll_click.is_some() {
                                     self.scroll_click = None;
-                                    // FIXME: Turn pointer capture off with the correct device id.
-                                    base_state.pointer_capture.insert(DUMMY_DEVICE_ID, false);
+                                    base_state.pointer_capture.insert(pointer_button.pointer_id(), false);
                                     event.prevent_propagate();
                                     event.prevent_defaults();
                                 }
@@ -88,6 +87,21 @@ impl ScrollState {
                         }
                     }
                 }
+                CraftMessage::AccessibilityAction(request) => {
+                    // Scroll by a page, like the page up and page down keys do.
+                    let page_height = element.layout_item.computed_box_transformed.padding_rectangle().height;
+                    let delta = match request.action {
+                        Action::ScrollUp => -page_height,
+                        Action::ScrollDown => page_height,
+                        _ => return,
+                    };
+                    let max_scroll_y = element.layout_item.max_scroll_y;
+
+                    self.scroll_y = (self.scroll_y + delta).clamp(0.0, max_scroll_y);
+
+                    event.prevent_propagate();
+                    event.prevent_defaults();
+                }
                 CraftMessage::PointerMovedEvent(pointer_motion) => {
                     if let Some((click_x, click_y)) = self.scroll_click {
                         // Todo: Translate scroll wheel pixel to scroll position for diff.
@@ -113,4 +127,17 @@ impl ScrollState {
             }
         }
     }
+
+    /// Exposes the scroll position of a scrollable element and lets assistive technologies scroll it.
+    pub(crate) fn update_accessibility_node(&self, element: &ElementData, node: &mut Node) {
+        if !element.is_scrollable() {
+            return;
+        }
+
+        node.set_scroll_y(self.scroll_y as f64);
+        node.set_scroll_y_min(0.0);
+        node.set_scroll_y_max(element.layout_item.max_scroll_y as f64);
+        node.add_action(Action::ScrollUp);
+        node.add_action(Action::ScrollDown);
+    }
 }
@@ -1,7 +1,6 @@
 use crate::components::component::ComponentSpecification;
 use crate::components::Props;
 use crate::components::Event;
-use crate::elements::base_element_state::DUMMY_DEVICE_ID;
 use crate::elements::element::Element;
 use crate::elements::element_data::ElementData;
 use crate::elements::element_styles::ElementStyles;
@@ -16,6 +15,7 @@ use crate::renderer::Brush;
 use crate::style::{Display, Style, Unit};
 use crate::{generate_component_methods, palette};
 use peniko::Color;
+use accesskit::{Action, ActionData, Node, Orientation, Role};
 use std::any::Any;
 use std::sync::Arc;
 use taffy::{NodeId, TaffyTree};
@@ -190,12 +190,10 @@ impl Element for Slider {
 
             if pointer.state == ElementState::Pressed {
                 state.dragging = true;
-                // FIXME: Turn pointer capture on with the correct device id.
-                base_state.base.pointer_capture.insert(DUMMY_DEVICE_ID, true);
+                base_state.base.pointer_capture.insert(pointer.pointer_id(), true);
             } else if pointer.state == ElementState::Released {
                 state.dragging = false;
-                // FIXME: Turn pointer capture on with the correct device id.
-                base_state.base.pointer_capture.remove(&DUMMY_DEVICE_ID);
+                base_state.base.pointer_capture.remove(&pointer.pointer_id());
             }
 
             let value = self.compute_slider_value(&pointer.position);
@@ -204,6 +202,19 @@ impl Element for Slider {
             return;
         }
 
+        if let CraftMessage::AccessibilityAction(request) = _message {
+            let value = match (request.action, &request.data) {
+                (Action::Increment, _) => state.value + self.step,
+                (Action::Decrement, _) => state.value - self.step,
+                (Action::SetValue, Some(ActionData::NumericValue(value))) => *value,
+                _ => return,
+            };
+
+            state.value = value.clamp(self.min, self.max);
+            event.result_message(CraftMessage::SliderValueChanged(state.value));
+            return;
+        }
+
         if let CraftMessage::PointerMovedEvent(pointer) = _message {
             if !state.dragging {
                 return;
@@ -243,6 +254,28 @@ impl Element for Slider {
 
         style
     }
+
+    fn default_accessibility_role(&self) -> Role {
+        Role::Slider
+    }
+
+    fn update_accessibility_node(&self, node: &mut Node, element_state: &ElementStateStore) {
+        let state = self.get_state(element_state);
+        node.set_numeric_value(state.value);
+        node.set_min_numeric_value(self.min);
+        node.set_max_numeric_value(self.max);
+        node.set_numeric_value_step(self.step);
+        if self.direction == SliderDirection::Vertical {
+            node.set_orientation(Orientation::Vertical);
+        }
+        node.add_action(Action::Increment);
+        node.add_action(Action::Decrement);
+        node.add_action(Action::SetValue);
+    }
+
+    fn is_focusable(&self) -> bool {
+        true
+    }
 }
 
 impl Slider {
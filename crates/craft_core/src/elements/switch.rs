@@ -12,6 +12,7 @@ use crate::renderer::renderer::RenderList;
 use crate::style::{Display, Style, Unit};
 use crate::ComponentSpecification;
 use crate::{generate_component_methods_no_children, palette};
+use accesskit::{Action, Node, Role, Toggled};
 use std::any::Any;
 use std::sync::Arc;
 use taffy::{NodeId, TaffyTree};
@@ -206,6 +207,21 @@ impl Element for Switch {
         
         style
     }
+
+    fn default_accessibility_role(&self) -> Role {
+        Role::Switch
+    }
+
+    fn update_accessibility_node(&self, node: &mut Node, element_state: &ElementStateStore) {
+        let state = self.get_state(element_state);
+        let toggled = if state.toggled.unwrap_or(self.default_toggled) { Toggled::True } else { Toggled::False };
+        node.set_toggled(toggled);
+        node.add_action(Action::Click);
+    }
+
+    fn is_focusable(&self) -> bool {
+        true
+    }
 }
 
 impl Default for Switch {
@@ -0,0 +1,726 @@
+use crate::components::component::ComponentSpecification;
+use crate::components::Event;
+use crate::components::Props;
+use crate::elements::element::{resolve_clip_for_scrollable, Element};
+use crate::elements::element_data::ElementData;
+use crate::elements::element_styles::ElementStyles;
+use crate::elements::scroll_state::ScrollState;
+use crate::elements::{Container, Text};
+use crate::events::CraftMessage;
+use crate::generate_component_methods_no_children;
+use crate::geometry::{Point, Rectangle, Size, TrblRectangle};
+use crate::layout::layout_context::LayoutContext;
+use crate::reactive::element_state_store::{ElementStateStore, ElementStateStoreItem};
+use crate::renderer::renderer::RenderList;
+use crate::style::{Display, JustifyContent, Overflow, Style, Unit, Weight};
+use crate::text::text_context::TextContext;
+use crate::WindowContext;
+use accesskit::{Node, Role};
+use peniko::Color;
+use std::any::Any;
+use std::sync::Arc;
+use taffy::{NodeId, TaffyTree};
+use winit::event::{ElementState, MouseButton};
+use winit::keyboard::{Key, NamedKey};
+use winit::window::Window;
+
+/// How far from a column border, in logical pixels, the border can be grabbed to resize the column.
+const RESIZE_HANDLE_WIDTH: f32 = 4.0;
+
+/// How the cells of a column are aligned.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum ColumnAlignment {
+    #[default]
+    Start,
+    Center,
+    End,
+}
+
+/// The direction that a [`Table`] is sorted in.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SortDirection {
+    Ascending,
+    Descending,
+}
+
+/// What can be selected in a [`Table`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum TableSelectionMode {
+    None,
+    #[default]
+    Row,
+    Cell,
+}
+
+/// A column of a [`Table`].
+#[derive(Clone, Debug)]
+pub struct TableColumn {
+    header: String,
+    /// The width in logical pixels, until the column is resized.
+    width: f32,
+    min_width: f32,
+    alignment: ColumnAlignment,
+    sortable: bool,
+}
+
+impl TableColumn {
+    /// A sortable column that is 120 logical pixels wide.
+    pub fn new(header: &str) -> Self {
+        TableColumn {
+            header: header.to_string(),
+            width: 120.0,
+            min_width: 40.0,
+            alignment: ColumnAlignment::Start,
+            sortable: true,
+        }
+    }
+
+    /// Sets the width of the column in logical pixels. The column can be made wider or narrower by dragging its border.
+    pub fn width(mut self, width: f32) -> Self {
+        self.width = width;
+        self
+    }
+
+    /// Sets the width in logical pixels that the column can not be resized below. Defaults to 40.
+    pub fn min_width(mut self, min_width: f32) -> Self {
+        self.min_width = min_width;
+        self
+    }
+
+    pub fn alignment(mut self, alignment: ColumnAlignment) -> Self {
+        self.alignment = alignment;
+        self
+    }
+
+    /// Whether clicking the header sorts the table by this column. Defaults to true.
+    pub fn sortable(mut self, sortable: bool) -> Self {
+        self.sortable = sortable;
+        self
+    }
+}
+
+/// An element for displaying rows of text in columns.
+///
+/// The header stays at the top while the rows scroll. Clicking the header of a sortable column emits a
+/// [`CraftMessage::TableSorted`] event, and the rows have to be sorted by the component, since the table does not know
+/// how to compare them. Columns can be resized by dragging the border of their header, and rows or cells can be
+/// selected with the pointer and the arrow keys.
+///
+/// When the table has an id, the header cells get the id `{id}-header-{column}` and the other cells
+/// `{id}-{row}-{column}`.
+#[derive(Clone)]
+pub struct Table {
+    pub element_data: ElementData,
+    columns: Vec<TableColumn>,
+    rows: Vec<Vec<String>>,
+    selection_mode: TableSelectionMode,
+    sort: Option<(usize, SortDirection)>,
+    header_style: Style,
+    cell_style: Style,
+    selected_style: Style,
+    /// The layout nodes of the header row, followed by the other rows.
+    row_nodes: Vec<NodeId>,
+    /// The part of the table that the rows are visible in, below the header.
+    body_clip_bounds: Option<Rectangle>,
+    scale_factor: f64,
+}
+
+#[derive(Clone, Default)]
+pub struct TableState {
+    pub(crate) scroll_state: ScrollState,
+    /// The widths of the columns in logical pixels, for the columns that were resized.
+    column_widths: Vec<Option<f32>>,
+    sort: Option<(usize, SortDirection)>,
+    /// The sort that the table was last built with, so that the sort only follows it when it changes.
+    sorted_by: Option<(usize, SortDirection)>,
+    /// The selected row and column. The column is ignored when whole rows are selected.
+    selection: Option<(usize, usize)>,
+    /// The column whose border is dragged, with the pointer position and the column width when the drag started.
+    resizing: Option<(usize, f32, f32)>,
+    /// Whether the selection was moved with the keyboard and has to be scrolled into view.
+    scroll_to_selection: bool,
+}
+
+impl Element for Table {
+    fn element_data(&self) -> &ElementData {
+        &self.element_data
+    }
+
+    fn element_data_mut(&mut self) -> &mut ElementData {
+        &mut self.element_data
+    }
+
+    fn name(&self) -> &'static str {
+        "Table"
+    }
+
+    fn draw(
+        &mut self,
+        renderer: &mut RenderList,
+        text_context: &mut TextContext,
+        taffy_tree: &mut TaffyTree<LayoutContext>,
+        _root_node: NodeId,
+        element_state: &mut ElementStateStore,
+        pointer: Option<Point>,
+        window: Option<Arc<dyn Window>>,
+    ) {
+        let base_state = self.get_base_state_mut(element_state);
+        let current_style = base_state.base.current_style(self.element_data());
+
+        if !current_style.visible() {
+            return;
+        }
+
+        // We draw the borders before we start any layers, so that we don't clip the borders.
+        self.draw_borders(renderer, element_state);
+        self.maybe_start_layer(renderer);
+        {
+            // The rows are drawn first, in a layer below the header, so that they scroll under it.
+            let column_count = self.columns.len();
+            let body_clip_bounds = self.body_clip_bounds;
+            if let Some(body_clip_bounds) = body_clip_bounds {
+                renderer.push_layer(body_clip_bounds);
+            }
+            for cell in self.element_data.children.iter_mut().skip(column_count) {
+                let Some(cell_node) = cell.internal.taffy_node_id() else {
+                    continue;
+                };
+                cell.internal.draw(
+                    renderer,
+                    text_context,
+                    taffy_tree,
+                    cell_node,
+                    element_state,
+                    pointer,
+                    window.clone(),
+                );
+            }
+            if body_clip_bounds.is_some() {
+                renderer.pop_layer();
+            }
+
+            for cell in self.element_data.children.iter_mut().take(column_count) {
+                let Some(cell_node) = cell.internal.taffy_node_id() else {
+                    continue;
+                };
+                cell.internal.draw(
+                    renderer,
+                    text_context,
+                    taffy_tree,
+                    cell_node,
+                    element_state,
+                    pointer,
+                    window.clone(),
+                );
+            }
+        }
+        self.maybe_end_layer(renderer);
+
+        self.draw_scrollbar(renderer);
+    }
+
+    fn compute_layout(
+        &mut self,
+        taffy_tree: &mut TaffyTree<LayoutContext>,
+        element_state: &mut ElementStateStore,
+        scale_factor: f64,
+    ) -> Option<NodeId> {
+        self.merge_default_style();
+        self.scale_factor = scale_factor;
+
+        // The cells are laid out in a row for the header and a row for each table row.
+        self.row_nodes.clear();
+        let column_count = self.columns.len().max(1);
+        for row in self.element_data.children.chunks_mut(column_count) {
+            let cell_nodes: Vec<NodeId> = row
+                .iter_mut()
+                .filter_map(|cell| cell.internal.compute_layout(taffy_tree, element_state, scale_factor))
+                .collect();
+            let row_node = taffy_tree.new_with_children(row_style(), &cell_nodes).unwrap();
+            self.row_nodes.push(row_node);
+            self.element_data.layout_item.push_child(&Some(row_node));
+        }
+
+        let base_state = self.get_base_state_mut(element_state);
+        base_state.base.current_style_mut(&mut self.element_data).scale(scale_factor);
+
+        let current_style = {
+            let base_state = self.get_base_state(element_state);
+            base_state.base.current_style(&self.element_data).to_taffy_style()
+        };
+
+        self.element_data.layout_item.build_tree(taffy_tree, current_style)
+    }
+
+    fn finalize_layout(
+        &mut self,
+        taffy_tree: &mut TaffyTree<LayoutContext>,
+        root_node: NodeId,
+        position: Point,
+        z_index: &mut u32,
+        transform: glam::Mat4,
+        element_state: &mut ElementStateStore,
+        pointer: Option<Point>,
+        text_context: &mut TextContext,
+        clip_bounds: Option<Rectangle>,
+    ) {
+        let result = taffy_tree.layout(root_node).unwrap();
+        self.resolve_box(position, transform, result, z_index);
+        self.finalize_borders(element_state);
+
+        self.element_data.layout_item.scrollbar_size =
+            Size::new(result.scrollbar_size.width, result.scrollbar_size.height);
+        self.element_data.layout_item.computed_scrollbar_size =
+            Size::new(result.scroll_width(), result.scroll_height());
+
+        let mut scroll_y = self.get_state(element_state).scroll_state.scroll_y;
+        self.finalize_scrollbar(scroll_y);
+        self.resolve_clip(clip_bounds);
+
+        // The positions of the rows relative to the padding box.
+        let computed_box = self.element_data.layout_item.computed_box;
+        let row_bounds = |row_node: NodeId| {
+            let row_layout = taffy_tree.layout(row_node).unwrap();
+            let top = row_layout.location.y - computed_box.border.top;
+            (top, top + row_layout.size.height)
+        };
+        let header_bottom = self.row_nodes.first().map(|header_node| row_bounds(*header_node).1).unwrap_or(0.0);
+
+        let state = self.get_state_mut(element_state);
+        let selected_row = state.selection.filter(|_| state.scroll_to_selection).map(|(row, _)| row);
+        if let Some(row_node) = selected_row.and_then(|row| self.row_nodes.get(row + 1)) {
+            let (top, bottom) = row_bounds(*row_node);
+            let client_height = computed_box.padding_rectangle().height;
+            if top < scroll_y + header_bottom {
+                scroll_y = top - header_bottom;
+            } else if bottom > scroll_y + client_height {
+                scroll_y = bottom - client_height;
+            }
+            scroll_y = scroll_y.clamp(0.0, self.element_data.layout_item.max_scroll_y);
+        }
+        state.scroll_to_selection = false;
+        state.scroll_state.scroll_y = scroll_y;
+        self.finalize_scrollbar(scroll_y);
+
+        let header_clip_bounds = self.element_data.layout_item.clip_bounds;
+        let body_top = self.element_data.layout_item.computed_box_transformed.position.y
+            + computed_box.border.top
+            + header_bottom;
+        self.body_clip_bounds = header_clip_bounds.map(|clip_bounds| {
+            let top = body_top.max(clip_bounds.top());
+            Rectangle::new(clip_bounds.x, top, clip_bounds.width, (clip_bounds.bottom() - top).max(0.0))
+        });
+
+        let scroll_transform = glam::Mat4::from_translation(glam::Vec3::new(0.0, -scroll_y, 0.0));
+        let column_count = self.columns.len().max(1);
+        for (row_index, (row, row_node)) in
+            self.element_data.children.chunks_mut(column_count).zip(self.row_nodes.iter()).enumerate()
+        {
+            let row_position = computed_box.position + taffy_tree.layout(*row_node).unwrap().location.into();
+            // The header does not scroll.
+            let (row_transform, row_clip_bounds) = if row_index == 0 {
+                (transform, header_clip_bounds)
+            } else {
+                (transform * scroll_transform, self.body_clip_bounds)
+            };
+
+            for cell in row.iter_mut() {
+                let Some(cell_node) = cell.internal.element_data().layout_item.taffy_node_id else {
+                    continue;
+                };
+
+                cell.internal.finalize_layout(
+                    taffy_tree,
+                    cell_node,
+                    row_position,
+                    z_index,
+                    row_transform,
+                    element_state,
+                    pointer,
+                    text_context,
+                    row_clip_bounds,
+                );
+            }
+        }
+    }
+
+    fn resolve_clip(&mut self, clip_bounds: Option<Rectangle>) {
+        resolve_clip_for_scrollable(self, clip_bounds);
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn on_event(
+        &self,
+        message: &CraftMessage,
+        element_state: &mut ElementStateStore,
+        _text_context: &mut TextContext,
+        should_style: bool,
+        event: &mut Event,
+    ) {
+        self.on_style_event(message, element_state, should_style, event);
+        let base_state = self.get_base_state_mut(element_state);
+        let state = base_state.data.as_mut().downcast_mut::<TableState>().unwrap();
+
+        state.scroll_state.on_event(message, &self.element_data, &mut base_state.base, event);
+        // The scrollbar was used.
+        if !event.propagate {
+            return;
+        }
+
+        match message {
+            CraftMessage::PointerButtonEvent(pointer_button)
+                if pointer_button.button.mouse_button() == MouseButton::Left =>
+            {
+                let position = pointer_button.position;
+                if pointer_button.state == ElementState::Pressed {
+                    if let Some(column) = self.resize_handle_at(position) {
+                        state.resizing = Some((column, position.x, self.column_width(state, column)));
+                        base_state.base.pointer_capture.insert(pointer_button.pointer_id(), true);
+                        event.prevent_propagate();
+                    }
+                    return;
+                }
+
+                if let Some((column, ..)) = state.resizing.take() {
+                    base_state.base.pointer_capture.remove(&pointer_button.pointer_id());
+                    event.result_message(CraftMessage::TableColumnResized(column, self.column_width(state, column)));
+                    event.prevent_propagate();
+                    return;
+                }
+                match self.cell_at(position) {
+                    Some((None, column)) if self.columns[column].sortable => {
+                        let direction = match state.sort {
+                            Some((sorted_column, SortDirection::Ascending)) if sorted_column == column => {
+                                SortDirection::Descending
+                            }
+                            _ => SortDirection::Ascending,
+                        };
+                        state.sort = Some((column, direction));
+                        event.result_message(CraftMessage::TableSorted(column, direction));
+                    }
+                    Some((Some(row), column)) => self.select(state, row, column, event),
+                    _ => {}
+                }
+            }
+            CraftMessage::PointerMovedEvent(pointer_moved) => {
+                if let Some((column, start_x, start_width)) = state.resizing {
+                    let width = start_width + (pointer_moved.position.x - start_x) / self.scale_factor as f32;
+                    state.column_widths[column] = Some(width.max(self.columns[column].min_width));
+                    event.prevent_propagate();
+                }
+            }
+            CraftMessage::KeyboardInputEvent(keyboard_input) if keyboard_input.event.state.is_pressed() => {
+                if self.rows.is_empty() || self.columns.is_empty() {
+                    return;
+                }
+
+                let last_row = self.rows.len() - 1;
+                let last_column = self.columns.len() - 1;
+                let selects_cells = self.selection_mode == TableSelectionMode::Cell;
+                let (row, column) = match (&keyboard_input.event.logical_key, state.selection) {
+                    (Key::Named(NamedKey::ArrowUp | NamedKey::ArrowDown | NamedKey::Home), None) => (0, 0),
+                    (Key::Named(NamedKey::End), None) => (last_row, 0),
+                    (Key::Named(NamedKey::ArrowUp), Some((row, column))) => (row.saturating_sub(1), column),
+                    (Key::Named(NamedKey::ArrowDown), Some((row, column))) => ((row + 1).min(last_row), column),
+                    (Key::Named(NamedKey::ArrowLeft), Some((row, column))) if selects_cells => {
+                        (row, column.saturating_sub(1))
+                    }
+                    (Key::Named(NamedKey::ArrowRight), Some((row, column))) if selects_cells => {
+                        (row, (column + 1).min(last_column))
+                    }
+                    (Key::Named(NamedKey::Home), Some((_, column))) => (0, column),
+                    (Key::Named(NamedKey::End), Some((_, column))) => (last_row, column),
+                    _ => return,
+                };
+
+                self.select(state, row, column, event);
+                state.scroll_to_selection = true;
+                event.prevent_propagate();
+            }
+            _ => {}
+        }
+    }
+
+    fn initialize_state(&mut self, _scaling_factor: f64) -> ElementStateStoreItem {
+        ElementStateStoreItem {
+            base: Default::default(),
+            data: Box::new(TableState::default()),
+        }
+    }
+
+    fn materialize_children(
+        &mut self,
+        _children: Vec<ComponentSpecification>,
+        element_state: &mut ElementStateStore,
+        _window: &WindowContext,
+        _scaling_factor: f64,
+    ) -> Vec<ComponentSpecification> {
+        let (row_count, column_count) = (self.rows.len(), self.columns.len());
+        let sort = self.sort;
+        let state = self.get_state_mut(element_state);
+        state.column_widths.resize(column_count, None);
+        if sort != state.sorted_by {
+            state.sort = sort;
+            state.sorted_by = sort;
+        }
+        // The selected row may have been removed.
+        state.selection = state.selection.filter(|(row, column)| *row < row_count && *column < column_count);
+        let state = state.clone();
+
+        let header_style = Style::merge(&default_header_style(), &self.header_style);
+        let selected_style = Style::merge(&default_selected_style(), &self.selected_style);
+
+        let mut cells = Vec::with_capacity((row_count + 1) * column_count);
+        for (column_index, column) in self.columns.iter().enumerate() {
+            let header = match state.sort {
+                Some((sorted_column, SortDirection::Ascending)) if sorted_column == column_index => {
+                    format!("{} ▲", column.header)
+                }
+                Some((sorted_column, SortDirection::Descending)) if sorted_column == column_index => {
+                    format!("{} ▼", column.header)
+                }
+                _ => column.header.clone(),
+            };
+            let text = Text::new(&header).disable_selection().font_weight(Weight::BOLD);
+            let cell = self.cell(&state, column_index, text, &header_style);
+            cells.push(self.with_cell_id(cell, format_args!("header-{column_index}")).component());
+        }
+
+        for (row_index, row) in self.rows.iter().enumerate() {
+            for column_index in 0..column_count {
+                let text = row.get(column_index).map(String::as_str).unwrap_or_default();
+                let is_selected = match (self.selection_mode, state.selection) {
+                    (TableSelectionMode::Row, Some((selected_row, _))) => selected_row == row_index,
+                    (TableSelectionMode::Cell, selection) => selection == Some((row_index, column_index)),
+                    _ => false,
+                };
+                let style = if is_selected { &selected_style } else { &Style::default() };
+                let cell = self.cell(&state, column_index, Text::new(text).disable_selection(), style);
+                cells.push(self.with_cell_id(cell, format_args!("{row_index}-{column_index}")).component());
+            }
+        }
+
+        cells
+    }
+
+    fn default_style(&self) -> Style {
+        let mut style = Style::default();
+        *style.display_mut() = Display::Block;
+        *style.overflow_mut() = [Overflow::Visible, Overflow::Scroll];
+        *style.border_width_mut() = TrblRectangle::new_all(Unit::Px(1.0));
+        *style.border_color_mut() = TrblRectangle::new_all(Color::from_rgb8(200, 200, 200));
+
+        style
+    }
+
+    fn default_accessibility_role(&self) -> Role {
+        Role::Table
+    }
+
+    fn update_accessibility_node(&self, node: &mut Node, element_state: &ElementStateStore) {
+        node.set_row_count(self.rows.len() + 1);
+        node.set_column_count(self.columns.len());
+        self.get_state(element_state).scroll_state.update_accessibility_node(&self.element_data, node);
+    }
+
+    fn update_accessibility_child_node(&self, index: usize, node: &mut Node, element_state: &ElementStateStore) {
+        let Some(column_count) = Some(self.columns.len()).filter(|column_count| *column_count > 0) else {
+            return;
+        };
+        let state = self.get_state(element_state);
+        let (row, column) = (index / column_count, index % column_count);
+        node.set_row_index(row);
+        node.set_column_index(column);
+
+        if row == 0 {
+            node.set_role(Role::ColumnHeader);
+            match state.sort {
+                Some((sorted_column, SortDirection::Ascending)) if sorted_column == column => {
+                    node.set_sort_direction(accesskit::SortDirection::Ascending);
+                }
+                Some((sorted_column, SortDirection::Descending)) if sorted_column == column => {
+                    node.set_sort_direction(accesskit::SortDirection::Descending);
+                }
+                _ => {}
+            }
+        } else {
+            node.set_role(Role::Cell);
+            let is_selected = match (self.selection_mode, state.selection) {
+                (TableSelectionMode::Row, Some((selected_row, _))) => selected_row == row - 1,
+                (TableSelectionMode::Cell, selection) => selection == Some((row - 1, column)),
+                _ => false,
+            };
+            node.set_selected(is_selected);
+        }
+    }
+
+    fn is_focusable(&self) -> bool {
+        true
+    }
+}
+
+/// The style of the layout node that holds the cells of a row.
+fn default_header_style() -> Style {
+    let mut style = Style::default();
+    *style.background_mut() = Color::from_rgb8(240, 240, 240);
+    style
+}
+
+fn default_selected_style() -> Style {
+    let mut style = Style::default();
+    *style.background_mut() = Color::from_rgb8(204, 228, 255);
+    style
+}
+
+fn row_style() -> taffy::Style {
+    taffy::Style {
+        display: taffy::Display::Flex,
+        flex_direction: taffy::FlexDirection::Row,
+        ..Default::default()
+    }
+}
+
+impl Table {
+    fn get_state<'a>(&self, element_state: &'a ElementStateStore) -> &'a TableState {
+        element_state.storage.get(&self.element_data.component_id).unwrap().data.as_ref().downcast_ref().unwrap()
+    }
+
+    fn get_state_mut<'a>(&self, element_state: &'a mut ElementStateStore) -> &'a mut TableState {
+        element_state.storage.get_mut(&self.element_data.component_id).unwrap().data.as_mut().downcast_mut().unwrap()
+    }
+
+    /// The width of a column in logical pixels.
+    fn column_width(&self, state: &TableState, column: usize) -> f32 {
+        state.column_widths.get(column).copied().flatten().unwrap_or(self.columns[column].width)
+    }
+
+    /// Creates the cell of a column that displays `text`, with the cell style and then `style` on top. The text is not
+    /// selectable, since clicks select the cell.
+    fn cell(&self, state: &TableState, column: usize, text: Text, style: &Style) -> Container {
+        let justify_content = match self.columns[column].alignment {
+            ColumnAlignment::Start => JustifyContent::Start,
+            ColumnAlignment::Center => JustifyContent::Center,
+            ColumnAlignment::End => JustifyContent::End,
+        };
+
+        let mut cell = Container::new()
+            .display(Display::Flex)
+            .justify_content(justify_content)
+            .flex_shrink(0.0)
+            .padding(6, 8, 6, 8)
+            .border_width(0, 0, 1, 0)
+            .border_color(Color::from_rgb8(220, 220, 220))
+            .push(text);
+        *cell.styles_mut() = Style::merge(&Style::merge(cell.styles_mut(), &self.cell_style), style);
+
+        // The width of the column always wins, so that the cells line up with the header.
+        cell.width(Unit::Px(self.column_width(state, column)))
+    }
+
+    fn with_cell_id(&self, cell: Container, suffix: std::fmt::Arguments) -> Container {
+        match &self.element_data.id {
+            Some(id) => cell.id(&format!("{id}-{suffix}")),
+            None => cell,
+        }
+    }
+
+    /// Finds the cell at `position`, as the row, which is `None` for the header, and the column.
+    fn cell_at(&self, position: Point) -> Option<(Option<usize>, usize)> {
+        let column_count = self.columns.len();
+        let index = self.element_data.children.iter().position(|cell| cell.internal.in_bounds(position))?;
+        let row = index / column_count;
+
+        Some((row.checked_sub(1), index % column_count))
+    }
+
+    /// Finds the column whose right border in the header is at `position`.
+    fn resize_handle_at(&self, position: Point) -> Option<usize> {
+        let handle_width = RESIZE_HANDLE_WIDTH * self.scale_factor as f32;
+        self.element_data.children.iter().take(self.columns.len()).position(|header| {
+            let header = header.internal.computed_box_transformed().border_rectangle();
+            (position.x - header.right()).abs() <= handle_width
+                && position.y >= header.top()
+                && position.y <= header.bottom()
+        })
+    }
+
+    fn select(&self, state: &mut TableState, row: usize, column: usize, event: &mut Event) {
+        if self.selection_mode == TableSelectionMode::None || state.selection == Some((row, column)) {
+            return;
+        }
+
+        state.selection = Some((row, column));
+        let column = Some(column).filter(|_| self.selection_mode == TableSelectionMode::Cell);
+        event.result_message(CraftMessage::TableSelectionChanged(row, column));
+    }
+
+    pub fn new(columns: Vec<TableColumn>) -> Table {
+        Table {
+            element_data: Default::default(),
+            columns,
+            rows: Vec::new(),
+            selection_mode: TableSelectionMode::default(),
+            sort: None,
+            header_style: Style::default(),
+            cell_style: Style::default(),
+            selected_style: Style::default(),
+            row_nodes: Vec::new(),
+            body_clip_bounds: None,
+            scale_factor: 1.0,
+        }
+    }
+
+    /// Adds a row with a cell for each column.
+    pub fn row<S: Into<String>>(mut self, cells: impl IntoIterator<Item = S>) -> Self {
+        self.rows.push(cells.into_iter().map(Into::into).collect());
+        self
+    }
+
+    /// Adds rows with a cell for each column.
+    pub fn rows<S: Into<String>, R: IntoIterator<Item = S>>(mut self, rows: impl IntoIterator<Item = R>) -> Self {
+        self.rows.extend(rows.into_iter().map(|row| row.into_iter().map(Into::into).collect()));
+        self
+    }
+
+    /// Sets whether rows, cells or nothing can be selected. Defaults to rows.
+    pub fn selection_mode(mut self, selection_mode: TableSelectionMode) -> Self {
+        self.selection_mode = selection_mode;
+        self
+    }
+
+    /// Shows the table as sorted by `column` until a header is clicked, or until the sort changes.
+    pub fn sort(mut self, column: usize, direction: SortDirection) -> Self {
+        self.sort = Some((column, direction));
+        self
+    }
+
+    /// Sets the style of the header cells. This style will get merged with the default header style.
+    pub fn header_style(mut self, header_style: Style) -> Self {
+        self.header_style = header_style;
+        self
+    }
+
+    /// Sets the style of every cell, below the header and selected styles. This style will get merged with the
+    /// default cell style.
+    pub fn cell_style(mut self, cell_style: Style) -> Self {
+        self.cell_style = cell_style;
+        self
+    }
+
+    /// Sets the style of the selected cells. This style will get merged with the default selected style.
+    pub fn selected_style(mut self, selected_style: Style) -> Self {
+        self.selected_style = selected_style;
+        self
+    }
+
+    generate_component_methods_no_children!();
+}
+
+impl ElementStyles for Table {
+    fn styles_mut(&mut self) -> &mut Style {
+        self.element_data.current_style_mut()
+    }
+}
@@ -0,0 +1,481 @@
+use crate::components::component::ComponentSpecification;
+use crate::components::{Event, Props};
+use crate::elements::element::Element;
+use crate::elements::element_data::ElementData;
+use crate::elements::element_styles::ElementStyles;
+use crate::elements::{Container, Text};
+use crate::events::CraftMessage;
+use crate::geometry::{Point, Rectangle, TrblRectangle};
+use crate::layout::layout_context::LayoutContext;
+use crate::reactive::element_state_store::{ElementStateStore, ElementStateStoreItem};
+use crate::renderer::renderer::RenderList;
+use crate::style::{AlignItems, Display, FlexDirection, Style, Unit};
+use crate::text::text_context::TextContext;
+use crate::{generate_component_methods_no_children, WindowContext};
+use accesskit::{Node, Role};
+use peniko::Color;
+use std::any::Any;
+use std::sync::Arc;
+use taffy::{NodeId, TaffyTree};
+use winit::event::MouseScrollDelta;
+use winit::keyboard::{Key, NamedKey};
+use winit::window::Window;
+
+/// An element with a strip of tab titles and the panel of the selected tab below it.
+///
+/// Only the panel of the selected tab is mounted. Panels are keyed by their index, unless they have a key, and with
+/// [`Tabs::keep_panel_state`] they get their state back when their tab is selected again. Selecting a tab, by clicking
+/// its title or with the arrow keys, Home and End while the tabs have the focus, emits `TabChanged` with its index.
+/// When the titles do not fit, the strip scrolls with the mouse wheel and follows the selected tab.
+///
+/// Closable tabs have a close button in their title, which emits `TabClosed` with the index of the tab. The component
+/// has to remove the tab.
+///
+/// When the tabs have an id, the titles get the id `{id}-tab-{index}` and the close buttons `{id}-close-{index}`.
+#[derive(Clone, Default)]
+pub struct Tabs {
+    pub element_data: ElementData,
+    titles: Vec<String>,
+    /// When `state.selected` is None, select this tab.
+    default_selected: usize,
+    /// When set, this tab is always selected, and the component updates it on `TabChanged`.
+    selected: Option<usize>,
+    keep_panel_state: bool,
+    closable: bool,
+    /// The layout node of the strip with the titles, which are the first children.
+    strip_node: Option<NodeId>,
+    /// The part of the tabs that the titles are visible in.
+    strip_clip_bounds: Option<Rectangle>,
+}
+
+#[derive(Clone, Copy, Default)]
+pub struct TabsState {
+    selected: Option<usize>,
+    /// How far the titles are scrolled to the left, in physical pixels.
+    scroll_x: f32,
+    max_scroll_x: f32,
+    /// Whether the selected tab was changed and has to be scrolled into view.
+    scroll_to_selection: bool,
+    /// The last position of the pointer over the tabs, which decides whether the mouse wheel scrolls the titles.
+    pointer: Option<Point>,
+}
+
+impl Element for Tabs {
+    fn element_data(&self) -> &ElementData {
+        &self.element_data
+    }
+
+    fn element_data_mut(&mut self) -> &mut ElementData {
+        &mut self.element_data
+    }
+
+    fn name(&self) -> &'static str {
+        "Tabs"
+    }
+
+    fn draw(
+        &mut self,
+        renderer: &mut RenderList,
+        text_context: &mut TextContext,
+        taffy_tree: &mut TaffyTree<LayoutContext>,
+        _root_node: NodeId,
+        element_state: &mut ElementStateStore,
+        pointer: Option<Point>,
+        window: Option<Arc<dyn Window>>,
+    ) {
+        if !self.element_data.style.visible() {
+            return;
+        }
+        self.draw_borders(renderer, element_state);
+
+        // The titles are drawn in a layer of the strip, so that they are cut off where it ends.
+        let title_count = self.title_count();
+        if let Some(strip_clip_bounds) = self.strip_clip_bounds {
+            renderer.push_layer(strip_clip_bounds);
+        }
+        for title in self.element_data.children.iter_mut().take(title_count) {
+            let Some(title_node) = title.internal.taffy_node_id() else {
+                continue;
+            };
+            title.internal.draw(renderer, text_context, taffy_tree, title_node, element_state, pointer, window.clone());
+        }
+        if self.strip_clip_bounds.is_some() {
+            renderer.pop_layer();
+        }
+
+        for panel in self.element_data.children.iter_mut().skip(title_count) {
+            let Some(panel_node) = panel.internal.taffy_node_id() else {
+                continue;
+            };
+            panel.internal.draw(renderer, text_context, taffy_tree, panel_node, element_state, pointer, window.clone());
+        }
+    }
+
+    fn compute_layout(
+        &mut self,
+        taffy_tree: &mut TaffyTree<LayoutContext>,
+        element_state: &mut ElementStateStore,
+        scale_factor: f64,
+    ) -> Option<NodeId> {
+        self.merge_default_style();
+
+        // The titles are laid out in a row that does not grow with them, above the panel.
+        let title_count = self.title_count();
+        let title_nodes: Vec<NodeId> = self.element_data.children[..title_count]
+            .iter_mut()
+            .filter_map(|title| title.internal.compute_layout(taffy_tree, element_state, scale_factor))
+            .collect();
+        let strip_node = taffy_tree.new_with_children(strip_style(), &title_nodes).unwrap();
+        self.strip_node = Some(strip_node);
+        self.element_data.layout_item.push_child(&Some(strip_node));
+
+        for panel in self.element_data.children[title_count..].iter_mut() {
+            let panel_node = panel.internal.compute_layout(taffy_tree, element_state, scale_factor);
+            self.element_data.layout_item.push_child(&panel_node);
+        }
+
+        self.element_data.style.scale(scale_factor);
+        let style: taffy::Style = self.element_data.style.to_taffy_style();
+
+        self.element_data.layout_item.build_tree(taffy_tree, style)
+    }
+
+    fn finalize_layout(
+        &mut self,
+        taffy_tree: &mut TaffyTree<LayoutContext>,
+        root_node: NodeId,
+        position: Point,
+        z_index: &mut u32,
+        transform: glam::Mat4,
+        element_state: &mut ElementStateStore,
+        pointer: Option<Point>,
+        text_context: &mut TextContext,
+        clip_bounds: Option<Rectangle>,
+    ) {
+        let result = taffy_tree.layout(root_node).unwrap();
+        self.resolve_box(position, transform, result, z_index);
+        self.resolve_clip(clip_bounds);
+        self.finalize_borders(element_state);
+
+        let computed_box = self.element_data.layout_item.computed_box;
+        let title_count = self.title_count();
+        let strip_layout = *taffy_tree.layout(self.strip_node.unwrap()).unwrap();
+        let strip_position = computed_box.position + strip_layout.location.into();
+
+        // The horizontal extent of each title in the strip.
+        let title_bounds: Vec<(f32, f32)> = self.element_data.children[..title_count]
+            .iter()
+            .map(|title| {
+                let layout = title.internal.taffy_node_id().map(|node| taffy_tree.layout(node).unwrap());
+                layout.map(|layout| (layout.location.x, layout.location.x + layout.size.width)).unwrap_or_default()
+            })
+            .collect();
+
+        let selected = self.selected_index(element_state);
+        let state = self.get_state_mut(element_state);
+        let strip_width = strip_layout.size.width;
+        state.max_scroll_x = (title_bounds.last().map(|(_, right)| *right).unwrap_or(0.0) - strip_width).max(0.0);
+        if let Some((left, right)) = title_bounds.get(selected).filter(|_| state.scroll_to_selection) {
+            if *left < state.scroll_x {
+                state.scroll_x = *left;
+            } else if *right > state.scroll_x + strip_width {
+                state.scroll_x = right - strip_width;
+            }
+        }
+        state.scroll_to_selection = false;
+        state.scroll_x = state.scroll_x.clamp(0.0, state.max_scroll_x);
+        let scroll_x = state.scroll_x;
+
+        let transformed_position = self.element_data.layout_item.computed_box_transformed.position;
+        let strip_rectangle = Rectangle::new(
+            transformed_position.x + strip_layout.location.x,
+            transformed_position.y + strip_layout.location.y,
+            strip_width,
+            strip_layout.size.height,
+        );
+        self.strip_clip_bounds = match self.element_data.layout_item.clip_bounds {
+            Some(clip_bounds) => strip_rectangle.intersection(&clip_bounds),
+            None => Some(strip_rectangle),
+        };
+
+        let scroll_transform = glam::Mat4::from_translation(glam::Vec3::new(-scroll_x, 0.0, 0.0));
+        for (index, child) in self.element_data.children.iter_mut().enumerate() {
+            let Some(child_node) = child.internal.element_data().layout_item.taffy_node_id else {
+                continue;
+            };
+
+            let (child_position, child_transform, child_clip_bounds) = if index < title_count {
+                (strip_position, transform * scroll_transform, self.strip_clip_bounds)
+            } else {
+                (computed_box.position, transform, self.element_data.layout_item.clip_bounds)
+            };
+            child.internal.finalize_layout(
+                taffy_tree,
+                child_node,
+                child_position,
+                z_index,
+                child_transform,
+                element_state,
+                pointer,
+                text_context,
+                child_clip_bounds,
+            );
+        }
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn on_event(
+        &self,
+        message: &CraftMessage,
+        element_state: &mut ElementStateStore,
+        _text_context: &mut TextContext,
+        should_style: bool,
+        event: &mut Event,
+    ) {
+        self.on_style_event(message, element_state, should_style, event);
+        let title_count = self.title_count();
+        if title_count == 0 {
+            return;
+        }
+
+        let is_focused = self.get_base_state(element_state).base.focused;
+        let selected = self.selected_index(element_state);
+        let titles = &self.children()[..title_count];
+        let title_at = |point: Point| titles.iter().position(|title| title.in_bounds(point));
+        // The close button is the last child of a title.
+        let close_button_at = |point: Point| {
+            let is_close_button =
+                |title: &&dyn Element| title.children().last().is_some_and(|close| close.in_bounds(point));
+            titles.iter().position(is_close_button).filter(|_| self.closable)
+        };
+        let state = self.get_state_mut(element_state);
+
+        match message {
+            CraftMessage::PointerMovedEvent(pointer_moved) => {
+                state.pointer = Some(pointer_moved.position);
+            }
+            CraftMessage::PointerButtonEvent(pointer_button) if pointer_button.clicked() => {
+                let position = pointer_button.position;
+                if let Some(index) = close_button_at(position) {
+                    // The tabs after the closed one move to the left.
+                    if self.selected.is_none() && index < selected {
+                        state.selected = Some(selected - 1);
+                    }
+                    event.result_message(CraftMessage::TabClosed(index));
+                    event.prevent_propagate();
+                } else if let Some(index) = title_at(position) {
+                    self.select(state, selected, index, event);
+                    event.prevent_propagate();
+                }
+            }
+            CraftMessage::MouseWheelEvent(mouse_wheel) => {
+                let is_over_strip = state.pointer.is_some_and(|pointer| {
+                    self.strip_clip_bounds.is_some_and(|strip_clip_bounds| strip_clip_bounds.contains(&pointer))
+                });
+                if is_over_strip && state.max_scroll_x > 0.0 {
+                    let delta = match mouse_wheel.delta {
+                        MouseScrollDelta::LineDelta(x, y) => (if x != 0.0 { x } else { y }) * 20.0,
+                        MouseScrollDelta::PixelDelta(delta) => (if delta.x != 0.0 { delta.x } else { delta.y }) as f32,
+                    };
+                    state.scroll_x = (state.scroll_x - delta).clamp(0.0, state.max_scroll_x);
+                    event.prevent_propagate();
+                }
+            }
+            CraftMessage::KeyboardInputEvent(keyboard_input) if is_focused => {
+                if !keyboard_input.event.state.is_pressed() {
+                    return;
+                }
+                let index = match keyboard_input.event.logical_key {
+                    Key::Named(NamedKey::ArrowRight) => Some((selected + 1) % title_count),
+                    Key::Named(NamedKey::ArrowLeft) => Some((selected + title_count - 1) % title_count),
+                    Key::Named(NamedKey::Home) => Some(0),
+                    Key::Named(NamedKey::End) => Some(title_count - 1),
+                    _ => None,
+                };
+                if let Some(index) = index {
+                    self.select(state, selected, index, event);
+                    event.prevent_defaults();
+                }
+            }
+            _ => {}
+        }
+    }
+
+    fn initialize_state(&mut self, _scaling_factor: f64) -> ElementStateStoreItem {
+        ElementStateStoreItem {
+            base: Default::default(),
+            data: Box::new(TabsState::default()),
+        }
+    }
+
+    fn materialize_children(
+        &mut self,
+        children: Vec<ComponentSpecification>,
+        element_state: &mut ElementStateStore,
+        _window: &WindowContext,
+        _scaling_factor: f64,
+    ) -> Vec<ComponentSpecification> {
+        let selected = self.selected_index(element_state);
+        let id = self.element_data.id.as_deref();
+
+        let mut tabs = Vec::with_capacity(self.titles.len() + 1);
+        for (index, title) in self.titles.iter().enumerate() {
+            let accent = if index == selected { Color::from_rgb8(0, 120, 215) } else { Color::TRANSPARENT };
+            let mut title_element = Container::new()
+                .display(Display::Flex)
+                .align_items(AlignItems::Center)
+                .gap(Unit::Px(6.0))
+                .flex_shrink(0.0)
+                .padding(Unit::Px(6.0), Unit::Px(12.0), Unit::Px(6.0), Unit::Px(12.0))
+                .border_width(Unit::Px(0.0), Unit::Px(0.0), Unit::Px(2.0), Unit::Px(0.0))
+                .border_color(accent)
+                .push(Text::new(title).disable_selection());
+            if let Some(id) = id {
+                title_element = title_element.id(&format!("{id}-tab-{index}"));
+            }
+            if self.closable {
+                let mut close_button = Text::new("×").disable_selection();
+                if let Some(id) = id {
+                    close_button = close_button.id(&format!("{id}-close-{index}"));
+                }
+                title_element = title_element.push(close_button);
+            }
+            tabs.push(title_element.component());
+        }
+
+        if let Some(mut panel) = children.into_iter().nth(selected) {
+            panel.key.get_or_insert_with(|| selected.to_string());
+            tabs.push(panel);
+        }
+
+        tabs
+    }
+
+    fn retained_children_limit(&self) -> usize {
+        if self.keep_panel_state {
+            self.titles.len()
+        } else {
+            0
+        }
+    }
+
+    fn default_style(&self) -> Style {
+        let mut style = Style::default();
+        *style.display_mut() = Display::Flex;
+        *style.flex_direction_mut() = FlexDirection::Column;
+        *style.border_width_mut() = TrblRectangle::new_all(Unit::Px(1.0));
+        *style.border_color_mut() = TrblRectangle::new_all(Color::from_rgb8(200, 200, 200));
+
+        style
+    }
+
+    fn default_accessibility_role(&self) -> Role {
+        Role::TabList
+    }
+
+    fn update_accessibility_child_node(&self, index: usize, node: &mut Node, element_state: &ElementStateStore) {
+        if index < self.title_count() {
+            node.set_role(Role::Tab);
+            node.set_selected(index == self.selected_index(element_state));
+        } else {
+            node.set_role(Role::TabPanel);
+        }
+    }
+
+    fn is_focusable(&self) -> bool {
+        true
+    }
+}
+
+fn strip_style() -> taffy::Style {
+    taffy::Style {
+        display: taffy::Display::Flex,
+        flex_direction: taffy::FlexDirection::Row,
+        flex_shrink: 0.0,
+        // The titles overflow the strip instead of widening the tabs.
+        overflow: taffy::Point {
+            x: taffy::Overflow::Hidden,
+            y: taffy::Overflow::Visible,
+        },
+        ..Default::default()
+    }
+}
+
+impl Tabs {
+    pub fn new() -> Tabs {
+        Tabs::default()
+    }
+
+    /// Adds a tab with the given title and panel after the other tabs.
+    pub fn tab<T: Into<ComponentSpecification>>(mut self, title: &str, panel: T) -> Self {
+        self.titles.push(title.to_string());
+        self.element_data.child_specs.push(panel.into());
+        self
+    }
+
+    pub fn default_selected(mut self, index: usize) -> Self {
+        self.default_selected = index;
+        self
+    }
+
+    /// Controls the selected tab. Selecting a tab only emits `TabChanged`, and the selection changes when the component
+    /// passes the new index.
+    pub fn selected(mut self, index: usize) -> Self {
+        self.selected = Some(index);
+        self
+    }
+
+    /// Whether the panels of the other tabs keep their state, instead of starting over when their tab is selected
+    /// again. Defaults to false.
+    pub fn keep_panel_state(mut self, keep_panel_state: bool) -> Self {
+        self.keep_panel_state = keep_panel_state;
+        self
+    }
+
+    /// Whether the titles have a close button that emits `TabClosed`. Defaults to false.
+    pub fn closable(mut self, closable: bool) -> Self {
+        self.closable = closable;
+        self
+    }
+
+    /// The number of titles at the start of the children.
+    fn title_count(&self) -> usize {
+        self.titles.len().min(self.element_data.children.len())
+    }
+
+    fn selected_index(&self, element_state: &ElementStateStore) -> usize {
+        let selected = self.selected.or(self.get_state(element_state).selected).unwrap_or(self.default_selected);
+        selected.min(self.titles.len().saturating_sub(1))
+    }
+
+    fn select(&self, state: &mut TabsState, selected: usize, index: usize, event: &mut Event) {
+        state.scroll_to_selection = true;
+        if index == selected {
+            return;
+        }
+        if self.selected.is_none() {
+            state.selected = Some(index);
+        }
+        event.result_message(CraftMessage::TabChanged(index));
+    }
+
+    #[allow(dead_code)]
+    fn get_state<'a>(&self, element_state: &'a ElementStateStore) -> &'a TabsState {
+        element_state.storage.get(&self.element_data.component_id).unwrap().data.as_ref().downcast_ref().unwrap()
+    }
+
+    fn get_state_mut<'a>(&self, element_state: &'a mut ElementStateStore) -> &'a mut TabsState {
+        element_state.storage.get_mut(&self.element_data.component_id).unwrap().data.as_mut().downcast_mut().unwrap()
+    }
+
+    generate_component_methods_no_children!();
+}
+
+impl ElementStyles for Tabs {
+    fn styles_mut(&mut self) -> &mut Style {
+        self.element_data.current_style_mut()
+    }
+}
@@ -0,0 +1,96 @@
+use crate::accessibility::Toggled;
+use crate::components::{Component, ComponentId, ComponentSpecification, Event};
+use crate::elements::{CheckState, Checkbox, Container, ElementStyles, Radio, RadioGroup, Text};
+use crate::geometry::Size;
+use crate::style::FlexDirection;
+use crate::testing::helpers::{accessibility_node, focused_id, state, toggled};
+use crate::testing::TestHarness;
+use crate::WindowContext;
+use winit::keyboard::Key;
+
+/// Checkboxes and a group of radio buttons. The `all` checkbox is controlled and starts indeterminate.
+pub(super) struct Preferences {
+    terms: bool,
+    all: CheckState,
+    pub(super) size: String,
+}
+
+impl Default for Preferences {
+    fn default() -> Self {
+        Preferences {
+            terms: false,
+            all: CheckState::Indeterminate,
+            size: String::new(),
+        }
+    }
+}
+
+impl Component for Preferences {
+    type GlobalState = ();
+    type Props = ();
+    type Message = ();
+
+    fn view(
+        &self,
+        _global_state: &Self::GlobalState,
+        _props: &Self::Props,
+        _children: Vec<ComponentSpecification>,
+        _id: ComponentId,
+        _window: &WindowContext,
+    ) -> ComponentSpecification {
+        Container::new()
+            .flex_direction(FlexDirection::Column)
+            .push(
+                Checkbox::default()
+                    .id("terms")
+                    .push(Text::new("I agree").id("terms-label").disable_selection())
+                    .on_checkbox_toggled(|state: &mut Preferences, _: &mut (), _: &mut Event, checked: bool| {
+                        state.terms = checked;
+                    }),
+            )
+            .push(Checkbox::default().id("all").label("All").checked(self.all).on_checkbox_toggled(
+                |state: &mut Preferences, _: &mut (), _: &mut Event, checked: bool| {
+                    state.all = if checked { CheckState::Checked } else { CheckState::Unchecked };
+                },
+            ))
+            .push(Checkbox::default().id("locked").label("Locked").enabled(false))
+            .push(
+                RadioGroup::new("Size")
+                    .id("size")
+                    .default_selected("m")
+                    .push(Radio::new("s").id("s").label("Small"))
+                    .push(Radio::new("m").id("m").label("Medium"))
+                    .push(Container::new().push(Radio::new("l").id("l").label("Large")))
+                    .push(Radio::new("xl").id("xl").label("Extra large").enabled(false))
+                    .on_radio_selected(|state: &mut Preferences, _: &mut (), _: &mut Event, size: &str| {
+                        state.size = size.to_string();
+                    }),
+            )
+            .component()
+    }
+}
+
+#[test]
+fn checkbox_toggles_from_its_label_and_the_keyboard() {
+    let mut harness = TestHarness::new(Preferences::component(), (), Size::new(400.0, 400.0));
+
+    harness.click_element("terms-label");
+    assert!(state::<Preferences>(&harness).terms);
+    assert_eq!(toggled(&harness, "terms"), Some(Toggled::True));
+    assert_eq!(focused_id(&harness).as_deref(), Some("terms"));
+
+    harness.key_press(Key::Character(" ".into()));
+    assert!(!state::<Preferences>(&harness).terms);
+    assert_eq!(toggled(&harness, "terms"), Some(Toggled::False));
+
+    // A controlled checkbox shows the state that the component passes.
+    assert_eq!(toggled(&harness, "all"), Some(Toggled::Mixed));
+    harness.click_element("all");
+    assert_eq!(state::<Preferences>(&harness).all, CheckState::Checked);
+    assert_eq!(toggled(&harness, "all"), Some(Toggled::True));
+
+    harness.click_element("locked");
+    let tree = harness.accessibility_tree();
+    let locked = accessibility_node(&harness, &tree, "locked");
+    assert_eq!((locked.toggled(), locked.is_disabled(), locked.label()), (Some(Toggled::False), true, Some("Locked")));
+}
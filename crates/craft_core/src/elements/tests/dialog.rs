@@ -0,0 +1,134 @@
+use crate::accessibility::Role;
+use crate::components::{Component, ComponentId, ComponentSpecification, Event};
+use crate::elements::{Container, Dialog, ElementStyles, Text, TextInput};
+use crate::events::{KeyboardInput, PointerButton};
+use crate::geometry::Point;
+use crate::testing::helpers::{accessibility_node, focused_id, mount, state};
+use crate::WindowContext;
+use winit::keyboard::{Key, NamedKey};
+
+#[derive(Default)]
+struct Confirmation {
+    open: bool,
+    log: Vec<&'static str>,
+}
+
+impl Component for Confirmation {
+    type GlobalState = ();
+    /// Whether the dialog closes on Escape and on backdrop clicks.
+    type Props = (bool, bool);
+    type Message = ();
+
+    fn view(
+        &self,
+        _global_state: &Self::GlobalState,
+        props: &Self::Props,
+        _children: Vec<ComponentSpecification>,
+        _id: ComponentId,
+        _window: &WindowContext,
+    ) -> ComponentSpecification {
+        let (close_on_escape, close_on_backdrop_click) = *props;
+        let mut root = Container::new()
+            .id("root")
+            .width("100%")
+            .height("100%")
+            .on_keyboard_input(|state: &mut Confirmation, _: &mut (), _: &mut Event, _: &KeyboardInput| {
+                state.log.push("key")
+            })
+            .push(TextInput::new("").id("search"))
+            .push(Container::new().id("delete").width(80).height(30).on_pointer_button(
+                |state: &mut Confirmation, _: &mut (), _: &mut Event, pointer_button: &PointerButton| {
+                    if pointer_button.clicked() {
+                        state.open = true;
+                        state.log.push("delete");
+                    }
+                },
+            ));
+
+        if self.open {
+            root = root.push(
+                Dialog::new()
+                    .id("dialog")
+                    .close_on_escape(close_on_escape)
+                    .close_on_backdrop_click(close_on_backdrop_click)
+                    .on_dialog_closed(|state: &mut Confirmation, _: &mut (), _: &mut Event| {
+                        state.open = false;
+                        state.log.push("closed");
+                    })
+                    .push(Text::new("Delete the file?"))
+                    .push(TextInput::new("").id("reason"))
+                    .push(TextInput::new("").id("note"))
+                    .push(Container::new().id("confirm").width(60).height(20).on_pointer_button(
+                        |state: &mut Confirmation, _: &mut (), _: &mut Event, pointer_button: &PointerButton| {
+                            if pointer_button.clicked() {
+                                state.open = false;
+                                state.log.push("confirm");
+                            }
+                        },
+                    )),
+            );
+        }
+
+        root.component()
+    }
+}
+
+#[test]
+fn dialog_keeps_the_events_and_the_focus_inside() {
+    let mut harness = mount::<Confirmation>((true, true));
+    harness.click_element("search");
+    harness.click_element("delete");
+    assert_eq!(state::<Confirmation>(&harness).log, ["delete"]);
+
+    let dialog = harness.element_bounds("dialog").unwrap();
+    assert_eq!((dialog.x + dialog.width / 2.0, dialog.y + dialog.height / 2.0), (200.0, 150.0));
+    assert!(harness.render_list_snapshot().contains("overlay"));
+    let tree = harness.accessibility_tree();
+    let node = accessibility_node(&harness, &tree, "dialog");
+    assert_eq!(node.role(), Role::Dialog);
+    assert!(node.is_modal());
+
+    // The elements underneath do not receive the pointer or the keys, even the one with the focus.
+    harness.click_element("delete");
+    harness.key_press(Key::Character("a".into()));
+    assert_eq!(state::<Confirmation>(&harness).log, ["delete"]);
+
+    // Tab moves the focus into the dialog and cycles through its elements.
+    harness.key_press(Key::Named(NamedKey::Tab));
+    assert_eq!(focused_id(&harness).as_deref(), Some("reason"));
+    harness.key_press(Key::Named(NamedKey::Tab));
+    assert_eq!(focused_id(&harness).as_deref(), Some("note"));
+    harness.key_press(Key::Named(NamedKey::Tab));
+    assert_eq!(focused_id(&harness).as_deref(), Some("reason"));
+
+    harness.click_element("confirm");
+    assert!(harness.find_element_by_id("dialog").is_none());
+    harness.click_element("search");
+    harness.key_press(Key::Character("a".into()));
+    assert_eq!(state::<Confirmation>(&harness).log, ["delete", "confirm", "key", "key"]);
+}
+
+#[test]
+fn dialog_closes_on_escape_and_backdrop_clicks_unless_disabled() {
+    let mut harness = mount::<Confirmation>((true, true));
+    harness.click_element("delete");
+    harness.click_element("reason");
+    harness.key_press(Key::Named(NamedKey::Escape));
+    assert!(harness.find_element_by_id("dialog").is_none());
+
+    // Clicks inside of the dialog keep it open.
+    harness.click_element("delete");
+    let dialog = harness.element_bounds("dialog").unwrap();
+    harness.click(Point::new(dialog.x + 4.0, dialog.y + 4.0));
+    assert!(harness.find_element_by_id("dialog").is_some());
+    harness.click(Point::new(dialog.x - 10.0, dialog.y));
+    assert!(harness.find_element_by_id("dialog").is_none());
+    assert_eq!(state::<Confirmation>(&harness).log, ["delete", "closed", "delete", "closed"]);
+
+    let mut harness = mount::<Confirmation>((false, false));
+    harness.click_element("delete");
+    harness.key_press(Key::Named(NamedKey::Escape));
+    harness.click(Point::new(5.0, 5.0));
+    assert!(harness.find_element_by_id("dialog").is_some());
+    assert_eq!(state::<Confirmation>(&harness).log, ["delete"]);
+}
@@ -0,0 +1,134 @@
+use crate::components::{Component, ComponentId, ComponentSpecification, Event};
+use crate::elements::{Container, ContextMenu, ElementStyles, MenuBar, MenuItem};
+use crate::geometry::Size;
+use crate::style::FlexDirection;
+use crate::testing::helpers::{accessibility_node, focused_id, hover_element, right_click, state};
+use crate::testing::TestHarness;
+use crate::WindowContext;
+use winit::keyboard::{Key, NamedKey};
+
+/// A canvas with a context menu, below a menu bar. The chosen items are recorded.
+#[derive(Default)]
+struct Editor {
+    chosen: Vec<String>,
+}
+
+impl Component for Editor {
+    type GlobalState = ();
+    type Props = ();
+    type Message = ();
+
+    fn view(
+        &self,
+        _global_state: &Self::GlobalState,
+        _props: &Self::Props,
+        _children: Vec<ComponentSpecification>,
+        _id: ComponentId,
+        _window: &WindowContext,
+    ) -> ComponentSpecification {
+        let record = |state: &mut Editor, _: &mut (), _: &mut Event, id: &str| state.chosen.push(id.to_string());
+        let context_items = vec![
+            MenuItem::new("Copy").id("copy").shortcut("Ctrl+C"),
+            MenuItem::new("Paste").id("paste").disabled(true),
+            MenuItem::separator(),
+            MenuItem::new("View").id("view").push(MenuItem::new("Zoom in").id("zoom")).push(MenuItem::new("Reset")),
+        ];
+
+        Container::new()
+            .flex_direction(FlexDirection::Column)
+            .push(
+                MenuBar::new()
+                    .id("bar")
+                    .menu("File", vec![MenuItem::new("New").id("new"), MenuItem::new("Open").id("open")])
+                    .menu("Edit", vec![MenuItem::new("Undo").id("undo")])
+                    .on_menu_item_selected(record),
+            )
+            .push(
+                ContextMenu::new(context_items)
+                    .id("editor")
+                    .push(Container::new().id("canvas").width(300).height(200))
+                    .on_menu_item_selected(record),
+            )
+            .push(Container::new().id("outside").width(300).height(100))
+            .component()
+    }
+}
+
+#[test]
+fn context_menu_opens_on_right_click_and_chooses_items() {
+    let mut harness = TestHarness::new(Editor::component(), (), Size::new(400.0, 400.0));
+    assert!(harness.find_element_by_id("editor-copy").is_none());
+
+    right_click(&mut harness, "canvas");
+    let canvas = harness.element_bounds("canvas").unwrap();
+    let copy = harness.element_bounds("editor-copy").unwrap();
+    assert_eq!((copy.x, copy.y), (canvas.x + 11.0, canvas.y + 15.0));
+    assert!(harness.render_list_snapshot().contains("overlay"));
+
+    // Disabled items can not be chosen, and the submenu opens on hover.
+    harness.click_element("editor-paste");
+    assert!(harness.find_element_by_id("editor-zoom").is_none());
+    hover_element(&mut harness, "editor-view");
+    let view = harness.element_bounds("editor-view").unwrap();
+    assert!(harness.element_bounds("editor-zoom").unwrap().x >= view.right());
+    harness.click_element("editor-zoom");
+    assert_eq!(state::<Editor>(&harness).chosen, ["zoom"]);
+    assert!(harness.find_element_by_id("editor-copy").is_none());
+
+    // Clicking outside of the menu closes it.
+    right_click(&mut harness, "canvas");
+    harness.click_element("outside");
+    assert!(harness.find_element_by_id("editor-copy").is_none());
+    assert_eq!(state::<Editor>(&harness).chosen, ["zoom"]);
+}
+
+#[test]
+fn context_menu_is_navigated_with_the_keyboard() {
+    let mut harness = TestHarness::new(Editor::component(), (), Size::new(400.0, 400.0));
+    right_click(&mut harness, "canvas");
+    assert_eq!(focused_id(&harness).as_deref(), Some("editor"));
+
+    // The highlight skips the disabled item and the separator, and wraps around.
+    for key in [NamedKey::ArrowDown, NamedKey::ArrowDown, NamedKey::ArrowRight] {
+        harness.key_press(Key::Named(key));
+    }
+    assert!(harness.find_element_by_id("editor-zoom").is_some());
+    harness.key_press(Key::Named(NamedKey::Escape));
+    assert!(harness.find_element_by_id("editor-zoom").is_none());
+    harness.key_press(Key::Named(NamedKey::ArrowDown));
+    harness.key_press(Key::Named(NamedKey::Enter));
+    assert_eq!(state::<Editor>(&harness).chosen, ["copy"]);
+    assert!(harness.find_element_by_id("editor-copy").is_none());
+
+    right_click(&mut harness, "canvas");
+    harness.key_press(Key::Named(NamedKey::Escape));
+    assert!(harness.find_element_by_id("editor-copy").is_none());
+    assert_eq!(state::<Editor>(&harness).chosen, ["copy"]);
+}
+
+#[test]
+fn menu_bar_opens_menus_from_their_titles() {
+    let mut harness = TestHarness::new(Editor::component(), (), Size::new(400.0, 400.0));
+    let expanded =
+        |harness: &TestHarness, id: &str| accessibility_node(harness, &harness.accessibility_tree(), id).is_expanded();
+
+    harness.click_element("bar-menu-0");
+    assert_eq!(expanded(&harness, "bar-menu-0"), Some(true));
+    let title = harness.element_bounds("bar-menu-0").unwrap();
+    assert!(harness.element_bounds("bar-new").unwrap().y >= title.bottom());
+
+    // Hovering another title while a menu is open switches to its menu, and the arrow keys move between the menus.
+    hover_element(&mut harness, "bar-menu-1");
+    assert!(harness.find_element_by_id("bar-new").is_none());
+    assert!(harness.find_element_by_id("bar-undo").is_some());
+    harness.key_press(Key::Named(NamedKey::ArrowRight));
+    assert_eq!(expanded(&harness, "bar-menu-0"), Some(true));
+    harness.key_press(Key::Named(NamedKey::ArrowDown));
+    harness.key_press(Key::Named(NamedKey::Enter));
+    assert_eq!(state::<Editor>(&harness).chosen, ["open"]);
+    assert_eq!(expanded(&harness, "bar-menu-0"), Some(false));
+
+    harness.click_element("bar-menu-1");
+    harness.click_element("bar-menu-1");
+    assert!(harness.find_element_by_id("bar-undo").is_none());
+}
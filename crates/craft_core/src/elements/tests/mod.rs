@@ -0,0 +1,9 @@
+mod checkbox;
+mod dialog;
+mod menu;
+mod radio;
+mod table;
+mod tabs;
+mod text_input;
+mod tooltip;
+mod virtual_list;
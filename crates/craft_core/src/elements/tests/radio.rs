@@ -0,0 +1,94 @@
+use crate::accessibility::{Role, Toggled};
+use crate::components::{Component, ComponentId, ComponentSpecification, Event};
+use crate::elements::{Checkbox, Radio, RadioGroup, Text};
+use crate::geometry::Size;
+use crate::testing::helpers::{accessibility_node, focused_id, state, toggled};
+use crate::testing::TestHarness;
+use crate::WindowContext;
+use super::checkbox::Preferences;
+use winit::keyboard::{Key, NamedKey};
+
+#[test]
+fn radio_group_selects_a_single_value() {
+    let mut harness = TestHarness::new(Preferences::component(), (), Size::new(400.0, 400.0));
+    let selected = |harness: &TestHarness| -> Vec<&'static str> {
+        let tree = harness.accessibility_tree();
+        ["s", "m", "l", "xl"]
+            .into_iter()
+            .filter(|id| accessibility_node(harness, &tree, id).toggled() == Some(Toggled::True))
+            .collect()
+    };
+    let tree = harness.accessibility_tree();
+    let group = accessibility_node(&harness, &tree, "size");
+    assert_eq!((group.role(), group.label()), (Role::RadioGroup, Some("Size")));
+    assert_eq!(selected(&harness), ["m"]);
+
+    harness.click_element("s");
+    assert_eq!(state::<Preferences>(&harness).size, "s");
+    assert_eq!(selected(&harness), ["s"]);
+
+    // The arrow keys skip the disabled radio button and wrap around.
+    for (expected, key) in [("m", NamedKey::ArrowDown), ("l", NamedKey::ArrowDown), ("s", NamedKey::ArrowRight)] {
+        harness.key_press(Key::Named(key));
+        assert_eq!(state::<Preferences>(&harness).size, expected);
+        assert_eq!(selected(&harness), [expected]);
+        assert_eq!(focused_id(&harness).as_deref(), Some(expected));
+    }
+
+    harness.click_element("xl");
+    assert_eq!(selected(&harness), ["s"]);
+}
+
+/// A group of sizes with a radio button in a nested group of colors, and one in a checkbox that stops the click.
+#[derive(Default)]
+struct NestedRadios {
+    size: String,
+}
+
+impl Component for NestedRadios {
+    type GlobalState = ();
+    type Props = ();
+    type Message = ();
+
+    fn view(
+        &self,
+        _global_state: &Self::GlobalState,
+        _props: &Self::Props,
+        _children: Vec<ComponentSpecification>,
+        _id: ComponentId,
+        _window: &WindowContext,
+    ) -> ComponentSpecification {
+        RadioGroup::new("Size")
+            .push(Radio::new("s").id("s").label("Small"))
+            .push(
+                RadioGroup::new("Color")
+                    .push(Radio::new("red").id("red").label("Red"))
+                    .push(Radio::new("l").id("l").group("Size").label("Large")),
+            )
+            .push(Checkbox::default().push(Radio::new("m").id("m").label("Medium")))
+            .push(Text::new("Pick a size").id("note"))
+            .on_radio_selected(|state: &mut NestedRadios, _: &mut (), _: &mut Event, size: &str| {
+                state.size = size.to_string();
+            })
+            .component()
+    }
+}
+
+#[test]
+fn radio_buttons_belong_to_the_group_they_name() {
+    let mut harness = TestHarness::new(NestedRadios::component(), (), Size::new(400.0, 400.0));
+
+    harness.click_element("l");
+    assert_eq!(state::<NestedRadios>(&harness).size, "l");
+    assert_eq!(toggled(&harness, "l"), Some(Toggled::True));
+
+    harness.click_element("red");
+    assert_eq!(state::<NestedRadios>(&harness).size, "l");
+    assert_eq!((toggled(&harness, "red"), toggled(&harness, "l")), (Some(Toggled::True), Some(Toggled::True)));
+
+    // A click that does not reach the group does not select the radio button later on.
+    harness.click_element("m");
+    harness.click_element("note");
+    assert_eq!(state::<NestedRadios>(&harness).size, "l");
+    assert_eq!(toggled(&harness, "m"), Some(Toggled::False));
+}
@@ -0,0 +1,206 @@
+use crate::accessibility::Role;
+use crate::components::{Component, ComponentId, ComponentSpecification, Event};
+use crate::elements::{Container, ElementStyles, SortDirection, Table, TableColumn, TableSelectionMode};
+use crate::events::PointerButton;
+use crate::geometry::Point;
+use crate::style::Style;
+use crate::testing::helpers::{accessibility_node, mount, state};
+use crate::testing::TestHarness;
+use crate::{rgb, WindowContext};
+use winit::event::{ElementState, MouseButton};
+use winit::keyboard::{Key, NamedKey};
+
+#[derive(Default)]
+struct Inventory {
+    sort: Option<(usize, SortDirection)>,
+    selection: Option<(usize, Option<usize>)>,
+    resized: Option<(usize, f32)>,
+}
+
+impl Component for Inventory {
+    type GlobalState = ();
+    type Props = TableSelectionMode;
+    type Message = ();
+
+    fn view(
+        &self,
+        _global_state: &Self::GlobalState,
+        selection_mode: &Self::Props,
+        _children: Vec<ComponentSpecification>,
+        _id: ComponentId,
+        _window: &WindowContext,
+    ) -> ComponentSpecification {
+        let mut items: Vec<(String, usize)> =
+            (0..20).map(|index| (format!("Item {index}"), (index * 7) % 20)).collect();
+        if let Some((column, direction)) = self.sort {
+            match column {
+                0 => items.sort_by(|a, b| a.0.cmp(&b.0)),
+                _ => items.sort_by_key(|(_, count)| *count),
+            }
+            if direction == SortDirection::Descending {
+                items.reverse();
+            }
+        }
+
+        Table::new(vec![TableColumn::new("Name").width(150.0), TableColumn::new("Count").width(80.0).min_width(60.0)])
+            .rows(items.into_iter().map(|(name, count)| [name, count.to_string()]))
+            .selection_mode(*selection_mode)
+            .id("table")
+            .width(300)
+            .height(150)
+            .on_table_sorted(|state: &mut Inventory, _: &mut (), _: &mut Event, column, direction| {
+                state.sort = Some((column, direction));
+            })
+            .on_table_selection_changed(|state: &mut Inventory, _: &mut (), _: &mut Event, row, column| {
+                state.selection = Some((row, column));
+            })
+            .on_table_column_resized(|state: &mut Inventory, _: &mut (), _: &mut Event, column, width| {
+                state.resized = Some((column, width));
+            })
+            .component()
+    }
+}
+
+#[test]
+fn table_headers_sort_the_rows() {
+    let mut harness = mount::<Inventory>(TableSelectionMode::Row);
+    assert_eq!(state::<Inventory>(&harness).sort, None);
+
+    harness.click_element("table-header-1");
+    assert_eq!(state::<Inventory>(&harness).sort, Some((1, SortDirection::Ascending)));
+    harness.click_element("table-header-1");
+    assert_eq!(state::<Inventory>(&harness).sort, Some((1, SortDirection::Descending)));
+
+    // Another column starts out ascending.
+    harness.click_element("table-header-0");
+    assert_eq!(state::<Inventory>(&harness).sort, Some((0, SortDirection::Ascending)));
+    let tree = harness.accessibility_tree();
+    let header = accessibility_node(&harness, &tree, "table-header-0");
+    assert_eq!(header.role(), Role::ColumnHeader);
+    assert_eq!(header.sort_direction(), Some(accesskit::SortDirection::Ascending));
+}
+
+#[test]
+fn table_selects_rows_and_cells_with_the_pointer_and_keyboard() {
+    let mut harness = mount::<Inventory>(TableSelectionMode::Row);
+    harness.click_element("table-1-1");
+    assert_eq!(state::<Inventory>(&harness).selection, Some((1, None)));
+
+    harness.key_press(Key::Named(NamedKey::ArrowDown));
+    assert_eq!(state::<Inventory>(&harness).selection, Some((2, None)));
+    harness.key_press(Key::Named(NamedKey::ArrowRight));
+    assert_eq!(state::<Inventory>(&harness).selection, Some((2, None)));
+
+    // Moving the selection past the bottom of the table scrolls the selected row into view.
+    harness.key_press(Key::Named(NamedKey::End));
+    assert_eq!(state::<Inventory>(&harness).selection, Some((19, None)));
+    let table = harness.element_bounds("table").unwrap();
+    let last_row = harness.element_bounds("table-19-0").unwrap();
+    assert!(last_row.bottom() <= table.bottom());
+
+    let mut harness = mount::<Inventory>(TableSelectionMode::Cell);
+    harness.click_element("table-1-0");
+    harness.key_press(Key::Named(NamedKey::ArrowRight));
+    assert_eq!(state::<Inventory>(&harness).selection, Some((1, Some(1))));
+}
+
+#[test]
+fn table_columns_resize_and_the_header_stays_visible() {
+    let mut harness = mount::<Inventory>(TableSelectionMode::Row);
+    let header = harness.element_bounds("table-header-0").unwrap();
+    assert_eq!(header.width, 150.0);
+
+    // Drag the border between the columns.
+    let border = Point::new(header.right(), header.y + header.height / 2.0);
+    harness.pointer_button(border, MouseButton::Left, ElementState::Pressed);
+    harness.pointer_moved(Point::new(border.x + 30.0, border.y));
+    harness.pointer_button(Point::new(border.x + 30.0, border.y), MouseButton::Left, ElementState::Released);
+    assert_eq!(state::<Inventory>(&harness).resized, Some((0, 180.0)));
+    assert_eq!(harness.element_bounds("table-header-0").unwrap().width, 180.0);
+    assert_eq!(harness.element_bounds("table-0-0").unwrap().width, 180.0);
+    assert_eq!(harness.element_bounds("table-header-1").unwrap().x, header.x + 180.0);
+    // Sorting is not triggered by the drag.
+    assert_eq!(state::<Inventory>(&harness).sort, None);
+
+    // Columns do not shrink below their minimum width.
+    let header = harness.element_bounds("table-header-1").unwrap();
+    let border = Point::new(header.right(), header.y + header.height / 2.0);
+    harness.pointer_button(border, MouseButton::Left, ElementState::Pressed);
+    harness.pointer_moved(Point::new(border.x - 50.0, border.y));
+    harness.pointer_button(Point::new(border.x - 50.0, border.y), MouseButton::Left, ElementState::Released);
+    assert_eq!(harness.element_bounds("table-header-1").unwrap().width, 60.0);
+
+    let first_row = harness.element_bounds("table-0-0").unwrap();
+    harness.scroll(Point::new(100.0, 100.0), 50.0);
+    assert_eq!(harness.element_bounds("table-header-0").unwrap().y, header.y);
+    assert_eq!(harness.element_bounds("table-0-0").unwrap().y, first_row.y - 50.0);
+
+    // The rows that scrolled under the header can not be clicked through it.
+    harness.click_element("table-header-0");
+    let inventory = state::<Inventory>(&harness);
+    assert_eq!((inventory.sort, inventory.selection), (Some((0, SortDirection::Ascending)), None));
+}
+
+/// A table whose sort is set by a button, and not by its headers.
+#[derive(Default)]
+struct SortedByButton {
+    sort_by_count: bool,
+}
+
+impl Component for SortedByButton {
+    type GlobalState = ();
+    type Props = ();
+    type Message = ();
+
+    fn view(
+        &self,
+        _global_state: &Self::GlobalState,
+        _props: &Self::Props,
+        _children: Vec<ComponentSpecification>,
+        _id: ComponentId,
+        _window: &WindowContext,
+    ) -> ComponentSpecification {
+        let mut header_style = Style::default();
+        *header_style.background_mut() = rgb(10, 20, 30);
+        let mut table = Table::new(vec![TableColumn::new("Name"), TableColumn::new("Count")])
+            .row(["Item", "1"])
+            .header_style(header_style)
+            .id("table")
+            .sort(0, SortDirection::Ascending);
+        if self.sort_by_count {
+            table = table.sort(1, SortDirection::Descending);
+        }
+
+        Container::new()
+            .push(table)
+            .push(Container::new().id("sort-by-count").width(100).height(20).on_pointer_button(
+                |state: &mut SortedByButton, _: &mut (), _: &mut Event, pointer_button: &PointerButton| {
+                    if pointer_button.clicked() {
+                        state.sort_by_count = true;
+                    }
+                },
+            ))
+            .component()
+    }
+}
+
+#[test]
+fn table_sort_follows_the_sort_when_it_changes() {
+    let mut harness = mount::<SortedByButton>(());
+    let header_sort = |harness: &TestHarness, id: &str| {
+        let tree = harness.accessibility_tree();
+        accessibility_node(harness, &tree, id).sort_direction()
+    };
+    assert_eq!(header_sort(&harness, "table-header-0"), Some(accesskit::SortDirection::Ascending));
+
+    // A click on a header sorts the table while the sort stays the same.
+    harness.click_element("table-header-0");
+    assert_eq!(header_sort(&harness, "table-header-0"), Some(accesskit::SortDirection::Descending));
+
+    harness.click_element("sort-by-count");
+    assert_eq!(header_sort(&harness, "table-header-0"), None);
+    assert_eq!(header_sort(&harness, "table-header-1"), Some(accesskit::SortDirection::Descending));
+
+    // The cells are styled by the table.
+    assert_eq!(harness.find_element_by_id("table-header-1").unwrap().style().background(), rgb(10, 20, 30));
+}
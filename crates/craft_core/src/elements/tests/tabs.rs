@@ -0,0 +1,123 @@
+use crate::accessibility::Role;
+use crate::components::{Component, ComponentId, ComponentSpecification, Event, Props};
+use crate::elements::{ElementStyles, Tabs};
+use crate::geometry::Point;
+use crate::testing::helpers::{accessibility_node, focused_id, mount, state, ToggleRow};
+use crate::WindowContext;
+use winit::keyboard::{Key, NamedKey};
+
+struct Notebook {
+    pages: Vec<usize>,
+    log: Vec<String>,
+}
+
+impl Default for Notebook {
+    fn default() -> Self {
+        Notebook {
+            pages: (0..8).collect(),
+            log: Vec::new(),
+        }
+    }
+}
+
+impl Component for Notebook {
+    type GlobalState = ();
+    /// Whether the panels keep their state.
+    type Props = bool;
+    type Message = ();
+
+    fn view(
+        &self,
+        _global_state: &Self::GlobalState,
+        keep_panel_state: &Self::Props,
+        _children: Vec<ComponentSpecification>,
+        _id: ComponentId,
+        _window: &WindowContext,
+    ) -> ComponentSpecification {
+        let mut tabs = Tabs::new()
+            .id("tabs")
+            .width(300)
+            .closable(true)
+            .keep_panel_state(*keep_panel_state)
+            .on_tab_changed(|state: &mut Notebook, _: &mut (), _: &mut Event, index: usize| {
+                state.log.push(format!("changed {index}"))
+            })
+            .on_tab_closed(|state: &mut Notebook, _: &mut (), _: &mut Event, index: usize| {
+                state.pages.remove(index);
+                state.log.push(format!("closed {index}"));
+            });
+        for page in &self.pages {
+            let panel = ToggleRow::component().props(Props::new(*page)).key(&page.to_string());
+            tabs = tabs.tab(&format!("Page {page}"), panel);
+        }
+
+        tabs.component()
+    }
+}
+
+#[test]
+fn tabs_mount_only_the_selected_panel() {
+    let mut harness = mount::<Notebook>(false);
+    assert!(harness.find_element_by_id("row-0").is_some());
+    assert!(harness.find_element_by_id("row-1").is_none());
+
+    harness.click_element("row-0");
+    harness.click_element("tabs-tab-1");
+    assert!(harness.find_element_by_id("row-1").is_some());
+    assert!(harness.find_element_by_id("on-0").is_none());
+    harness.click_element("tabs-tab-0");
+    assert!(harness.find_element_by_id("row-0").is_some());
+    assert_eq!(state::<Notebook>(&harness).log, ["changed 1", "changed 0"]);
+
+    // The panels can keep their state while another tab is selected.
+    let mut harness = mount::<Notebook>(true);
+    harness.click_element("row-0");
+    harness.click_element("tabs-tab-1");
+    harness.click_element("tabs-tab-0");
+    assert!(harness.find_element_by_id("on-0").is_some());
+}
+
+#[test]
+fn tabs_are_selected_with_the_keyboard_and_the_strip_scrolls() {
+    let mut harness = mount::<Notebook>(false);
+    let tabs = harness.element_bounds("tabs").unwrap();
+    let first = harness.element_bounds("tabs-tab-0").unwrap();
+    harness.click_element("tabs-tab-0");
+    assert_eq!(focused_id(&harness).as_deref(), Some("tabs"));
+
+    // The titles do not fit, and the selected one is scrolled into view.
+    harness.key_press(Key::Named(NamedKey::End));
+    let last = harness.element_bounds("tabs-tab-7").unwrap();
+    assert!(last.right() <= tabs.right());
+    assert!(harness.element_bounds("tabs-tab-0").unwrap().x < tabs.x);
+    let tree = harness.accessibility_tree();
+    assert_eq!(accessibility_node(&harness, &tree, "tabs-tab-7").role(), Role::Tab);
+    assert_eq!(accessibility_node(&harness, &tree, "tabs-tab-7").is_selected(), Some(true));
+
+    harness.key_press(Key::Named(NamedKey::ArrowRight));
+    assert!(harness.find_element_by_id("row-0").is_some());
+    assert_eq!(harness.element_bounds("tabs-tab-0").unwrap().x, first.x);
+    harness.key_press(Key::Named(NamedKey::ArrowLeft));
+    assert!(harness.find_element_by_id("row-7").is_some());
+    assert_eq!(state::<Notebook>(&harness).log, ["changed 7", "changed 0", "changed 7"]);
+
+    // The mouse wheel scrolls the titles.
+    harness.scroll(Point::new(tabs.x + 20.0, tabs.y + 10.0), -1000.0);
+    assert_eq!(harness.element_bounds("tabs-tab-0").unwrap().x, first.x);
+    harness.scroll(Point::new(tabs.x + 20.0, tabs.y + 10.0), 1000.0);
+    assert_eq!(harness.element_bounds("tabs-tab-7").unwrap().right(), last.right());
+}
+
+#[test]
+fn closing_a_tab_keeps_the_selected_panel() {
+    let mut harness = mount::<Notebook>(false);
+    harness.click_element("tabs-tab-2");
+    harness.click_element("tabs-close-1");
+    assert!(harness.find_element_by_id("tabs-tab-7").is_none());
+    assert!(harness.find_element_by_id("row-2").is_some());
+
+    // When the selected tab is closed, the next one is selected.
+    harness.click_element("tabs-close-1");
+    assert!(harness.find_element_by_id("row-3").is_some());
+    assert_eq!(state::<Notebook>(&harness).log, ["changed 2", "closed 1", "closed 1"]);
+}
@@ -0,0 +1,254 @@
+use crate::accessibility::{Action, Role};
+use crate::components::{Component, ComponentId, ComponentSpecification, Event, Props};
+use crate::elements::{AllowedCharacters, ElementStyles, InputMask, TextInput};
+use crate::geometry::Size;
+use crate::testing::helpers::{accessibility_node, state, Form};
+use crate::testing::TestHarness;
+use crate::WindowContext;
+use winit::event::Ime;
+use winit::keyboard::{Key, ModifiersState, NamedKey};
+
+/// The modifier that is held down for shortcuts like Ctrl+Z.
+fn action_modifier() -> ModifiersState {
+    if cfg!(target_os = "macos") { ModifiersState::META } else { ModifiersState::CONTROL }
+}
+
+#[test]
+fn text_input_undoes_and_redoes_edits() {
+    let mut harness = TestHarness::new(Form::component(), (), Size::new(400.0, 600.0));
+    harness.key_press(Key::Named(NamedKey::Tab));
+
+    // Consecutive typing is undone at once, moving the cursor starts a new edit.
+    harness.type_text("Ada");
+    harness.key_press(Key::Named(NamedKey::ArrowLeft));
+    harness.type_text("x");
+    assert_eq!(state::<Form>(&harness).name, "Adxa");
+
+    harness.modifiers_changed(action_modifier());
+    harness.key_press(Key::Character("z".into()));
+    assert_eq!(state::<Form>(&harness).name, "Ada");
+    harness.key_press(Key::Character("z".into()));
+    assert_eq!(state::<Form>(&harness).name, "");
+    harness.key_press(Key::Character("z".into()));
+    assert_eq!(state::<Form>(&harness).name, "");
+
+    harness.modifiers_changed(action_modifier() | ModifiersState::SHIFT);
+    harness.key_press(Key::Character("Z".into()));
+    assert_eq!(state::<Form>(&harness).name, "Ada");
+    harness.modifiers_changed(action_modifier());
+    harness.key_press(Key::Character("y".into()));
+    assert_eq!(state::<Form>(&harness).name, "Adxa");
+
+    // Undo restores the cursor, and a new edit clears the redo history.
+    harness.key_press(Key::Character("z".into()));
+    harness.modifiers_changed(ModifiersState::empty());
+    harness.type_text("b");
+    assert_eq!(state::<Form>(&harness).name, "Adba");
+    harness.modifiers_changed(action_modifier());
+    harness.key_press(Key::Character("y".into()));
+    assert_eq!(state::<Form>(&harness).name, "Adba");
+}
+
+#[test]
+fn text_input_history_can_be_disabled() {
+    let input = TextInput::new("").id("input").history_depth(0);
+    let mut harness = TestHarness::new(input.component(), (), Size::new(400.0, 600.0));
+    harness.key_press(Key::Named(NamedKey::Tab));
+
+    harness.type_text("Ada");
+    harness.modifiers_changed(action_modifier());
+    harness.key_press(Key::Character("z".into()));
+    harness.redraw();
+
+    let tree = harness.accessibility_tree();
+    assert_eq!(accessibility_node(&harness, &tree, "input").value(), Some("Ada"));
+}
+
+/// A text input that is configured by the props, and the text that it last reported and submitted.
+#[derive(Default)]
+struct Field {
+    value: String,
+    submitted: Option<String>,
+}
+
+impl Component for Field {
+    type GlobalState = ();
+    type Props = TextInput;
+    type Message = ();
+
+    fn view(
+        &self,
+        _global_state: &Self::GlobalState,
+        text_input: &Self::Props,
+        _children: Vec<ComponentSpecification>,
+        _id: ComponentId,
+        _window: &WindowContext,
+    ) -> ComponentSpecification {
+        text_input
+            .clone()
+            .id("field")
+            .width(200)
+            .on_text_input_changed(|state: &mut Field, _: &mut (), _: &mut Event, value: &str| {
+                state.value = value.to_string();
+            })
+            .on_text_input_submitted(|state: &mut Field, _: &mut (), _: &mut Event, value: &str| {
+                state.submitted = Some(value.to_string());
+            })
+            .component()
+    }
+}
+
+/// Focuses the text input and types `text` into it.
+fn type_into(text_input: TextInput, text: &str) -> TestHarness {
+    let mut harness = TestHarness::new(Field::component().props(Props::new(text_input)), (), Size::new(400.0, 300.0));
+    harness.key_press(Key::Named(NamedKey::Tab));
+    harness.type_text(text);
+    harness
+}
+
+#[test]
+fn text_input_shows_the_placeholder_while_empty() {
+    let mut harness = type_into(TextInput::new("").placeholder("Search"), "");
+    assert!(harness.render_list_snapshot().contains("glyphs=6"));
+    let tree = harness.accessibility_tree();
+    assert_eq!(accessibility_node(&harness, &tree, "field").placeholder(), Some("Search"));
+
+    harness.type_text("ab");
+    let snapshot = harness.render_list_snapshot();
+    assert!(!snapshot.contains("glyphs=6") && snapshot.contains("glyphs=2"));
+}
+
+#[test]
+fn text_input_limits_and_filters_insertions() {
+    let harness = type_into(TextInput::new("").max_length(5).input_filter(AllowedCharacters::digits()), "12a345678");
+    assert_eq!(state::<Field>(&harness).value, "12345");
+
+    let harness = type_into(TextInput::new("").input_filter(InputMask::new("(###) ###-####")), "555x123-45678");
+    assert_eq!(state::<Field>(&harness).value, "(555) 123-4567");
+
+    // Read only text can still be selected.
+    let mut harness = type_into(TextInput::new("Fixed").read_only(true), "abc");
+    harness.key_press(Key::Named(NamedKey::Backspace));
+    assert_eq!(state::<Field>(&harness).value, "Fixed");
+    let tree = harness.accessibility_tree();
+    let field = accessibility_node(&harness, &tree, "field");
+    assert!(field.is_read_only() && !field.supports_action(Action::SetValue));
+}
+
+#[test]
+fn input_masks_format_edits_in_the_middle_of_the_text() {
+    let mut harness = type_into(TextInput::new("").input_filter(InputMask::new("(###) ###-####")), "5551234");
+    assert_eq!(state::<Field>(&harness).value, "(555) 123-4");
+
+    // The characters after the cursor move to the next slots.
+    harness.key_press(Key::Named(NamedKey::Home));
+    harness.key_press(Key::Named(NamedKey::ArrowRight));
+    harness.key_press(Key::Named(NamedKey::ArrowRight));
+    harness.type_text("9");
+    assert_eq!(state::<Field>(&harness).value, "(595) 512-34");
+    // A character that does not fit the slot at the cursor is rejected.
+    harness.type_text("x");
+    assert_eq!(state::<Field>(&harness).value, "(595) 512-34");
+
+    // They move back when text in front of them is deleted.
+    harness.key_press(Key::Named(NamedKey::Backspace));
+    assert_eq!(state::<Field>(&harness).value, "(555) 123-4");
+    harness.key_press(Key::Named(NamedKey::Delete));
+    assert_eq!(state::<Field>(&harness).value, "(551) 234");
+
+    // The cursor stays where the text was deleted.
+    harness.type_text("7");
+    assert_eq!(state::<Field>(&harness).value, "(575) 123-4");
+}
+
+#[test]
+fn password_input_shows_bullets_and_edits_the_password() {
+    let mut harness = type_into(TextInput::new("").password(true), "secret");
+    assert_eq!(state::<Field>(&harness).value, "secret");
+
+    harness.key_press(Key::Named(NamedKey::Backspace));
+    harness.key_press(Key::Named(NamedKey::ArrowLeft));
+    harness.key_press(Key::Named(NamedKey::ArrowLeft));
+    harness.type_text("x");
+    assert_eq!(state::<Field>(&harness).value, "secxre");
+
+    harness.redraw();
+    let tree = harness.accessibility_tree();
+    let field = accessibility_node(&harness, &tree, "field");
+    assert_eq!((field.role(), field.value()), (Role::PasswordInput, Some("••••••")));
+
+    harness.modifiers_changed(action_modifier());
+    harness.key_press(Key::Character("z".into()));
+    assert_eq!(state::<Field>(&harness).value, "secre");
+}
+
+#[test]
+fn password_input_shows_bullets_while_composing() {
+    let composed = |input: TextInput, text: &str| {
+        let mut harness = type_into(input, "ab");
+        harness.ime(Ime::Preedit(text.to_string(), Some((text.len(), text.len()))));
+        assert_eq!(state::<Field>(&harness).value, "ab");
+        harness.render_list_snapshot()
+    };
+
+    let bullets = composed(TextInput::new("").password(true), "••••••");
+    assert_eq!(composed(TextInput::new("").password(true), "secret"), bullets);
+    assert_ne!(composed(TextInput::new(""), "secret"), composed(TextInput::new(""), "••••••"));
+}
+
+#[test]
+fn multiline_text_input_scrolls_to_the_caret_and_submits() {
+    let mut harness = type_into(TextInput::new("").multiline(true).height(60), "one");
+    for line in ["two", "three", "four", "five", "six"] {
+        harness.key_press(Key::Named(NamedKey::Enter));
+        harness.type_text(line);
+    }
+    assert_eq!(state::<Field>(&harness).value, "one\ntwo\nthree\nfour\nfive\nsix");
+
+    harness.redraw();
+    let tree = harness.accessibility_tree();
+    let field = accessibility_node(&harness, &tree, "field");
+    assert!(field.scroll_y().unwrap() > 0.0);
+
+    harness.key_press(Key::Named(NamedKey::PageUp));
+    harness.key_press(Key::Named(NamedKey::PageUp));
+    harness.redraw();
+    let tree = harness.accessibility_tree();
+    assert_eq!(accessibility_node(&harness, &tree, "field").scroll_y(), Some(0.0));
+
+    harness.modifiers_changed(action_modifier());
+    harness.key_press(Key::Named(NamedKey::Enter));
+    let field = state::<Field>(&harness);
+    assert_eq!(field.submitted.as_deref(), Some(field.value.as_str()));
+    assert!(!field.value.contains("\n\n"));
+}
+
+#[test]
+fn multiline_text_input_wraps_and_numbers_lines() {
+    let text = "a long first line that does not fit into the width of the text area\nsecond";
+    let mut harness = type_into(TextInput::new(text).multiline(true), "");
+    let snapshot = harness.render_list_snapshot();
+    assert!(snapshot.lines().filter(|line| line.trim_start().starts_with("line")).count() > 2);
+    assert_eq!(snapshot.lines().filter(|line| line.trim_start().starts_with("text")).count(), 1);
+
+    let content = harness.find_element_by_id("field").unwrap().computed_box().content_rectangle();
+    let padding = harness.find_element_by_id("field").unwrap().style().padding();
+
+    let mut harness = type_into(TextInput::new(text).multiline(true).line_numbers(true), "");
+    let snapshot = harness.render_list_snapshot();
+    assert_eq!(snapshot.lines().filter(|line| line.trim_start().starts_with("text")).count(), 2);
+    // The gutter only numbers the first line of each paragraph.
+    let gutter = snapshot.split("  text ").nth(1).unwrap();
+    let numbered_lines: Vec<bool> = gutter.split("    line\n").skip(1).map(|line| line.contains("run")).collect();
+    assert_eq!(numbered_lines, [true, false, false, false, true]);
+
+    // The gutter moves the text to the right, by its width and gap rounded to whole pixels, without changing the
+    // padding of the text input.
+    let field = harness.find_element_by_id("field").unwrap();
+    let gutter_content = field.computed_box().content_rectangle();
+    assert_eq!(gutter_content.x, content.x + (2.0 * 16.0 * 0.6 + 16.0 * 0.75_f32).round());
+    assert_eq!(field.style().padding(), padding);
+    harness.redraw();
+    let field = harness.find_element_by_id("field").unwrap();
+    assert_eq!(field.computed_box().content_rectangle().x, gutter_content.x);
+}
@@ -0,0 +1,73 @@
+use crate::components::ComponentSpecification;
+use crate::elements::{Container, ElementStyles, Tooltip, TooltipPlacement};
+use crate::geometry::{Point, Rectangle, Size};
+use crate::style::FlexDirection;
+use crate::testing::helpers::accessibility_node;
+use crate::testing::TestHarness;
+use std::time::Duration;
+use winit::keyboard::{Key, NamedKey};
+
+fn tooltips() -> ComponentSpecification {
+    Container::new()
+        .flex_direction(FlexDirection::Column)
+        .gap("40px")
+        .push(Tooltip::new("Saves the file").id("save").push(Container::new().width(80).height(20)))
+        .push(
+            Tooltip::new("Opens a file")
+                .id("open")
+                .placement(TooltipPlacement::Bottom)
+                .delay(Duration::from_millis(100))
+                .push(Container::new().width(80).height(20)),
+        )
+        .component()
+}
+
+/// The bounds of the text of the tooltip in the overlay, if one is drawn.
+fn tooltip_text_bounds(harness: &mut TestHarness) -> Option<Rectangle> {
+    let snapshot = harness.render_list_snapshot();
+    let overlay = &snapshot[snapshot.find("overlay")?..];
+    let line = overlay.lines().find(|line| line.trim_start().starts_with("text "))?;
+    let values: Vec<f32> = line
+        .split_whitespace()
+        .filter_map(|field| field.split_once('=').and_then(|(_, value)| value.parse().ok()))
+        .collect();
+    Some(Rectangle::new(values[0], values[1], values[2], values[3]))
+}
+
+#[test]
+fn tooltip_shows_after_the_hover_delay() {
+    let mut harness = TestHarness::new(tooltips(), (), Size::new(400.0, 400.0));
+    let target = harness.element_bounds("open").unwrap();
+
+    harness.pointer_moved(Point::new(target.x + 10.0, target.y + 10.0));
+    assert!(tooltip_text_bounds(&mut harness).is_none());
+    assert!(harness.is_animating());
+
+    harness.advance_time(Duration::from_millis(100));
+    let text = tooltip_text_bounds(&mut harness).unwrap();
+    assert!(text.y > target.bottom());
+    assert!(!harness.is_animating());
+
+    harness.pointer_moved(Point::new(300.0, 300.0));
+    assert!(tooltip_text_bounds(&mut harness).is_none());
+}
+
+#[test]
+fn tooltip_hides_on_escape_and_flips_inside_the_window() {
+    let mut harness = TestHarness::new(tooltips(), (), Size::new(400.0, 400.0));
+    let target = harness.element_bounds("save").unwrap();
+    let tree = harness.accessibility_tree();
+    assert_eq!(accessibility_node(&harness, &tree, "save").description(), Some("Saves the file"));
+
+    // There is no room above the target at the top of the window, so the tooltip is shown below it.
+    harness.pointer_moved(Point::new(target.x + 10.0, target.y + 10.0));
+    harness.advance_time(Duration::from_millis(500));
+    let text = tooltip_text_bounds(&mut harness).unwrap();
+    assert!(text.y > target.bottom());
+    assert!(text.x >= 0.0);
+
+    harness.key_press(Key::Named(NamedKey::Escape));
+    assert!(tooltip_text_bounds(&mut harness).is_none());
+    harness.advance_time(Duration::from_millis(500));
+    assert!(tooltip_text_bounds(&mut harness).is_none());
+}
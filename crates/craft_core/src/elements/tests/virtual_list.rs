@@ -0,0 +1,105 @@
+use crate::components::{Component, Props};
+use crate::elements::{Container, ElementStyles, VirtualList};
+use crate::geometry::{Point, Size};
+use crate::testing::helpers::ToggleRow;
+use crate::testing::TestHarness;
+
+fn virtual_list(item_count: usize) -> VirtualList {
+    VirtualList::new(item_count, 20.0, |index| Container::new().id(&format!("row-{index}")).width(100).height(20))
+        .id("list")
+        .width(100)
+        .height(100)
+}
+
+fn row_range(harness: &TestHarness) -> (usize, usize) {
+    let list = harness.find_element_by_id("list").unwrap();
+    let rows: Vec<usize> = list
+        .children()
+        .iter()
+        .map(|row| row.get_id().as_deref().unwrap().trim_start_matches("row-").parse().unwrap())
+        .collect();
+    (*rows.first().unwrap(), *rows.last().unwrap())
+}
+
+#[test]
+fn virtual_list_only_creates_the_visible_rows() {
+    let mut harness = TestHarness::new(virtual_list(10_000).component(), (), Size::new(200.0, 200.0));
+
+    // Before the viewport is known, rows are created for the height of the window, plus three rows of overscan.
+    assert_eq!(row_range(&harness), (0, 12));
+    assert_eq!(harness.element_bounds("row-5").unwrap().y, 100.0);
+    let list = harness.find_element_by_id("list").unwrap();
+    assert_eq!(list.element_data().layout_item.max_scroll_y, 10_000.0 * 20.0 - 100.0);
+
+    harness.scroll(Point::new(50.0, 50.0), 1000.0);
+    assert_eq!(row_range(&harness), (47, 62));
+    assert_eq!(harness.element_bounds("row-50").unwrap().y, 0.0);
+    assert!(harness.find_element_by_id("row-10").is_none());
+}
+
+#[test]
+fn virtual_list_jumps_to_an_index() {
+    let list = virtual_list(10_000).scroll_to_index(9_999);
+    let harness = TestHarness::new(list.component(), (), Size::new(200.0, 200.0));
+
+    // The last row can only scroll to the bottom of the list.
+    assert_eq!(row_range(&harness).1, 9_999);
+    assert_eq!(harness.element_bounds("row-9999").unwrap().y, 80.0);
+}
+
+#[test]
+fn virtual_list_measures_rows_with_estimated_heights() {
+    let list = VirtualList::new(100, 20.0, |index| Container::new().id(&format!("row-{index}")).width(100).height(30))
+        .estimated_item_height(20.0)
+        .id("list")
+        .width(100)
+        .height(100);
+    let mut harness = TestHarness::new(list.component(), (), Size::new(200.0, 200.0));
+    harness.redraw();
+
+    // The 13 rows that were created are measured, the others are still estimated.
+    let list = harness.find_element_by_id("list").unwrap();
+    assert_eq!(list.element_data().layout_item.max_scroll_y, 13.0 * 30.0 + 87.0 * 20.0 - 100.0);
+}
+
+#[test]
+fn virtual_list_rows_keep_their_state_when_they_scroll_back_into_view() {
+    let list = VirtualList::new(1_000, 20.0, |index| ToggleRow::component().props(Props::new(index)))
+        .id("list")
+        .width(100)
+        .height(100);
+    let mut harness = TestHarness::new(list.component(), (), Size::new(200.0, 200.0));
+
+    harness.click_element("row-2");
+    assert!(harness.find_element_by_id("on-2").is_some());
+
+    harness.scroll(Point::new(50.0, 50.0), 2000.0);
+    assert!(harness.find_element_by_id("on-2").is_none());
+    // The rows that took the place of the toggled row start out off.
+    assert!(harness.find_element_by_id("row-102").is_some());
+
+    harness.scroll(Point::new(50.0, 50.0), -2000.0);
+    assert!(harness.find_element_by_id("on-2").is_some());
+    assert!(harness.find_element_by_id("row-3").is_some());
+}
+
+#[test]
+fn virtual_list_rows_lose_their_state_past_the_retained_rows() {
+    let list = VirtualList::new(1_000, 20.0, |index| ToggleRow::component().props(Props::new(index)))
+        .id("list")
+        .width(100)
+        .height(100)
+        .retained_rows(10);
+    let mut harness = TestHarness::new(list.component(), (), Size::new(200.0, 200.0));
+
+    harness.click_element("row-2");
+    assert!(harness.find_element_by_id("on-2").is_some());
+
+    // Many more rows than are retained scroll out of view after the toggled one.
+    for _ in 0..10 {
+        harness.scroll(Point::new(50.0, 50.0), 200.0);
+    }
+    harness.scroll(Point::new(50.0, 50.0), -2000.0);
+    assert!(harness.find_element_by_id("row-2").is_some());
+    assert!(harness.find_element_by_id("on-2").is_none());
+}
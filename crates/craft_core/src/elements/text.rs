@@ -15,6 +15,7 @@ use crate::text::text_render_data;
 use crate::text::text_render_data::TextRender;
 use parley::{Alignment, AlignmentOptions, Selection};
 use rustc_hash::FxHasher;
+use accesskit::{Node, Role};
 use std::any::Any;
 use std::collections::HashMap;
 use std::hash::{Hash, Hasher};
@@ -270,7 +271,7 @@ impl Element for Text {
             text: std::mem::take(&mut self.text),
             text_hash: Some(hash),
             text_render: None,
-            last_text_style: *self.style(),
+            last_text_style: self.style().clone(),
             layout: None,
             cache: Default::default(),
             current_layout_key: None,
@@ -319,7 +320,7 @@ impl Element for Text {
 
         let last_style = &state.last_text_style;
 
-        let current_style = *base_state.base.current_style(self.element_data());
+        let current_style = base_state.base.current_style(self.element_data()).clone();
         if last_style.color() != current_style.color() {
             if let Some(text_render) = state.text_render.as_mut() {
                 text_render.override_brush = Some(ColorBrush::new(current_style.color()));
@@ -348,6 +349,16 @@ impl Element for Text {
 
         state.last_text_style = current_style;
     }
+
+    fn default_accessibility_role(&self) -> Role {
+        Role::Label
+    }
+
+    fn update_accessibility_node(&self, node: &mut Node, element_state: &ElementStateStore) {
+        if let Some(text) = self.text(element_state) {
+            node.set_label(text);
+        }
+    }
 }
 
 fn hash_string(text: &str) -> u64 {
@@ -358,6 +369,12 @@ fn hash_string(text: &str) -> u64 {
 
 impl Text {
     generate_component_methods_no_children!();
+
+    /// The text that was last laid out.
+    pub(crate) fn text<'a>(&self, element_state: &'a ElementStateStore) -> Option<&'a str> {
+        let state: &TextState = element_state.storage.get(&self.element_data.component_id)?.data.downcast_ref()?;
+        state.text.as_deref()
+    }
 }
 
 impl ElementStyles for Text {
@@ -375,8 +392,7 @@ impl TextState {
     ) -> Size<f32> {
         if self.layout.is_none() {
             let mut builder = text_context.tree_builder(self.scale_factor, &self.last_text_style.to_text_style());
-            let text = std::mem::take(&mut self.text).unwrap();
-            builder.push_text(&text);
+            builder.push_text(self.text.as_ref().unwrap());
             let (layout, _) = builder.build();
             self.layout = Some(layout);
         }
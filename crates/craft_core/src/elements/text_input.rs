@@ -3,7 +3,9 @@ use crate::components::component::ComponentSpecification;
 use crate::components::{ImeAction, Props};
 use crate::components::Event;
 use crate::elements::element::{resolve_clip_for_scrollable, Element, ElementBoxed};
+use crate::elements::edit_history::{EditHistory, EditKind, EditSnapshot, DEFAULT_HISTORY_DEPTH};
 use crate::elements::element_data::ElementData;
+use crate::elements::input_filter::{FilteredEdit, InputFilter, TextInputEdit};
 use crate::layout::layout_context::{LayoutContext, TaffyTextInputContext};
 use crate::elements::scroll_state::ScrollState;
 use crate::elements::ElementStyles;
@@ -11,13 +13,17 @@ use crate::geometry::{Point, Rectangle, Size, TrblRectangle};
 use crate::reactive::element_state_store::{ElementStateStore, ElementStateStoreItem};
 use crate::renderer::color::Color;
 use crate::renderer::renderer::{RenderList, TextScroll};
-use crate::style::{Display, Style, Unit};
+use crate::style::{Display, Overflow, Style, Unit};
 use crate::{generate_component_methods_no_children};
+use accesskit::{Action, ActionData, Node, Role};
 use std::any::Any;
 use std::collections::HashMap;
+use std::ops::Range;
 use std::sync::Arc;
 use parley::{PlainEditor, PlainEditorDriver};
+use parley::Alignment;
 use taffy::{AvailableSpace, NodeId, TaffyTree};
+use unicode_segmentation::UnicodeSegmentation;
 
 #[cfg(target_arch = "wasm32")]
 use web_time as time;
@@ -33,6 +39,14 @@ use crate::text::text_context::{ColorBrush, TextContext};
 use crate::text::text_render_data;
 use crate::text::text_render_data::TextRender;
 
+/// The character that is shown for each grapheme of a password.
+const PASSWORD_BULLET: char = '•';
+
+/// The color of the placeholder when no placeholder style is set.
+const PLACEHOLDER_COLOR: Color = Color::from_rgb8(150, 150, 158);
+
+const LINE_NUMBER_COLOR: Color = Color::from_rgb8(150, 150, 158);
+
 // A stateful element that shows text.
 #[derive(Clone, Default)]
 pub struct TextInput {
@@ -41,6 +55,17 @@ pub struct TextInput {
     /// NOTE: The editor will always use the user provided text on initialization.
     use_text_value_on_update: bool,
     pub text: Option<String>,
+    /// The number of edits that can be undone, or `None` for the default.
+    history_depth: Option<usize>,
+    placeholder: Option<String>,
+    placeholder_style: Option<Style>,
+    /// The maximum length of the text in grapheme clusters.
+    max_length: Option<usize>,
+    read_only: bool,
+    password: bool,
+    input_filter: Option<Arc<dyn InputFilter>>,
+    multiline: bool,
+    line_numbers: bool,
 }
 
 #[derive(Clone, Default, Debug)]
@@ -55,6 +80,19 @@ pub struct TextInputState {
     #[allow(dead_code)]
     pub(crate) ime_state: ImeState,
     pub(crate) editor: PlainEditor<ColorBrush>,
+    history: EditHistory,
+    /// The text of a password input. The editor only contains a bullet for each grapheme.
+    password: Option<String>,
+    placeholder: Option<PlainEditor<ColorBrush>>,
+    placeholder_render: Option<TextRender>,
+    /// The editor for the line numbers, which has an empty line for each line that is wrapped.
+    gutter: Option<PlainEditor<ColorBrush>>,
+    gutter_render: Option<TextRender>,
+    /// The width of the line numbers and the space between them and the text, in physical pixels.
+    gutter_width: f32,
+    gutter_gap: f32,
+    /// Whether the caret moved and has to be scrolled into view.
+    scroll_to_cursor: bool,
 
     cache: HashMap<TextHashKey, taffy::Size<f32>>,
     current_key: Option<TextHashKey>,
@@ -78,13 +116,131 @@ impl TextInput {
             text: Some(text.to_string()),
             element_data: ElementData::default(),
             use_text_value_on_update: true,
+            history_depth: None,
+            placeholder: None,
+            placeholder_style: None,
+            max_length: None,
+            read_only: false,
+            password: false,
+            input_filter: None,
+            multiline: false,
+            line_numbers: false,
+        }
+    }
+
+    /// Inserts `text` in place of the selection, after it is checked by the input filter and limited to the maximum
+    /// length.
+    fn insert(&self, state: &mut TextInputState, text: &str, text_context: &mut TextContext) {
+        let value = state.value();
+        let range = state.selection_range();
+        let mut edit = match &self.input_filter {
+            Some(input_filter) => {
+                let edit = TextInputEdit { text: value, range, insert: text };
+                let Some(edit) = input_filter.filter_edit(&edit) else {
+                    return;
+                };
+                edit
+            }
+            None => FilteredEdit { range, insert: text.to_string(), cursor: text.len() },
+        };
+
+        if let Some(max_length) = self.max_length {
+            let kept_length = value.graphemes(true).count() - value[edit.range.clone()].graphemes(true).count();
+            if let Some((end, _)) = edit.insert.grapheme_indices(true).nth(max_length.saturating_sub(kept_length)) {
+                edit.insert.truncate(end);
+                edit.cursor = edit.cursor.min(end);
+                if edit.insert.is_empty() {
+                    return;
+                }
+            }
+        }
+
+        state.apply_edit(edit, text_context);
+    }
+
+    /// Deletes text with `delete`, and then lets the input filter change the text around the deletion.
+    fn delete(
+        &self,
+        state: &mut TextInputState,
+        text_context: &mut TextContext,
+        delete: impl FnOnce(&mut PlainEditorDriver<ColorBrush>),
+    ) {
+        let before = state.snapshot();
+        state.edit("", text_context, delete);
+        let Some(input_filter) = &self.input_filter else {
+            return;
+        };
+
+        // The deleted text was right after the cursor.
+        let start = state.selection_range().start;
+        let range = start..start + before.text.len() - state.value().len();
+        if range.is_empty() {
+            return;
+        }
+        let edit = input_filter.filter_edit(&TextInputEdit { text: &before.text, range: range.clone(), insert: "" });
+        if edit.as_ref().is_some_and(|edit| edit.range == range && edit.insert.is_empty()) {
+            return;
+        }
+
+        state.restore(before, text_context);
+        if let Some(edit) = edit {
+            state.apply_edit(edit, text_context);
+        }
+    }
+
+    /// Creates the editor for the line numbers, when they are shown.
+    fn update_gutter(&self, state: &mut TextInputState, scaling_factor: f64) {
+        if !(self.multiline && self.line_numbers) {
+            state.gutter = None;
+            state.gutter_render = None;
+            return;
+        }
+        if state.gutter.is_some() {
+            return;
         }
+
+        let mut style = self.style().clone();
+        *style.color_mut() = LINE_NUMBER_COLOR;
+        let mut editor = PlainEditor::new(style.font_size());
+        editor.set_scale(scaling_factor as f32);
+        editor.set_alignment(Alignment::End);
+        style.add_styles_to_style_set(editor.edit_styles());
+        state.gutter = Some(editor);
+        state.cache.clear();
+    }
+
+    /// Creates the editor for the placeholder, when the placeholder was set or changed.
+    fn update_placeholder(&self, state: &mut TextInputState, scaling_factor: f64) {
+        let Some(placeholder) = &self.placeholder else {
+            state.placeholder = None;
+            state.placeholder_render = None;
+            return;
+        };
+        if state.placeholder.as_ref().is_some_and(|editor| editor.raw_text() == placeholder) {
+            return;
+        }
+
+        let style = self.placeholder_style.clone().unwrap_or_else(|| {
+            let mut style = self.style().clone();
+            *style.color_mut() = PLACEHOLDER_COLOR;
+            style
+        });
+        let mut editor = PlainEditor::new(style.font_size());
+        editor.set_scale(scaling_factor as f32);
+        style.add_styles_to_style_set(editor.edit_styles());
+        editor.set_text(placeholder);
+        state.placeholder = Some(editor);
+        state.cache.clear();
     }
 
     #[allow(dead_code)]
     fn get_state<'a>(&self, element_state: &'a ElementStateStore) -> &'a TextInputState {
         element_state.storage.get(&self.element_data.component_id).unwrap().data.as_ref().downcast_ref().unwrap()
     }
+
+    fn get_state_mut<'a>(&self, element_state: &'a mut ElementStateStore) -> &'a mut TextInputState {
+        element_state.storage.get_mut(&self.element_data.component_id).unwrap().data.as_mut().downcast_mut().unwrap()
+    }
 }
 
 impl Element for TextInput {
@@ -145,6 +301,19 @@ impl Element for TextInput {
         if let Some(state) =
             element_state.storage.get_mut(&self.element_data.component_id).unwrap().data.downcast_mut::<TextInputState>()
         {
+            if let Some(gutter_render) = state.gutter_render.as_ref() {
+                let gutter_rectangle = Rectangle::new(
+                    content_rectangle.x - state.gutter_width - state.gutter_gap,
+                    content_rectangle.y,
+                    state.gutter_width,
+                    content_rectangle.height,
+                );
+                renderer.draw_text(gutter_render.clone(), gutter_rectangle, text_scroll, false);
+            }
+            let placeholder_render = state.placeholder_render.as_ref().filter(|_| state.editor.raw_text().is_empty());
+            if let Some(placeholder_render) = placeholder_render {
+                renderer.draw_text(placeholder_render.clone(), content_rectangle, text_scroll, false);
+            }
             if let Some(text_render) = state.text_render.as_ref() {
                 renderer.draw_text(text_render.clone(), content_rectangle, text_scroll, state.cursor_visible);
             }
@@ -158,12 +327,37 @@ impl Element for TextInput {
     fn compute_layout(
         &mut self,
         taffy_tree: &mut TaffyTree<LayoutContext>,
-        _element_state: &mut ElementStateStore,
+        element_state: &mut ElementStateStore,
         scale_factor: f64,
     ) -> Option<NodeId> {
         self.merge_default_style();
+
         self.element_data.style.scale(scale_factor);
-        let style: taffy::Style = self.element_data.style.to_taffy_style();
+        let mut style: taffy::Style = self.element_data.style.to_taffy_style();
+
+        // The line numbers are drawn in the left padding, which is widened to fit the largest number. The style is
+        // left alone, so that the gutter is only added to the layout.
+        let state: &mut TextInputState = element_state
+            .storage
+            .get_mut(&self.element_data.component_id)
+            .unwrap()
+            .data
+            .as_mut()
+            .downcast_mut()
+            .unwrap();
+        if state.gutter.is_some() {
+            let line_count = state.new_text.as_deref().unwrap_or(state.editor.raw_text()).split('\n').count();
+            let font_size = self.element_data.style.font_size();
+            let gutter_width = line_count.to_string().len().max(2) as f32 * font_size * 0.6;
+            state.gutter_width = gutter_width * scale_factor as f32;
+            state.gutter_gap = font_size * 0.75 * scale_factor as f32;
+
+            let padding_left = match self.element_data.style.padding().left {
+                Unit::Px(padding_left) => padding_left,
+                _ => 0.0,
+            };
+            style.padding.left = taffy::LengthPercentage::length(padding_left + state.gutter_width + state.gutter_gap);
+        }
 
         self.element_data.layout_item.build_tree_with_context(
             taffy_tree,
@@ -229,6 +423,26 @@ impl Element for TextInput {
         };
 
         self.finalize_scrollbar(scroll_y);
+
+        // Keep the caret in view, and the scroll position in range after PageUp or PageDown.
+        if self.element_data.is_scrollable() {
+            let visible_height = self.computed_box().content_rectangle().height;
+            let max_scroll_y = self.element_data.layout_item.max_scroll_y;
+            let state = self.get_state_mut(element_state);
+            let mut scroll_y = state.scroll_state.scroll_y;
+            let cursor = state.text_render.as_ref().and_then(|text_render| text_render.cursor);
+            if let Some(cursor) = cursor.filter(|_| state.scroll_to_cursor) {
+                if cursor.y < scroll_y {
+                    scroll_y = cursor.y;
+                } else if cursor.y + cursor.height > scroll_y + visible_height {
+                    scroll_y = cursor.y + cursor.height - visible_height;
+                }
+            }
+            state.scroll_to_cursor = false;
+            state.scroll_state.scroll_y = scroll_y.clamp(0.0, max_scroll_y);
+            let scroll_y = state.scroll_state.scroll_y;
+            self.finalize_scrollbar(scroll_y);
+        }
     }
 
     fn resolve_clip(&mut self, clip_bounds: Option<Rectangle>) {
@@ -277,7 +491,10 @@ impl Element for TextInput {
                 state.modifiers = Some(*modifiers);
             }
             CraftMessage::KeyboardInputEvent(keyboard_input) if !state.editor.is_composing() => {
-                if !keyboard_input.event.state.is_pressed() {
+                // Tab is left to the focus manager, and Escape to the ancestors, like a dialog.
+                let is_passed_on =
+                    matches!(keyboard_input.event.logical_key, Key::Named(NamedKey::Tab | NamedKey::Escape));
+                if !keyboard_input.event.state.is_pressed() || is_passed_on {
                     return;
                 }
 
@@ -297,35 +514,57 @@ impl Element for TextInput {
                     })
                     .unwrap_or_default();
 
+                let edit_kind = match &keyboard_input.event.logical_key {
+                    Key::Character(c) if action_mod && matches!(c.to_lowercase().as_str(), "z" | "y") => None,
+                    Key::Character(_) if !action_mod => Some(EditKind::Typing),
+                    Key::Named(NamedKey::Backspace | NamedKey::Delete) if !action_mod => Some(EditKind::Deleting),
+                    _ => Some(EditKind::Other),
+                };
+                let before = state.snapshot();
+
                 let mut drv = state.driver(_text_context);
                 match &keyboard_input.event.logical_key {
                     #[cfg(any(target_os = "windows", target_os = "macos", target_os = "linux"))]
                     Key::Character(c) if action_mod && matches!(c.as_str(), "c" | "x" | "v") => {
                         use clipboard_rs::{Clipboard, ClipboardContext};
                         match c.to_lowercase().as_str() {
-                            "c" => {
+                            // Passwords can not be copied.
+                            "c" if !self.password => {
                                 if let Some(text) = drv.editor.selected_text() {
                                     let cb = ClipboardContext::new().unwrap();
                                     cb.set_text(text.to_owned()).ok();
                                 }
                             }
-                            "x" => {
+                            "x" if !self.password => {
                                 if let Some(text) = drv.editor.selected_text() {
                                     let cb = ClipboardContext::new().unwrap();
                                     cb.set_text(text.to_owned()).ok();
-                                    drv.delete_selection();
-                                    state.cache.clear();
+                                    if !self.read_only {
+                                        self.delete(state, _text_context, |drv| drv.delete_selection());
+                                    }
                                 }
                             }
-                            "v" => {
+                            "v" if !self.read_only => {
                                 let cb = ClipboardContext::new().unwrap();
                                 let text = cb.get_text().unwrap_or_default();
-                                drv.insert_or_replace_selection(&text);
-                                state.cache.clear();
+                                self.insert(state, &text, _text_context);
                             }
                             _ => (),
                         }
                     }
+                    Key::Character(c) if action_mod && matches!(c.to_lowercase().as_str(), "z" | "y") => {
+                        // Ctrl+Shift+Z and Ctrl+Y redo.
+                        let snapshot = if self.read_only {
+                            None
+                        } else if shift || c.to_lowercase() == "y" {
+                            state.history.redo(before.clone())
+                        } else {
+                            state.history.undo(before.clone())
+                        };
+                        if let Some(snapshot) = snapshot {
+                            state.restore(snapshot, _text_context);
+                        }
+                    }
                     Key::Character(c) if action_mod && matches!(c.to_lowercase().as_str(), "a") => {
                         if shift {
                             drv.collapse_selection();
@@ -401,40 +640,58 @@ impl Element for TextInput {
                             drv.move_to_line_end();
                         }
                     }
-                    Key::Named(NamedKey::Delete) => {
+                    Key::Named(NamedKey::Delete) if !self.read_only => {
                         if action_mod {
-                            drv.delete_word();
-                            state.cache.clear();
+                            self.delete(state, _text_context, |drv| drv.delete_word());
                         } else {
-                            drv.delete();
-                            state.cache.clear();
+                            self.delete(state, _text_context, |drv| drv.delete());
                         }
                     }
-                    Key::Named(NamedKey::Backspace) => {
+                    Key::Named(NamedKey::Backspace) if !self.read_only => {
                         if action_mod {
-                            drv.backdelete_word();
-                            state.cache.clear();
+                            self.delete(state, _text_context, |drv| drv.backdelete_word());
                         } else {
-                            drv.backdelete();
-                            state.cache.clear();
+                            self.delete(state, _text_context, |drv| drv.backdelete());
                         }
                     }
-                    Key::Named(NamedKey::Enter) => {
-                        drv.insert_or_replace_selection("\n");
-                        state.cache.clear();
+                    Key::Named(NamedKey::Enter) if self.multiline && action_mod => {
+                        event.prevent_defaults();
+                        event.prevent_propagate();
+                        event.result_message(CraftMessage::TextInputSubmitted(state.value().to_string()));
+                        return;
                     }
-                    Key::Character(s) => {
-                        drv.insert_or_replace_selection(s);
-                        state.cache.clear();
+                    Key::Named(key @ (NamedKey::PageUp | NamedKey::PageDown)) if self.multiline => {
+                        // Move the caret and the view by the visible height.
+                        let page = self.computed_box().content_rectangle().height;
+                        let page = if *key == NamedKey::PageUp { -page } else { page };
+                        if let Some(cursor) = drv.editor.cursor_geometry(1.0) {
+                            let (x, y) = (cursor.x0 as f32, (cursor.y0 + cursor.y1) as f32 / 2.0 + page);
+                            if shift {
+                                drv.extend_selection_to_point(x, y);
+                            } else {
+                                drv.move_to_point(x, y);
+                            }
+                        }
+                        state.scroll_state.scroll_y += page;
+                    }
+                    Key::Named(NamedKey::Enter) if !self.read_only => {
+                        self.insert(state, "\n", _text_context);
+                    }
+                    Key::Character(s) if !self.read_only => {
+                        self.insert(state, s, _text_context);
                     }
                     _ => (),
                 }
 
+                if let Some(edit_kind) = edit_kind {
+                    state.record_edit(before, edit_kind);
+                }
+                state.scroll_to_cursor = true;
 
                 // FIXME: This is more of a hack, we should be doing this somewhere else.
                 event.prevent_defaults();
                 event.prevent_propagate();
-                event.result_message(CraftMessage::TextInputChanged(state.editor.text().to_string()))
+                event.result_message(CraftMessage::TextInputChanged(state.value().to_string()))
             }
             // WindowEvent::Touch(Touch {
             //     phase, location, ..
@@ -464,6 +721,7 @@ impl Element for TextInput {
                     state.pointer_down = pointer_button.state.is_pressed();
                     state.cursor_reset();
                     if state.pointer_down && !state.editor.is_composing() {
+                        state.history.break_coalescing();
                         let now = Instant::now();
                         if let Some(last) = state.last_click_time.take() {
                             if now.duration_since(last).as_secs_f64() < 0.25 {
@@ -497,17 +755,40 @@ impl Element for TextInput {
                     state.driver(_text_context).extend_selection_to_point(cursor_pos.0, cursor_pos.1);
                 }
             }
+            CraftMessage::AccessibilityAction(request) if !state.editor.is_composing() && !self.read_only => {
+                if let (Action::SetValue, Some(ActionData::Value(value))) = (request.action, &request.data) {
+                    let before = state.snapshot();
+                    state.driver(_text_context).select_all();
+                    self.insert(state, value, _text_context);
+                    state.record_edit(before, EditKind::Other);
+                    event.result_message(CraftMessage::TextInputChanged(state.value().to_string()));
+                }
+            }
+            CraftMessage::Focus => {
+                state.cursor_reset();
+            }
+            CraftMessage::Blur => {
+                state.disable_blink();
+                state.cursor_visible = false;
+            }
             CraftMessage::ImeEvent(Ime::Disabled) => {
                 state.driver(_text_context).clear_compose();
                 state.cache.clear();
             }
-            CraftMessage::ImeEvent(Ime::Commit(text)) => {
-                state.driver(_text_context).insert_or_replace_selection(text);
-                state.cache.clear();
+            CraftMessage::ImeEvent(Ime::Commit(text)) if !self.read_only => {
+                let before = state.snapshot();
+                self.insert(state, text, _text_context);
+                state.record_edit(before, EditKind::Other);
+                state.scroll_to_cursor = true;
             }
-            CraftMessage::ImeEvent(Ime::Preedit(text, cursor)) => {
+            CraftMessage::ImeEvent(Ime::Preedit(text, cursor)) if !self.read_only => {
                 if text.is_empty() {
                     state.driver(_text_context).clear_compose();
+                } else if state.password.is_some() {
+                    // The text that is being composed is a part of the password, so it is shown as bullets too.
+                    let bullet_offset = |offset: usize| password_bullets(&text[..offset]).len();
+                    let cursor = cursor.map(|(start, end)| (bullet_offset(start), bullet_offset(end)));
+                    state.driver(_text_context).set_compose(&password_bullets(text), cursor);
                 } else {
                     state.driver(_text_context).set_compose(text, *cursor);
                 }
@@ -525,16 +806,31 @@ impl Element for TextInput {
         let style_set = editor.edit_styles();
         self.style().add_styles_to_style_set(style_set);
         
-        let text_input_state = TextInputState {
+        let mut new_text = std::mem::take(&mut self.text);
+        let password = if self.password { new_text.take() } else { None };
+        if let Some(password) = &password {
+            new_text = Some(password_bullets(password));
+        }
+
+        let mut text_input_state = TextInputState {
             ime_state: ImeState::default(),
             is_active: false,
             scroll_state: ScrollState::default(),
             editor,
+            history: EditHistory::new(self.history_depth.unwrap_or(DEFAULT_HISTORY_DEPTH)),
+            password,
+            placeholder: None,
+            placeholder_render: None,
+            gutter: None,
+            gutter_render: None,
+            gutter_width: 0.0,
+            gutter_gap: 0.0,
+            scroll_to_cursor: false,
             cache: Default::default(),
             current_key: None,
             last_requested_key: None,
             text_render: None,
-            new_text: std::mem::take(&mut self.text),
+            new_text,
             last_click_time: None,
             click_count: 0,
             pointer_down: false,
@@ -544,6 +840,8 @@ impl Element for TextInput {
             start_time: None,
             blink_period: Default::default(),
         };
+        self.update_placeholder(&mut text_input_state, scaling_factor);
+        self.update_gutter(&mut text_input_state, scaling_factor);
 
         ElementStateStoreItem {
             base: Default::default(),
@@ -566,9 +864,28 @@ impl Element for TextInput {
             .downcast_mut()
             .unwrap();
 
+        state.history.set_depth(self.history_depth.unwrap_or(DEFAULT_HISTORY_DEPTH));
+        self.update_placeholder(state, scaling_factor);
+        self.update_gutter(state, scaling_factor);
+
+        // The editor shows the text or the bullets of a password, depending on the mode.
+        if self.password != state.password.is_some() {
+            let value = state.new_text.take().unwrap_or_else(|| state.editor.raw_text().to_string());
+            if self.password {
+                state.new_text = Some(password_bullets(&value));
+                state.password = Some(value);
+            } else {
+                state.new_text = state.password.take();
+            }
+            state.cache.clear();
+        }
+
         if let Some(layout) = state.editor.try_layout() {
             if layout.scale() != scaling_factor as f32 {
                 state.editor.set_scale(scaling_factor as f32);
+                for editor in state.placeholder.iter_mut().chain(state.gutter.iter_mut()) {
+                    editor.set_scale(scaling_factor as f32);
+                }
                 state.cache.clear();
                 state.new_text = Some(state.editor.text().to_string());
             }
@@ -584,9 +901,43 @@ impl Element for TextInput {
         *style.border_radius_mut() = [(5.0, 5.0); 4];
         let padding = Unit::Px(4.0);
         *style.padding_mut() = TrblRectangle::new_all(padding);
+        if self.multiline {
+            *style.overflow_mut() = [Overflow::Visible, Overflow::Scroll];
+        }
 
         style
     }
+
+    fn default_accessibility_role(&self) -> Role {
+        if self.password {
+            Role::PasswordInput
+        } else {
+            Role::TextInput
+        }
+    }
+
+    fn update_accessibility_node(&self, node: &mut Node, element_state: &ElementStateStore) {
+        let state: &TextInputState = self.get_base_state(element_state).data.downcast_ref().unwrap();
+
+        // The new text is only applied to the editor during layout.
+        match state.new_text.as_deref() {
+            Some(text) => node.set_value(text),
+            None => node.set_value(state.editor.text().to_string()),
+        }
+        if let Some(placeholder) = &self.placeholder {
+            node.set_placeholder(placeholder.as_str());
+        }
+        if self.read_only {
+            node.set_read_only();
+        } else {
+            node.add_action(Action::SetValue);
+        }
+        state.scroll_state.update_accessibility_node(&self.element_data, node);
+    }
+
+    fn is_focusable(&self) -> bool {
+        true
+    }
 }
 
 impl TextInput {
@@ -598,6 +949,63 @@ impl TextInput {
         self.use_text_value_on_update = use_initial_text_value;
         self
     }
+
+    /// Sets the number of edits that can be undone with Ctrl+Z, 100 by default. A depth of 0 disables undo and redo.
+    pub fn history_depth(mut self, depth: usize) -> Self {
+        self.history_depth = Some(depth);
+        self
+    }
+
+    /// Sets the text that is shown while the text input is empty.
+    pub fn placeholder(mut self, placeholder: &str) -> Self {
+        self.placeholder = Some(placeholder.to_string());
+        self
+    }
+
+    /// Sets the style of the placeholder. By default, the placeholder uses the style of the text input in gray.
+    pub fn placeholder_style(mut self, placeholder_style: Style) -> Self {
+        self.placeholder_style = Some(placeholder_style);
+        self
+    }
+
+    /// Limits the length of the text to `max_length` grapheme clusters. Text that is typed, pasted or committed past
+    /// the limit is cut off.
+    pub fn max_length(mut self, max_length: usize) -> Self {
+        self.max_length = Some(max_length);
+        self
+    }
+
+    /// Whether the text can only be selected and copied, not edited.
+    pub fn read_only(mut self, read_only: bool) -> Self {
+        self.read_only = read_only;
+        self
+    }
+
+    /// Whether the text is shown as bullets. The text of a password can not be copied.
+    pub fn password(mut self, password: bool) -> Self {
+        self.password = password;
+        self
+    }
+
+    /// Makes the text input a text area. The text wraps to the width of the text area and scrolls vertically to keep
+    /// the caret in view. Enter inserts a newline, and Ctrl+Enter, or Cmd+Enter on macOS, emits a
+    /// [`CraftMessage::TextInputSubmitted`] event.
+    pub fn multiline(mut self, multiline: bool) -> Self {
+        self.multiline = multiline;
+        self
+    }
+
+    /// Whether the lines of a multi-line text input are numbered in a gutter.
+    pub fn line_numbers(mut self, line_numbers: bool) -> Self {
+        self.line_numbers = line_numbers;
+        self
+    }
+
+    /// Sets a filter that checks or transforms the text before it is inserted.
+    pub fn input_filter(mut self, input_filter: impl InputFilter + 'static) -> Self {
+        self.input_filter = Some(Arc::new(input_filter));
+        self
+    }
 }
 
 impl ElementStyles for TextInput {
@@ -660,11 +1068,43 @@ impl TextInputState {
         self.editor.refresh_layout(&mut text_context.font_context, &mut text_context.layout_context);
 
         let layout = self.editor.try_layout().unwrap();
-        let width = layout.width();
-        let height = layout.height();
+        let mut width = layout.width();
+        let mut height = layout.height();
 
         self.text_render = Some(text_render_data::from_editor(layout));
 
+        if let Some(gutter) = self.gutter.as_mut() {
+            // Number the first line of each paragraph, and leave the lines that were wrapped empty.
+            let layout = self.editor.try_layout().unwrap();
+            let text = self.editor.raw_text();
+            let mut line_number = 0;
+            let line_numbers: Vec<String> = layout
+                .lines()
+                .map(|line| {
+                    let start = line.text_range().start;
+                    if start == 0 || text.as_bytes()[start - 1] == b'\n' {
+                        line_number += 1;
+                        line_number.to_string()
+                    } else {
+                        String::new()
+                    }
+                })
+                .collect();
+            gutter.set_text(&line_numbers.join("\n"));
+            gutter.set_width(Some(self.gutter_width));
+            gutter.refresh_layout(&mut text_context.font_context, &mut text_context.layout_context);
+            self.gutter_render = Some(text_render_data::from_editor(gutter.try_layout().unwrap()));
+        }
+
+        if let Some(placeholder) = self.placeholder.as_mut() {
+            placeholder.set_width(width_constraint);
+            placeholder.refresh_layout(&mut text_context.font_context, &mut text_context.layout_context);
+            let layout = placeholder.try_layout().unwrap();
+            width = width.max(layout.width());
+            height = height.max(layout.height());
+            self.placeholder_render = Some(text_render_data::from_editor(layout));
+        }
+
         let size = taffy::Size { width, height };
 
         self.cache.insert(key, size);
@@ -679,7 +1119,6 @@ impl TextInputState {
         self.cursor_visible = true;
     }
 
-    #[allow(dead_code)]
     pub fn disable_blink(&mut self) {
         self.start_time = None;
     }
@@ -705,8 +1144,127 @@ impl TextInputState {
         });
     }
 
+    /// The text of the text input, which is not the text of the editor for passwords.
+    fn value(&self) -> &str {
+        self.password.as_deref().unwrap_or(self.editor.raw_text())
+    }
+
+    /// The byte range of the value that is selected.
+    fn selection_range(&self) -> Range<usize> {
+        let range = self.editor.raw_selection().text_range();
+        match &self.password {
+            Some(password) => {
+                let bullet_length = PASSWORD_BULLET.len_utf8();
+                let start = grapheme_offset(password, range.start / bullet_length);
+                start..grapheme_offset(password, range.end / bullet_length)
+            }
+            None => range,
+        }
+    }
+
+    /// The byte offset in the editor of a byte offset in the value, which differ for passwords.
+    fn editor_offset(&self, offset: usize) -> usize {
+        match &self.password {
+            Some(password) => password[..offset].graphemes(true).count() * PASSWORD_BULLET.len_utf8(),
+            None => offset,
+        }
+    }
+
+    /// Makes an edit that was checked by the input filter, and places the cursor in the inserted text.
+    fn apply_edit(&mut self, edit: FilteredEdit, text_context: &mut TextContext) {
+        if edit.range != self.selection_range() {
+            let (start, end) = (self.editor_offset(edit.range.start), self.editor_offset(edit.range.end));
+            self.driver(text_context).select_byte_range(start, end);
+        }
+        self.replace_selection(&edit.insert, text_context);
+
+        if edit.cursor != edit.insert.len() {
+            let cursor = self.editor_offset(edit.range.start + edit.cursor);
+            self.driver(text_context).select_byte_range(cursor, cursor);
+        }
+    }
+
+    /// Replaces the selection with `text`.
+    fn replace_selection(&mut self, text: &str, text_context: &mut TextContext) {
+        let shown_text = match self.password {
+            Some(_) => password_bullets(text),
+            None => text.to_string(),
+        };
+        self.edit(text, text_context, |drv| drv.insert_or_replace_selection(&shown_text));
+    }
+
+    /// Makes an edit that inserts `inserted` at the selection, or deletes text when it is empty.
+    ///
+    /// For passwords, the edit is made to the bullets in the editor and then repeated on the password. The cursor
+    /// ends up after the inserted text, which tells where the removed graphemes were.
+    fn edit(
+        &mut self,
+        inserted: &str,
+        text_context: &mut TextContext,
+        edit: impl FnOnce(&mut PlainEditorDriver<ColorBrush>),
+    ) {
+        let bullet_length = PASSWORD_BULLET.len_utf8();
+        let old_length = self.editor.raw_text().len() / bullet_length;
+        edit(&mut self.driver(text_context));
+        self.cache.clear();
+
+        let new_length = self.editor.raw_text().len() / bullet_length;
+        let cursor = self.editor.raw_selection().focus().index() / bullet_length;
+        let Some(password) = self.password.as_mut() else {
+            return;
+        };
+        let inserted_length = inserted.graphemes(true).count();
+        let removed_length = (old_length + inserted_length).saturating_sub(new_length);
+        let start = cursor.saturating_sub(inserted_length);
+        let range = grapheme_offset(password, start)..grapheme_offset(password, start + removed_length);
+        password.replace_range(range, inserted);
+    }
+
+    /// The current text and selection, for the edit history.
+    fn snapshot(&self) -> EditSnapshot {
+        let selection = self.editor.raw_selection();
+        EditSnapshot {
+            text: self.value().to_string(),
+            selection: (selection.anchor().index(), selection.focus().index()),
+        }
+    }
+
+    /// Adds an edit to the history if it changed the text. Moving the cursor starts a new edit.
+    fn record_edit(&mut self, before: EditSnapshot, kind: EditKind) {
+        let after = self.snapshot();
+        if after.text != before.text {
+            self.history.record(before, kind);
+        } else if after.selection != before.selection {
+            self.history.break_coalescing();
+        }
+    }
+
+    /// Restores the text and selection from the edit history.
+    fn restore(&mut self, snapshot: EditSnapshot, text_context: &mut TextContext) {
+        match self.password.as_mut() {
+            Some(password) => {
+                self.editor.set_text(&password_bullets(&snapshot.text));
+                *password = snapshot.text;
+            }
+            None => self.editor.set_text(&snapshot.text),
+        }
+        let (anchor, focus) = snapshot.selection;
+        self.driver(text_context).select_byte_range(anchor, focus);
+        self.cache.clear();
+    }
+
     fn driver<'a>(&'a mut self, text_context: &'a mut TextContext) -> PlainEditorDriver<'a, ColorBrush> {
         self.editor.driver(&mut text_context.font_context, &mut text_context.layout_context)
     }
 
-}
\ No newline at end of file
+}
+
+/// Shows a bullet for each grapheme of a password.
+fn password_bullets(password: &str) -> String {
+    password.graphemes(true).map(|_| PASSWORD_BULLET).collect()
+}
+
+/// The byte offset of the grapheme at `index`, or the length of `text` past the last grapheme.
+fn grapheme_offset(text: &str, index: usize) -> usize {
+    text.grapheme_indices(true).nth(index).map(|(offset, _)| offset).unwrap_or(text.len())
+}
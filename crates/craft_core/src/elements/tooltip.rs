@@ -0,0 +1,409 @@
+use crate::components::component::ComponentSpecification;
+use crate::components::Props;
+use crate::elements::element::Element;
+use crate::elements::element_data::ElementData;
+use crate::elements::element_styles::ElementStyles;
+use crate::events::CraftMessage;
+use crate::geometry::{Point, Rectangle, Size};
+use crate::layout::layout_context::LayoutContext;
+use crate::reactive::element_state_store::{ElementStateStore, ElementStateStoreItem};
+use crate::renderer::renderer::RenderList;
+use crate::renderer::Brush;
+use crate::style::Style;
+use crate::text::text_context::{ColorBrush, TextContext};
+use crate::text::text_render_data;
+use crate::text::text_render_data::TextRender;
+use crate::{generate_component_methods, palette, WindowContext};
+use accesskit::Node;
+use parley::PlainEditor;
+use peniko::kurbo::{RoundedRect, Shape};
+use peniko::Color;
+use std::any::Any;
+use std::sync::Arc;
+use taffy::{NodeId, TaffyTree};
+use winit::keyboard::{Key, NamedKey};
+use winit::window::Window;
+
+#[cfg(target_arch = "wasm32")]
+use web_time as time;
+#[cfg(not(target_arch = "wasm32"))]
+use std::time as time;
+use time::{Duration, Instant};
+
+/// The hover delay when no delay is set.
+const DEFAULT_DELAY: Duration = Duration::from_millis(500);
+
+/// The space between the tooltip and its target, and the tooltip and the edges of the window, in logical pixels.
+const SPACING: f32 = 6.0;
+
+/// The horizontal and the vertical padding around the text of the tooltip in logical pixels.
+const PADDING: (f32, f32) = (8.0, 4.0);
+
+/// The widest a tooltip gets before its text wraps, in logical pixels.
+const MAX_WIDTH: f32 = 300.0;
+
+/// Where a [`Tooltip`] is placed relative to its target.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum TooltipPlacement {
+    #[default]
+    Top,
+    Bottom,
+    Left,
+    Right,
+}
+
+impl TooltipPlacement {
+    fn opposite(self) -> Self {
+        match self {
+            TooltipPlacement::Top => TooltipPlacement::Bottom,
+            TooltipPlacement::Bottom => TooltipPlacement::Top,
+            TooltipPlacement::Left => TooltipPlacement::Right,
+            TooltipPlacement::Right => TooltipPlacement::Left,
+        }
+    }
+}
+
+/// An element that shows a text next to its children, the target, after the pointer rests on them.
+///
+/// The tooltip is drawn in the overlay layer once the pointer has been over the target for the delay, and hides when
+/// the pointer leaves the target or Escape is pressed. It is placed on the preferred side of the target, and on the
+/// opposite side when it would not fit into the window there.
+#[derive(Clone, Default)]
+pub struct Tooltip {
+    pub element_data: ElementData,
+    text: String,
+    placement: TooltipPlacement,
+    delay: Option<Duration>,
+    /// The style of the tooltip. The background, color, font size and border radius are used.
+    tooltip_style: Style,
+}
+
+#[derive(Clone, Default)]
+pub struct TooltipState {
+    /// The time of the current frame.
+    now: Option<Instant>,
+    /// When the pointer entered the target.
+    hovered_since: Option<Instant>,
+    /// Whether Escape hid the tooltip. It is shown again after the pointer leaves and enters the target.
+    dismissed: bool,
+    pub(crate) visible: bool,
+    /// The size of the window in physical pixels.
+    window_size: Size<f32>,
+    scale_factor: f32,
+    editor: Option<PlainEditor<ColorBrush>>,
+    text_render: Option<TextRender>,
+    /// The box of the tooltip and the side of the target that it is placed on.
+    pub(crate) bounds: Rectangle,
+    pub(crate) placement: TooltipPlacement,
+}
+
+impl TooltipState {
+    /// Hides the tooltip until the pointer enters the target again.
+    pub(crate) fn dismiss(&mut self) {
+        self.dismissed = self.hovered_since.is_some();
+        self.visible = false;
+    }
+}
+
+impl Element for Tooltip {
+    fn element_data(&self) -> &ElementData {
+        &self.element_data
+    }
+
+    fn element_data_mut(&mut self) -> &mut ElementData {
+        &mut self.element_data
+    }
+
+    fn name(&self) -> &'static str {
+        "Tooltip"
+    }
+
+    fn draw(
+        &mut self,
+        renderer: &mut RenderList,
+        text_context: &mut TextContext,
+        taffy_tree: &mut TaffyTree<LayoutContext>,
+        _root_node: NodeId,
+        element_state: &mut ElementStateStore,
+        pointer: Option<Point>,
+        window: Option<Arc<dyn Window>>,
+    ) {
+        if !self.element_data.style.visible() {
+            return;
+        }
+        self.draw_borders(renderer, element_state);
+        self.draw_children(renderer, text_context, taffy_tree, element_state, pointer, window);
+
+        let state = self.get_state(element_state);
+        let Some(text_render) = state.text_render.clone().filter(|_| state.visible) else {
+            return;
+        };
+        let style = self.resolved_tooltip_style();
+        let bounds = state.bounds;
+        let radius = style.border_radius()[0].0 * state.scale_factor;
+        let padding = Size::new(PADDING.0 * state.scale_factor, PADDING.1 * state.scale_factor);
+
+        renderer.start_overlay();
+        let background = RoundedRect::from_rect(bounds.to_kurbo(), radius as f64);
+        renderer.fill_bez_path(background.to_path(0.1), Brush::Color(style.background()));
+        let text_rectangle = Rectangle::new(
+            bounds.x + padding.width,
+            bounds.y + padding.height,
+            bounds.width - padding.width * 2.0,
+            bounds.height - padding.height * 2.0,
+        );
+        renderer.draw_text(text_render, text_rectangle, None, false);
+        renderer.end_overlay();
+    }
+
+    fn compute_layout(
+        &mut self,
+        taffy_tree: &mut TaffyTree<LayoutContext>,
+        element_state: &mut ElementStateStore,
+        scale_factor: f64,
+    ) -> Option<NodeId> {
+        self.merge_default_style();
+
+        for child in self.element_data.children.iter_mut() {
+            let child_node = child.internal.compute_layout(taffy_tree, element_state, scale_factor);
+            self.element_data.layout_item.push_child(&child_node);
+        }
+
+        self.element_data.style.scale(scale_factor);
+        let style: taffy::Style = self.element_data.style.to_taffy_style();
+
+        self.element_data.layout_item.build_tree(taffy_tree, style)
+    }
+
+    fn finalize_layout(
+        &mut self,
+        taffy_tree: &mut TaffyTree<LayoutContext>,
+        root_node: NodeId,
+        position: Point,
+        z_index: &mut u32,
+        transform: glam::Mat4,
+        element_state: &mut ElementStateStore,
+        pointer: Option<Point>,
+        text_context: &mut TextContext,
+        clip_bounds: Option<Rectangle>,
+    ) {
+        let result = taffy_tree.layout(root_node).unwrap();
+        self.resolve_box(position, transform, result, z_index);
+        self.resolve_clip(clip_bounds);
+        self.finalize_borders(element_state);
+
+        for child in self.element_data.children.iter_mut() {
+            let taffy_child_node_id = child.internal.element_data().layout_item.taffy_node_id;
+            if taffy_child_node_id.is_none() {
+                continue;
+            }
+
+            child.internal.finalize_layout(
+                taffy_tree,
+                taffy_child_node_id.unwrap(),
+                self.element_data.layout_item.computed_box.position,
+                z_index,
+                transform,
+                element_state,
+                pointer,
+                text_context,
+                clip_bounds,
+            );
+        }
+
+        // The pointer is over the target when it is over the tooltip element, which is as large as its children.
+        let is_hovered = pointer.is_some_and(|pointer| self.in_bounds(pointer));
+        let target = self.computed_box_transformed().border_rectangle();
+        let delay = self.delay.unwrap_or(DEFAULT_DELAY);
+        let state = self.get_state_mut(element_state);
+        let now = state.now.unwrap_or_else(Instant::now);
+        if !is_hovered {
+            state.hovered_since = None;
+            state.dismissed = false;
+        }
+        let hovered_since = is_hovered.then(|| *state.hovered_since.get_or_insert(now));
+        state.visible = hovered_since.is_some_and(|since| now.saturating_duration_since(since) >= delay)
+            && !state.dismissed
+            && !self.text.is_empty();
+        if !state.visible {
+            return;
+        }
+
+        let Some(editor) = state.editor.as_mut() else {
+            return;
+        };
+        editor.refresh_layout(&mut text_context.font_context, &mut text_context.layout_context);
+        let layout = editor.try_layout().unwrap();
+        let text_size = Size::new(layout.width(), layout.height());
+        state.text_render = Some(text_render_data::from_editor(layout));
+
+        let scale_factor = state.scale_factor;
+        let size = Size::new(
+            text_size.width + PADDING.0 * scale_factor * 2.0,
+            text_size.height + PADDING.1 * scale_factor * 2.0,
+        );
+        let window = Rectangle::new(0.0, 0.0, state.window_size.width, state.window_size.height);
+        let spacing = SPACING * scale_factor;
+        let fits = |bounds: &Rectangle| {
+            bounds.x >= 0.0 && bounds.y >= 0.0 && bounds.right() <= window.right() && bounds.bottom() <= window.bottom()
+        };
+
+        let mut placement = self.placement;
+        let mut bounds = place(target, size, placement, spacing);
+        if !fits(&bounds) && fits(&place(target, size, placement.opposite(), spacing)) {
+            placement = placement.opposite();
+            bounds = place(target, size, placement, spacing);
+        }
+        // Keep the tooltip in the window along the side of the target.
+        bounds.x = bounds.x.min(window.right() - spacing - bounds.width).max(spacing);
+        bounds.y = bounds.y.min(window.bottom() - spacing - bounds.height).max(spacing);
+        state.bounds = bounds;
+        state.placement = placement;
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn on_global_event(&self, message: &CraftMessage, element_state: &mut ElementStateStore, _is_target: bool) {
+        // Escape hides the tooltip, wherever the focus is.
+        let is_escape = matches!(
+            message,
+            CraftMessage::KeyboardInputEvent(keyboard_input)
+                if keyboard_input.event.state.is_pressed()
+                    && keyboard_input.event.logical_key == Key::Named(NamedKey::Escape)
+        );
+        if is_escape {
+            self.get_state_mut(element_state).dismiss();
+        }
+    }
+
+    fn initialize_state(&mut self, scaling_factor: f64) -> ElementStateStoreItem {
+        let mut state = TooltipState::default();
+        self.update_editor(&mut state, scaling_factor as f32);
+        ElementStateStoreItem {
+            base: Default::default(),
+            data: Box::new(state),
+        }
+    }
+
+    fn update_state(&mut self, element_state: &mut ElementStateStore, _reload_fonts: bool, scaling_factor: f64) {
+        let state = self.get_state_mut(element_state);
+        self.update_editor(state, scaling_factor as f32);
+    }
+
+    fn materialize_children(
+        &mut self,
+        children: Vec<ComponentSpecification>,
+        element_state: &mut ElementStateStore,
+        window: &WindowContext,
+        scaling_factor: f64,
+    ) -> Vec<ComponentSpecification> {
+        // The tooltip is kept inside the window.
+        let window_size = window.window_size;
+        self.get_state_mut(element_state).window_size =
+            Size::new(window_size.width * scaling_factor as f32, window_size.height * scaling_factor as f32);
+        children
+    }
+
+    fn advance_timers(&mut self, element_state: &mut ElementStateStore, now: Instant) -> bool {
+        let state = self.get_state_mut(element_state);
+        state.now = Some(now);
+
+        // Keep drawing frames until the tooltip is shown.
+        state.hovered_since.is_some() && !state.visible && !state.dismissed
+    }
+
+    fn update_accessibility_node(&self, node: &mut Node, _element_state: &ElementStateStore) {
+        if !self.text.is_empty() {
+            node.set_description(self.text.as_str());
+        }
+    }
+}
+
+impl Tooltip {
+    /// Creates a tooltip that shows `text` for its children.
+    pub fn new(text: &str) -> Tooltip {
+        Tooltip {
+            text: text.to_string(),
+            ..Default::default()
+        }
+    }
+
+    /// Sets the side of the target that the tooltip is shown on, when it fits into the window there.
+    pub fn placement(mut self, placement: TooltipPlacement) -> Self {
+        self.placement = placement;
+        self
+    }
+
+    /// Sets how long the pointer has to rest on the target before the tooltip is shown.
+    pub fn delay(mut self, delay: Duration) -> Self {
+        self.delay = Some(delay);
+        self
+    }
+
+    /// Sets the style of the tooltip. This style will get merged with the default tooltip style.
+    pub fn tooltip_style(mut self, tooltip_style: Style) -> Self {
+        self.tooltip_style = tooltip_style;
+        self
+    }
+
+    fn default_tooltip_style() -> Style {
+        let mut style = Style::default();
+        *style.background_mut() = Color::from_rgb8(50, 50, 56);
+        *style.color_mut() = palette::css::WHITE;
+        *style.font_size_mut() = 13.0;
+        *style.border_radius_mut() = [(4.0, 4.0); 4];
+        style
+    }
+
+    fn resolved_tooltip_style(&self) -> Style {
+        Style::merge(&Self::default_tooltip_style(), &self.tooltip_style)
+    }
+
+    /// Creates the editor that lays out the text, when the text or the scale factor changed.
+    fn update_editor(&self, state: &mut TooltipState, scale_factor: f32) {
+        let is_current = state.editor.as_ref().is_some_and(|editor| editor.raw_text() == self.text);
+        if is_current && state.scale_factor == scale_factor {
+            return;
+        }
+
+        let style = self.resolved_tooltip_style();
+        let mut editor = PlainEditor::new(style.font_size());
+        editor.set_scale(scale_factor);
+        editor.set_width(Some(MAX_WIDTH * scale_factor));
+        style.add_styles_to_style_set(editor.edit_styles());
+        editor.set_text(&self.text);
+        state.editor = Some(editor);
+        state.scale_factor = scale_factor;
+    }
+
+    #[allow(dead_code)]
+    fn get_state<'a>(&self, element_state: &'a ElementStateStore) -> &'a TooltipState {
+        element_state.storage.get(&self.element_data.component_id).unwrap().data.as_ref().downcast_ref().unwrap()
+    }
+
+    fn get_state_mut<'a>(&self, element_state: &'a mut ElementStateStore) -> &'a mut TooltipState {
+        element_state.storage.get_mut(&self.element_data.component_id).unwrap().data.as_mut().downcast_mut().unwrap()
+    }
+
+    generate_component_methods!();
+}
+
+impl ElementStyles for Tooltip {
+    fn styles_mut(&mut self) -> &mut Style {
+        self.element_data.current_style_mut()
+    }
+}
+
+/// Places a box of `size` on the `placement` side of `target`, centered along that side.
+fn place(target: Rectangle, size: Size<f32>, placement: TooltipPlacement, spacing: f32) -> Rectangle {
+    let center = Point::new(target.x + target.width / 2.0, target.y + target.height / 2.0);
+    let (x, y) = match placement {
+        TooltipPlacement::Top => (center.x - size.width / 2.0, target.y - spacing - size.height),
+        TooltipPlacement::Bottom => (center.x - size.width / 2.0, target.bottom() + spacing),
+        TooltipPlacement::Left => (target.x - spacing - size.width, center.y - size.height / 2.0),
+        TooltipPlacement::Right => (target.right() + spacing, center.y - size.height / 2.0),
+    };
+    Rectangle::new(x, y, size.width, size.height)
+}
@@ -0,0 +1,404 @@
+use crate::components::component::ComponentSpecification;
+use crate::components::Event;
+use crate::components::Props;
+use crate::elements::element::{resolve_clip_for_scrollable, Element};
+use crate::elements::element_data::ElementData;
+use crate::elements::element_styles::ElementStyles;
+use crate::elements::scroll_state::ScrollState;
+use crate::events::CraftMessage;
+use crate::generate_component_methods_no_children;
+use crate::geometry::{Point, Rectangle, Size};
+use crate::layout::layout_context::LayoutContext;
+use crate::reactive::element_state_store::{ElementStateStore, ElementStateStoreItem};
+use crate::renderer::renderer::RenderList;
+use crate::style::{Display, Overflow, Style};
+use crate::text::text_context::TextContext;
+use crate::WindowContext;
+use accesskit::Node;
+use std::any::Any;
+use std::ops::Range;
+use std::sync::Arc;
+use taffy::{NodeId, TaffyTree};
+use winit::window::Window;
+
+/// Builds the row at an index of a [`VirtualList`].
+pub type RowBuilder = Arc<dyn Fn(usize) -> ComponentSpecification + Send + Sync>;
+
+/// An element for scrolling through a large number of rows, of which only the visible ones are created.
+///
+/// The rows are built on demand when they scroll into view, along with `overscan` rows above and below the viewport.
+/// Rows are keyed by their index, unless the row builder gives them a key, and get their state back when they scroll
+/// out of view and in again, for up to [`VirtualList::retained_rows`] rows.
+#[derive(Clone)]
+pub struct VirtualList {
+    pub element_data: ElementData,
+    item_count: usize,
+    /// The height of a row in logical pixels.
+    item_height: f32,
+    /// Whether the rows may differ from `item_height`, in which case they are measured once they are created.
+    is_estimated: bool,
+    overscan: usize,
+    /// The number of rows whose state is kept after they scroll out of view.
+    retained_rows: usize,
+    scroll_to_index: Option<usize>,
+    row_builder: RowBuilder,
+    /// The rows that were created for this frame.
+    rows: Range<usize>,
+    /// The height of the rows before and after the created ones, in logical pixels.
+    space_around_rows: (f32, f32),
+    scale_factor: f64,
+}
+
+#[derive(Clone, Default)]
+pub struct VirtualListState {
+    pub(crate) scroll_state: ScrollState,
+    /// The height of the padding box in the last frame, in logical pixels.
+    viewport_height: Option<f32>,
+    /// The measured heights of the rows that were displayed, when the item height is estimated.
+    row_heights: Vec<Option<f32>>,
+    /// The last index that the list jumped to, so that it only jumps again when `scroll_to_index` changes.
+    scrolled_to_index: Option<usize>,
+}
+
+impl Element for VirtualList {
+    fn element_data(&self) -> &ElementData {
+        &self.element_data
+    }
+
+    fn element_data_mut(&mut self) -> &mut ElementData {
+        &mut self.element_data
+    }
+
+    fn name(&self) -> &'static str {
+        "VirtualList"
+    }
+
+    fn draw(
+        &mut self,
+        renderer: &mut RenderList,
+        text_context: &mut TextContext,
+        taffy_tree: &mut TaffyTree<LayoutContext>,
+        _root_node: NodeId,
+        element_state: &mut ElementStateStore,
+        pointer: Option<Point>,
+        window: Option<Arc<dyn Window>>,
+    ) {
+        let base_state = self.get_base_state_mut(element_state);
+        let current_style = base_state.base.current_style(self.element_data());
+
+        if !current_style.visible() {
+            return;
+        }
+
+        // We draw the borders before we start any layers, so that we don't clip the borders.
+        self.draw_borders(renderer, element_state);
+        self.maybe_start_layer(renderer);
+        {
+            self.draw_children(renderer, text_context, taffy_tree, element_state, pointer, window);
+        }
+        self.maybe_end_layer(renderer);
+
+        self.draw_scrollbar(renderer);
+    }
+
+    fn compute_layout(
+        &mut self,
+        taffy_tree: &mut TaffyTree<LayoutContext>,
+        element_state: &mut ElementStateStore,
+        scale_factor: f64,
+    ) -> Option<NodeId> {
+        self.merge_default_style();
+        self.scale_factor = scale_factor;
+
+        // The rows that were not created are replaced by empty space, so that the scroll height stays the same.
+        let (space_above, space_below) = self.space_around_rows;
+        let space_above = Some(taffy_tree.new_leaf(spacer_style(space_above * scale_factor as f32)).unwrap());
+        self.element_data.layout_item.push_child(&space_above);
+
+        for child in &mut self.element_data.children {
+            let child_node = child.internal.compute_layout(taffy_tree, element_state, scale_factor);
+            self.element_data.layout_item.push_child(&child_node);
+        }
+
+        let space_below = Some(taffy_tree.new_leaf(spacer_style(space_below * scale_factor as f32)).unwrap());
+        self.element_data.layout_item.push_child(&space_below);
+
+        let base_state = self.get_base_state_mut(element_state);
+        base_state.base.current_style_mut(&mut self.element_data).scale(scale_factor);
+
+        let current_style = {
+            let base_state = self.get_base_state(element_state);
+            base_state.base.current_style(&self.element_data).to_taffy_style()
+        };
+
+        self.element_data.layout_item.build_tree(taffy_tree, current_style)
+    }
+
+    fn finalize_layout(
+        &mut self,
+        taffy_tree: &mut TaffyTree<LayoutContext>,
+        root_node: NodeId,
+        position: Point,
+        z_index: &mut u32,
+        transform: glam::Mat4,
+        element_state: &mut ElementStateStore,
+        pointer: Option<Point>,
+        text_context: &mut TextContext,
+        clip_bounds: Option<Rectangle>,
+    ) {
+        let result = taffy_tree.layout(root_node).unwrap();
+        self.resolve_box(position, transform, result, z_index);
+        self.finalize_borders(element_state);
+
+        self.element_data.layout_item.scrollbar_size =
+            Size::new(result.scrollbar_size.width, result.scrollbar_size.height);
+        self.element_data.layout_item.computed_scrollbar_size =
+            Size::new(result.scroll_width(), result.scroll_height());
+
+        // A jump to one of the last rows can go past the end, so the scroll position is clamped once the rows are laid
+        // out.
+        self.element_data.layout_item.resolve_max_scroll_y();
+        let scroll_y = self.get_state(element_state).scroll_state.scroll_y.min(self.element_data.layout_item.max_scroll_y);
+        self.finalize_scrollbar(scroll_y);
+        self.resolve_clip(clip_bounds);
+
+        let child_transform = glam::Mat4::from_translation(glam::Vec3::new(0.0, -scroll_y, 0.0));
+
+        for child in self.element_data.children.iter_mut() {
+            let taffy_child_node_id = child.internal.element_data().layout_item.taffy_node_id;
+            if taffy_child_node_id.is_none() {
+                continue;
+            }
+
+            child.internal.finalize_layout(
+                taffy_tree,
+                taffy_child_node_id.unwrap(),
+                self.element_data.layout_item.computed_box.position,
+                z_index,
+                transform * child_transform,
+                element_state,
+                pointer,
+                text_context,
+                self.element_data.layout_item.clip_bounds,
+            );
+        }
+
+        let scale_factor = self.scale_factor as f32;
+        let viewport_height = self.element_data.layout_item.computed_box.padding_rectangle().height / scale_factor;
+        let measured_heights: Vec<f32> = if self.is_estimated {
+            self.element_data
+                .children
+                .iter()
+                .map(|child| child.internal.computed_box().margin_rectangle().height / scale_factor)
+                .collect()
+        } else {
+            Vec::new()
+        };
+
+        let state = self.get_state_mut(element_state);
+        state.scroll_state.scroll_y = scroll_y;
+        state.viewport_height = Some(viewport_height);
+        for (index, height) in self.rows.clone().zip(measured_heights) {
+            state.row_heights[index] = Some(height);
+        }
+    }
+
+    fn resolve_clip(&mut self, clip_bounds: Option<Rectangle>) {
+        resolve_clip_for_scrollable(self, clip_bounds);
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn on_event(
+        &self,
+        message: &CraftMessage,
+        element_state: &mut ElementStateStore,
+        _text_context: &mut TextContext,
+        should_style: bool,
+        event: &mut Event,
+    ) {
+        self.on_style_event(message, element_state, should_style, event);
+        let base_state = self.get_base_state_mut(element_state);
+        let state = base_state.data.as_mut().downcast_mut::<VirtualListState>().unwrap();
+
+        state.scroll_state.on_event(message, &self.element_data, &mut base_state.base, event);
+    }
+
+    fn initialize_state(&mut self, _scaling_factor: f64) -> ElementStateStoreItem {
+        ElementStateStoreItem {
+            base: Default::default(),
+            data: Box::new(VirtualListState::default()),
+        }
+    }
+
+    fn materialize_children(
+        &mut self,
+        _children: Vec<ComponentSpecification>,
+        element_state: &mut ElementStateStore,
+        window: &WindowContext,
+        scaling_factor: f64,
+    ) -> Vec<ComponentSpecification> {
+        let scale_factor = scaling_factor as f32;
+        let (item_count, item_height, is_estimated) = (self.item_count, self.item_height, self.is_estimated);
+        let scroll_to_index = self.scroll_to_index;
+        let state = self.get_state_mut(element_state);
+
+        // With estimated heights, the offsets of the rows from the top of the list are summed up from the measured
+        // heights, followed by the height of the whole list. Rows of a fixed height are found from the height alone.
+        let offsets = if is_estimated {
+            state.row_heights.resize(item_count, None);
+            let mut offsets = Vec::with_capacity(item_count + 1);
+            let mut offset = 0.0;
+            offsets.push(offset);
+            for row_height in state.row_heights.iter() {
+                offset += row_height.unwrap_or(item_height);
+                offsets.push(offset);
+            }
+            offsets
+        } else {
+            Vec::new()
+        };
+        let offset_of = |index: usize| {
+            if is_estimated {
+                offsets[index]
+            } else {
+                index as f32 * item_height
+            }
+        };
+        let list_height = offset_of(item_count);
+
+        if scroll_to_index != state.scrolled_to_index {
+            if let Some(index) = scroll_to_index {
+                state.scroll_state.scroll_y = offset_of(index.min(item_count)) * scale_factor;
+            }
+            state.scrolled_to_index = scroll_to_index;
+        }
+
+        // The viewport is only known after a layout and may have grown since, so rows are created for at least the
+        // height of the window, which is as much of the list as can be visible.
+        let window_height = window.window_size.height;
+        let viewport_height = state.viewport_height.unwrap_or(window_height);
+        let top = (state.scroll_state.scroll_y / scale_factor).min(list_height - viewport_height).max(0.0);
+        let bottom = top + viewport_height.max(window_height);
+
+        let (first_visible, last_visible) = if is_estimated {
+            (
+                offsets[1..].partition_point(|end| *end <= top),
+                offsets[..item_count].partition_point(|start| *start < bottom),
+            )
+        } else {
+            (
+                ((top / item_height).floor() as usize).min(item_count),
+                ((bottom / item_height).ceil() as usize).min(item_count),
+            )
+        };
+        self.rows = first_visible.saturating_sub(self.overscan)..(last_visible + self.overscan).min(item_count);
+        self.space_around_rows = (offset_of(self.rows.start), list_height - offset_of(self.rows.end));
+
+        self.rows
+            .clone()
+            .map(|index| {
+                let mut row = (self.row_builder)(index);
+                row.key.get_or_insert_with(|| index.to_string());
+                row
+            })
+            .collect()
+    }
+
+    fn retained_children_limit(&self) -> usize {
+        self.retained_rows
+    }
+
+    fn update_accessibility_node(&self, node: &mut Node, element_state: &ElementStateStore) {
+        self.get_state(element_state).scroll_state.update_accessibility_node(&self.element_data, node);
+    }
+
+    fn default_style(&self) -> Style {
+        let mut style = Style::default();
+        *style.display_mut() = Display::Block;
+        *style.overflow_mut() = [Overflow::Visible, Overflow::Scroll];
+
+        style
+    }
+}
+
+/// The style of the empty space that stands in for the rows that were not created.
+fn spacer_style(height: f32) -> taffy::Style {
+    taffy::Style {
+        size: taffy::Size {
+            width: taffy::Dimension::auto(),
+            height: taffy::Dimension::length(height),
+        },
+        ..Default::default()
+    }
+}
+
+impl VirtualList {
+    fn get_state<'a>(&self, element_state: &'a ElementStateStore) -> &'a VirtualListState {
+        element_state.storage.get(&self.element_data.component_id).unwrap().data.as_ref().downcast_ref().unwrap()
+    }
+
+    fn get_state_mut<'a>(&self, element_state: &'a mut ElementStateStore) -> &'a mut VirtualListState {
+        element_state.storage.get_mut(&self.element_data.component_id).unwrap().data.as_mut().downcast_mut().unwrap()
+    }
+
+    /// Creates a list of `item_count` rows that are `item_height` logical pixels tall, which are built by `row_builder`
+    /// when they scroll into view.
+    pub fn new<F, T>(item_count: usize, item_height: f32, row_builder: F) -> VirtualList
+    where
+        F: Fn(usize) -> T + Send + Sync + 'static,
+        T: Into<ComponentSpecification>,
+    {
+        VirtualList {
+            element_data: Default::default(),
+            item_count,
+            item_height,
+            is_estimated: false,
+            overscan: 3,
+            retained_rows: 100,
+            scroll_to_index: None,
+            row_builder: Arc::new(move |index| row_builder(index).into()),
+            rows: 0..0,
+            space_around_rows: (0.0, 0.0),
+            scale_factor: 1.0,
+        }
+    }
+
+    /// Treats the item height as an estimate for rows of different heights. The rows are measured once they are
+    /// displayed.
+    pub fn estimated_item_height(mut self, item_height: f32) -> Self {
+        self.item_height = item_height;
+        self.is_estimated = true;
+        self
+    }
+
+    /// The number of rows that are created above and below the viewport, so that they are ready before they scroll
+    /// into view.
+    pub fn overscan(mut self, overscan: usize) -> Self {
+        self.overscan = overscan;
+        self
+    }
+
+    /// The number of rows that keep their state after they scroll out of view, 100 by default. The rows that
+    /// scrolled out of view the longest ago lose their state first.
+    pub fn retained_rows(mut self, retained_rows: usize) -> Self {
+        self.retained_rows = retained_rows;
+        self
+    }
+
+    /// Scrolls to the top of the row at `index` when the list is displayed, or when the index changes.
+    pub fn scroll_to_index(mut self, index: usize) -> Self {
+        self.scroll_to_index = Some(index);
+        self
+    }
+
+    generate_component_methods_no_children!();
+}
+
+impl ElementStyles for VirtualList {
+    fn styles_mut(&mut self) -> &mut Style {
+        self.element_data.current_style_mut()
+    }
+}
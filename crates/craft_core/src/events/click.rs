@@ -0,0 +1,98 @@
+use crate::components::ComponentId;
+use crate::geometry::Point;
+use winit::event::MouseButton;
+
+#[cfg(target_arch = "wasm32")]
+use web_time as time;
+#[cfg(not(target_arch = "wasm32"))]
+use std::time as time;
+use time::{Duration, Instant};
+
+/// The longest time between two clicks that still counts them as a double click, the default of the platform. It is
+/// used unless [`CraftOptions::double_click_interval`](crate::CraftOptions::double_click_interval) is set.
+#[cfg(any(target_os = "windows", target_os = "macos"))]
+pub(crate) const DEFAULT_DOUBLE_CLICK_INTERVAL: Duration = Duration::from_millis(500);
+#[cfg(not(any(target_os = "windows", target_os = "macos")))]
+pub(crate) const DEFAULT_DOUBLE_CLICK_INTERVAL: Duration = Duration::from_millis(400);
+
+/// How far, in pixels, the pointer may move between two clicks that count as a double click.
+pub(crate) const DOUBLE_CLICK_DISTANCE: f32 = 4.0;
+
+/// A mouse button was pressed and released over the same element.
+#[derive(Clone, Copy, Debug)]
+pub struct Click {
+    pub button: MouseButton,
+    /// The position of the pointer when the button was released.
+    pub position: Point,
+    /// The number of clicks in quick succession, 2 for a double click and 3 for a triple click.
+    pub count: u32,
+}
+
+/// A press of a mouse button that may become a click.
+#[derive(Clone, Debug)]
+struct Press {
+    button: MouseButton,
+    position: Point,
+    time: Instant,
+    count: u32,
+    /// The elements under the pointer, from the target to the root.
+    elements: Vec<ComponentId>,
+}
+
+/// Counts the clicks of the mouse buttons.
+#[derive(Clone, Debug, Default)]
+pub(crate) struct ClickTracker {
+    /// The last press of a button, which is kept after the release to count the next click.
+    press: Option<Press>,
+    is_pressed: bool,
+}
+
+impl ClickTracker {
+    /// Records that `button` was pressed over `elements`, the target and its ancestors. A press within `interval` of
+    /// the last one continues its count.
+    pub(crate) fn press(
+        &mut self,
+        button: MouseButton,
+        position: Point,
+        time: Instant,
+        interval: Duration,
+        elements: Vec<ComponentId>,
+    ) {
+        let is_repeated = self.press.as_ref().is_some_and(|press| {
+            press.button == button
+                && time.duration_since(press.time) <= interval
+                && (press.position.x - position.x).abs() <= DOUBLE_CLICK_DISTANCE
+                && (press.position.y - position.y).abs() <= DOUBLE_CLICK_DISTANCE
+        });
+        let count = if is_repeated { self.press.as_ref().unwrap().count + 1 } else { 1 };
+
+        self.press = Some(Press {
+            button,
+            position,
+            time,
+            count,
+            elements,
+        });
+        self.is_pressed = true;
+    }
+
+    /// Records that `button` was released over `elements`, the target and its ancestors, and returns the click and the
+    /// closest element that the button was both pressed and released over.
+    pub(crate) fn release(
+        &mut self,
+        button: MouseButton,
+        position: Point,
+        elements: &[ComponentId],
+    ) -> Option<(ComponentId, Click)> {
+        let press = self.press.as_ref().filter(|press| self.is_pressed && press.button == button)?;
+        self.is_pressed = false;
+
+        let target = elements.iter().find(|element| press.elements.contains(element))?;
+        let click = Click {
+            button,
+            position,
+            count: press.count,
+        };
+        Some((*target, click))
+    }
+}
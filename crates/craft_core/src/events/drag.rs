@@ -0,0 +1,71 @@
+use crate::components::{ComponentId, Props};
+use crate::events::PointerId;
+use crate::geometry::Point;
+
+/// How far, in pixels, the pointer has to move while it is pressed before an element is dragged, unless the element
+/// sets its own threshold.
+pub(crate) const DEFAULT_DRAG_THRESHOLD: f32 = 4.0;
+
+/// An element is dragged with the pointer.
+#[derive(Clone, Debug)]
+pub struct Drag {
+    /// The payload of the dragged element, see `draggable`.
+    pub payload: Props,
+    /// The position of the pointer.
+    pub position: Point,
+}
+
+/// A press on a draggable element, which becomes a drag once the pointer moves past the threshold.
+#[derive(Clone, Debug)]
+pub(crate) struct DragState {
+    /// The pointer that drags the element.
+    pub(crate) pointer_id: PointerId,
+    /// The dragged element.
+    pub(crate) source: ComponentId,
+    pub(crate) payload: Props,
+    /// The position of the pointer when the button was pressed.
+    pub(crate) origin: Point,
+    pub(crate) threshold: f32,
+    /// Whether a copy of the element follows the pointer.
+    pub(crate) preview: bool,
+    pub(crate) is_dragging: bool,
+    /// The elements under the pointer while dragging, from the innermost to the root.
+    pub(crate) over: Vec<ComponentId>,
+    pub(crate) position: Point,
+}
+
+impl DragState {
+    pub(crate) fn new(
+        pointer_id: PointerId,
+        source: ComponentId,
+        payload: Props,
+        origin: Point,
+        threshold: f32,
+        preview: bool,
+    ) -> Self {
+        DragState {
+            pointer_id,
+            source,
+            payload,
+            origin,
+            threshold,
+            preview,
+            is_dragging: false,
+            over: Vec::new(),
+            position: origin,
+        }
+    }
+
+    /// Whether the pointer at `position` is far enough from where it was pressed to start dragging.
+    pub(crate) fn is_past_threshold(&self, position: Point) -> bool {
+        let distance = ((position.x - self.origin.x).powi(2) + (position.y - self.origin.y).powi(2)).sqrt();
+        distance > self.threshold
+    }
+
+    pub(crate) fn drag(&self) -> Drag {
+        Drag {
+            payload: self.payload.clone(),
+            position: self.position,
+        }
+    }
+}
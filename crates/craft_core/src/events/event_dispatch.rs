@@ -1,9 +1,14 @@
-use crate::components::{Event, PointerCapture};
-use crate::elements::base_element_state::DUMMY_DEVICE_ID;
+use crate::components::{ComponentId, Event, EventPhase, FocusRequest, PointerCapture};
+use crate::elements::base_element_state::BaseElementState;
 use crate::elements::Element;
+use crate::events::focus::{apply_focus_request, is_focusable, next_in_tab_order};
 use crate::events::update_queue_entry::UpdateQueueEntry;
-use crate::events::{CraftMessage, EventDispatchType, Message};
+use crate::events::{
+    CraftMessage, DragState, EventDispatchType, FileDrag, FileDragEvent, FileDragState, Message, PointerId,
+    PointerMoved, DEFAULT_DOUBLE_CLICK_INTERVAL, DEFAULT_DRAG_THRESHOLD,
+};
 use crate::geometry::Point;
+use crate::reactive::element_state_store::ElementStateStore;
 use crate::reactive::fiber_tree;
 use crate::reactive::fiber_tree::FiberNode;
 use crate::reactive::tree::ComponentTreeNode;
@@ -11,12 +16,19 @@ use crate::resource_manager::ResourceManager;
 use crate::text::text_context::TextContext;
 use crate::{GlobalState, ReactiveTree, WindowContext};
 use std::cell::RefCell;
-use std::collections::VecDeque;
+use std::collections::{HashSet, VecDeque};
 use std::rc::Rc;
 use std::sync::Arc;
-use winit::event::{ElementState, Ime, MouseButton};
+use winit::event::{ButtonSource, ElementState, MouseButton, PointerSource};
+use winit::keyboard::{Key, NamedKey};
 use craft_logging::{span, Level};
 
+#[cfg(target_arch = "wasm32")]
+use web_time as time;
+#[cfg(not(target_arch = "wasm32"))]
+use std::time as time;
+use time::Instant;
+
 #[allow(clippy::too_many_arguments)]
 pub(crate) fn dispatch_event(
     message: &Message,
@@ -33,6 +45,11 @@ pub(crate) fn dispatch_event(
     let _enter = span.enter();
 
     let mut effects: Vec<(EventDispatchType, Message)> = Vec::new();
+    let mut focus_request: Option<FocusRequest> = None;
+
+    if let Message::CraftMessage(CraftMessage::ModifiersChangedEvent(modifiers)) = message {
+        reactive_tree.modifiers = *modifiers;
+    }
 
     let current_element_tree = if let Some(current_element_tree) = reactive_tree.element_tree.as_ref() {
         current_element_tree
@@ -53,20 +70,52 @@ pub(crate) fn dispatch_event(
         }
     }
 
-    let is_pointer_event = matches!(
-        message,
-        Message::CraftMessage(CraftMessage::PointerMovedEvent(_))
-            | Message::CraftMessage(CraftMessage::PointerButtonEvent(_))
-    );
-    let is_ime_event = matches!(
+    // Every pointer has its own capture, and the events of a pointer are hit tested where that pointer is, since two
+    // fingers can touch the screen at once.
+    let (pointer, mouse_position) = match message {
+        Message::CraftMessage(CraftMessage::PointerMovedEvent(pointer_moved)) => {
+            (Some((pointer_moved.pointer_id(), pointer_moved.primary)), Some(pointer_moved.position))
+        }
+        Message::CraftMessage(CraftMessage::PointerButtonEvent(pointer_button)) => {
+            (Some((pointer_button.pointer_id(), pointer_button.primary)), Some(pointer_button.position))
+        }
+        _ => (None, mouse_position),
+    };
+    let is_pointer_event = pointer.is_some();
+    if let Some((pointer_id, true)) = pointer {
+        reactive_tree.primary_pointer = pointer_id;
+    }
+    // The pointer that captures apply to, which is the primary pointer for the events that do not come from a pointer.
+    let pointer_id = pointer.map_or(reactive_tree.primary_pointer, |(pointer_id, _)| pointer_id);
+    let is_keyboard_event = matches!(
         message,
-        Message::CraftMessage(CraftMessage::ImeEvent(Ime::Enabled))
-            | Message::CraftMessage(CraftMessage::ImeEvent(Ime::Disabled))
+        Message::CraftMessage(CraftMessage::KeyboardInputEvent(_))
+            | Message::CraftMessage(CraftMessage::ModifiersChangedEvent(_))
+            | Message::CraftMessage(CraftMessage::ImeEvent(_))
     );
 
+    // While a dialog is open, the events stay inside of the topmost one.
+    let modal = reactive_tree.modal.as_ref();
+    let is_inside_modal =
+        |component_id: &ComponentId| modal.is_none_or(|modal| modal.element_ids.contains(component_id));
+    let root_id = modal.map_or(current_element_tree.component_id(), |modal| modal.root);
+
+    // Keyboard events go to the focused element, or to the root element if nothing has the focus.
+    let dispatch_type = match dispatch_type {
+        EventDispatchType::Bubbling if is_keyboard_event => {
+            let focus = reactive_tree.focus.filter(|focus| is_inside_modal(focus));
+            EventDispatchType::Targeted(focus.unwrap_or(root_id))
+        }
+        dispatch_type => dispatch_type,
+    };
+
     match dispatch_type {
-        EventDispatchType::Bubbling => {
+        EventDispatchType::Bubbling | EventDispatchType::Targeted(_) => {
             nodes.retain_mut(|node| node.borrow().element.is_some());
+            let elements: Vec<&dyn Element> = nodes.iter().filter_map(|node| node.borrow().element).collect();
+            if matches!(dispatch_type, EventDispatchType::Bubbling) {
+                nodes.retain(|node| is_inside_modal(&node.borrow().element.unwrap().component_id()));
+            }
 
             // Sort by layout order descending.
             nodes.sort_by(|a, b| {
@@ -85,28 +134,39 @@ pub(crate) fn dispatch_event(
             // 1. Do a hit test to find the target element.
             // We order by the overlay depth descending and layout order descending.
             let mut target: Option<Rc<RefCell<FiberNode>>> = None;
+            // The element under the pointer, even when another element has pointer capture.
+            let mut hit_target: Option<Rc<RefCell<FiberNode>>> = None;
+            let mut captured_target: Option<Rc<RefCell<FiberNode>>> = None;
             let mut targets: VecDeque<Rc<RefCell<FiberNode>>> = VecDeque::new();
 
             for node in nodes {
                 if let Some(element) = node.borrow().element {
+                    if let EventDispatchType::Targeted(target_id) = dispatch_type {
+                        if element.component_id() == target_id {
+                            target = Some(node.clone());
+                            break;
+                        }
+                        continue;
+                    }
+
                     let should_pass_hit_test = mouse_position.is_some() && element.in_bounds(mouse_position.unwrap());
 
                     // The first element to pass the hit test should be the target.
-                    if should_pass_hit_test && target.is_none() {
-                        target = Some(node.clone());
+                    if should_pass_hit_test && hit_target.is_none() {
+                        hit_target = Some(node.clone());
                     }
 
                     // Unless another element has pointer capture.
-                    if is_pointer_event || is_ime_event {
-                        if let Some(element_id) = reactive_tree.pointer_captures.get(&DUMMY_DEVICE_ID) {
-                            if *element_id == element.component_id() {
-                                target = Some(node.clone());
-                                break;
-                            }
-                        }
+                    if is_pointer_event
+                        && reactive_tree.pointer_captures.get(&pointer_id) == Some(&element.component_id())
+                    {
+                        captured_target = Some(node.clone());
                     }
                 }
             }
+            if target.is_none() {
+                target = captured_target.or(hit_target.clone());
+            }
             if target.is_none() {
                 return;
             }
@@ -117,21 +177,204 @@ pub(crate) fn dispatch_event(
                 targets.push_back(current_target.clone().unwrap());
                 current_target = current_target.clone().unwrap().borrow().parent.clone();
             }
-            
+
+            // The events do not bubble past the modal dialog, except for the element events of the dialog, which
+            // still reach the component that owns it.
+            if let Some(modal) = modal {
+                let is_modal = |node: &Rc<RefCell<FiberNode>>| {
+                    node.borrow().element.is_some_and(|element| element.component_id() == modal.root)
+                };
+                if let Some(modal_index) = targets.iter().position(is_modal) {
+                    let component =
+                        targets.iter().skip(modal_index).find(|node| node.borrow().element.is_none()).cloned();
+                    targets.truncate(modal_index + 1);
+                    targets.extend(component);
+                }
+            }
+
             if targets.is_empty() {
                 return;
             }
+
+            // Enter and leave events only go to the element that was entered or left, and do not bubble.
+            let is_enter_or_leave = matches!(
+                message,
+                Message::CraftMessage(CraftMessage::PointerEnter(_))
+                    | Message::CraftMessage(CraftMessage::PointerLeave(_))
+                    | Message::CraftMessage(CraftMessage::DragLeave(_))
+                    | Message::CraftMessage(CraftMessage::FileHoverCancelled(_))
+            );
+            if is_enter_or_leave {
+                targets.truncate(1);
+            }
+
+            if matches!(dispatch_type, EventDispatchType::Bubbling) {
+                let target_ids: Vec<ComponentId> = targets
+                    .iter()
+                    .filter_map(|node| node.borrow().element)
+                    .map(|element| element.component_id())
+                    .collect();
+
+                match message {
+                    Message::CraftMessage(CraftMessage::PointerMovedEvent(pointer_moved)) => {
+                        let hovered = reactive_tree.hovered.insert(pointer_id, target_ids.clone()).unwrap_or_default();
+                        for left in hovered.iter().filter(|id| !target_ids.contains(id)) {
+                            let leave = CraftMessage::PointerLeave(pointer_moved.clone());
+                            effects.push((EventDispatchType::Targeted(*left), Message::CraftMessage(leave)));
+                        }
+                        for entered in target_ids.iter().rev().filter(|id| !hovered.contains(id)) {
+                            let enter = CraftMessage::PointerEnter(pointer_moved.clone());
+                            effects.push((EventDispatchType::Targeted(*entered), Message::CraftMessage(enter)));
+                        }
+
+                        if let Some(drag) = reactive_tree.drag.as_mut().filter(|drag| drag.pointer_id == pointer_id) {
+                            drag.position = pointer_moved.position;
+                            if !drag.is_dragging && drag.is_past_threshold(pointer_moved.position) {
+                                drag.is_dragging = true;
+                                // The element captures the pointer until the drag ends, and the release is not a click.
+                                reactive_tree.clicks.remove(&pointer_id);
+                                if let Some(element_state) = reactive_tree.element_state.storage.get_mut(&drag.source) {
+                                    apply_pointer_capture(&mut element_state.base, PointerCapture::Set, pointer_id);
+                                    if drag.preview {
+                                        element_state.base.drag_origin = Some(drag.origin);
+                                    }
+                                }
+                                let drag_start = Message::CraftMessage(CraftMessage::DragStart(drag.drag()));
+                                effects.push((EventDispatchType::Targeted(drag.source), drag_start));
+                            }
+
+                            // The elements under the pointer are the drop targets, not the dragged element.
+                            if drag.is_dragging {
+                                if let Some(element_state) = reactive_tree.element_state.storage.get_mut(&drag.source) {
+                                    element_state.base.drag_position =
+                                        element_state.base.drag_origin.map(|_| pointer_moved.position);
+                                }
+                                let over = hit_target.as_ref().map(element_ids).unwrap_or_default();
+                                for left in drag.over.iter().filter(|id| !over.contains(id)) {
+                                    let drag_leave = Message::CraftMessage(CraftMessage::DragLeave(drag.drag()));
+                                    effects.push((EventDispatchType::Targeted(*left), drag_leave));
+                                }
+                                if let Some(innermost) = over.first() {
+                                    let drag_over = Message::CraftMessage(CraftMessage::DragOver(drag.drag()));
+                                    effects.push((EventDispatchType::Targeted(*innermost), drag_over));
+                                }
+                                drag.over = over;
+                            }
+                        }
+                    }
+                    Message::CraftMessage(CraftMessage::FileHovered(file_drag)) => {
+                        if let Some(file_drag_state) = reactive_tree.file_drag.as_mut() {
+                            for left in file_drag_state.over.iter().filter(|id| !target_ids.contains(id)) {
+                                let cancelled = CraftMessage::FileHoverCancelled(file_drag.clone());
+                                effects.push((EventDispatchType::Targeted(*left), Message::CraftMessage(cancelled)));
+                            }
+                            file_drag_state.over = target_ids;
+                        }
+                    }
+                    Message::CraftMessage(CraftMessage::PointerButtonEvent(pointer_button)) => {
+                        let button = pointer_button.button.mouse_button();
+                        // Another pointer cannot take over a drag, or the press of another pointer.
+                        let is_dragging = reactive_tree
+                            .drag
+                            .as_ref()
+                            .is_some_and(|drag| drag.is_dragging || drag.pointer_id != pointer_id);
+                        match pointer_button.state {
+                            ElementState::Pressed if button == MouseButton::Left && !is_dragging => {
+                                let draggable = targets
+                                    .iter()
+                                    .filter_map(|node| node.borrow().element)
+                                    .find(|element| element.element_data().drag_payload.is_some());
+                                reactive_tree.drag = draggable.map(|element| {
+                                    let element_data = element.element_data();
+                                    DragState::new(
+                                        pointer_id,
+                                        element.component_id(),
+                                        element_data.drag_payload.clone().unwrap(),
+                                        pointer_button.position,
+                                        element_data.drag_threshold.unwrap_or(DEFAULT_DRAG_THRESHOLD),
+                                        element_data.drag_preview,
+                                    )
+                                });
+                            }
+                            ElementState::Released if button == MouseButton::Left => {
+                                let drag = reactive_tree.drag.take_if(|drag| drag.pointer_id == pointer_id);
+                                if let Some(mut drag) = drag.filter(|drag| drag.is_dragging) {
+                                    drag.position = pointer_button.position;
+                                    end_drag(&mut reactive_tree.element_state, drag, true, &mut effects);
+                                }
+                            }
+                            _ => {}
+                        }
+
+                        if pointer_button.state == ElementState::Pressed {
+                            let now = reactive_tree.event_time.unwrap_or_else(Instant::now);
+                            let clicks = reactive_tree.clicks.entry(pointer_id).or_default();
+                            let interval = reactive_tree.double_click_interval.unwrap_or(DEFAULT_DOUBLE_CLICK_INTERVAL);
+                            clicks.press(button, pointer_button.position, now, interval, target_ids);
+                        } else if let Some((click_target, click)) = reactive_tree
+                            .clicks
+                            .get_mut(&pointer_id)
+                            .and_then(|clicks| clicks.release(button, pointer_button.position, &target_ids))
+                        {
+                            effects.push((
+                                EventDispatchType::Targeted(click_target),
+                                Message::CraftMessage(CraftMessage::Click(click)),
+                            ));
+                        }
+
+                        // A lifted finger leaves the elements that it touched.
+                        let button_and_state = (pointer_button.button, pointer_button.state);
+                        if let (ButtonSource::Touch { finger_id, force }, ElementState::Released) = button_and_state {
+                            let pointer_moved = PointerMoved {
+                                device_id: pointer_button.device_id,
+                                position: pointer_button.position,
+                                source: PointerSource::Touch { finger_id, force },
+                                primary: pointer_button.primary,
+                            };
+                            for left in reactive_tree.hovered.remove(&pointer_id).unwrap_or_default() {
+                                let leave = CraftMessage::PointerLeave(pointer_moved.clone());
+                                effects.push((EventDispatchType::Targeted(left), Message::CraftMessage(leave)));
+                            }
+                        }
+                    }
+                    _ => {}
+                }
+            }
+
+            // Pressing a pointer button moves the focus to the closest focusable element.
+            if let Message::CraftMessage(CraftMessage::PointerButtonEvent(pointer_button)) = message {
+                if pointer_button.state == ElementState::Pressed {
+                    let focus = targets
+                        .iter()
+                        .filter_map(|node| node.borrow().element)
+                        .find(|element| is_focusable(*element))
+                        .map(|element| element.component_id());
+                    // Clicking the backdrop of a dialog keeps the focus inside of it.
+                    if focus.is_some() || modal.is_none() {
+                        focus_request = Some(FocusRequest::Element(focus));
+                    }
+                }
+            }
             
             let mut element_events: VecDeque<(CraftMessage, &dyn Element)> = VecDeque::new();
 
             let target = targets[0].clone();
             let mut propagate = true;
             let mut prevent_defaults = false;
-            for current_target in targets.iter() {
+            let mut stop_immediate = false;
+            // The event is captured from the root to the target by the elements with a capture handler, and then
+            // bubbles up from the target.
+            let capturing = targets
+                .iter()
+                .rev()
+                .filter(|node| node.borrow().element.is_some_and(|element| element.element_data().on_capture.is_some()))
+                .map(|node| (EventPhase::Capturing, node));
+            let bubbling = targets.iter().map(|node| (EventPhase::Bubbling, node));
+            for (phase, current_target) in capturing.chain(bubbling) {
                 if !propagate {
                     break;
                 }
-                
+
                 if current_target.borrow().element.is_none() {
                     continue;
                 }
@@ -152,11 +395,13 @@ pub(crate) fn dispatch_event(
                 if let Some(node) = closest_ancestor_component {
                     let state = reactive_tree.user_state.storage.get_mut(&node.id).unwrap().as_mut();
                     let mut event = Event::with_window_context(window_context.clone());
+                    event.phase = phase;
                     event.target = Some(target.borrow().element.unwrap());
                     event.current_target = Some(current_target.borrow().element.unwrap());
                     (node.update)(state, global_state, node.props.clone(), &mut event, message);
+                    event.window.set_window_opener(node.id);
 
-                    if !event.prevent_defaults && event.propagate {
+                    if !event.prevent_defaults && (event.propagate || event.stop_immediate) {
                         if let Some(ref result_message) = event.result_message {
                             element_events.push_back((result_message.clone(), *event.target.as_ref().unwrap()));
                         }
@@ -164,6 +409,9 @@ pub(crate) fn dispatch_event(
 
                     *window_context = event.window.clone();
                     effects.append(&mut event.effects);
+                    if event.focus.is_some() {
+                        focus_request = event.focus.take();
+                    }
                     propagate = propagate && event.propagate;
                     let element_state = &mut reactive_tree
                         .element_state
@@ -171,16 +419,9 @@ pub(crate) fn dispatch_event(
                         .get_mut(&current_target.borrow().component.id)
                         .unwrap()
                         .base;
-                    match event.pointer_capture {
-                        PointerCapture::None => {}
-                        PointerCapture::Set => {
-                            element_state.pointer_capture.insert(DUMMY_DEVICE_ID, true);
-                        }
-                        PointerCapture::Unset => {
-                            element_state.pointer_capture.remove(&DUMMY_DEVICE_ID);
-                        }
-                    }
+                    apply_pointer_capture(element_state, event.pointer_capture, pointer_id);
                     prevent_defaults = prevent_defaults || event.prevent_defaults;
+                    stop_immediate = stop_immediate || event.stop_immediate;
                     if event.future.is_some() {
                         reactive_tree.update_queue.push_back(UpdateQueueEntry::new(
                             node.id,
@@ -195,14 +436,19 @@ pub(crate) fn dispatch_event(
             for element_state in reactive_tree.element_state.storage.values_mut() {
                 if let Message::CraftMessage(message) = &message {
                     match message {
-                        CraftMessage::PointerMovedEvent(..) => {
-                            element_state.base.hovered = false;
+                        CraftMessage::PointerMovedEvent(pointer_moved) => {
+                            element_state.base.hovered.remove(&pointer_moved.pointer_id());
                         }
                         CraftMessage::PointerButtonEvent(pointer_button) => {
                             if pointer_button.button.mouse_button() == MouseButton::Left
                                 && pointer_button.state == ElementState::Released
                             {
-                                element_state.base.active = false;
+                                let pointer_id = pointer_button.pointer_id();
+                                element_state.base.active.remove(&pointer_id);
+                                // A lifted finger no longer hovers anything.
+                                if pointer_id.is_touch() {
+                                    element_state.base.hovered.remove(&pointer_id);
+                                }
                             }
                         }
                         _ => {}
@@ -210,8 +456,8 @@ pub(crate) fn dispatch_event(
                 }
             }
 
-            // Handle element events if prevent defaults was not set to true.
-            if !prevent_defaults {
+            // Handle element events if prevent defaults was not set to true and the event was not stopped.
+            if !prevent_defaults && !stop_immediate {
                 let mut propagate = true;
                 for target in targets.iter() {
                     if !propagate || prevent_defaults {
@@ -231,6 +477,9 @@ pub(crate) fn dispatch_event(
                             if let Some(result_message) = res.result_message {
                                 element_events.push_back((result_message, element));
                             }
+                            if res.focus.is_some() {
+                                focus_request = res.focus;
+                            }
 
                             propagate = propagate && res.propagate;
                             prevent_defaults = prevent_defaults || res.prevent_defaults;
@@ -239,6 +488,42 @@ pub(crate) fn dispatch_event(
                 }
             }
 
+            // Every element sees the event once it was handled, whether or not it reached the element.
+            if let Message::CraftMessage(message) = message {
+                let target_ids: Vec<ComponentId> = targets
+                    .iter()
+                    .filter_map(|node| node.borrow().element)
+                    .map(|element| element.component_id())
+                    .collect();
+                for element in elements.iter() {
+                    let is_target = target_ids.contains(&element.component_id());
+                    element.on_global_event(message, &mut reactive_tree.element_state, is_target);
+                }
+            }
+
+            // Escape cancels the drag, wherever the focus is.
+            if let Message::CraftMessage(CraftMessage::KeyboardInputEvent(keyboard_input)) = message {
+                let is_escape = keyboard_input.event.state.is_pressed()
+                    && keyboard_input.event.logical_key == Key::Named(NamedKey::Escape);
+                let drag = reactive_tree.drag.take_if(|_| is_escape).filter(|drag| drag.is_dragging);
+                if let Some(drag) = drag {
+                    end_drag(&mut reactive_tree.element_state, drag, false, &mut effects);
+                }
+            }
+
+            // Tab and Shift+Tab move the focus, unless the key press was handled. While a dialog is open, the focus
+            // cycles through its elements.
+            if let Message::CraftMessage(CraftMessage::KeyboardInputEvent(keyboard_input)) = message {
+                let is_tab = keyboard_input.event.state.is_pressed()
+                    && keyboard_input.event.logical_key == Key::Named(NamedKey::Tab);
+                if is_tab && !prevent_defaults {
+                    let backwards = reactive_tree.modifiers.state().shift_key();
+                    let root = current_element_tree.pre_order_iter().find(|element| element.component_id() == root_id);
+                    let focus = root.and_then(|root| next_in_tab_order(root, reactive_tree.focus, backwards));
+                    focus_request = Some(FocusRequest::Element(focus));
+                }
+            }
+
             for (message, target_element) in element_events.iter() {
                 let mut propagate = true;
                 let mut prevent_defaults = false;
@@ -273,9 +558,13 @@ pub(crate) fn dispatch_event(
                             &mut event,
                             &Message::CraftMessage(message.clone()),
                         );
+                        event.window.set_window_opener(current_target.component.id);
                     }
                     *window_context = event.window.clone();
                     effects.append(&mut event.effects);
+                    if event.focus.is_some() {
+                        focus_request = event.focus.take();
+                    }
                     propagate = propagate && event.propagate;
                     prevent_defaults = prevent_defaults || event.prevent_defaults;
                     if event.future.is_some() {
@@ -304,6 +593,9 @@ pub(crate) fn dispatch_event(
                             );
 
                             effects.append(&mut res.effects);
+                            if res.focus.is_some() {
+                                focus_request = res.focus;
+                            }
                         }
 
                         break;
@@ -314,8 +606,12 @@ pub(crate) fn dispatch_event(
                         event.current_target = None;
                         event.target = None;
                         (component.update)(state, global_state, component.props.clone(), &mut event, message);
+                        event.window.set_window_opener(component.id);
                         *window_context = event.window.clone();
                         effects.append(&mut event.effects);
+                        if event.focus.is_some() {
+                            focus_request = event.focus.take();
+                        }
                         if event.future.is_some() {
                             reactive_tree.update_queue.push_back(UpdateQueueEntry::new(
                                 component.id,
@@ -346,4 +642,142 @@ pub(crate) fn dispatch_event(
             false,
         );
     }
+
+    if let Some(focus_request) = focus_request {
+        apply_focus_request(focus_request, _resource_manager, reactive_tree, global_state, text_context, window_context);
+    }
+}
+
+/// Dispatches a file drag of the operating system to the element under the dragged files.
+pub(crate) fn dispatch_file_drag(
+    file_drag_event: FileDragEvent,
+    resource_manager: &mut Arc<ResourceManager>,
+    reactive_tree: &mut ReactiveTree,
+    global_state: &mut GlobalState,
+    text_context: &mut Option<TextContext>,
+    window_context: &mut WindowContext,
+) {
+    let (message, position) = match file_drag_event {
+        FileDragEvent::Entered(paths, position) => {
+            let file_drag_state = FileDragState::new(paths, position);
+            let message = CraftMessage::FileHovered(file_drag_state.file_drag());
+            reactive_tree.file_drag = Some(file_drag_state);
+            (message, position)
+        }
+        FileDragEvent::Moved(position) => {
+            let Some(file_drag_state) = reactive_tree.file_drag.as_mut() else {
+                return;
+            };
+            file_drag_state.position = position;
+            (CraftMessage::FileHovered(file_drag_state.file_drag()), position)
+        }
+        FileDragEvent::Dropped(paths, position) => {
+            reactive_tree.file_drag = None;
+            (CraftMessage::FileDropped(FileDrag { paths, position }), position)
+        }
+        FileDragEvent::Left => {
+            let Some(file_drag_state) = reactive_tree.file_drag.take() else {
+                return;
+            };
+            for over in file_drag_state.over.iter() {
+                dispatch_event(
+                    &Message::CraftMessage(CraftMessage::FileHoverCancelled(file_drag_state.file_drag())),
+                    EventDispatchType::Targeted(*over),
+                    resource_manager,
+                    None,
+                    reactive_tree,
+                    global_state,
+                    text_context,
+                    window_context,
+                    false,
+                );
+            }
+            return;
+        }
+    };
+
+    dispatch_event(
+        &Message::CraftMessage(message),
+        EventDispatchType::Bubbling,
+        resource_manager,
+        Some(position),
+        reactive_tree,
+        global_state,
+        text_context,
+        window_context,
+        false,
+    );
+}
+
+fn apply_pointer_capture(element_state: &mut BaseElementState, pointer_capture: PointerCapture, pointer_id: PointerId) {
+    match pointer_capture {
+        PointerCapture::None => {}
+        PointerCapture::Set => {
+            element_state.pointer_capture.insert(pointer_id, true);
+        }
+        PointerCapture::Unset => {
+            element_state.pointer_capture.remove(&pointer_id);
+        }
+    }
+}
+
+/// The topmost modal element of a tree, see [`Element::is_modal`].
+pub(crate) struct Modal {
+    /// The modal element.
+    pub(crate) root: ComponentId,
+    /// The ids of the modal element and of the elements inside of it.
+    pub(crate) element_ids: HashSet<ComponentId>,
+}
+
+impl Modal {
+    /// Finds the topmost modal element of the tree of `root`. It is found once whenever the tree changes, instead of
+    /// for every event.
+    pub(crate) fn find(root: &dyn Element) -> Option<Modal> {
+        let modal = root.pre_order_iter().filter(|element| element.is_modal()).last()?;
+        Some(Modal {
+            root: modal.component_id(),
+            element_ids: modal.pre_order_iter().map(|element| element.component_id()).collect(),
+        })
+    }
+}
+
+/// The ids of the element of `node` and of its ancestor elements, from the innermost to the root.
+fn element_ids(node: &Rc<RefCell<FiberNode>>) -> Vec<ComponentId> {
+    let mut ids = Vec::new();
+    let mut current = Some(node.clone());
+    while let Some(node) = current {
+        if let Some(element) = node.borrow().element {
+            ids.push(element.component_id());
+        }
+        current = node.borrow().parent.clone();
+    }
+    ids
+}
+
+/// Ends the drag by dropping the dragged element on the element under the pointer, or by cancelling it.
+fn end_drag(
+    element_state: &mut ElementStateStore,
+    drag: DragState,
+    is_dropped: bool,
+    effects: &mut Vec<(EventDispatchType, Message)>,
+) {
+    if let Some(element_state) = element_state.storage.get_mut(&drag.source) {
+        apply_pointer_capture(&mut element_state.base, PointerCapture::Unset, drag.pointer_id);
+        element_state.base.drag_origin = None;
+        element_state.base.drag_position = None;
+    }
+
+    if is_dropped {
+        if let Some(innermost) = drag.over.first() {
+            let drop = Message::CraftMessage(CraftMessage::Drop(drag.drag()));
+            effects.push((EventDispatchType::Targeted(*innermost), drop));
+        }
+    } else {
+        for over in drag.over.iter() {
+            let drag_leave = Message::CraftMessage(CraftMessage::DragLeave(drag.drag()));
+            effects.push((EventDispatchType::Targeted(*over), drag_leave));
+        }
+    }
+    let drag_end = Message::CraftMessage(CraftMessage::DragEnd(drag.drag()));
+    effects.push((EventDispatchType::Targeted(drag.source), drag_end));
 }
@@ -0,0 +1,50 @@
+use crate::components::ComponentId;
+use crate::geometry::Point;
+use std::path::PathBuf;
+
+/// Files from the operating system that are dragged over or dropped on the window.
+#[derive(Clone, Debug)]
+pub struct FileDrag {
+    pub paths: Vec<PathBuf>,
+    /// The position of the pointer.
+    pub position: Point,
+}
+
+/// A file drag of the operating system, as reported by the window.
+#[derive(Clone, Debug)]
+pub enum FileDragEvent {
+    /// The files were dragged into the window at the position.
+    Entered(Vec<PathBuf>, Point),
+    /// The dragged files moved over the window.
+    Moved(Point),
+    /// The files were dropped on the window at the position.
+    Dropped(Vec<PathBuf>, Point),
+    /// The files were dragged out of the window, or the drag was cancelled.
+    Left,
+}
+
+/// The files that are dragged over the window.
+#[derive(Clone, Debug)]
+pub(crate) struct FileDragState {
+    pub(crate) paths: Vec<PathBuf>,
+    pub(crate) position: Point,
+    /// The elements under the files, from the innermost to the root.
+    pub(crate) over: Vec<ComponentId>,
+}
+
+impl FileDragState {
+    pub(crate) fn new(paths: Vec<PathBuf>, position: Point) -> Self {
+        FileDragState {
+            paths,
+            position,
+            over: Vec::new(),
+        }
+    }
+
+    pub(crate) fn file_drag(&self) -> FileDrag {
+        FileDrag {
+            paths: self.paths.clone(),
+            position: self.position,
+        }
+    }
+}
@@ -0,0 +1,119 @@
+//! The keyboard focus: which element receives keyboard events, and the order in which Tab moves it between elements.
+
+use crate::components::{ComponentId, FocusRequest};
+use crate::elements::element::Element;
+use crate::events::event_dispatch::dispatch_event;
+use crate::events::{CraftMessage, EventDispatchType, Message};
+use crate::resource_manager::ResourceManager;
+use crate::style::Display;
+use crate::text::text_context::TextContext;
+use crate::{GlobalState, ReactiveTree, WindowContext};
+use std::sync::Arc;
+
+/// Whether the element can receive the focus, either by default or because it was given a tab index.
+pub(crate) fn is_focusable(element: &dyn Element) -> bool {
+    element.is_focusable() || element.element_data().tab_index.is_some()
+}
+
+/// Returns the elements that Tab visits, in order.
+///
+/// Elements with a positive tab index come first, in ascending order, followed by the other focusable elements in
+/// layout order. Elements with a negative tab index and elements that are not displayed are skipped.
+pub(crate) fn tab_order(root: &dyn Element) -> Vec<ComponentId> {
+    let mut elements: Vec<(i32, ComponentId)> = Vec::new();
+    collect_tab_stops(root, &mut elements);
+
+    // The sort is stable, so elements with the same tab index stay in layout order.
+    elements.sort_by_key(|(tab_index, _)| if *tab_index > 0 { (0, *tab_index) } else { (1, 0) });
+    elements.into_iter().map(|(_, component_id)| component_id).collect()
+}
+
+fn collect_tab_stops(element: &dyn Element, elements: &mut Vec<(i32, ComponentId)>) {
+    if matches!(element.style().display(), Display::None) {
+        return;
+    }
+
+    let tab_index = element.element_data().tab_index.unwrap_or(0);
+    if tab_index >= 0 && is_focusable(element) {
+        elements.push((tab_index, element.component_id()));
+    }
+
+    for child in element.children() {
+        collect_tab_stops(child, elements);
+    }
+}
+
+/// Returns the element that Tab, or Shift+Tab if `backwards` is set, moves the focus to. The tab order wraps around.
+pub(crate) fn next_in_tab_order(root: &dyn Element, focus: Option<ComponentId>, backwards: bool) -> Option<ComponentId> {
+    let order = tab_order(root);
+    if order.is_empty() {
+        return None;
+    }
+
+    let position = focus.and_then(|focus| order.iter().position(|component_id| *component_id == focus));
+    let index = match (position, backwards) {
+        (Some(position), false) => (position + 1) % order.len(),
+        (Some(position), true) => (position + order.len() - 1) % order.len(),
+        (None, false) => 0,
+        (None, true) => order.len() - 1,
+    };
+
+    Some(order[index])
+}
+
+/// Moves the focus and dispatches [`CraftMessage::Blur`] to the element that lost it and [`CraftMessage::Focus`] to
+/// the element that received it.
+///
+/// Requests for an id that does not belong to a focusable element are ignored.
+pub(crate) fn apply_focus_request(
+    request: FocusRequest,
+    resource_manager: &mut Arc<ResourceManager>,
+    reactive_tree: &mut ReactiveTree,
+    global_state: &mut GlobalState,
+    text_context: &mut Option<TextContext>,
+    window_context: &mut WindowContext,
+) {
+    let new_focus = match request {
+        FocusRequest::Element(focus) => focus,
+        FocusRequest::Id(id) => {
+            let root = if let Some(root) = reactive_tree.element_tree.as_ref() {
+                root
+            } else {
+                return;
+            };
+
+            match root.as_ref().pre_order_iter().find(|element| element.get_id().as_deref() == Some(id.as_str())) {
+                Some(element) if is_focusable(element) => Some(element.component_id()),
+                _ => return,
+            }
+        }
+    };
+
+    if new_focus == reactive_tree.focus {
+        return;
+    }
+
+    let old_focus = std::mem::replace(&mut reactive_tree.focus, new_focus);
+    for (component_id, focused) in [(old_focus, false), (new_focus, true)] {
+        if let Some(state) = component_id.and_then(|component_id| reactive_tree.element_state.storage.get_mut(&component_id)) {
+            state.base.focused = focused;
+        }
+    }
+
+    let messages = [(old_focus, CraftMessage::Blur), (new_focus, CraftMessage::Focus)];
+    for (component_id, message) in messages {
+        if let Some(component_id) = component_id {
+            dispatch_event(
+                &Message::CraftMessage(message),
+                EventDispatchType::Targeted(component_id),
+                resource_manager,
+                None,
+                reactive_tree,
+                global_state,
+                text_context,
+                window_context,
+                false,
+            );
+        }
+    }
+}
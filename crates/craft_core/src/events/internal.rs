@@ -2,31 +2,49 @@ use crate::components::component::UpdateFn;
 use crate::components::ComponentId;
 use crate::components::Props;
 use crate::events::resource_event::ResourceEvent;
-use crate::events::{KeyboardInput, MouseWheel, PointerButton, PointerMoved};
+use crate::events::{FileDragEvent, KeyboardInput, MouseWheel, PointerButton, PointerMoved};
 use crate::geometry::Size;
+use crate::options::WindowOptions;
 use crate::renderer::renderer::Renderer;
+use crate::WindowId;
+use accesskit::{ActionRequest, TreeUpdate};
 use std::any::Any;
 use std::sync::Arc;
 use winit::dpi::PhysicalSize;
 use winit::event::Ime;
 use winit::window::Window;
 
+/// The messages sent between the winit thread and the app.
+///
+/// The messages that concern a single window carry the id of that window.
 pub(crate) enum InternalMessage {
-    RequestRedraw(f64, Size<f32>),
+    RequestRedraw(WindowId, f64, Size<f32>),
     Close,
     Confirmation,
-    Resume(Arc<dyn Window>, Option<Box<dyn Renderer + Send>>),
-    Resize(PhysicalSize<u32>),
-    PointerButton(PointerButton),
-    PointerMoved(PointerMoved),
-    MouseWheel(MouseWheel),
-    KeyboardInput(KeyboardInput),
-    ModifiersChanged(winit::event::Modifiers),
-    Ime(Ime),
+    Resume(WindowId, Arc<dyn Window>, Option<Box<dyn Renderer + Send>>),
+    Resize(WindowId, PhysicalSize<u32>),
+    PointerButton(WindowId, PointerButton),
+    PointerMoved(WindowId, PointerMoved),
+    MouseWheel(WindowId, MouseWheel),
+    KeyboardInput(WindowId, KeyboardInput),
+    ModifiersChanged(WindowId, winit::event::Modifiers),
+    Ime(WindowId, Ime),
+    FileDrag(WindowId, FileDragEvent),
     ProcessUserEvents,
     #[cfg(not(target_arch = "wasm32"))]
-    GotUserMessage((UpdateFn, ComponentId, Box<dyn Any + Send + Sync + 'static>, Props)),
+    GotUserMessage((WindowId, UpdateFn, ComponentId, Box<dyn Any + Send + Sync + 'static>, Props)),
     #[cfg(target_arch = "wasm32")]
-    GotUserMessage((UpdateFn, ComponentId, Box<dyn Any>, Props)),
+    GotUserMessage((WindowId, UpdateFn, ComponentId, Box<dyn Any>, Props)),
     ResourceEvent(ResourceEvent),
+    /// An assistive technology started observing the accessibility tree of a window.
+    AccessibilityActivated(WindowId),
+    /// No assistive technology is observing the accessibility tree of a window anymore.
+    AccessibilityDeactivated(WindowId),
+    AccessibilityAction(WindowId, ActionRequest),
+    /// The accessibility tree of the last frame, sent back to the winit thread before the redraw is confirmed.
+    AccessibilityTreeUpdate(WindowId, TreeUpdate),
+    /// Asks the winit thread to create a window for a component that called [`crate::WindowContext::open_window`].
+    CreateWindow(WindowId, WindowOptions),
+    /// A window was closed, either by a component or by the user.
+    CloseWindow(WindowId),
 }
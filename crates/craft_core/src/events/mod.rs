@@ -1,25 +1,39 @@
+mod click;
+mod drag;
+mod file_drag;
 mod keyboard_input;
 mod mouse_wheel;
 mod pointer_button;
+mod pointer_id;
 mod pointer_moved;
 
 pub(crate) mod internal;
 pub(crate) mod resource_event;
 pub mod update_queue_entry;
 pub(crate) mod event_dispatch;
+pub(crate) mod focus;
 
-//#[cfg(test)]
-//mod tests;
+#[cfg(test)]
+mod tests;
 
+pub use click::Click;
+pub(crate) use click::{ClickTracker, DEFAULT_DOUBLE_CLICK_INTERVAL};
+pub use drag::Drag;
+pub(crate) use drag::{DragState, DEFAULT_DRAG_THRESHOLD};
+pub use file_drag::{FileDrag, FileDragEvent};
+pub(crate) use file_drag::FileDragState;
 pub use keyboard_input::KeyboardInput;
 pub use mouse_wheel::MouseWheel;
 pub use pointer_button::PointerButton;
+pub use pointer_id::PointerId;
 pub use pointer_moved::PointerMoved;
 pub use winit::event::ButtonSource;
 pub use winit::event::ElementState;
 
 use crate::components::ComponentId;
+use crate::elements::SortDirection;
 use crate::events::CraftMessage::PointerButtonEvent;
+use crate::WindowId;
 use std::any::Any;
 use std::sync::Arc;
 pub use winit::event::Modifiers;
@@ -30,6 +44,8 @@ pub use winit::event::MouseButton;
 pub enum EventDispatchType {
     Bubbling,
     Direct(ComponentId),
+    /// Bubbles the event from the element with the given id instead of the element under the pointer.
+    Targeted(ComponentId),
 }
 
 #[derive(Clone, Debug)]
@@ -38,10 +54,39 @@ pub enum CraftMessage {
     PointerButtonEvent(PointerButton),
     KeyboardInputEvent(KeyboardInput),
     PointerMovedEvent(PointerMoved),
+    /// Generated when the pointer moves onto an element or one of its descendants. The event does not bubble.
+    PointerEnter(PointerMoved),
+    /// Generated when the pointer moves off an element and its descendants. The event does not bubble.
+    PointerLeave(PointerMoved),
+    /// Generated when a mouse button is pressed and released over an element, with the number of clicks in quick
+    /// succession. The event bubbles from the closest element that the button was both pressed and released over.
+    Click(Click),
+    /// Generated on a draggable element when the pointer moves past the drag threshold while it is pressed.
+    DragStart(Drag),
+    /// Generated on the element under the pointer while an element is dragged. The event bubbles, so any ancestor can
+    /// accept the drop.
+    DragOver(Drag),
+    /// Generated when the dragged pointer moves off an element and its descendants, or when the drag is cancelled. The
+    /// event does not bubble.
+    DragLeave(Drag),
+    /// Generated on the element under the pointer when the dragged element is released.
+    Drop(Drag),
+    /// Generated on the dragged element when the drag ends, after the drop or when it is cancelled with Escape.
+    DragEnd(Drag),
+    /// Generated on the element under the pointer while files from the operating system are dragged over the window.
+    /// The event bubbles.
+    FileHovered(FileDrag),
+    /// Generated when the dragged files move off an element and its descendants, leave the window or the drag is
+    /// cancelled. The event does not bubble.
+    FileHoverCancelled(FileDrag),
+    /// Generated on the element under the pointer when files from the operating system are dropped on the window.
+    FileDropped(FileDrag),
     MouseWheelEvent(MouseWheel),
     ModifiersChangedEvent(winit::event::Modifiers),
     ImeEvent(Ime),
     TextInputChanged(String),
+    /// Generated when Ctrl+Enter, or Cmd+Enter on macOS, is pressed in a multi-line text input, with its text.
+    TextInputSubmitted(String),
     /// Generated when a dropdown is opened or closed. The boolean is the status of is_open after the event has occurred.
     DropdownToggled(bool),
     /// The index of the item selected in the list.
@@ -49,7 +94,41 @@ pub enum CraftMessage {
     DropdownItemSelected(usize),
     /// Generated when a switch is toggled. The boolean is the status of toggled after the event has occurred.
     SwitchToggled(bool),
+    /// Generated when a checkbox is toggled. The boolean is whether the checkbox is checked after the event.
+    CheckboxToggled(bool),
+    /// Generated by a radio group when one of its radio buttons is selected, with the value of the radio button.
+    RadioSelected(String),
     SliderValueChanged(f64),
+    /// Generated when the header of a sortable table column is clicked, with the index of the column and the new sort
+    /// direction. The table only shows the direction, the rows have to be sorted by the component.
+    TableSorted(usize, SortDirection),
+    /// Generated when a column border of a table was dragged, with the index of the column and its new width in logical
+    /// pixels.
+    TableColumnResized(usize, f32),
+    /// Generated when the selection of a table changes, with the selected row and, when cells are selected, the column.
+    TableSelectionChanged(usize, Option<usize>),
+    /// Generated by a context menu or a menu bar when an item is chosen, with the id of the item.
+    MenuItemSelected(String),
+    /// Generated when another tab of a tabs element is selected, with the index of the tab.
+    TabChanged(usize),
+    /// Generated when the close button of a tab is clicked, with the index of the tab. The component has to remove the
+    /// tab.
+    TabClosed(usize),
+    /// Generated by a dialog when it is closed with Escape or a click on its backdrop. The component has to remove the
+    /// dialog.
+    DialogClosed,
+    /// Generated when an element receives the keyboard focus. The event bubbles from the focused element.
+    Focus,
+    /// Generated when an element loses the keyboard focus. The event bubbles from the element that lost the focus.
+    Blur,
+    /// Generated on the component that opened a window with [`WindowContext::open_window`] when the user closes that
+    /// window. Windows that are closed with [`WindowContext::close_window`] do not generate it.
+    ///
+    /// [`WindowContext::open_window`]: crate::WindowContext::open_window
+    /// [`WindowContext::close_window`]: crate::WindowContext::close_window
+    WindowClosed(WindowId),
+    /// An action requested by an assistive technology, like a screen reader, for the target element.
+    AccessibilityAction(accesskit::ActionRequest),
     ElementMessage(Arc<UserMessage>),
 }
 
@@ -0,0 +1,22 @@
+use winit::event::{DeviceId, FingerId};
+
+/// Identifies a pointer: the mouse or pen of a device, or a finger that touches the screen.
+///
+/// Every pointer has its own pointer capture, hover and pressed state, so two fingers can drag two sliders at once.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct PointerId {
+    pub device_id: Option<DeviceId>,
+    /// The finger, when the pointer is a touch.
+    pub finger_id: Option<FingerId>,
+}
+
+impl PointerId {
+    pub fn new(device_id: Option<DeviceId>, finger_id: Option<FingerId>) -> Self {
+        Self { device_id, finger_id }
+    }
+
+    /// Whether the pointer is a finger, which stops hovering the elements when it is lifted.
+    pub fn is_touch(&self) -> bool {
+        self.finger_id.is_some()
+    }
+}
@@ -1,3 +1,4 @@
+use crate::events::PointerId;
 use crate::geometry::Point;
 use winit::dpi::PhysicalPosition;
 use winit::event::{DeviceId, PointerSource};
@@ -39,4 +40,12 @@ impl PointerMoved {
             primary,
         }
     }
+
+    /// The pointer that moved, a finger for a touch.
+    pub fn pointer_id(&self) -> PointerId {
+        match self.source {
+            PointerSource::Touch { finger_id, .. } => PointerId::new(self.device_id, Some(finger_id)),
+            _ => PointerId::new(self.device_id, None),
+        }
+    }
 }
@@ -0,0 +1,115 @@
+use crate::components::{Component, ComponentId, ComponentSpecification, Event};
+use crate::elements::{Container, ElementStyles};
+use crate::events::{Click, PointerMoved};
+use crate::geometry::{Point, Size};
+use crate::testing::helpers::state;
+use crate::testing::TestHarness;
+use crate::WindowContext;
+use std::time::Duration;
+use winit::event::{ElementState, MouseButton};
+
+#[derive(Default)]
+struct PointerLog {
+    hovers: Vec<String>,
+    clicks: Vec<String>,
+}
+
+impl PointerLog {
+    fn target(id: &str) -> Container {
+        let enter_id = id.to_string();
+        let leave_id = id.to_string();
+        let click_id = id.to_string();
+        Container::new()
+            .id(id)
+            .on_pointer_enter(move |state: &mut PointerLog, _: &mut (), _: &mut Event, _: &PointerMoved| {
+                state.hovers.push(format!("enter {enter_id}"));
+            })
+            .on_pointer_leave(move |state: &mut PointerLog, _: &mut (), _: &mut Event, _: &PointerMoved| {
+                state.hovers.push(format!("leave {leave_id}"));
+            })
+            .on_click(move |state: &mut PointerLog, _: &mut (), _: &mut Event, click: Click| {
+                state.clicks.push(format!("{click_id} {}", click.count));
+            })
+    }
+}
+
+impl Component for PointerLog {
+    type GlobalState = ();
+    type Props = ();
+    type Message = ();
+
+    fn view(
+        &self,
+        _global_state: &Self::GlobalState,
+        _props: &Self::Props,
+        _children: Vec<ComponentSpecification>,
+        _id: ComponentId,
+        _window: &WindowContext,
+    ) -> ComponentSpecification {
+        PointerLog::target("outer")
+            .width(200)
+            .height(100)
+            .push(PointerLog::target("left").width(80).height(80))
+            .push(PointerLog::target("right").width(80).height(80))
+            .component()
+    }
+}
+
+#[test]
+fn pointer_enter_and_leave_follow_the_hovered_elements() {
+    let mut harness = TestHarness::new(PointerLog::component(), (), Size::new(400.0, 200.0));
+
+    harness.pointer_moved(Point::new(40.0, 40.0));
+    harness.pointer_moved(Point::new(45.0, 40.0));
+    assert_eq!(state::<PointerLog>(&harness).hovers, ["enter outer", "enter left"]);
+
+    harness.pointer_moved(Point::new(120.0, 40.0));
+    harness.pointer_moved(Point::new(300.0, 150.0));
+    assert_eq!(
+        state::<PointerLog>(&harness).hovers,
+        ["enter outer", "enter left", "leave left", "enter right", "leave right", "leave outer"]
+    );
+}
+
+#[test]
+fn clicks_are_counted_within_the_interval_and_distance() {
+    let mut harness = TestHarness::new(PointerLog::component(), (), Size::new(400.0, 200.0));
+    let left = Point::new(40.0, 40.0);
+
+    harness.click(left);
+    harness.click(left);
+    harness.click(Point::new(42.0, 41.0));
+    assert_eq!(
+        state::<PointerLog>(&harness).clicks,
+        ["left 1", "outer 1", "left 2", "outer 2", "left 3", "outer 3"]
+    );
+
+    // Waiting too long or moving too far starts a new count.
+    harness.advance_time(Duration::from_millis(600));
+    harness.click(left);
+    harness.click(Point::new(60.0, 40.0));
+    // The click goes to the closest element that the button was both pressed and released over.
+    harness.pointer_button(left, MouseButton::Left, ElementState::Pressed);
+    harness.pointer_button(Point::new(120.0, 40.0), MouseButton::Left, ElementState::Released);
+    assert_eq!(
+        state::<PointerLog>(&harness).clicks[6..],
+        ["left 1", "outer 1", "left 1", "outer 1", "outer 1"]
+    );
+}
+
+#[test]
+fn clicks_are_counted_within_the_double_click_interval_of_the_options() {
+    let mut harness = TestHarness::new(PointerLog::component(), (), Size::new(400.0, 200.0));
+    let left = Point::new(40.0, 40.0);
+    harness.set_double_click_interval(Duration::from_millis(1000));
+
+    harness.click(left);
+    harness.advance_time(Duration::from_millis(800));
+    harness.click(left);
+    harness.advance_time(Duration::from_millis(1200));
+    harness.click(left);
+    assert_eq!(
+        state::<PointerLog>(&harness).clicks,
+        ["left 1", "outer 1", "left 2", "outer 2", "left 1", "outer 1"]
+    );
+}
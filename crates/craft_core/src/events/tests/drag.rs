@@ -0,0 +1,124 @@
+use crate::components::{Component, ComponentId, ComponentSpecification, Event};
+use crate::elements::{Container, ElementStyles};
+use crate::events::Drag;
+use crate::geometry::Point;
+use crate::style::FlexDirection;
+use crate::testing::helpers::{mount, state};
+use crate::{rgb, WindowContext};
+use winit::event::{ElementState, MouseButton};
+use winit::keyboard::{Key, NamedKey};
+
+struct Board {
+    columns: [Vec<usize>; 2],
+    log: Vec<String>,
+}
+
+impl Default for Board {
+    fn default() -> Self {
+        Board {
+            columns: [vec![0, 1], vec![]],
+            log: Vec::new(),
+        }
+    }
+}
+
+impl Component for Board {
+    type GlobalState = ();
+    type Props = ();
+    type Message = ();
+
+    fn view(
+        &self,
+        _global_state: &Self::GlobalState,
+        _props: &Self::Props,
+        _children: Vec<ComponentSpecification>,
+        _id: ComponentId,
+        _window: &WindowContext,
+    ) -> ComponentSpecification {
+        let mut board = Container::new().flex_direction(FlexDirection::Row).gap(20);
+        for (index, cards) in self.columns.iter().enumerate() {
+            let mut column = Container::new()
+                .id(&format!("column-{index}"))
+                .flex_direction(FlexDirection::Column)
+                .width(100)
+                .height(200)
+                .on_drag_over(move |state: &mut Board, _: &mut (), _: &mut Event, _: &Drag| {
+                    if state.log.last() != Some(&format!("over {index}")) {
+                        state.log.push(format!("over {index}"));
+                    }
+                })
+                .on_drag_leave(move |state: &mut Board, _: &mut (), _: &mut Event, _: &Drag| {
+                    state.log.push(format!("leave {index}"));
+                })
+                .on_drop(move |state: &mut Board, _: &mut (), _: &mut Event, drag: &Drag| {
+                    let card = *drag.payload.get_data::<usize>().unwrap();
+                    state.columns.iter_mut().for_each(|cards| cards.retain(|other| *other != card));
+                    state.columns[index].push(card);
+                    state.log.push(format!("drop {card} on {index}"));
+                });
+            for card in cards {
+                column = column.push(
+                    Container::new()
+                        .id(&format!("card-{card}"))
+                        .height(30)
+                        .background(rgb(0, 0, 255))
+                        .draggable(*card)
+                        .drag_preview(true)
+                        .on_drag_start(|state: &mut Board, _: &mut (), _: &mut Event, drag: &Drag| {
+                            state.log.push(format!("start {}", drag.payload.get_data::<usize>().unwrap()));
+                        })
+                        .on_drag_end(|state: &mut Board, _: &mut (), _: &mut Event, drag: &Drag| {
+                            state.log.push(format!("end {}", drag.payload.get_data::<usize>().unwrap()));
+                        }),
+                );
+            }
+            board = board.push(column);
+        }
+
+        board.component()
+    }
+}
+
+#[test]
+fn dragging_a_card_drops_it_on_the_column_under_the_pointer() {
+    let mut harness = mount::<Board>(());
+    let card = harness.element_bounds("card-0").unwrap();
+    let done = harness.element_bounds("column-1").unwrap();
+    let start = Point::new(card.x + 10.0, card.y + 10.0);
+    let target = Point::new(done.x + 50.0, done.y + 50.0);
+
+    // The drag only starts once the pointer moves past the threshold.
+    harness.pointer_button(start, MouseButton::Left, ElementState::Pressed);
+    harness.pointer_moved(Point::new(start.x + 3.0, start.y));
+    assert!(state::<Board>(&harness).log.is_empty());
+
+    harness.pointer_moved(Point::new(start.x + 20.0, start.y));
+    let snapshot = harness.render_list_snapshot();
+    assert!(snapshot.contains("transform 1,0,0,1,20,0"), "{snapshot}");
+    assert!(snapshot.contains("opacity_layer 0.6"), "{snapshot}");
+
+    harness.pointer_moved(target);
+    harness.pointer_button(target, MouseButton::Left, ElementState::Released);
+    let board = state::<Board>(&harness);
+    assert_eq!(board.log, ["start 0", "over 0", "leave 0", "over 1", "drop 0 on 1", "end 0"]);
+    assert_eq!(board.columns, [vec![1], vec![0]]);
+    assert!(!harness.render_list_snapshot().contains("opacity_layer"));
+}
+
+#[test]
+fn escape_cancels_the_drag() {
+    let mut harness = mount::<Board>(());
+    let card = harness.element_bounds("card-1").unwrap();
+    let done = harness.element_bounds("column-1").unwrap();
+    let target = Point::new(done.x + 50.0, done.y + 50.0);
+
+    harness.pointer_button(Point::new(card.x + 10.0, card.y + 10.0), MouseButton::Left, ElementState::Pressed);
+    harness.pointer_moved(target);
+    harness.key_press(Key::Named(NamedKey::Escape));
+    harness.pointer_button(target, MouseButton::Left, ElementState::Released);
+    harness.pointer_moved(Point::new(target.x + 10.0, target.y));
+
+    let board = state::<Board>(&harness);
+    assert_eq!(board.log, ["start 1", "over 1", "leave 1", "end 1"]);
+    assert_eq!(board.columns, [vec![0, 1], vec![]]);
+}
@@ -0,0 +1,240 @@
+use crate::components::{Component, ComponentId, ComponentSpecification, Event};
+use crate::elements::{Container, ElementStyles, Slider, TextInput};
+use crate::events::{CraftMessage, Message, PointerButton};
+use crate::geometry::Point;
+use crate::style::FlexDirection;
+use crate::testing::helpers::{mount, state};
+use crate::WindowContext;
+use winit::event::{ElementState, FingerId};
+use winit::keyboard::Key;
+
+#[derive(Default)]
+struct Notepad {
+    text: String,
+    log: Vec<String>,
+}
+
+/// Logs the phase, the current target and the target of the pointer presses.
+fn log_pointer_press(state: &mut Notepad, event: &Event) {
+    let current_target = event.current_target.unwrap().get_id().clone().unwrap();
+    let target = event.target.unwrap().get_id().clone().unwrap();
+    state.log.push(format!("{:?} {current_target} {target}", event.phase));
+}
+
+impl Component for Notepad {
+    type GlobalState = ();
+    type Props = ();
+    type Message = ();
+
+    fn view(
+        &self,
+        _global_state: &Self::GlobalState,
+        _props: &Self::Props,
+        _children: Vec<ComponentSpecification>,
+        _id: ComponentId,
+        _window: &WindowContext,
+    ) -> ComponentSpecification {
+        let text_input = TextInput::new(&self.text)
+            .id("text")
+            .on_text_input_changed(|state: &mut Notepad, _: &mut (), _: &mut Event, text: &str| {
+                state.text = text.to_string();
+            })
+            .on_pointer_button(|state: &mut Notepad, _: &mut (), event: &mut Event, pointer_button: &PointerButton| {
+                if pointer_button.state == ElementState::Pressed {
+                    log_pointer_press(state, event);
+                }
+            });
+
+        Container::new()
+            .id("editor")
+            .on_capture(|state: &mut Notepad, _: &mut (), event: &mut Event, message: &CraftMessage| {
+                match message {
+                    CraftMessage::PointerButtonEvent(pointer_button) if pointer_button.state.is_pressed() => {
+                        log_pointer_press(state, event);
+                    }
+                    // The shortcut works wherever the focus is, and is not typed into the text input.
+                    CraftMessage::KeyboardInputEvent(keyboard_input)
+                        if keyboard_input.event.logical_key == Key::Character("s".into()) =>
+                    {
+                        if keyboard_input.event.state.is_pressed() {
+                            state.log.push("save".to_string());
+                        }
+                        event.stop_immediate_propagation();
+                    }
+                    _ => {}
+                }
+            })
+            .on_pointer_button(|state: &mut Notepad, _: &mut (), event: &mut Event, pointer_button: &PointerButton| {
+                if pointer_button.state == ElementState::Pressed {
+                    log_pointer_press(state, event);
+                }
+            })
+            .push(
+                Container::new()
+                    .id("toolbar")
+                    .on_capture(|state: &mut Notepad, _: &mut (), event: &mut Event, message: &CraftMessage| {
+                        if let CraftMessage::PointerButtonEvent(pointer_button) = message
+                            && pointer_button.state == ElementState::Pressed
+                        {
+                            log_pointer_press(state, event);
+                        }
+                    })
+                    .push(text_input),
+            )
+            .component()
+    }
+}
+
+#[test]
+fn capture_handlers_run_from_the_root_to_the_target_before_bubbling() {
+    let mut harness = mount::<Notepad>(());
+    harness.click_element("text");
+
+    assert_eq!(
+        state::<Notepad>(&harness).log,
+        ["Capturing editor text", "Capturing toolbar text", "Bubbling text text", "Bubbling editor text"]
+    );
+}
+
+#[test]
+fn stopping_a_captured_event_keeps_it_from_the_target() {
+    let mut harness = mount::<Notepad>(());
+    harness.click_element("text");
+    harness.type_text("ab");
+    harness.key_press(Key::Character("s".into()));
+    harness.type_text("c");
+
+    let editor = state::<Notepad>(&harness);
+    assert_eq!(editor.text, "abc");
+    assert_eq!(editor.log.last().map(String::as_str), Some("save"));
+}
+
+/// Overrides `update`, so it sees every event that reaches the component.
+#[derive(Default)]
+struct Shortcuts {
+    log: Vec<String>,
+}
+
+impl Component for Shortcuts {
+    type GlobalState = ();
+    type Props = ();
+    type Message = ();
+
+    fn view(
+        &self,
+        _global_state: &Self::GlobalState,
+        _props: &Self::Props,
+        _children: Vec<ComponentSpecification>,
+        _id: ComponentId,
+        _window: &WindowContext,
+    ) -> ComponentSpecification {
+        Container::new()
+            .id("root")
+            .on_capture(|_: &mut Shortcuts, _: &mut (), event: &mut Event, message: &CraftMessage| {
+                if let CraftMessage::KeyboardInputEvent(keyboard_input) = message
+                    && keyboard_input.event.state.is_pressed()
+                    && keyboard_input.event.logical_key == Key::Character("s".into())
+                {
+                    event.stop_immediate_propagation();
+                    event.result_message(CraftMessage::MenuItemSelected("save".to_string()));
+                }
+            })
+            .push(TextInput::new("").id("text"))
+            .component()
+    }
+
+    fn update(&mut self, _global_state: &mut (), _props: &(), event: &mut Event, message: &Message) {
+        match message {
+            Message::CraftMessage(CraftMessage::KeyboardInputEvent(keyboard_input))
+                if keyboard_input.event.state.is_pressed() =>
+            {
+                let current_target = event.current_target.unwrap().get_id().clone().unwrap();
+                self.log.push(format!("{:?} {current_target} {:?}", event.phase, keyboard_input.event.logical_key));
+            }
+            Message::CraftMessage(CraftMessage::MenuItemSelected(id)) => self.log.push(format!("selected {id}")),
+            _ => {}
+        }
+    }
+}
+
+#[test]
+fn components_that_override_update_only_see_the_bubbling_phase() {
+    let mut harness = mount::<Shortcuts>(());
+    harness.click_element("text");
+    harness.key_press(Key::Character("a".into()));
+    harness.key_press(Key::Character("s".into()));
+
+    // The capture handler stops the shortcut, but its result message is still sent.
+    assert_eq!(
+        state::<Shortcuts>(&harness).log,
+        ["Bubbling text Character(\"a\")", "Bubbling root Character(\"a\")", "selected save"]
+    );
+}
+
+#[derive(Default)]
+struct Mixer {
+    bass: f64,
+    treble: f64,
+}
+
+impl Component for Mixer {
+    type GlobalState = ();
+    type Props = ();
+    type Message = ();
+
+    fn view(
+        &self,
+        _global_state: &Self::GlobalState,
+        _props: &Self::Props,
+        _children: Vec<ComponentSpecification>,
+        _id: ComponentId,
+        _window: &WindowContext,
+    ) -> ComponentSpecification {
+        Container::new()
+            .id("root")
+            .width("100%")
+            .height("100%")
+            .flex_direction(FlexDirection::Column)
+            .push(Slider::new(16.0).id("bass").width(200).on_slider_value_changed(
+                |state: &mut Mixer, _: &mut (), _: &mut Event, bass: f64| {
+                    state.bass = bass;
+                },
+            ))
+            .push(Slider::new(16.0).id("treble").width(200).on_slider_value_changed(
+                |state: &mut Mixer, _: &mut (), _: &mut Event, treble: f64| {
+                    state.treble = treble;
+                },
+            ))
+            .component()
+    }
+}
+
+/// winit does not let applications create finger ids, so the tests make them from the integer that winit wraps.
+fn finger(id: usize) -> FingerId {
+    // SAFETY: `FingerId` only wraps a `usize`.
+    unsafe { std::mem::transmute::<usize, FingerId>(id) }
+}
+
+#[test]
+fn every_finger_drags_its_own_slider() {
+    let mut harness = mount::<Mixer>(());
+    let bass = harness.element_bounds("bass").unwrap();
+    let treble = harness.element_bounds("treble").unwrap();
+    let bass_y = bass.y + bass.height / 2.0;
+    let treble_y = treble.y + treble.height / 2.0;
+
+    harness.touch(finger(0), Point::new(bass.x, bass_y), ElementState::Pressed);
+    harness.touch(finger(1), Point::new(treble.x, treble_y), ElementState::Pressed);
+    // The fingers cross over, and every slider still follows the finger that pressed it.
+    harness.touch_moved(finger(0), Point::new(bass.x + bass.width * 0.75, treble_y));
+    harness.touch_moved(finger(1), Point::new(treble.x + treble.width * 0.25, bass_y));
+    harness.touch(finger(0), Point::new(bass.x + bass.width * 0.75, treble_y), ElementState::Released);
+    harness.touch(finger(1), Point::new(treble.x + treble.width * 0.25, bass_y), ElementState::Released);
+
+    let mixer = state::<Mixer>(&harness);
+    assert_eq!((mixer.bass, mixer.treble), (75.0, 25.0));
+
+    // The lifted fingers no longer hover or press anything.
+    let element_states = harness.reactive_tree.element_state.storage.values();
+    assert!(element_states.map(|state| &state.base).all(|base| base.hovered.is_empty() && base.active.is_empty()));
+}
@@ -0,0 +1,73 @@
+use crate::components::{Component, ComponentId, ComponentSpecification, Event};
+use crate::elements::{Container, ElementStyles};
+use crate::events::{FileDrag, FileDragEvent};
+use crate::geometry::{Point, Size};
+use crate::style::FlexDirection;
+use crate::testing::helpers::state;
+use crate::testing::TestHarness;
+use crate::WindowContext;
+use std::path::PathBuf;
+
+#[derive(Default)]
+struct FileImport {
+    log: Vec<String>,
+}
+
+impl Component for FileImport {
+    type GlobalState = ();
+    type Props = ();
+    type Message = ();
+
+    fn view(
+        &self,
+        _global_state: &Self::GlobalState,
+        _props: &Self::Props,
+        _children: Vec<ComponentSpecification>,
+        _id: ComponentId,
+        _window: &WindowContext,
+    ) -> ComponentSpecification {
+        Container::new()
+            .flex_direction(FlexDirection::Column)
+            .push(Container::new().id("header").width(200).height(50))
+            .push(
+                Container::new()
+                    .id("drop-zone")
+                    .width(200)
+                    .height(100)
+                    .on_file_hovered(|state: &mut FileImport, _: &mut (), _: &mut Event, file_drag: &FileDrag| {
+                        state.log.push(format!("hovered {}", file_drag.paths.len()));
+                    })
+                    .on_file_hover_cancelled(|state: &mut FileImport, _: &mut (), _: &mut Event, _: &FileDrag| {
+                        state.log.push("cancelled".to_string());
+                    })
+                    .on_file_dropped(|state: &mut FileImport, _: &mut (), _: &mut Event, file_drag: &FileDrag| {
+                        let paths: Vec<String> =
+                            file_drag.paths.iter().map(|path| path.display().to_string()).collect();
+                        state.log.push(format!("dropped {}", paths.join(",")));
+                    }),
+            )
+            .component()
+    }
+}
+
+#[test]
+fn files_from_the_operating_system_go_to_the_element_under_the_pointer() {
+    let mut harness = TestHarness::new(FileImport::component(), (), Size::new(200.0, 200.0));
+    let paths = vec![PathBuf::from("a.csv"), PathBuf::from("b.csv")];
+
+    harness.file_drag(FileDragEvent::Entered(paths.clone(), Point::new(20.0, 20.0)));
+    harness.file_drag(FileDragEvent::Moved(Point::new(20.0, 80.0)));
+    harness.file_drag(FileDragEvent::Moved(Point::new(20.0, 20.0)));
+    harness.file_drag(FileDragEvent::Moved(Point::new(20.0, 90.0)));
+    harness.file_drag(FileDragEvent::Left);
+    assert_eq!(
+        state::<FileImport>(&harness).log,
+        ["hovered 2", "cancelled", "hovered 2", "cancelled"]
+    );
+
+    harness.file_drag(FileDragEvent::Entered(paths.clone(), Point::new(20.0, 80.0)));
+    harness.file_drag(FileDragEvent::Dropped(paths, Point::new(20.0, 80.0)));
+    // Files that are dropped outside of the drop zone are ignored.
+    harness.file_drag(FileDragEvent::Dropped(vec![PathBuf::from("c.csv")], Point::new(20.0, 20.0)));
+    assert_eq!(state::<FileImport>(&harness).log[4..], ["hovered 2", "dropped a.csv,b.csv"]);
+}
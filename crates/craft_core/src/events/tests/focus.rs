@@ -0,0 +1,104 @@
+use crate::components::{Component, ComponentId, ComponentSpecification, Event};
+use crate::elements::{Container, ElementStyles, TextInput};
+use crate::events::PointerButton;
+use crate::geometry::Size;
+use crate::testing::helpers::{focused_id, mount, state, Form};
+use crate::testing::TestHarness;
+use crate::WindowContext;
+use winit::keyboard::{Key, ModifiersState, NamedKey};
+
+#[test]
+fn tab_moves_the_focus_and_keys_go_to_the_focused_element() {
+    let mut harness = TestHarness::new(Form::component(), (), Size::new(400.0, 600.0));
+    assert_eq!(focused_id(&harness), None);
+
+    harness.key_press(Key::Named(NamedKey::Tab));
+    assert_eq!(focused_id(&harness).as_deref(), Some("name"));
+
+    // The pointer is not over the text input, the key presses follow the focus.
+    harness.type_text("Ada");
+    assert_eq!(state::<Form>(&harness).name, "Ada");
+
+    harness.key_press(Key::Named(NamedKey::Tab));
+    assert_eq!(focused_id(&harness).as_deref(), Some("subscribed"));
+    harness.key_press(Key::Named(NamedKey::Tab));
+    assert_eq!(focused_id(&harness).as_deref(), Some("volume"));
+    harness.key_press(Key::Named(NamedKey::Tab));
+    assert_eq!(focused_id(&harness).as_deref(), Some("name"));
+
+    harness.modifiers_changed(ModifiersState::SHIFT);
+    harness.key_press(Key::Named(NamedKey::Tab));
+    assert_eq!(focused_id(&harness).as_deref(), Some("volume"));
+}
+
+#[derive(Default)]
+struct FocusLog {
+    log: Vec<String>,
+}
+
+impl FocusLog {
+    fn input(id: &'static str) -> TextInput {
+        TextInput::new("")
+            .id(id)
+            .on_focus(move |state: &mut FocusLog, _: &mut (), _: &mut Event| state.log.push(format!("focus {id}")))
+            .on_blur(move |state: &mut FocusLog, _: &mut (), _: &mut Event| state.log.push(format!("blur {id}")))
+    }
+}
+
+impl Component for FocusLog {
+    type GlobalState = ();
+    type Props = ();
+    type Message = ();
+
+    fn view(
+        &self,
+        _global_state: &Self::GlobalState,
+        _props: &Self::Props,
+        _children: Vec<ComponentSpecification>,
+        _id: ComponentId,
+        _window: &WindowContext,
+    ) -> ComponentSpecification {
+        Container::new()
+            .id("root")
+            .width("100%")
+            .height("100%")
+            .push(FocusLog::input("first"))
+            .push(FocusLog::input("second").tab_index(1))
+            .push(Container::new().id("panel").width(50).height(20).tab_index(-1))
+            .push(Container::new().id("button").width(50).height(20).on_pointer_button(
+                |_: &mut FocusLog, _: &mut (), event: &mut Event, pointer_button: &PointerButton| {
+                    if pointer_button.clicked() {
+                        event.request_focus("first");
+                    }
+                },
+            ))
+            .component()
+    }
+}
+
+#[test]
+fn focus_follows_tab_indices_clicks_and_requests() {
+    let mut harness = mount::<FocusLog>(());
+
+    // Positive tab indices come first and negative ones are skipped.
+    harness.key_press(Key::Named(NamedKey::Tab));
+    assert_eq!(focused_id(&harness).as_deref(), Some("second"));
+    harness.key_press(Key::Named(NamedKey::Tab));
+    assert_eq!(focused_id(&harness).as_deref(), Some("first"));
+    harness.key_press(Key::Named(NamedKey::Tab));
+    assert_eq!(focused_id(&harness).as_deref(), Some("second"));
+
+    // An element with a negative tab index can still be focused with the pointer.
+    harness.click_element("panel");
+    assert_eq!(focused_id(&harness).as_deref(), Some("panel"));
+
+    // Pressing the button blurs the panel, and releasing it requests the focus for the first input.
+    harness.click_element("button");
+    assert_eq!(focused_id(&harness).as_deref(), Some("first"));
+
+    let log = &state::<FocusLog>(&harness).log;
+    assert_eq!(
+        log,
+        &["focus second", "blur second", "focus first", "blur first", "focus second", "blur second", "focus first"]
+    );
+}
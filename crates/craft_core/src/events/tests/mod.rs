@@ -0,0 +1,5 @@
+mod click;
+mod drag;
+mod event_dispatch;
+mod file_drag;
+mod focus;
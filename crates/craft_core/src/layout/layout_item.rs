@@ -3,7 +3,9 @@ use crate::geometry::side::Side;
 use crate::geometry::{Border, ElementBox, Margin, Padding, Point, Rectangle, Size, TrblRectangle};
 use crate::layout::layout_context::LayoutContext;
 use crate::renderer::{Brush, RenderList};
-use crate::style::Style;
+use crate::style::{BoxShadow, Style, Transform, Unit};
+use peniko::kurbo;
+use peniko::kurbo::{Affine, BezPath, Shape, Vec2};
 use peniko::Color;
 use taffy::{NodeId, Position, TaffyTree};
 
@@ -27,6 +29,10 @@ pub struct LayoutItem {
 
     pub layout_order: u32,
     pub clip_bounds: Option<Rectangle>,
+    /// The `transform` of the element around its transform origin, in the coordinates of its parent.
+    pub transform: Affine,
+    /// Maps the coordinates of the element to the window, including the transforms of all of its ancestors.
+    pub window_transform: Affine,
 
     //  ---
     pub child_nodes: Vec<NodeId>,
@@ -117,20 +123,75 @@ impl LayoutItem {
     pub fn resolve_clip(&mut self, clip_bounds: Option<Rectangle>) {
         self.clip_bounds = clip_bounds;
     }
+
+    /// Computes how far the content can be scrolled down, once the box and the content size are resolved.
+    pub(crate) fn resolve_max_scroll_y(&mut self) {
+        let box_transformed = self.computed_box_transformed;
+
+        // Taffy is not adding the padding bottom to the content height, so we'll add it here.
+        let scroll_height = self.content_size.height + box_transformed.padding.bottom;
+        self.max_scroll_y = (scroll_height - box_transformed.padding_rectangle().height).max(0.0);
+    }
     
+    pub fn resolve_transform(&mut self, transform: Transform, transform_origin: [Unit; 2], parent_transform: Affine) {
+        self.transform = if transform.is_identity() {
+            Affine::IDENTITY
+        } else {
+            let border_rectangle = self.computed_box_transformed.border_rectangle();
+            let resolve = |unit: Unit, start: f32, length: f32| {
+                let offset = match unit {
+                    Unit::Px(px) => px,
+                    Unit::Percentage(percentage) => length * percentage / 100.0,
+                    Unit::Auto => length / 2.0,
+                };
+                (start + offset) as f64
+            };
+            let origin = Vec2::new(
+                resolve(transform_origin[0], border_rectangle.x, border_rectangle.width),
+                resolve(transform_origin[1], border_rectangle.y, border_rectangle.height),
+            );
+            Affine::translate(origin) * transform.to_affine() * Affine::translate(-origin)
+        };
+        self.window_transform = parent_transform * self.transform;
+    }
+
+    /// Maps a point in the window to the coordinates of the element, which its boxes are in.
+    pub fn window_to_local(&self, point: Point) -> Point {
+        if self.window_transform == Affine::IDENTITY {
+            return point;
+        }
+
+        let local = self.window_transform.inverse() * kurbo::Point::new(point.x as f64, point.y as f64);
+        Point::new(local.x as f32, local.y as f32)
+    }
+
     pub fn draw_borders(&self, renderer: &mut RenderList, current_style: &Style) {
         let background_color = current_style.background();
+        let box_shadow = current_style.box_shadow();
+
+        if let Some(box_shadow) = box_shadow.filter(|box_shadow| !box_shadow.inset) {
+            let (rectangle, radii) = self.box_shadow_rectangle(current_style, &box_shadow);
+            let std_dev = box_shadow_std_dev(&box_shadow);
+            draw_corners(renderer, rectangle, radii, std_dev, &mut |renderer, radius| {
+                renderer.draw_shadow(rectangle, radius, std_dev, box_shadow.color);
+            });
+        }
 
         // OPTIMIZATION: Draw a normal rectangle if no border values have been modified.
         if !current_style.has_border() {
-            renderer.draw_rect(self.computed_box_transformed.padding_rectangle(), background_color);
+            let padding_rectangle = self.computed_box_transformed.padding_rectangle();
+            renderer.draw_rect(padding_rectangle, background_color);
+            if current_style.background_gradient().is_some() || box_shadow.is_some_and(|box_shadow| box_shadow.inset) {
+                self.draw_background_layers(renderer, current_style, padding_rectangle.to_kurbo().to_path(0.1));
+            }
             return;
         }
 
         let computed_border_spec = &self.computed_border;
 
         let background_path = computed_border_spec.build_background_path();
-        renderer.fill_bez_path(background_path, Brush::Color(background_color));
+        renderer.fill_bez_path(background_path.clone(), Brush::Color(background_color));
+        self.draw_background_layers(renderer, current_style, background_path);
 
         let top = computed_border_spec.get_side(Side::Top);
         let right = computed_border_spec.get_side(Side::Right);
@@ -147,4 +208,81 @@ impl LayoutItem {
         renderer.fill_bez_path(border_bottom_path, Brush::Color(bottom.color));
         renderer.fill_bez_path(border_left_path, Brush::Color(left.color));
     }
-}
\ No newline at end of file
+
+    /// Draws the background gradient and the inset shadow, which are clipped to the background.
+    fn draw_background_layers(&self, renderer: &mut RenderList, current_style: &Style, background_path: BezPath) {
+        if let Some(gradient) = current_style.background_gradient() {
+            let border_rectangle = self.computed_box_transformed.border_rectangle();
+            renderer.fill_bez_path(background_path.clone(), Brush::Gradient(gradient.to_peniko(border_rectangle)));
+        }
+
+        if let Some(box_shadow) = current_style.box_shadow().filter(|box_shadow| box_shadow.inset) {
+            let (rectangle, radii) = self.box_shadow_rectangle(current_style, &box_shadow);
+            let std_dev = box_shadow_std_dev(&box_shadow);
+            draw_corners(renderer, rectangle, radii, std_dev, &mut |renderer, radius| {
+                renderer.draw_inset_shadow(background_path.clone(), rectangle, radius, std_dev, box_shadow.color);
+            });
+        }
+    }
+
+    /// Returns the rectangle and the corner radii of the shape that is blurred to draw the box shadow, in the order
+    /// top left, top right, bottom right and bottom left.
+    fn box_shadow_rectangle(&self, current_style: &Style, box_shadow: &BoxShadow) -> (Rectangle, [f32; 4]) {
+        let border_rectangle = self.computed_box_transformed.border_rectangle();
+        // Inset shadows are cut out of the background, so the spread shrinks the rectangle instead.
+        let spread = if box_shadow.inset { -box_shadow.spread_radius } else { box_shadow.spread_radius };
+        let radii = current_style.border_radius().map(|(radius, _)| (radius + spread).max(0.0));
+
+        let rectangle = Rectangle::new(
+            border_rectangle.x + box_shadow.offset_x - spread,
+            border_rectangle.y + box_shadow.offset_y - spread,
+            (border_rectangle.width + 2.0 * spread).max(0.0),
+            (border_rectangle.height + 2.0 * spread).max(0.0),
+        );
+        (rectangle, radii)
+    }
+}
+
+/// Draws a blurred rounded rectangle with `draw`, which only supports a single corner radius.
+///
+/// When the corners have different radii, the rectangle is drawn once per corner, clipped to the quarter of the
+/// shadow around that corner. The clips are split at whole pixels, so that the quarters do not leave seams.
+fn draw_corners(
+    renderer: &mut RenderList,
+    rectangle: Rectangle,
+    radii: [f32; 4],
+    std_dev: f32,
+    draw: &mut dyn FnMut(&mut RenderList, f32),
+) {
+    if radii.iter().all(|radius| *radius == radii[0]) {
+        draw(renderer, radii[0]);
+        return;
+    }
+
+    // The blur fades out within three standard deviations.
+    let extent = 3.0 * std_dev;
+    let (left, top) = (rectangle.x - extent, rectangle.y - extent);
+    let (right, bottom) = (rectangle.right() + extent, rectangle.bottom() + extent);
+    let center_x = (rectangle.x + rectangle.width / 2.0).round();
+    let center_y = (rectangle.y + rectangle.height / 2.0).round();
+
+    let quarters = [
+        Rectangle::new(left, top, center_x - left, center_y - top),
+        Rectangle::new(center_x, top, right - center_x, center_y - top),
+        Rectangle::new(center_x, center_y, right - center_x, bottom - center_y),
+        Rectangle::new(left, center_y, center_x - left, bottom - center_y),
+    ];
+    for (quarter, radius) in quarters.into_iter().zip(radii) {
+        renderer.push_layer(quarter);
+        draw(renderer, radius);
+        renderer.pop_layer();
+    }
+}
+
+/// Like in CSS, the standard deviation of the blur is half of the blur radius.
+///
+/// Blurred rectangles are numerically unstable with a tiny standard deviation, so shadows without a blur get a blur
+/// of half a pixel, which still looks sharp.
+fn box_shadow_std_dev(box_shadow: &BoxShadow) -> f32 {
+    (box_shadow.blur_radius / 2.0).max(0.5)
+}
@@ -1,2 +1,4 @@
 pub mod layout_context;
-pub mod layout_item;
\ No newline at end of file
+pub mod layout_item;
+#[cfg(test)]
+mod tests;
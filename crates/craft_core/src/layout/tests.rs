@@ -0,0 +1,188 @@
+use crate::components::ComponentSpecification;
+use crate::elements::{Container, ElementStyles};
+use crate::geometry::Size;
+use crate::style::{
+    Display, FlexDirection, GridAutoFlow, GridError, GridPlacement, GridTemplate, GridTemplateAreas, GridTrack,
+    GridTrackSize, Wrap,
+};
+use crate::testing::TestHarness;
+
+fn bounds(harness: &TestHarness, id: &str) -> (f32, f32, f32, f32) {
+    let bounds = harness.element_bounds(id).unwrap();
+    (bounds.x, bounds.y, bounds.width, bounds.height)
+}
+
+fn layout(grid: Container) -> TestHarness {
+    let root: ComponentSpecification = grid.id("grid").display(Display::Grid).width(300).height(200).component();
+    TestHarness::new(root, (), Size::new(400.0, 300.0))
+}
+
+#[test]
+fn grid_distributes_fr_columns_around_gaps() {
+    let harness = layout(
+        Container::new()
+            .grid_template_columns("100px 1fr 2fr")
+            .grid_template_rows("50px 1fr")
+            .gap(10)
+            .push(Container::new().id("a"))
+            .push(Container::new().id("b"))
+            .push(Container::new().id("c"))
+            .push(Container::new().id("d")),
+    );
+
+    // 300px - 100px - 2 * 10px of gaps leaves 180px for 3fr.
+    assert_eq!(bounds(&harness, "a"), (0.0, 0.0, 100.0, 50.0));
+    assert_eq!(bounds(&harness, "b"), (110.0, 0.0, 60.0, 50.0));
+    assert_eq!(bounds(&harness, "c"), (180.0, 0.0, 120.0, 50.0));
+    assert_eq!(bounds(&harness, "d"), (0.0, 60.0, 100.0, 140.0));
+}
+
+#[test]
+fn grid_uses_separate_row_and_column_gaps() {
+    let harness = layout(
+        Container::new()
+            .grid_template_columns("repeat(2, 1fr)")
+            .grid_template_rows("repeat(2, 1fr)")
+            .row_gap(20)
+            .column_gap(40)
+            .push(Container::new().id("a"))
+            .push(Container::new().id("b"))
+            .push(Container::new().id("c")),
+    );
+
+    // Like in CSS, the row gap separates the rows and the column gap separates the columns.
+    assert_eq!(bounds(&harness, "a"), (0.0, 0.0, 130.0, 90.0));
+    assert_eq!(bounds(&harness, "b"), (170.0, 0.0, 130.0, 90.0));
+    assert_eq!(bounds(&harness, "c"), (0.0, 110.0, 130.0, 90.0));
+}
+
+#[test]
+fn flex_row_gap_separates_wrapped_lines() {
+    let root: ComponentSpecification = Container::new()
+        .id("flex")
+        .display(Display::Flex)
+        .flex_direction(FlexDirection::Row)
+        .wrap(Wrap::Wrap)
+        .width(300)
+        .row_gap(20)
+        .column_gap(40)
+        .push(Container::new().id("a").width(100).height(50))
+        .push(Container::new().id("b").width(100).height(50))
+        .push(Container::new().id("c").width(100).height(50))
+        .component();
+    let harness = TestHarness::new(root, (), Size::new(400.0, 300.0));
+
+    assert_eq!(bounds(&harness, "a"), (0.0, 0.0, 100.0, 50.0));
+    assert_eq!(bounds(&harness, "b"), (140.0, 0.0, 100.0, 50.0));
+    assert_eq!(bounds(&harness, "c"), (0.0, 70.0, 100.0, 50.0));
+}
+
+#[test]
+fn grid_items_span_and_are_placed_at_lines() {
+    let harness = layout(
+        Container::new()
+            .grid_template_columns("repeat(3, 100px)")
+            .grid_template_rows("repeat(2, 100px)")
+            .push(Container::new().id("wide").grid_column_span(2))
+            .push(Container::new().id("tall").grid_row_span(2))
+            .push(Container::new().id("placed").grid_row(GridPlacement::Line(2), GridPlacement::Auto).grid_column(
+                GridPlacement::Line(1),
+                GridPlacement::Line(3),
+            )),
+    );
+
+    assert_eq!(bounds(&harness, "wide"), (0.0, 0.0, 200.0, 100.0));
+    assert_eq!(bounds(&harness, "tall"), (200.0, 0.0, 100.0, 200.0));
+    assert_eq!(bounds(&harness, "placed"), (0.0, 100.0, 200.0, 100.0));
+}
+
+#[test]
+fn grid_places_items_in_named_areas() {
+    let harness = layout(
+        Container::new()
+            .grid_template_columns("100px 1fr")
+            .grid_template_rows("40px 1fr 30px")
+            .grid_template_areas(&["header header", "sidebar main", ". footer"])
+            .push(Container::new().id("footer").grid_area("footer"))
+            .push(Container::new().id("main").grid_area("main"))
+            .push(Container::new().id("header").grid_area("header"))
+            .push(Container::new().id("sidebar").grid_area("sidebar")),
+    );
+
+    assert_eq!(bounds(&harness, "header"), (0.0, 0.0, 300.0, 40.0));
+    assert_eq!(bounds(&harness, "sidebar"), (0.0, 40.0, 100.0, 130.0));
+    assert_eq!(bounds(&harness, "main"), (100.0, 40.0, 200.0, 130.0));
+    assert_eq!(bounds(&harness, "footer"), (100.0, 170.0, 200.0, 30.0));
+}
+
+#[test]
+fn grid_auto_flow_column_fills_columns_first() {
+    let harness = layout(
+        Container::new()
+            .grid_template_rows("repeat(2, 100px)")
+            .grid_auto_flow(GridAutoFlow::Column)
+            .push(Container::new().id("a").width(50))
+            .push(Container::new().id("b").width(50))
+            .push(Container::new().id("c").width(50)),
+    );
+
+    assert_eq!(bounds(&harness, "a"), (0.0, 0.0, 50.0, 100.0));
+    assert_eq!(bounds(&harness, "b"), (0.0, 100.0, 50.0, 100.0));
+    // The implicit columns are auto sized, so they stretch to share the width of the grid.
+    assert_eq!(bounds(&harness, "c"), (150.0, 0.0, 50.0, 100.0));
+}
+
+#[test]
+fn grid_template_parses_repeat_and_minmax() {
+    let template = GridTemplate::try_from("100px repeat(2, minmax(50px, 1fr)) auto").unwrap();
+
+    assert_eq!(
+        template.tracks(),
+        &[
+            GridTrack::new(GridTrackSize::Px(100.0)),
+            GridTrack::minmax(GridTrackSize::Px(50.0), GridTrackSize::Fr(1.0)),
+            GridTrack::minmax(GridTrackSize::Px(50.0), GridTrackSize::Fr(1.0)),
+            GridTrack::new(GridTrackSize::Auto),
+        ]
+    );
+    assert_eq!(template.to_string(), "100px minmax(50px, 1fr) minmax(50px, 1fr) auto");
+    assert_eq!(
+        GridTemplate::try_from("1fr").unwrap().tracks(),
+        &[GridTrack::minmax(GridTrackSize::Auto, GridTrackSize::Fr(1.0))]
+    );
+}
+
+#[test]
+fn invalid_grid_templates_are_errors() {
+    assert_eq!(GridTemplate::try_from("1fx"), Err(GridError::InvalidTrack("1fx".to_string())));
+
+    // The element styles ignore invalid values instead of panicking.
+    let harness = layout(
+        Container::new()
+            .grid_template_columns("repeat(2, 1fr)")
+            .grid_template_columns("repeat(2, 1fx)")
+            .push(Container::new().id("a"))
+            .push(Container::new().id("b")),
+    );
+    assert_eq!(bounds(&harness, "b").0, 150.0);
+}
+
+#[test]
+fn grid_templates_and_areas_are_not_limited_in_size() {
+    let columns = GridTemplate::try_from("repeat(30, 10px)").unwrap();
+    assert_eq!(columns.tracks().len(), 30);
+
+    let names: Vec<String> = (0..20).map(|index| format!("a-very-long-area-name-{index}")).collect();
+    let row = names.join(" ");
+    let areas = GridTemplateAreas::new(&[row.as_str()]);
+    assert_eq!(areas.areas().len(), 20);
+    assert_eq!(areas.find("a-very-long-area-name-19").unwrap().column_start, 20);
+
+    let harness = layout(
+        Container::new()
+            .grid_template_columns(columns)
+            .grid_template_areas(&[row.as_str()])
+            .push(Container::new().id("last").grid_area("a-very-long-area-name-19")),
+    );
+    assert_eq!(bounds(&harness, "last"), (190.0, 0.0, 10.0, 200.0));
+}
@@ -11,6 +11,8 @@ pub mod style;
 #[cfg(test)]
 mod tests;
 pub mod text;
+#[cfg(not(target_arch = "wasm32"))]
+pub mod testing;
 
 pub mod app_message;
 #[cfg(feature = "dev_tools")]
@@ -21,14 +23,18 @@ mod view_introspection;
 pub mod layout;
 
 pub use craft_runtime::CraftRuntime;
-pub use options::CraftOptions;
+pub use options::{CraftOptions, WindowOptions};
 pub use renderer::color::palette;
 pub use renderer::color::Color;
 
 #[cfg(target_os = "android")]
 pub use winit::platform::android::activity::*;
 
-use crate::events::{CraftMessage, EventDispatchType, KeyboardInput, MouseWheel, PointerButton, PointerMoved};
+use crate::accessibility::{build_tree_update, dispatch_action_request};
+use crate::events::{
+    ClickTracker, CraftMessage, DragState, EventDispatchType, FileDragEvent, FileDragState, KeyboardInput, MouseWheel,
+    PointerButton, PointerId, PointerMoved,
+};
 pub use crate::options::RendererType;
 use crate::reactive::element_state_store::ElementStateStore;
 use crate::style::{Display, Unit, Wrap};
@@ -41,6 +47,7 @@ use events::internal::InternalMessage;
 use events::resource_event::ResourceEvent;
 use events::update_queue_entry::UpdateQueueEntry;
 use events::Message;
+use reactive::animation::animate;
 use reactive::element_id::reset_unique_element_id;
 use reactive::tree::{diff_trees, ComponentTreeNode};
 use renderer::renderer::Renderer;
@@ -69,8 +76,10 @@ use std::any::Any;
 use std::collections::{HashMap, HashSet, VecDeque};
 use std::future::Future;
 use std::pin::Pin;
+use std::sync::atomic::{AtomicU64, Ordering};
 use std::sync::Arc;
 
+use accesskit::ActionRequest;
 use cfg_if::cfg_if;
 use craft_logging::{info, span, Level};
 #[cfg(not(target_arch = "wasm32"))]
@@ -100,17 +109,69 @@ struct ReactiveTree {
     component_tree: Option<ComponentTreeNode>,
     element_ids: HashSet<ComponentId>,
     component_ids: HashSet<ComponentId>,
-    /// Stores a pointer device id and their pointer captured element.
-    pointer_captures: HashMap<i64, ComponentId>,
+    /// Stores a pointer and their pointer captured element.
+    pointer_captures: HashMap<PointerId, ComponentId>,
+    /// The last pointer that was the primary pointer of its device.
+    primary_pointer: PointerId,
+    /// The element that has the focus.
+    focus: Option<ComponentId>,
+    /// The topmost modal element of the element tree, which the events stay inside of.
+    modal: Option<Modal>,
+    /// The modifier keys that are held down.
+    modifiers: Modifiers,
+    /// The elements under each pointer, from the innermost to the root.
+    hovered: HashMap<PointerId, Vec<ComponentId>>,
+    /// Counts the clicks of each pointer for the click events.
+    clicks: HashMap<PointerId, ClickTracker>,
+    /// The element that is pressed or dragged with the pointer, if it is draggable.
+    drag: Option<DragState>,
+    /// The files of the operating system that are dragged over the window.
+    file_drag: Option<FileDragState>,
+    /// The time that the events happen at, or the current time if none is set.
+    event_time: Option<time::Instant>,
+    /// The longest time between two clicks that still counts them as a double click, or the default of the platform
+    /// if none is set.
+    double_click_interval: Option<time::Duration>,
     update_queue: VecDeque<UpdateQueueEntry>,
     user_state: StateStore,
     element_state: ElementStateStore,
 }
 
+/// Identifies a window of the application, see [`WindowContext::open_window`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct WindowId(u64);
+
+impl WindowId {
+    /// The window that the application starts with. Closing it exits the application.
+    pub const PRIMARY: WindowId = WindowId(0);
+
+    fn next() -> WindowId {
+        static NEXT_WINDOW_ID: AtomicU64 = AtomicU64::new(1);
+        WindowId(NEXT_WINDOW_ID.fetch_add(1, Ordering::Relaxed))
+    }
+}
+
+/// A window that was requested with [`WindowContext::open_window`].
+#[derive(Clone)]
+pub(crate) struct WindowRequest {
+    id: WindowId,
+    root: ComponentSpecification,
+    options: WindowOptions,
+    /// The component that requested the window, which is told when the user closes it.
+    opener: Option<ComponentId>,
+}
+
+impl std::fmt::Debug for WindowRequest {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("WindowRequest").field("id", &self.id).field("options", &self.options).finish_non_exhaustive()
+    }
+}
+
 #[derive(Debug, Clone)]
 /// User-level API to get and set common window properties.
 /// All values are in logical pixels.
 pub struct WindowContext {
+    id: WindowId,
     window_size: Size<f32>,
     mouse_position: Option<Point>,
     cursor: Option<Cursor>,
@@ -120,11 +181,18 @@ pub struct WindowContext {
     requested_mouse_position_x: Option<f32>,
     requested_mouse_position_y: Option<f32>,
     requested_cursor: Option<Cursor>,
+    requested_windows: Vec<WindowRequest>,
+    requested_window_closes: Vec<WindowId>,
 }
 
 impl WindowContext {
     pub(crate) fn new() -> WindowContext {
+        Self::for_window(WindowId::PRIMARY)
+    }
+
+    pub(crate) fn for_window(id: WindowId) -> WindowContext {
         Self {
+            id,
             window_size: Default::default(),
             mouse_position: None,
             cursor: None,
@@ -133,9 +201,16 @@ impl WindowContext {
             requested_mouse_position_x: None,
             requested_mouse_position_y: None,
             requested_cursor: None,
+            requested_windows: Vec::new(),
+            requested_window_closes: Vec::new(),
         }
     }
 
+    /// The window that this context belongs to.
+    pub fn window_id(&self) -> WindowId {
+        self.id
+    }
+
     pub fn cursor(&self) -> Option<Cursor> {
         self.cursor.clone()
     }
@@ -174,9 +249,37 @@ impl WindowContext {
     pub fn set_cursor(&mut self, cursor: Cursor) {
         self.requested_cursor = Some(cursor);
     }
-    
+
+    /// Opens a new window that displays `root`, which shares the global state with the other windows.
+    ///
+    /// The window is created once the event has been handled. The returned id can be used to close it.
+    pub fn open_window(&mut self, root: ComponentSpecification, options: WindowOptions) -> WindowId {
+        let id = WindowId::next();
+        self.requested_windows.push(WindowRequest {
+            id,
+            root,
+            options,
+            opener: None,
+        });
+        id
+    }
+
+    /// Closes the window with the given id once the event has been handled. Closing the primary window exits the
+    /// application.
+    pub fn close_window(&mut self, id: WindowId) {
+        self.requested_window_closes.push(id);
+    }
+
+    /// Makes `component_id` the opener of the windows that were requested since the last call.
+    pub(crate) fn set_window_opener(&mut self, component_id: ComponentId) {
+        for request in self.requested_windows.iter_mut().filter(|request| request.opener.is_none()) {
+            request.opener = Some(component_id);
+        }
+    }
+
     pub(crate) fn reset(&mut self) {
         *self = WindowContext {
+            id: self.id,
             window_size: self.window_size,
             mouse_position: self.mouse_position,
             cursor: None,
@@ -186,6 +289,8 @@ impl WindowContext {
             requested_mouse_position_x: None,
             requested_mouse_position_y: None,
             requested_cursor: None,
+            requested_windows: Vec::new(),
+            requested_window_closes: Vec::new(),
         }
     }
 }
@@ -198,23 +303,36 @@ pub(crate) fn get_scale_factor(window: &Option<Arc<dyn Window>>) -> f64 {
     }
 }
 
+/// The state that the windows of the application share.
 struct App {
-    app: ComponentSpecification,
     global_state: GlobalState,
-    window: Option<Arc<dyn Window>>,
     text_context: Option<TextContext>,
-    renderer: Option<Box<dyn Renderer + Send>>,
-    mouse_position: Option<Point>,
-    reload_fonts: bool,
     resource_manager: Arc<ResourceManager>,
     /// Resources that have already been collected.
     /// We use this in view_introspection, so that we don't request the download
     /// of a resource too many times.
     resources_collected: HashMap<ResourceIdentifier, bool>,
     winit_sender: Sender<AppMessage>,
+    /// The open windows, starting with the primary window.
+    windows: HashMap<WindowId, AppWindow>,
+    /// The double click interval of the [`CraftOptions`], which every window uses.
+    double_click_interval: Option<time::Duration>,
+}
+
+/// A window of the application and the component tree that it displays.
+struct AppWindow {
+    app: ComponentSpecification,
+    window: Option<Arc<dyn Window>>,
+    renderer: Option<Box<dyn Renderer + Send>>,
+    mouse_position: Option<Point>,
+    reload_fonts: bool,
 
     user_tree: ReactiveTree,
     window_context: WindowContext,
+    /// The window and the component that opened this window, which are told when the user closes it.
+    opener: Option<(WindowId, ComponentId)>,
+    /// Whether an assistive technology is observing the accessibility tree.
+    is_accessibility_active: bool,
 
     #[cfg(feature = "dev_tools")]
     is_dev_tools_open: bool,
@@ -247,6 +365,52 @@ impl App {
     }
 }
 
+impl AppWindow {
+    fn new(id: WindowId, app: ComponentSpecification, double_click_interval: Option<time::Duration>) -> Self {
+        let mut user_state = StateStore::default();
+
+        let dummy_root_value: Box<StateStoreItem> = Box::new(());
+        user_state.storage.insert(0, dummy_root_value);
+
+        #[cfg(feature = "dev_tools")]
+        let mut dev_tools_user_state = StateStore::default();
+        #[cfg(feature = "dev_tools")]
+        dev_tools_user_state.storage.insert(0, Box::new(()));
+
+        AppWindow {
+            app,
+            window: None,
+            renderer: None,
+            mouse_position: None,
+            reload_fonts: false,
+            user_tree: ReactiveTree {
+                user_state,
+                double_click_interval,
+                ..Default::default()
+            },
+            window_context: WindowContext::for_window(id),
+            opener: None,
+            is_accessibility_active: false,
+
+            #[cfg(feature = "dev_tools")]
+            is_dev_tools_open: false,
+
+            #[cfg(feature = "dev_tools")]
+            dev_tree: ReactiveTree {
+                user_state: dev_tools_user_state,
+                double_click_interval,
+                ..Default::default()
+            },
+        }
+    }
+
+    fn request_redraw(&self) {
+        if let Some(window) = self.window.as_ref() {
+            window.request_redraw();
+        }
+    }
+}
+
 #[cfg(target_os = "android")]
 pub fn internal_craft_main_with_options(
     application: ComponentSpecification,
@@ -272,7 +436,7 @@ use crate::reactive::state_store::{StateStore, StateStoreItem};
 use crate::resource_manager::resource_type::ResourceType;
 use crate::view_introspection::scan_view_for_resources;
 use craft_winit_state::CraftWinitState;
-use crate::events::event_dispatch::dispatch_event;
+use crate::events::event_dispatch::{dispatch_event, dispatch_file_drag, Modal};
 use crate::renderer::renderer::RenderList;
 use crate::resource_manager::ResourceIdentifier;
 use crate::text::text_context::TextContext;
@@ -367,8 +531,15 @@ fn craft_main_with_options_2(
     let app_sender_copy = app_sender.clone();
     let resource_manager_copy = resource_manager.clone();
 
-    let future =
-        async_main(application, app_receiver, winit_sender, app_sender_copy, resource_manager_copy, global_state);
+    let future = async_main(
+        application,
+        app_receiver,
+        winit_sender,
+        app_sender_copy,
+        resource_manager_copy,
+        global_state,
+        craft_options.double_click_interval,
+    );
 
     runtime.runtime_spawn(future);
 
@@ -395,52 +566,20 @@ async fn async_main(
     mut app_sender: Sender<AppMessage>,
     resource_manager: Arc<ResourceManager>,
     global_state: GlobalState,
+    double_click_interval: Option<time::Duration>,
 ) {
-    let mut user_state = StateStore::default();
-
-    let dummy_root_value: Box<StateStoreItem> = Box::new(());
-    user_state.storage.insert(0, dummy_root_value);
-
-    let mut dev_tools_user_state = StateStore::default();
-    dev_tools_user_state.storage.insert(0, Box::new(()));
+    let mut windows = HashMap::new();
+    let primary_window = AppWindow::new(WindowId::PRIMARY, component_spec_application, double_click_interval);
+    windows.insert(WindowId::PRIMARY, primary_window);
 
     let mut app = Box::new(App {
-        app: component_spec_application,
         global_state,
-        window: None,
         text_context: None,
-        renderer: None,
-        window_context: WindowContext::new(),
         resource_manager,
         resources_collected: Default::default(),
         winit_sender: winit_sender.clone(),
-        reload_fonts: false,
-        user_tree: ReactiveTree {
-            element_tree: None,
-            component_tree: None,
-            element_ids: Default::default(),
-            component_ids: Default::default(),
-            pointer_captures: Default::default(),
-            update_queue: VecDeque::new(),
-            user_state,
-            element_state: Default::default(),
-        },
-
-        #[cfg(feature = "dev_tools")]
-        is_dev_tools_open: false,
-
-        #[cfg(feature = "dev_tools")]
-        dev_tree: ReactiveTree {
-            element_tree: None,
-            component_tree: None,
-            update_queue: VecDeque::new(),
-            user_state: dev_tools_user_state,
-            element_state: Default::default(),
-            element_ids: Default::default(),
-            component_ids: Default::default(),
-            pointer_captures: Default::default(),
-        },
-        mouse_position: None,
+        windows,
+        double_click_interval,
     });
 
     info!("starting main event loop");
@@ -450,8 +589,8 @@ async fn async_main(
             dummy_message.blocking = app_message.blocking;
 
             match app_message.data {
-                InternalMessage::RequestRedraw(scale_factor, surface_size) => {
-                    on_request_redraw(&mut app, scale_factor, surface_size).await;
+                InternalMessage::RequestRedraw(window_id, scale_factor, surface_size) => {
+                    on_request_redraw(&mut app, window_id, scale_factor, surface_size).await;
                     send_response(dummy_message, &mut app.winit_sender).await;
                 }
                 InternalMessage::Close => {
@@ -461,49 +600,63 @@ async fn async_main(
                     break;
                 }
                 InternalMessage::Confirmation => {}
-                InternalMessage::Resume(window, renderer) => {
-                    on_resume(&mut app, window.clone(), renderer).await;
+                InternalMessage::Resume(window_id, window, renderer) => {
+                    on_resume(&mut app, window_id, window.clone(), renderer).await;
+                    send_response(dummy_message, &mut app.winit_sender).await;
+                }
+                InternalMessage::Resize(window_id, new_size) => {
+                    on_resize(&mut app, window_id, new_size).await;
                     send_response(dummy_message, &mut app.winit_sender).await;
                 }
-                InternalMessage::Resize(new_size) => {
-                    on_resize(&mut app, new_size).await;
+                InternalMessage::MouseWheel(window_id, mouse_wheel) => {
+                    on_mouse_wheel(&mut app, window_id, mouse_wheel).await;
                     send_response(dummy_message, &mut app.winit_sender).await;
                 }
-                InternalMessage::MouseWheel(mouse_wheel) => {
-                    on_mouse_wheel(&mut app, mouse_wheel).await;
+                InternalMessage::PointerButton(window_id, pointer_button) => {
+                    on_pointer_button(&mut app, window_id, pointer_button).await;
                     send_response(dummy_message, &mut app.winit_sender).await;
                 }
-                InternalMessage::PointerButton(pointer_button) => {
-                    on_pointer_button(&mut app, pointer_button).await;
+                InternalMessage::PointerMoved(window_id, pointer_moved) => {
+                    on_pointer_moved(&mut app, window_id, pointer_moved.clone()).await;
                     send_response(dummy_message, &mut app.winit_sender).await;
                 }
-                InternalMessage::PointerMoved(pointer_moved) => {
-                    on_pointer_moved(&mut app, pointer_moved.clone()).await;
+                InternalMessage::Ime(window_id, ime) => {
+                    on_ime(&mut app, window_id, ime.clone()).await;
                     send_response(dummy_message, &mut app.winit_sender).await;
                 }
-                InternalMessage::Ime(ime) => {
-                    on_ime(&mut app, ime.clone()).await;
+                InternalMessage::FileDrag(window_id, file_drag_event) => {
+                    on_file_drag(&mut app, window_id, file_drag_event.clone()).await;
                     send_response(dummy_message, &mut app.winit_sender).await;
                 }
                 InternalMessage::ProcessUserEvents => {
-                    on_process_user_events(app.window.clone(), &mut app_sender, &mut app.user_tree);
-                    #[cfg(feature = "dev_tools")]
-                    on_process_user_events(app.window.clone(), &mut app_sender, &mut app.dev_tree);
+                    for (&window_id, window) in app.windows.iter_mut() {
+                        on_process_user_events(window_id, window.window.clone(), &mut app_sender, &mut window.user_tree);
+                        #[cfg(feature = "dev_tools")]
+                        on_process_user_events(window_id, window.window.clone(), &mut app_sender, &mut window.dev_tree);
+                    }
                 }
                 InternalMessage::GotUserMessage(message) => {
-                    let update_fn = message.0;
-                    let source_component = message.1;
-                    let props = message.3;
-                    let message = message.2;
-
-                    let state = app.user_tree.user_state.storage.get_mut(&source_component).unwrap().as_mut();
-
-                    let mut event = Event::with_window_context(app.window_context.clone());
-
-                    update_fn(state, &mut app.global_state, props, &mut event, &Message::UserMessage(message));
-                    app.window_context = event.window;
-
-                    app.window.as_ref().unwrap().request_redraw();
+                    let window_id = message.0;
+                    let update_fn = message.1;
+                    let source_component = message.2;
+                    let props = message.4;
+                    let message = message.3;
+
+                    // The window may have been closed while the update was running.
+                    let Some(window) = app.windows.get_mut(&window_id) else {
+                        continue;
+                    };
+                    let Some(state) = window.user_tree.user_state.storage.get_mut(&source_component) else {
+                        continue;
+                    };
+
+                    let mut event = Event::with_window_context(window.window_context.clone());
+
+                    update_fn(state.as_mut(), &mut app.global_state, props, &mut event, &Message::UserMessage(message));
+                    event.window.set_window_opener(source_component);
+                    window.window_context = event.window;
+
+                    window.request_redraw();
                 }
                 InternalMessage::ResourceEvent(resource_event) => {
                     let resource_manager = &mut app.resource_manager;
@@ -518,30 +671,56 @@ async fn async_main(
                                     }
                                 }
 
-                                app.reload_fonts = true;
-                                app.window.as_ref().unwrap().request_redraw();
+                                for window in app.windows.values_mut() {
+                                    window.reload_fonts = true;
+                                    window.request_redraw();
+                                }
                             } else if resource_type == ResourceType::Image || resource_type == ResourceType::TinyVg {
                                 resource_manager.resources.insert(resource_identifier, Arc::new(resource));
-                                app.window.as_ref().unwrap().request_redraw();
+                                for window in app.windows.values() {
+                                    window.request_redraw();
+                                }
                             }
                         }
                         ResourceEvent::UnLoaded(_) => {}
                     }
                 }
-                InternalMessage::KeyboardInput(keyboard_input) => {
-                    on_keyboard_input(&mut app, keyboard_input).await;
+                InternalMessage::KeyboardInput(window_id, keyboard_input) => {
+                    on_keyboard_input(&mut app, window_id, keyboard_input).await;
                     send_response(dummy_message, &mut app.winit_sender).await;
                 }
-                InternalMessage::ModifiersChanged(modifiers) => {
-                    on_modifiers_input(&mut app, modifiers).await;
+                InternalMessage::ModifiersChanged(window_id, modifiers) => {
+                    on_modifiers_input(&mut app, window_id, modifiers).await;
                     send_response(dummy_message, &mut app.winit_sender).await;
                 }
+                InternalMessage::AccessibilityActivated(window_id) => {
+                    if let Some(window) = app.windows.get_mut(&window_id) {
+                        window.is_accessibility_active = true;
+                    }
+                }
+                InternalMessage::AccessibilityDeactivated(window_id) => {
+                    if let Some(window) = app.windows.get_mut(&window_id) {
+                        window.is_accessibility_active = false;
+                    }
+                }
+                InternalMessage::AccessibilityAction(window_id, action_request) => {
+                    on_accessibility_action(&mut app, window_id, action_request).await;
+                }
+                InternalMessage::AccessibilityTreeUpdate(..) => {}
+                InternalMessage::CreateWindow(..) => {}
+                InternalMessage::CloseWindow(window_id) => {
+                    // The user closed a secondary window.
+                    if let Some(opener) = app.windows.remove(&window_id).and_then(|window| window.opener) {
+                        on_window_closed(&mut app, window_id, opener);
+                    }
+                }
             }
         }
     }
 }
 
 fn on_process_user_events(
+    window_id: WindowId,
     window: Option<Arc<dyn Window>>,
     app_sender: &mut Sender<AppMessage>,
     reactive_tree: &mut ReactiveTree,
@@ -559,7 +738,13 @@ fn on_process_user_events(
             app_sender_copy
                 .send(AppMessage::new(
                     0,
-                    InternalMessage::GotUserMessage((event.update_function, event.source_component, res, event.props)),
+                    InternalMessage::GotUserMessage((
+                        window_id,
+                        event.update_function,
+                        event.source_component,
+                        res,
+                        event.props,
+                    )),
                 ))
                 .await
                 .expect("send failed");
@@ -569,24 +754,30 @@ fn on_process_user_events(
     }
 }
 
-async fn on_pointer_moved(app: &mut Box<App>, mouse_moved: PointerMoved) {
-    app.mouse_position = Some(Point::new(mouse_moved.position.x, mouse_moved.position.y));
+async fn on_pointer_moved(app: &mut Box<App>, window_id: WindowId, mouse_moved: PointerMoved) {
+    let Some(window) = app.windows.get_mut(&window_id) else {
+        return;
+    };
+    // The other fingers of a touch do not move the mouse.
+    if mouse_moved.primary {
+        window.mouse_position = Some(Point::new(mouse_moved.position.x, mouse_moved.position.y));
+
+        let scale_factor = get_scale_factor(&window.window);
+        let logical_mouse_position: LogicalPosition<f32> = LogicalPosition::from_physical(PhysicalPosition::new(mouse_moved.position.x, mouse_moved.position.y), scale_factor);
+        window.window_context.mouse_position = Some(Point::new(logical_mouse_position.x, logical_mouse_position.y));
+    }
 
-    let scale_factor = get_scale_factor(&app.window);
-    let logical_mouse_position: LogicalPosition<f32> = LogicalPosition::from_physical(PhysicalPosition::new(mouse_moved.position.x, mouse_moved.position.y), scale_factor);
-    app.window_context.mouse_position = Some(Point::new(logical_mouse_position.x, logical_mouse_position.y));
-    
     let message = Message::CraftMessage(CraftMessage::PointerMovedEvent(mouse_moved));
 
     dispatch_event(
         &message,
         EventDispatchType::Bubbling,
         &mut app.resource_manager,
-        app.mouse_position,
-        &mut app.user_tree,
+        window.mouse_position,
+        &mut window.user_tree,
         &mut app.global_state,
         &mut app.text_context,
-        &mut app.window_context,
+        &mut window.window_context,
         true,
     );
 
@@ -595,20 +786,21 @@ async fn on_pointer_moved(app: &mut Box<App>, mouse_moved: PointerMoved) {
         &message,
         EventDispatchType::Bubbling,
         &mut app.resource_manager,
-        app.mouse_position,
-        &mut app.dev_tree,
+        window.mouse_position,
+        &mut window.dev_tree,
         &mut app.global_state,
         &mut app.text_context,
-        &mut app.window_context,
+        &mut window.window_context,
         true,
     );
 
-    if let Some(window) = app.window.as_ref() {
-        window.request_redraw();
-    }
+    window.request_redraw();
 }
 
-async fn on_mouse_wheel(app: &mut Box<App>, mouse_wheel: MouseWheel) {
+async fn on_mouse_wheel(app: &mut Box<App>, window_id: WindowId, mouse_wheel: MouseWheel) {
+    let Some(window) = app.windows.get_mut(&window_id) else {
+        return;
+    };
     let event = CraftMessage::MouseWheelEvent(mouse_wheel);
     let message = Message::CraftMessage(event);
 
@@ -616,11 +808,11 @@ async fn on_mouse_wheel(app: &mut Box<App>, mouse_wheel: MouseWheel) {
         &message,
         EventDispatchType::Bubbling,
         &mut app.resource_manager,
-        app.mouse_position,
-        &mut app.user_tree,
+        window.mouse_position,
+        &mut window.user_tree,
         &mut app.global_state,
         &mut app.text_context,
-        &mut app.window_context,
+        &mut window.window_context,
         false,
     );
 
@@ -629,18 +821,21 @@ async fn on_mouse_wheel(app: &mut Box<App>, mouse_wheel: MouseWheel) {
         &message,
         EventDispatchType::Bubbling,
         &mut app.resource_manager,
-        app.mouse_position,
-        &mut app.dev_tree,
+        window.mouse_position,
+        &mut window.dev_tree,
         &mut app.global_state,
         &mut app.text_context,
-        &mut app.window_context,
+        &mut window.window_context,
         false,
     );
 
-    app.window.as_ref().unwrap().request_redraw();
+    window.request_redraw();
 }
 
-async fn on_ime(app: &mut Box<App>, ime: Ime) {
+async fn on_ime(app: &mut Box<App>, window_id: WindowId, ime: Ime) {
+    let Some(window) = app.windows.get_mut(&window_id) else {
+        return;
+    };
     let event = CraftMessage::ImeEvent(ime);
     let message = Message::CraftMessage(event);
 
@@ -648,11 +843,11 @@ async fn on_ime(app: &mut Box<App>, ime: Ime) {
         &message,
         EventDispatchType::Bubbling,
         &mut app.resource_manager,
-        app.mouse_position,
-        &mut app.user_tree,
+        window.mouse_position,
+        &mut window.user_tree,
         &mut app.global_state,
         &mut app.text_context,
-        &mut app.window_context,
+        &mut window.window_context,
         false,
     );
 
@@ -661,29 +856,49 @@ async fn on_ime(app: &mut Box<App>, ime: Ime) {
         &message,
         EventDispatchType::Bubbling,
         &mut app.resource_manager,
-        app.mouse_position,
-        &mut app.dev_tree,
+        window.mouse_position,
+        &mut window.dev_tree,
         &mut app.global_state,
         &mut app.text_context,
-        &mut app.window_context,
+        &mut window.window_context,
         false,
     );
 
-    app.window.as_ref().unwrap().request_redraw();
+    window.request_redraw();
+}
+
+async fn on_file_drag(app: &mut Box<App>, window_id: WindowId, file_drag_event: FileDragEvent) {
+    let Some(window) = app.windows.get_mut(&window_id) else {
+        return;
+    };
+
+    dispatch_file_drag(
+        file_drag_event,
+        &mut app.resource_manager,
+        &mut window.user_tree,
+        &mut app.global_state,
+        &mut app.text_context,
+        &mut window.window_context,
+    );
+
+    window.request_redraw();
 }
 
-async fn on_modifiers_input(app: &mut Box<App>, modifiers: Modifiers) {
+async fn on_modifiers_input(app: &mut Box<App>, window_id: WindowId, modifiers: Modifiers) {
+    let Some(window) = app.windows.get_mut(&window_id) else {
+        return;
+    };
     let modifiers_event = CraftMessage::ModifiersChangedEvent(modifiers);
     let message = Message::CraftMessage(modifiers_event);
     dispatch_event(
         &message,
         EventDispatchType::Bubbling,
         &mut app.resource_manager,
-        app.mouse_position,
-        &mut app.user_tree,
+        window.mouse_position,
+        &mut window.user_tree,
         &mut app.global_state,
         &mut app.text_context,
-        &mut app.window_context,
+        &mut window.window_context,
         false,
     );
 
@@ -693,18 +908,21 @@ async fn on_modifiers_input(app: &mut Box<App>, modifiers: Modifiers) {
             &message,
             EventDispatchType::Bubbling,
             &mut app.resource_manager,
-            app.mouse_position,
-            &mut app.dev_tree,
+            window.mouse_position,
+            &mut window.dev_tree,
             &mut app.global_state,
             &mut app.text_context,
-            &mut app.window_context,
+            &mut window.window_context,
             false,
         );
     }
-    app.window.as_ref().unwrap().request_redraw();
+    window.request_redraw();
 }
 
-async fn on_keyboard_input(app: &mut Box<App>, keyboard_input: KeyboardInput) {
+async fn on_keyboard_input(app: &mut Box<App>, window_id: WindowId, keyboard_input: KeyboardInput) {
+    let Some(window) = app.windows.get_mut(&window_id) else {
+        return;
+    };
     let keyboard_event = CraftMessage::KeyboardInputEvent(keyboard_input.clone());
     let message = Message::CraftMessage(keyboard_event);
 
@@ -712,11 +930,11 @@ async fn on_keyboard_input(app: &mut Box<App>, keyboard_input: KeyboardInput) {
         &message,
         EventDispatchType::Bubbling,
         &mut app.resource_manager,
-        app.mouse_position,
-        &mut app.user_tree,
+        window.mouse_position,
+        &mut window.user_tree,
         &mut app.global_state,
         &mut app.text_context,
-        &mut app.window_context,
+        &mut window.window_context,
         false,
     );
 
@@ -726,11 +944,11 @@ async fn on_keyboard_input(app: &mut Box<App>, keyboard_input: KeyboardInput) {
             &message,
             EventDispatchType::Bubbling,
             &mut app.resource_manager,
-            app.mouse_position,
-            &mut app.dev_tree,
+            window.mouse_position,
+            &mut window.dev_tree,
             &mut app.global_state,
             &mut app.text_context,
-            &mut app.window_context,
+            &mut window.window_context,
             false,
         );
 
@@ -739,47 +957,71 @@ async fn on_keyboard_input(app: &mut Box<App>, keyboard_input: KeyboardInput) {
 
         if key_state.is_pressed() {
             if let Key::Named(NamedKey::F12) = logical_key {
-                app.is_dev_tools_open = !app.is_dev_tools_open;
+                window.is_dev_tools_open = !window.is_dev_tools_open;
             }
         }
     }
-    app.window.as_ref().unwrap().request_redraw();
+    window.request_redraw();
+}
+
+async fn on_accessibility_action(app: &mut Box<App>, window_id: WindowId, action_request: ActionRequest) {
+    let Some(window) = app.windows.get_mut(&window_id) else {
+        return;
+    };
+    dispatch_action_request(
+        action_request,
+        &mut app.resource_manager,
+        &mut window.user_tree,
+        &mut app.global_state,
+        &mut app.text_context,
+        &mut window.window_context,
+    );
+
+    window.request_redraw();
 }
 
-async fn on_resize(app: &mut Box<App>, new_size: PhysicalSize<u32>) {
-    let scale_factor = get_scale_factor(&app.window);
+async fn on_resize(app: &mut Box<App>, window_id: WindowId, new_size: PhysicalSize<u32>) {
+    let Some(window) = app.windows.get_mut(&window_id) else {
+        return;
+    };
+    let scale_factor = get_scale_factor(&window.window);
     let window_size: LogicalSize<f32> = new_size.to_logical(scale_factor);
-    app.window_context.window_size = Size::new(window_size.width, window_size.height);
-    if let Some(renderer) = app.renderer.as_mut() {
+    window.window_context.window_size = Size::new(window_size.width, window_size.height);
+    if let Some(renderer) = window.renderer.as_mut() {
         renderer.resize_surface(new_size.width.max(1) as f32, new_size.height.max(1) as f32);
     }
 
     // On macOS the window needs to be redrawn manually after resizing
     #[cfg(target_os = "macos")]
     {
-        app.window.as_ref().unwrap().request_redraw();
+        window.request_redraw();
     }
 }
 
-async fn on_pointer_button(app: &mut Box<App>, pointer_button: PointerButton) {
+async fn on_pointer_button(app: &mut Box<App>, window_id: WindowId, pointer_button: PointerButton) {
+    let Some(window) = app.windows.get_mut(&window_id) else {
+        return;
+    };
     let event = CraftMessage::PointerButtonEvent(pointer_button);
     let message = Message::CraftMessage(event);
 
-    app.mouse_position = Some(Point::new(pointer_button.position.x, pointer_button.position.y));
+    if pointer_button.primary {
+        window.mouse_position = Some(Point::new(pointer_button.position.x, pointer_button.position.y));
+
+        let scale_factor = get_scale_factor(&window.window);
+        let logical_mouse_position: LogicalPosition<f32> = LogicalPosition::from_physical(PhysicalPosition::new(pointer_button.position.x, pointer_button.position.y), scale_factor);
+        window.window_context.mouse_position = Some(Point::new(logical_mouse_position.x, logical_mouse_position.y));
+    }
 
-    let scale_factor = get_scale_factor(&app.window);
-    let logical_mouse_position: LogicalPosition<f32> = LogicalPosition::from_physical(PhysicalPosition::new(pointer_button.position.x, pointer_button.position.y), scale_factor);
-    app.window_context.mouse_position = Some(Point::new(logical_mouse_position.x, logical_mouse_position.y));
-    
     dispatch_event(
         &message,
         EventDispatchType::Bubbling,
         &mut app.resource_manager,
-        app.mouse_position,
-        &mut app.user_tree,
+        window.mouse_position,
+        &mut window.user_tree,
         &mut app.global_state,
         &mut app.text_context,
-        &mut app.window_context,
+        &mut window.window_context,
         true,
     );
 
@@ -788,38 +1030,54 @@ async fn on_pointer_button(app: &mut Box<App>, pointer_button: PointerButton) {
         &message,
         EventDispatchType::Bubbling,
         &mut app.resource_manager,
-        app.mouse_position,
-        &mut app.dev_tree,
+        window.mouse_position,
+        &mut window.dev_tree,
         &mut app.global_state,
         &mut app.text_context,
-        &mut app.window_context,
+        &mut window.window_context,
         false,
     );
 
-    app.window.as_ref().unwrap().request_redraw();
+    window.request_redraw();
 }
 
-async fn on_resume(app: &mut App, window: Arc<dyn Window>, renderer: Option<Box<dyn Renderer + Send>>) {
-    if app.user_tree.element_tree.is_none() {
+async fn on_resume(
+    app: &mut App,
+    window_id: WindowId,
+    window: Arc<dyn Window>,
+    renderer: Option<Box<dyn Renderer + Send>>,
+) {
+    app.setup_text_context();
+
+    let Some(app_window) = app.windows.get_mut(&window_id) else {
+        // The window was closed by a component before winit created it.
+        app.winit_sender
+            .send(AppMessage::new(0, InternalMessage::CloseWindow(window_id)))
+            .await
+            .expect("send failed");
+        return;
+    };
+
+    // Secondary windows share the element id counter with the primary window, so it may only be reset at startup.
+    if window_id == WindowId::PRIMARY && app_window.user_tree.element_tree.is_none() {
         reset_unique_element_id();
         //let new_view = app.app.view();
         //app.element_tree = Some(new_view);
     }
 
-    app.setup_text_context();
     if renderer.is_some() {
-        app.renderer = renderer;
+        app_window.renderer = renderer;
 
         // We can't guarantee the order of events on wasm.
         // This ensures a resize is not missed if the renderer was not finished creating when resize is called.
         #[cfg(target_arch = "wasm32")]
-        app.renderer
+        app_window.renderer
             .as_mut()
             .unwrap()
             .resize_surface(window.surface_size().width as f32, window.surface_size().height as f32);
     }
 
-    app.window = Some(window.clone());
+    app_window.window = Some(window.clone());
 }
 
 #[allow(clippy::too_many_arguments)]
@@ -864,25 +1122,32 @@ async fn update_reactive_tree(
         resources_collected,
     )
     .await;
+    reactive_tree.modal = Modal::find(new_tree.element_tree.internal.as_ref());
     reactive_tree.element_tree = Some(new_tree.element_tree.internal);
     reactive_tree.component_tree = Some(new_tree.component_tree);
     reactive_tree.component_ids = new_tree.component_ids;
     reactive_tree.element_ids = new_tree.element_ids;
     reactive_tree.pointer_captures = new_tree.pointer_captures;
+    // The focused element may have been unmounted.
+    reactive_tree.focus = reactive_tree.focus.filter(|focus| reactive_tree.element_ids.contains(focus));
 }
 
+/// Lays out and draws the tree with its animations at `now`.
+///
+/// Returns whether an animation is still running, in which case another frame should be drawn.
 #[allow(clippy::too_many_arguments)]
 async fn draw_reactive_tree(
     reactive_tree: &mut ReactiveTree,
     resource_manager: Arc<ResourceManager>,
-    renderer: &mut Box<dyn Renderer + Send>,
+    renderer: &mut (dyn Renderer + Send),
     viewport_size: Size<f32>,
     origin: Point,
     text_context: &mut TextContext,
     scale_factor: f64,
     mouse_position: Option<Point>,
     window: Option<Arc<dyn Window>>,
-) {
+    now: time::Instant,
+) -> bool {
     let root = reactive_tree.element_tree.as_mut().unwrap();
 
     let mut root_size = viewport_size;
@@ -895,6 +1160,7 @@ async fn draw_reactive_tree(
     }
 
     style_root_element(root, root_size);
+    let is_animating = animate(root.as_mut(), &mut reactive_tree.element_state, now);
 
     let (mut taffy_tree, taffy_root) = {
         let span = span!(Level::INFO, "layout");
@@ -912,8 +1178,6 @@ async fn draw_reactive_tree(
         )
     };
 
-    let renderer = renderer.as_mut();
-
     {
         let span = span!(Level::INFO, "render");
         let _enter = span.enter();
@@ -937,68 +1201,98 @@ async fn draw_reactive_tree(
         };
         renderer.prepare_render_list(render_list, resource_manager, window);
     }
+
+    is_animating
 }
 
-async fn on_request_redraw(app: &mut App, scale_factor: f64, surface_size: Size<f32>) {
+async fn on_request_redraw(app: &mut App, window_id: WindowId, scale_factor: f64, surface_size: Size<f32>) {
     if app.text_context.is_none() {
         app.setup_text_context();
     }
     let text_context = app.text_context.as_mut().unwrap();
 
-    let old_element_ids = app.user_tree.element_ids.clone();
-    let old_component_ids = app.user_tree.component_ids.clone();
-    update_reactive_tree(
-        app.app.clone(),
-        &mut app.user_tree,
-        &mut app.global_state,
-        app.resource_manager.clone(),
-        &mut app.reload_fonts,
-        text_context,
-        scale_factor,
-        &mut app.resources_collected,
-        &mut app.window_context
-    )
-    .await;
+    let window_requests = {
+        let Some(window) = app.windows.get_mut(&window_id) else {
+            return;
+        };
+
+        let old_element_ids = window.user_tree.element_ids.clone();
+        let old_component_ids = window.user_tree.component_ids.clone();
+        update_reactive_tree(
+            window.app.clone(),
+            &mut window.user_tree,
+            &mut app.global_state,
+            app.resource_manager.clone(),
+            &mut window.reload_fonts,
+            text_context,
+            scale_factor,
+            &mut app.resources_collected,
+            &mut window.window_context
+        )
+        .await;
 
-    let window_context = &mut app.window_context;
+        let window_context = &mut window.window_context;
 
-    // Handle window requests:
-    if let Some(window) = app.window.clone() {
-        if let Some(requested_cursor) = &window_context.requested_cursor {
-            window.set_cursor(requested_cursor.clone());
-        };
-        
-        if let Some(requested_window_width) = window_context.requested_window_width {
-            let _ = window.request_surface_size(winit::dpi::Size::Logical(LogicalSize::new(requested_window_width as f64, window_context.window_size.height as f64)));
-        };
-        
-        if let Some(requested_window_height) = window_context.requested_window_height {
-            let _ = window.request_surface_size(winit::dpi::Size::Logical(LogicalSize::new(window_context.window_size.width as f64, requested_window_height as f64)));
-        };
-        
-        if let Some(requested_mouse_position_x) = window_context.requested_mouse_position_x {
-            let mouse_y = window_context.requested_mouse_position_y.unwrap_or_default() as f64;
-            let _ = window.set_cursor_position(winit::dpi::Position::Logical(LogicalPosition::new(requested_mouse_position_x as f64, mouse_y)));
-        };
-        
-        if let Some(requested_mouse_position_y) = window_context.requested_mouse_position_y {
-            let mouse_x = window_context.requested_mouse_position_x.unwrap_or_default() as f64;
-            let _ = window.set_cursor_position(winit::dpi::Position::Logical(LogicalPosition::new(mouse_x, requested_mouse_position_y as f64)));
-        };   
-    }
-    
-    // Reset the requested values:
-    window_context.reset();
+        // Handle window requests:
+        if let Some(window) = window.window.clone() {
+            if let Some(requested_cursor) = &window_context.requested_cursor {
+                window.set_cursor(requested_cursor.clone());
+            };
+            
+            if let Some(requested_window_width) = window_context.requested_window_width {
+                let _ = window.request_surface_size(winit::dpi::Size::Logical(LogicalSize::new(requested_window_width as f64, window_context.window_size.height as f64)));
+            };
+            
+            if let Some(requested_window_height) = window_context.requested_window_height {
+                let _ = window.request_surface_size(winit::dpi::Size::Logical(LogicalSize::new(window_context.window_size.width as f64, requested_window_height as f64)));
+            };
+            
+            if let Some(requested_mouse_position_x) = window_context.requested_mouse_position_x {
+                let mouse_y = window_context.requested_mouse_position_y.unwrap_or_default() as f64;
+                let _ = window.set_cursor_position(winit::dpi::Position::Logical(LogicalPosition::new(requested_mouse_position_x as f64, mouse_y)));
+            };
+            
+            if let Some(requested_mouse_position_y) = window_context.requested_mouse_position_y {
+                let mouse_x = window_context.requested_mouse_position_x.unwrap_or_default() as f64;
+                let _ = window.set_cursor_position(winit::dpi::Position::Logical(LogicalPosition::new(mouse_x, requested_mouse_position_y as f64)));
+            };   
+        }
 
-    // Cleanup unmounted components and elements.
-    app.user_tree.user_state.remove_unused_state(&old_component_ids, &app.user_tree.component_ids);
-    app.user_tree.element_state.remove_unused_state(&old_element_ids, &app.user_tree.element_ids);
+        // The windows that were opened or closed are applied once we are done with this window.
+        let window_requests = (
+            std::mem::take(&mut window_context.requested_windows),
+            std::mem::take(&mut window_context.requested_window_closes),
+        );
+
+        // Reset the requested values:
+        window_context.reset();
+
+        // Cleanup unmounted components and elements.
+        window.user_tree.user_state.remove_unused_state(&old_component_ids, &window.user_tree.component_ids);
+        window.user_tree.element_state.remove_unused_state(&old_element_ids, &window.user_tree.element_ids);
+
+        window_requests
+    };
+
+    apply_window_requests(
+        window_id,
+        &mut app.windows,
+        &app.winit_sender,
+        window_requests.0,
+        window_requests.1,
+        app.double_click_interval,
+    )
+    .await;
+
+    let Some(window) = app.windows.get_mut(&window_id) else {
+        return;
+    };
 
-    if app.renderer.is_none() {
+    if window.renderer.is_none() {
         return;
     }
 
-    let renderer = app.renderer.as_mut().unwrap();
+    let renderer = window.renderer.as_mut().unwrap();
 
     cfg_if! {
         if #[cfg(feature = "dev_tools")] {
@@ -1012,57 +1306,126 @@ async fn on_request_redraw(app: &mut App, scale_factor: f64, surface_size: Size<
 
     #[cfg(feature = "dev_tools")]
     {
-        if app.is_dev_tools_open {
+        if window.is_dev_tools_open {
             let dev_tools_size = Size::new(350.0, root_size.height);
             root_size.width -= dev_tools_size.width;
         }
     }
 
-    draw_reactive_tree(
-        &mut app.user_tree,
+    let now = time::Instant::now();
+    #[allow(unused_mut)]
+    let mut is_animating = draw_reactive_tree(
+        &mut window.user_tree,
         app.resource_manager.clone(),
-        renderer,
+        renderer.as_mut(),
         root_size,
         Point::new(0.0, 0.0),
         text_context,
         scale_factor,
-        app.mouse_position,
-        app.window.clone(),
+        window.mouse_position,
+        window.window.clone(),
+        now,
     )
     .await;
 
     #[cfg(feature = "dev_tools")]
     {
-        if app.is_dev_tools_open {
+        if window.is_dev_tools_open {
             update_reactive_tree(
-                dev_tools_view(app.user_tree.element_tree.clone().unwrap()),
-                &mut app.dev_tree,
+                dev_tools_view(window.user_tree.element_tree.clone().unwrap()),
+                &mut window.dev_tree,
                 &mut app.global_state,
                 app.resource_manager.clone(),
-                &mut app.reload_fonts,
+                &mut window.reload_fonts,
                 text_context,
                 scale_factor,
                 &mut app.resources_collected,
-                &mut app.window_context
+                &mut window.window_context
             )
             .await;
 
-            draw_reactive_tree(
-                &mut app.dev_tree,
+            is_animating |= draw_reactive_tree(
+                &mut window.dev_tree,
                 app.resource_manager.clone(),
-                renderer,
+                renderer.as_mut(),
                 Size::new(surface_size.width - root_size.width, root_size.height),
                 Point::new(root_size.width, 0.0),
                 text_context,
                 scale_factor,
-                app.mouse_position,
-                app.window.clone(),
+                window.mouse_position,
+                window.window.clone(),
+                now,
             )
             .await;
         }
     }
 
     renderer.submit(app.resource_manager.clone());
+
+    // Keep drawing frames until the transitions and animations are finished.
+    if is_animating {
+        window.request_redraw();
+    }
+
+    if window.is_accessibility_active {
+        let root = window.user_tree.element_tree.as_ref().unwrap();
+        let tree_update = build_tree_update(root.as_ref(), &window.user_tree.element_state, window.user_tree.focus);
+        app.winit_sender
+            .send(AppMessage::new(0, InternalMessage::AccessibilityTreeUpdate(window_id, tree_update)))
+            .await
+            .expect("send failed");
+    }
+}
+
+/// Tells the component that opened the window `window_id` that the user closed it.
+fn on_window_closed(app: &mut App, window_id: WindowId, opener: (WindowId, ComponentId)) {
+    let (opener_window_id, opener_id) = opener;
+    let Some(window) = app.windows.get_mut(&opener_window_id) else {
+        return;
+    };
+
+    dispatch_event(
+        &Message::CraftMessage(CraftMessage::WindowClosed(window_id)),
+        EventDispatchType::Direct(opener_id),
+        &mut app.resource_manager,
+        window.mouse_position,
+        &mut window.user_tree,
+        &mut app.global_state,
+        &mut app.text_context,
+        &mut window.window_context,
+        false,
+    );
+
+    window.request_redraw();
+}
+
+/// Opens and closes the windows that the components of the window `window_id` asked for, and tells the winit thread
+/// about them.
+async fn apply_window_requests(
+    window_id: WindowId,
+    windows: &mut HashMap<WindowId, AppWindow>,
+    winit_sender: &Sender<AppMessage>,
+    requested_windows: Vec<WindowRequest>,
+    requested_window_closes: Vec<WindowId>,
+    double_click_interval: Option<time::Duration>,
+) {
+    for request in requested_windows {
+        let mut window = AppWindow::new(request.id, request.root, double_click_interval);
+        window.opener = request.opener.map(|opener_id| (window_id, opener_id));
+        windows.insert(request.id, window);
+        winit_sender
+            .send(AppMessage::new(0, InternalMessage::CreateWindow(request.id, request.options)))
+            .await
+            .expect("send failed");
+    }
+
+    for window_id in requested_window_closes {
+        // Closing the primary window closes the application, which the winit thread takes care of.
+        if window_id != WindowId::PRIMARY {
+            windows.remove(&window_id);
+        }
+        winit_sender.send(AppMessage::new(0, InternalMessage::CloseWindow(window_id))).await.expect("send failed");
+    }
 }
 
 fn style_root_element(root: &mut Box<dyn Element>, root_size: Size<f32>) {
@@ -1139,6 +1502,7 @@ fn layout(
         text_context,
         None,
     );
+    root_element.resolve_transform(element_state, peniko::kurbo::Affine::IDENTITY);
 
     // root_element.print_tree();
     // taffy_tree.print_tree(root_node);
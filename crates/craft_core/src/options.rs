@@ -1,5 +1,6 @@
 use std::fmt::{Display, Formatter};
 use crate::geometry::Size;
+use std::time::Duration;
 
 /// Configuration options for the Craft application.
 ///
@@ -17,7 +18,11 @@ pub struct CraftOptions {
     /// Defaults to `"craft"`.
     pub window_title: String,
     /// The initial size of the window.
-    pub window_size: Option<Size<f32>>
+    pub window_size: Option<Size<f32>>,
+    /// The longest time between two clicks that still counts them as a double click.
+    ///
+    /// Defaults to the interval of the platform: 500 milliseconds on Windows and macOS, and 400 milliseconds elsewhere.
+    pub double_click_interval: Option<Duration>,
 }
 
 impl Default for CraftOptions {
@@ -26,6 +31,7 @@ impl Default for CraftOptions {
             renderer: RendererType::default(),
             window_title: "craft".to_string(),
             window_size: None,
+            double_click_interval: None,
         }
     }
 }
@@ -84,7 +90,53 @@ impl CraftOptions {
             renderer: RendererType::default(),
             window_title: title.to_string(),
             window_size: None,
+            double_click_interval: None,
         }
     }
     
 }
+
+/// Configuration options for a window that is opened with [`WindowContext::open_window`].
+///
+/// [`WindowContext::open_window`]: crate::WindowContext::open_window
+#[derive(Clone, Debug)]
+pub struct WindowOptions {
+    /// The title of the window.
+    ///
+    /// Defaults to `"craft"`.
+    pub title: String,
+    /// The initial size of the window.
+    pub size: Option<Size<f32>>,
+}
+
+impl Default for WindowOptions {
+    fn default() -> Self {
+        Self {
+            title: "craft".to_string(),
+            size: None,
+        }
+    }
+}
+
+impl WindowOptions {
+    pub fn basic(title: &str) -> Self {
+        Self {
+            title: title.to_string(),
+            size: None,
+        }
+    }
+
+    pub fn size(mut self, width: f32, height: f32) -> Self {
+        self.size = Some(Size::new(width, height));
+        self
+    }
+}
+
+impl From<&CraftOptions> for WindowOptions {
+    fn from(craft_options: &CraftOptions) -> Self {
+        Self {
+            title: craft_options.window_title.clone(),
+            size: craft_options.window_size,
+        }
+    }
+}
@@ -0,0 +1,107 @@
+//! Applies the transitions and keyframe animations of the elements to their styles before each layout, and advances
+//! the timers of the elements.
+
+use crate::elements::element::Element;
+use crate::reactive::element_state_store::ElementStateStore;
+use crate::style::{interpolate, properties_differ, Style};
+
+#[cfg(target_arch = "wasm32")]
+use web_time as time;
+#[cfg(not(target_arch = "wasm32"))]
+use std::time as time;
+use time::Instant;
+
+/// The progress of the transitions and the keyframe animation of an element.
+#[derive(Clone, Debug, Default)]
+pub(crate) struct AnimationState {
+    /// The style that the transitions were heading to in the last frame.
+    target: Option<Style>,
+    /// The style that was displayed in the last frame, without the keyframe animation.
+    displayed: Option<Style>,
+    /// The running transitions, by the index of their transition on the element.
+    transitions: Vec<Option<RunningTransition>>,
+    animation_start: Option<Instant>,
+}
+
+#[derive(Clone, Debug)]
+struct RunningTransition {
+    from: Style,
+    start: Instant,
+}
+
+/// Replaces the current style of every element in the tree with its animated style at `now`.
+///
+/// Elements are rebuilt for every frame, so this has to run once per frame, before the tree is laid out.
+/// Returns whether any transition, animation or timer is still running, in which case another frame should be drawn.
+pub(crate) fn animate(element: &mut dyn Element, element_state: &mut ElementStateStore, now: Instant) -> bool {
+    let mut is_animating = animate_element(element, element_state, now);
+    is_animating |= element.advance_timers(element_state, now);
+
+    for child in element.children_mut() {
+        is_animating |= animate(child.internal.as_mut(), element_state, now);
+    }
+
+    is_animating
+}
+
+fn animate_element(element: &mut dyn Element, element_state: &mut ElementStateStore, now: Instant) -> bool {
+    let state = match element_state.storage.get_mut(&element.component_id()) {
+        Some(state) => state,
+        None => return false,
+    };
+
+    if element.element_data().transitions.is_empty() && element.element_data().animation.is_none() {
+        state.base.animation = AnimationState::default();
+        return false;
+    }
+
+    // The animated properties are flagged as set, so the default style has to be merged in before we set them.
+    element.merge_default_style();
+
+    let element_data = element.element_data_mut();
+    let target = state.base.current_style(element_data).clone();
+    let animation_state = &mut state.base.animation;
+    let mut style = target.clone();
+    let mut is_animating = false;
+
+    animation_state.transitions.resize(element_data.transitions.len(), None);
+    for (transition, running) in element_data.transitions.iter().zip(animation_state.transitions.iter_mut()) {
+        if let Some(previous_target) = animation_state.target.as_ref().filter(|previous_target| {
+            properties_differ(previous_target, &target, transition.properties)
+        }) {
+            *running = Some(RunningTransition {
+                from: animation_state.displayed.as_ref().unwrap_or(previous_target).clone(),
+                start: now,
+            });
+        }
+
+        let progress = running.as_ref().map(|running| {
+            now.saturating_duration_since(running.start).as_secs_f32() / transition.duration.as_secs_f32()
+        });
+        match (running.as_ref(), progress) {
+            (Some(running), Some(progress)) if progress < 1.0 => {
+                style = interpolate(&running.from, &style, transition.properties, transition.easing.apply(progress));
+                is_animating = true;
+            }
+            _ => *running = None,
+        }
+    }
+
+    animation_state.target = Some(target);
+    animation_state.displayed = Some(style.clone());
+
+    match &element_data.animation {
+        Some(animation) => {
+            let start = *animation_state.animation_start.get_or_insert(now);
+            if let Some(animated_style) = animation.sample(&style, now.saturating_duration_since(start)) {
+                style = animated_style;
+                is_animating = true;
+            }
+        }
+        None => animation_state.animation_start = None,
+    }
+
+    *state.base.current_style_mut(element_data) = style;
+
+    is_animating
+}
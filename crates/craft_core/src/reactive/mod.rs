@@ -1,3 +1,4 @@
+pub(crate) mod animation;
 pub mod element_id;
 pub(crate) mod fiber_tree;
 pub mod tree;
@@ -1,6 +1,6 @@
 use crate::components::{Component, ComponentId, ComponentSpecification};
 use crate::elements::element::ElementBoxed;
-use crate::elements::{Container, Text};
+use crate::elements::{Container, Text, VirtualList};
 use crate::events::update_queue_entry::UpdateQueueEntry;
 use crate::reactive::element_id::reset_unique_element_id;
 use crate::reactive::element_state_store::ElementStateStore;
@@ -406,4 +406,102 @@ fn diff_trees_after_one_iteration_same_position_different_components_different_c
     let updated_id = &tree_2.component_tree.children[0].children[0].id;
 
     assert_ne!(initial_id, updated_id, "Different Components in the same position should not have the same element child id.");
-}
\ No newline at end of file
+}
+
+#[test]
+fn diff_trees_after_one_iteration_same_position_different_element_keys_same_id() {
+    let mut text_context = TextContext::new();
+    reset_unique_element_id();
+
+    let root_node_1 = Container::new().component().push(Text::new("Foo").component().key("key_1"));
+    let root_node_2 = Container::new().component().push(Text::new("Foo").component().key("key_2"));
+
+    let root_element: ElementBoxed = Container::new().into();
+    let mut user_state = StateStore::default();
+    let mut element_state = ElementStateStore::default();
+    let mut global_state = GlobalState::from(Box::new(()));
+    let mut window_context = WindowContext::new();
+    let mut update_queue: VecDeque<UpdateQueueEntry> = VecDeque::new();
+
+    let tree_1 = diff_trees(
+        root_node_1,
+        root_element.clone(),
+        None,
+        &mut user_state,
+        &mut global_state,
+        &mut element_state,
+        false,
+        &mut text_context,
+        1.0,
+        &mut window_context,
+        &mut update_queue,
+    );
+
+    let tree_2 = diff_trees(
+        root_node_2,
+        root_element.clone(),
+        Some(&tree_1.component_tree),
+        &mut user_state,
+        &mut global_state,
+        &mut element_state,
+        false,
+        &mut text_context,
+        1.0,
+        &mut window_context,
+        &mut update_queue,
+    );
+
+    let initial_id = &tree_1.component_tree.children[0].children[0].id;
+    let updated_id = &tree_2.component_tree.children[0].children[0].id;
+
+    assert_eq!(initial_id, updated_id, "Elements without a matching key should fall back to the same position.");
+}
+
+#[test]
+fn diff_trees_after_one_iteration_same_position_different_virtual_list_keys_different_id() {
+    let mut text_context = TextContext::new();
+    reset_unique_element_id();
+
+    let root_node_1 = VirtualList::new(1, 20.0, |_| Text::new("Foo").component().key("key_1")).component();
+    let root_node_2 = VirtualList::new(1, 20.0, |_| Text::new("Foo").component().key("key_2")).component();
+
+    let root_element: ElementBoxed = Container::new().into();
+    let mut user_state = StateStore::default();
+    let mut element_state = ElementStateStore::default();
+    let mut global_state = GlobalState::from(Box::new(()));
+    let mut window_context = WindowContext::new();
+    let mut update_queue: VecDeque<UpdateQueueEntry> = VecDeque::new();
+
+    let tree_1 = diff_trees(
+        root_node_1,
+        root_element.clone(),
+        None,
+        &mut user_state,
+        &mut global_state,
+        &mut element_state,
+        false,
+        &mut text_context,
+        1.0,
+        &mut window_context,
+        &mut update_queue,
+    );
+
+    let tree_2 = diff_trees(
+        root_node_2,
+        root_element.clone(),
+        Some(&tree_1.component_tree),
+        &mut user_state,
+        &mut global_state,
+        &mut element_state,
+        false,
+        &mut text_context,
+        1.0,
+        &mut window_context,
+        &mut update_queue,
+    );
+
+    let initial_id = &tree_1.component_tree.children[0].children[0].id;
+    let updated_id = &tree_2.component_tree.children[0].children[0].id;
+
+    assert_ne!(initial_id, updated_id, "Rows of a virtual list with different keys should have different ids.");
+}
@@ -2,16 +2,16 @@ use crate::components::component::{ComponentId, ComponentOrElement, ComponentSpe
 use crate::components::{Event, Props};
 use crate::elements::container::ContainerState;
 use crate::elements::element::{Element, ElementBoxed};
-use crate::events::{CraftMessage, Message};
+use crate::events::{CraftMessage, Message, PointerId};
 use crate::reactive::element_id::create_unique_element_id;
 use crate::reactive::element_state_store::{ElementStateStore, ElementStateStoreItem};
 use crate::reactive::state_store::{StateStore, StateStoreItem};
 
-use crate::elements::base_element_state::DUMMY_DEVICE_ID;
 use crate::events::update_queue_entry::UpdateQueueEntry;
 use crate::text::text_context::TextContext;
 use crate::{GlobalState, WindowContext};
 use std::collections::{HashMap, HashSet, VecDeque};
+use std::sync::Arc;
 
 #[derive(Clone)]
 pub(crate) struct ComponentTreeNode {
@@ -24,6 +24,9 @@ pub(crate) struct ComponentTreeNode {
     pub id: ComponentId,
     pub(crate) parent_id: Option<ComponentId>,
     pub props: Props,
+    /// The keyed children that were unmounted by an element that keeps their state, the most recently unmounted
+    /// first. See [`Element::retained_children_limit`].
+    pub(crate) retained_children: Vec<(String, Arc<ComponentTreeNode>)>,
 }
 
 #[derive(Clone)]
@@ -69,12 +72,29 @@ fn dummy_update(
 ) {
 }
 
+/// Collects the ids of the components and elements in the tree of `node`.
+fn collect_ids(
+    node: &ComponentTreeNode,
+    component_ids: &mut HashSet<ComponentId>,
+    element_ids: &mut HashSet<ComponentId>,
+) {
+    if node.is_element {
+        element_ids.insert(node.id);
+    } else {
+        component_ids.insert(node.id);
+    }
+
+    for child in node.children.iter().chain(node.retained_children.iter().map(|(_, child)| child.as_ref())) {
+        collect_ids(child, component_ids, element_ids);
+    }
+}
+
 pub struct DiffTreesResult {
     pub(crate) component_tree: ComponentTreeNode,
     pub(crate) element_tree: ElementBoxed,
     pub(crate) component_ids: HashSet<ComponentId>,
     pub(crate) element_ids: HashSet<ComponentId>,
-    pub(crate) pointer_captures: HashMap<i64, ComponentId>,
+    pub(crate) pointer_captures: HashMap<PointerId, ComponentId>,
 }
 
 #[allow(clippy::too_many_arguments)]
@@ -104,6 +124,7 @@ pub(crate) fn diff_trees(
             id: 0,
             parent_id: None,
             props: Props::new(()),
+            retained_children: Vec::new(),
         };
 
         // Make sure to set a default state for the root.
@@ -128,7 +149,7 @@ pub(crate) fn diff_trees(
 
         let mut new_component_ids: HashSet<ComponentId> = HashSet::new();
         let mut new_element_ids: HashSet<ComponentId> = HashSet::new();
-        let mut pointer_captures: HashMap<i64, ComponentId> = HashMap::new();
+        let mut pointer_captures: HashMap<PointerId, ComponentId> = HashMap::new();
 
         let mut to_visit: Vec<TreeVisitorNode> = vec![TreeVisitorNode {
             component_specification,
@@ -167,10 +188,9 @@ pub(crate) fn diff_trees(
                     if should_update {
                         // Collect the pointer captures.
                         let base_state = element.internal.get_base_state(element_state);
-                        // FIXME: Collect pointer captures with the correct device id.
-                        for is_captured in base_state.base.pointer_capture.values() {
+                        for (pointer_id, is_captured) in base_state.base.pointer_capture.iter() {
                             if *is_captured {
-                                pointer_captures.insert(DUMMY_DEVICE_ID /*device_id*/, id);
+                                pointer_captures.insert(*pointer_id, id);
                             }
                         }
 
@@ -180,6 +200,16 @@ pub(crate) fn diff_trees(
                         element_state.storage.insert(id, state);
                     }
 
+                    let children = element.internal.materialize_children(
+                        new_spec.children,
+                        element_state,
+                        window_context,
+                        scaling_factor,
+                    );
+                    let retained_children_limit =
+                        if should_update { element.internal.retained_children_limit() } else { 0 };
+                    let retains_unmounted_children = retained_children_limit > 0;
+
                     // Move the new element into it's parent and set the parent element to be the new element.
                     tree_node.parent_element_ptr.as_mut().unwrap().children_mut().push(element);
                     parent_element_ptr = tree_node
@@ -202,6 +232,7 @@ pub(crate) fn diff_trees(
                         id,
                         parent_id: Some((*parent_component_ptr).id),
                         props: Props::new(()),
+                        retained_children: Vec::new(),
                     };
 
                     // Add the new component node to the tree and get a pointer to it.
@@ -217,34 +248,75 @@ pub(crate) fn diff_trees(
                         }
                     }
 
+                    let mounted_keys: HashSet<String> = children.iter().filter_map(|child| child.key.clone()).collect();
+
                     let mut new_to_visits: Vec<TreeVisitorNode> = vec![];
                     // Add the children of the new element to the to visit list.
-                    for (index, child) in new_spec.children.into_iter().enumerate() {
+                    for (index, child) in children.into_iter().enumerate() {
                         // Find old child by key and if no key is found, find by index.
+                        // The keyed children of an element that retains them never take over the old child of a
+                        // different key, or a row could get the state of another one.
                         let key = &child.key;
 
-                        let mut index = index;
+                        let mut old_component_node =
+                            if key.is_none() || !retains_unmounted_children { olds.get(index).copied() } else { None };
 
-                        for (old_index, old_child) in olds.iter().enumerate() {
+                        for old_child in olds.iter() {
                             let old_key = (*(*old_child)).key.as_deref();
 
                             if old_key == key.as_deref() {
                                 if old_key.is_none() || key.is_none() {
                                     continue;
                                 }
-                                index = old_index;
+                                old_component_node = Some(*old_child);
                                 break;
                             }
                         }
 
+                        // A keyed child that was unmounted gets its old state back when it is mounted again.
+                        if retains_unmounted_children {
+                            let retained = key.as_ref().and_then(|key| {
+                                let retained_children = &(*tree_node.old_component_node.unwrap()).retained_children;
+                                retained_children.iter().find(|(retained_key, _)| retained_key == key)
+                            });
+                            if let Some((_, retained)) = retained {
+                                old_component_node = Some(Arc::as_ptr(retained));
+                            }
+                        }
+
                         new_to_visits.push(TreeVisitorNode {
                             component_specification: child,
                             parent_element_ptr,
                             parent_component_node: new_component_pointer,
-                            old_component_node: olds.get(index).copied(),
+                            old_component_node,
                         });
                     }
 
+                    if retains_unmounted_children {
+                        let old_component_node = &*tree_node.old_component_node.unwrap();
+                        let mut retained_children: Vec<(String, Arc<ComponentTreeNode>)> = Vec::new();
+                        for old_child in old_component_node.children.iter() {
+                            if let Some(key) = old_child.key.as_ref().filter(|key| !mounted_keys.contains(*key)) {
+                                retained_children.push((key.clone(), Arc::new(old_child.clone())));
+                            }
+                        }
+                        for (key, old_child) in old_component_node.retained_children.iter() {
+                            let is_retained = retained_children.iter().any(|(retained_key, _)| retained_key == key);
+                            if !mounted_keys.contains(key) && !is_retained {
+                                retained_children.push((key.clone(), old_child.clone()));
+                            }
+                        }
+                        // The children that were unmounted the longest ago are dropped first, and their state is
+                        // removed with them.
+                        retained_children.truncate(retained_children_limit);
+
+                        // Keep the state of the retained children from being removed with the unmounted ones.
+                        for (_, retained_child) in retained_children.iter() {
+                            collect_ids(retained_child, &mut new_component_ids, &mut new_element_ids);
+                        }
+                        (*new_component_pointer).retained_children = retained_children;
+                    }
+
                     to_visit.extend(new_to_visits.into_iter().rev());
                 }
                 ComponentOrElement::ComponentSpec(component_data) => {
@@ -324,6 +396,7 @@ pub(crate) fn diff_trees(
                         id,
                         parent_id: Some((*parent_component_ptr).id),
                         props,
+                        retained_children: Vec::new(),
                     };
 
                     // Add the new component node to the tree and get a pointer to it.
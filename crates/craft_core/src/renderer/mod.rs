@@ -13,6 +13,9 @@ pub(crate) mod vello_cpu;
 pub(crate) mod vello_hybrid;
 pub(crate) mod blank_renderer;
 mod image_adapter;
+#[cfg(any(feature = "vello_hybrid_renderer", test))]
+pub(crate) mod shadow;
+mod snapshot;
 pub(crate) mod tinyvg_helpers;
 
 pub use renderer::RenderCommand;
@@ -14,8 +14,17 @@ pub enum RenderCommand {
     DrawTinyVg(Rectangle, ResourceIdentifier, Option<Color>),
     DrawText(TextRender, Rectangle, Option<TextScroll>, bool),
     PushLayer(Rectangle),
+    /// Draws the commands up to the matching `PopLayer` with the given opacity.
+    PushOpacityLayer(f32),
     PopLayer,
+    /// Transforms the commands up to the matching `PopTransform`.
+    PushTransform(kurbo::Affine),
+    PopTransform,
     FillBezPath(kurbo::BezPath, Brush),
+    /// A rounded rectangle with the given corner radius, blurred with the given standard deviation.
+    DrawShadow(Rectangle, f32, f32, Color),
+    /// Fills the path with the color, except for a blurred rounded rectangle inside of it.
+    DrawInsetShadow(kurbo::BezPath, Rectangle, f32, f32, Color),
     StartOverlay,
     EndOverlay,
 }
@@ -51,14 +60,14 @@ impl TextScroll {
 }
 
 #[derive(Debug)]
-enum SortedItem {
+pub(super) enum SortedItem {
     Overlay(SortedCommands),
     Other(u32)
 }
 
 #[derive(Debug)]
 pub struct SortedCommands {
-    children: Vec<SortedItem>,
+    pub(super) children: Vec<SortedItem>,
 }
 
 impl SortedCommands {
@@ -116,6 +125,21 @@ impl RenderList {
         self.commands.push(RenderCommand::FillBezPath(path, brush));
     }
 
+    pub fn draw_shadow(&mut self, rectangle: Rectangle, radius: f32, std_dev: f32, color: Color) {
+        self.commands.push(RenderCommand::DrawShadow(rectangle, radius, std_dev, color));
+    }
+
+    pub fn draw_inset_shadow(
+        &mut self,
+        clip: kurbo::BezPath,
+        rectangle: Rectangle,
+        radius: f32,
+        std_dev: f32,
+        color: Color,
+    ) {
+        self.commands.push(RenderCommand::DrawInsetShadow(clip, rectangle, radius, std_dev, color));
+    }
+
     pub fn draw_text(
         &mut self,
         text_render: TextRender,
@@ -137,10 +161,22 @@ impl RenderList {
         self.commands.push(RenderCommand::PushLayer(rect));
     }
 
+    pub fn push_opacity_layer(&mut self, opacity: f32) {
+        self.commands.push(RenderCommand::PushOpacityLayer(opacity));
+    }
+
     pub fn pop_layer(&mut self) {
         self.commands.push(RenderCommand::PopLayer);
     }
 
+    pub fn push_transform(&mut self, transform: kurbo::Affine) {
+        self.commands.push(RenderCommand::PushTransform(transform));
+    }
+
+    pub fn pop_transform(&mut self) {
+        self.commands.push(RenderCommand::PopTransform);
+    }
+
     pub fn start_overlay(&mut self) {
         self.commands.push(RenderCommand::StartOverlay);
     }
@@ -178,13 +214,26 @@ pub trait Renderer {
                 | RenderCommand::DrawImage(rect, _)
                 | RenderCommand::DrawTinyVg(rect, _, _)
                 | RenderCommand::DrawText(_, rect, _, _) => *rect,
-                RenderCommand::FillBezPath(path, _) => path.bounding_box().into(),
+                RenderCommand::FillBezPath(path, _) | RenderCommand::DrawInsetShadow(path, _, _, _, _) => {
+                    path.bounding_box().into()
+                }
+                // The blur fades out within three standard deviations.
+                RenderCommand::DrawShadow(rect, _, std_dev, _) => Rectangle::new(
+                    rect.x - 3.0 * std_dev,
+                    rect.y - 3.0 * std_dev,
+                    rect.width + 6.0 * std_dev,
+                    rect.height + 6.0 * std_dev,
+                ),
                 _ => unreachable!("Cannot compute the bounding rect of this render command."),
             }
         }
 
         let window_height = self.surface_height();
 
+        // Commands are culled by where they end up in the window, so we have to follow the transforms.
+        let mut transform = kurbo::Affine::IDENTITY;
+        let mut transforms: Vec<kurbo::Affine> = Vec::new();
+
         let mut current: *mut SortedCommands = &mut overlay_render;
         let mut stack: Vec<*mut SortedCommands> = vec![current];
         for (index, command) in render_list.commands.iter().enumerate() {
@@ -211,15 +260,32 @@ pub trait Renderer {
                 }
 
                 // FIXME: If this is a clipping layer, and it is not in bounds we should discard all commands in the clip.
-                RenderCommand::PushLayer(_) | RenderCommand::PopLayer => {
+                RenderCommand::PushLayer(_) | RenderCommand::PushOpacityLayer(_) | RenderCommand::PopLayer => {
                     // Normal Draw Command
                     unsafe {
                         (*current).children.push(SortedItem::Other(index as u32));
                     }
                 }
 
+                RenderCommand::PushTransform(_) | RenderCommand::PopTransform => {
+                    if let RenderCommand::PushTransform(command_transform) = command {
+                        transforms.push(transform);
+                        transform *= *command_transform;
+                    } else {
+                        transform = transforms.pop().unwrap_or(kurbo::Affine::IDENTITY);
+                    }
+                    unsafe {
+                        (*current).children.push(SortedItem::Other(index as u32));
+                    }
+                }
+
                 _ => {
                     let bounding_rect = bounding_rect(command);
+                    let bounding_rect = if transform == kurbo::Affine::IDENTITY {
+                        bounding_rect
+                    } else {
+                        transform.transform_rect_bbox(bounding_rect.to_kurbo()).into()
+                    };
                     if !should_cull(&bounding_rect, window_height) {
                         unsafe {
                             (*current).children.push(SortedItem::Other(index as u32));
@@ -0,0 +1,59 @@
+use crate::geometry::Rectangle;
+use crate::renderer::color::Color;
+
+/// The standard deviation of a shadow without a blur radius, which is drawn with hard edges.
+const MAX_UNBLURRED_STD_DEV: f32 = 0.5;
+
+/// The maximum number of rounded rectangles that a blurred shadow is approximated with.
+const MAX_SHADOW_STEPS: usize = 16;
+
+/// One of the rounded rectangles that approximate a blurred shadow.
+#[derive(Clone, Copy, Debug)]
+pub(crate) struct ShadowStep {
+    pub(crate) rectangle: Rectangle,
+    pub(crate) radius: f32,
+    pub(crate) color: Color,
+}
+
+/// Approximates the rounded rectangle `rectangle` blurred with `std_dev` for the renderers that cannot blur.
+///
+/// The rectangles go from 1.5 standard deviations outside of the edge to 1.5 inside of it, from the outermost to the
+/// innermost. Drawn on top of each other in that order, the alpha rises linearly from zero to the alpha of `color`.
+pub(crate) fn blurred_rounded_rect_steps(
+    rectangle: Rectangle,
+    radius: f32,
+    std_dev: f32,
+    color: Color,
+) -> Vec<ShadowStep> {
+    if std_dev <= MAX_UNBLURRED_STD_DEV {
+        return vec![ShadowStep {
+            rectangle,
+            radius,
+            color,
+        }];
+    }
+
+    let count = ((3.0 * std_dev).ceil() as usize).clamp(1, MAX_SHADOW_STEPS);
+    let alpha = color.components[3];
+    let step_alpha = alpha / count as f32;
+
+    (0..count)
+        .map(|index| {
+            let outset = 1.5 * std_dev - 3.0 * std_dev * (index as f32 + 0.5) / count as f32;
+            // Every step covers the pixels of the steps inside of it, so the steps are more opaque towards the
+            // inside to add up to the same amount of alpha each.
+            let covered_alpha = alpha * index as f32 / count as f32;
+            ShadowStep {
+                rectangle: Rectangle::new(
+                    rectangle.x - outset,
+                    rectangle.y - outset,
+                    (rectangle.width + 2.0 * outset).max(0.0),
+                    (rectangle.height + 2.0 * outset).max(0.0),
+                ),
+                radius: (radius + outset).max(0.0),
+                color: color.with_alpha(step_alpha / (1.0 - covered_alpha)),
+            }
+        })
+        .filter(|step| step.rectangle.width > 0.0 && step.rectangle.height > 0.0)
+        .collect()
+}
@@ -0,0 +1,284 @@
+use crate::geometry::Rectangle;
+use crate::renderer::color::Color;
+use crate::renderer::renderer::{Brush, RenderCommand, RenderList, SortedCommands, SortedItem};
+use crate::resource_manager::ResourceIdentifier;
+use crate::text::text_render_data::TextRender;
+use peniko::color::{DynamicColor, Srgb};
+use peniko::kurbo::{BezPath, PathEl, Point};
+use peniko::GradientKind;
+use std::fmt::Write;
+
+const INDENT: &str = "  ";
+
+impl RenderList {
+    /// Serializes the commands that will be drawn into a stable, line based text format.
+    ///
+    /// Commands are written in the order they are drawn, and culled commands are left out, so this must be called
+    /// after the renderer has sorted and culled the list. Commands inside a layer or an overlay are indented. Numbers
+    /// are rounded to two decimals, so the output only changes when the layout or the styling does.
+    ///
+    /// Text is written as its lines, glyph runs, selections, and cursor, not as the individual glyphs, because glyph
+    /// ids and positions depend on the fonts installed on the machine.
+    pub fn to_snapshot(&self) -> String {
+        let mut snapshot = String::new();
+        let mut depth = 0;
+        write_sorted_commands(&mut snapshot, self, &self.overlay, &mut depth);
+        snapshot
+    }
+}
+
+fn write_sorted_commands(snapshot: &mut String, render_list: &RenderList, sorted_commands: &SortedCommands, depth: &mut usize) {
+    // Mirror `SortedCommands::draw`, which draws the overlays after everything else.
+    for child in &sorted_commands.children {
+        if let SortedItem::Other(command_index) = child {
+            write_command(snapshot, &render_list.commands[*command_index as usize], depth);
+        }
+    }
+
+    for child in &sorted_commands.children {
+        if let SortedItem::Overlay(overlay) = child {
+            write_line(snapshot, *depth, "overlay");
+            *depth += 1;
+            write_sorted_commands(snapshot, render_list, overlay, depth);
+            *depth -= 1;
+        }
+    }
+}
+
+fn write_command(snapshot: &mut String, command: &RenderCommand, depth: &mut usize) {
+    match command {
+        RenderCommand::DrawRect(rectangle, color) => {
+            write_line(snapshot, *depth, &format!("rect {} {}", format_rectangle(rectangle), format_color(*color)));
+        }
+        RenderCommand::DrawRectOutline(rectangle, color) => {
+            write_line(snapshot, *depth, &format!("rect_outline {} {}", format_rectangle(rectangle), format_color(*color)));
+        }
+        RenderCommand::DrawImage(rectangle, resource_identifier) => {
+            write_line(
+                snapshot,
+                *depth,
+                &format!("image {} {}", format_rectangle(rectangle), format_resource_identifier(resource_identifier)),
+            );
+        }
+        RenderCommand::DrawTinyVg(rectangle, resource_identifier, override_color) => {
+            let mut line = format!("tinyvg {} {}", format_rectangle(rectangle), format_resource_identifier(resource_identifier));
+            if let Some(override_color) = override_color {
+                let _ = write!(line, " color={}", format_color(*override_color));
+            }
+            write_line(snapshot, *depth, &line);
+        }
+        RenderCommand::DrawText(text_render, rectangle, text_scroll, show_cursor) => {
+            let mut line = format!("text {}", format_rectangle(rectangle));
+            if let Some(text_scroll) = text_scroll {
+                let _ = write!(
+                    line,
+                    " scroll_y={} scroll_height={}",
+                    format_number(text_scroll.scroll_y as f64),
+                    format_number(text_scroll.scroll_height as f64)
+                );
+            }
+            if *show_cursor {
+                line.push_str(" show_cursor");
+            }
+            write_line(snapshot, *depth, &line);
+            write_text_render(snapshot, text_render, *depth + 1);
+        }
+        RenderCommand::PushLayer(rectangle) => {
+            write_line(snapshot, *depth, &format!("layer {}", format_rectangle(rectangle)));
+            *depth += 1;
+        }
+        RenderCommand::PushTransform(transform) => {
+            let coefficients: Vec<String> =
+                transform.as_coeffs().iter().map(|coefficient| format_number(*coefficient)).collect();
+            write_line(snapshot, *depth, &format!("transform {}", coefficients.join(",")));
+            *depth += 1;
+        }
+        RenderCommand::PopLayer | RenderCommand::PopTransform => {
+            *depth = depth.saturating_sub(1);
+        }
+        RenderCommand::PushOpacityLayer(opacity) => {
+            write_line(snapshot, *depth, &format!("opacity_layer {}", format_number(*opacity as f64)));
+            *depth += 1;
+        }
+        RenderCommand::FillBezPath(path, brush) => {
+            write_line(snapshot, *depth, &format!("path {}", format_brush(brush)));
+            write_line(snapshot, *depth + 1, &format_path(path));
+        }
+        RenderCommand::DrawShadow(rectangle, radius, std_dev, color) => {
+            write_line(
+                snapshot,
+                *depth,
+                &format!(
+                    "shadow {} radius={} std_dev={} {}",
+                    format_rectangle(rectangle),
+                    format_number(*radius as f64),
+                    format_number(*std_dev as f64),
+                    format_color(*color)
+                ),
+            );
+        }
+        RenderCommand::DrawInsetShadow(clip, rectangle, radius, std_dev, color) => {
+            write_line(
+                snapshot,
+                *depth,
+                &format!(
+                    "inset_shadow {} radius={} std_dev={} {}",
+                    format_rectangle(rectangle),
+                    format_number(*radius as f64),
+                    format_number(*std_dev as f64),
+                    format_color(*color)
+                ),
+            );
+            write_line(snapshot, *depth + 1, &format_path(clip));
+        }
+        // The overlays are already nested by `sort_and_cull_render_list`.
+        RenderCommand::StartOverlay | RenderCommand::EndOverlay => {}
+    }
+}
+
+fn write_text_render(snapshot: &mut String, text_render: &TextRender, depth: usize) {
+    if let Some(override_brush) = &text_render.override_brush {
+        write_line(snapshot, depth, &format!("override_color {}", format_color(override_brush.color)));
+    }
+
+    for line in &text_render.lines {
+        write_line(snapshot, depth, "line");
+        for item in &line.items {
+            write_line(
+                snapshot,
+                depth + 1,
+                &format!(
+                    "run font_size={} color={} glyphs={}",
+                    format_number(item.font_size as f64),
+                    format_color(item.brush.color),
+                    item.glyphs.len()
+                ),
+            );
+        }
+        for selection in &line.selections {
+            write_line(snapshot, depth + 1, &format!("selection {}", format_rectangle(selection)));
+        }
+    }
+
+    if let Some(cursor) = &text_render.cursor {
+        write_line(snapshot, depth, &format!("cursor {}", format_rectangle(cursor)));
+    }
+}
+
+fn write_line(snapshot: &mut String, depth: usize, line: &str) {
+    for _ in 0..depth {
+        snapshot.push_str(INDENT);
+    }
+    snapshot.push_str(line);
+    snapshot.push('\n');
+}
+
+fn format_number(number: f64) -> String {
+    let rounded = (number * 100.0).round() / 100.0;
+    // Avoid printing -0.
+    let rounded = if rounded == 0.0 { 0.0 } else { rounded };
+    format!("{rounded}")
+}
+
+fn format_path(path: &BezPath) -> String {
+    let mut elements = String::new();
+    for element in path.elements() {
+        if !elements.is_empty() {
+            elements.push(' ');
+        }
+        match element {
+            PathEl::MoveTo(point) => {
+                let _ = write!(elements, "M{}", format_point(*point));
+            }
+            PathEl::LineTo(point) => {
+                let _ = write!(elements, "L{}", format_point(*point));
+            }
+            PathEl::QuadTo(control, point) => {
+                let _ = write!(elements, "Q{} {}", format_point(*control), format_point(*point));
+            }
+            PathEl::CurveTo(control_1, control_2, point) => {
+                let _ = write!(
+                    elements,
+                    "C{} {} {}",
+                    format_point(*control_1),
+                    format_point(*control_2),
+                    format_point(*point)
+                );
+            }
+            PathEl::ClosePath => elements.push('Z'),
+        }
+    }
+    elements
+}
+
+fn format_point(point: Point) -> String {
+    format!("{},{}", format_number(point.x), format_number(point.y))
+}
+
+fn format_rectangle(rectangle: &Rectangle) -> String {
+    format!(
+        "x={} y={} w={} h={}",
+        format_number(rectangle.x as f64),
+        format_number(rectangle.y as f64),
+        format_number(rectangle.width as f64),
+        format_number(rectangle.height as f64)
+    )
+}
+
+fn format_color(color: Color) -> String {
+    let rgba = color.to_rgba8();
+    format!("#{:02x}{:02x}{:02x}{:02x}", rgba.r, rgba.g, rgba.b, rgba.a)
+}
+
+fn format_dynamic_color(color: DynamicColor) -> String {
+    format_color(color.to_alpha_color::<Srgb>())
+}
+
+fn format_brush(brush: &Brush) -> String {
+    match brush {
+        Brush::Color(color) => format_color(*color),
+        Brush::Gradient(gradient) => {
+            let mut formatted = match gradient.kind {
+                GradientKind::Linear { start, end } => {
+                    format!("linear_gradient {} {}", format_point(start), format_point(end))
+                }
+                GradientKind::Radial {
+                    start_center,
+                    start_radius,
+                    end_center,
+                    end_radius,
+                } => format!(
+                    "radial_gradient {} r={} {} r={}",
+                    format_point(start_center),
+                    format_number(start_radius as f64),
+                    format_point(end_center),
+                    format_number(end_radius as f64)
+                ),
+                GradientKind::Sweep {
+                    center,
+                    start_angle,
+                    end_angle,
+                } => format!(
+                    "sweep_gradient {} start_angle={} end_angle={}",
+                    format_point(center),
+                    format_number(start_angle as f64),
+                    format_number(end_angle as f64)
+                ),
+            };
+            for stop in gradient.stops.iter() {
+                let _ = write!(formatted, " {}@{}", format_dynamic_color(stop.color), format_number(stop.offset as f64));
+            }
+            formatted
+        }
+    }
+}
+
+fn format_resource_identifier(resource_identifier: &ResourceIdentifier) -> String {
+    match resource_identifier {
+        #[cfg(feature = "http_client")]
+        ResourceIdentifier::Url(url) => format!("url={url:?}"),
+        ResourceIdentifier::File(path) => format!("file={:?}", path.to_string_lossy()),
+        // The address of the bytes changes between runs, so only the length is stable.
+        ResourceIdentifier::Bytes(bytes) => format!("bytes={}", bytes.len()),
+    }
+}
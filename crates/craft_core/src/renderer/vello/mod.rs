@@ -9,7 +9,7 @@ use crate::resource_manager::{ResourceManager};
 use std::sync::Arc;
 use peniko::BrushRef;
 use vello::kurbo::{Affine, Rect, Stroke};
-use vello::peniko::{BlendMode, Blob, Fill};
+use vello::peniko::{BlendMode, Blob, Compose, Fill, Mix};
 use vello::util::{RenderContext, RenderSurface};
 use vello::{kurbo, peniko, AaConfig, RendererOptions};
 use vello::{Glyph, Scene};
@@ -147,15 +147,24 @@ impl Renderer for VelloRenderer<'_> {
     }
 
     fn prepare_render_list(&mut self, render_list: RenderList, resource_manager: Arc<ResourceManager>, window: Rectangle) {
+        // Transformed elements are drawn into their own scene, which is appended to its parent with the transform.
+        let mut transformed_scenes: Vec<(Scene, Affine)> = Vec::new();
+        // The part of the current element that is visible in the window.
+        let surface_window = window;
+        let mut window = window;
+
         SortedCommands::draw(&render_list, &render_list.overlay, &mut |command: &RenderCommand| {
-            let scene = &mut self.scene;
+            let scene = match transformed_scenes.last_mut() {
+                Some((scene, _)) => scene,
+                None => &mut self.scene,
+            };
 
             match command {
                 RenderCommand::DrawRect(rectangle, fill_color) => {
                     vello_draw_rect(scene, *rectangle, *fill_color);
                 }
                 RenderCommand::DrawRectOutline(rectangle, outline_color) => {
-                    self.scene.stroke(&Stroke::new(1.0), Affine::IDENTITY, outline_color, None, &rectangle.to_kurbo());
+                    scene.stroke(&Stroke::new(1.0), Affine::IDENTITY, outline_color, None, &rectangle.to_kurbo());
                 }
                 RenderCommand::DrawImage(rectangle, resource_identifier) => {
                     let resource = resource_manager.resources.get(resource_identifier);
@@ -282,12 +291,55 @@ impl Renderer for VelloRenderer<'_> {
                     );
                     scene.push_layer(BlendMode::default(), 1.0, Affine::IDENTITY, &clip);
                 }
+                RenderCommand::PushOpacityLayer(opacity) => {
+                    scene.push_layer(BlendMode::default(), *opacity, Affine::IDENTITY, &window.to_kurbo());
+                }
                 RenderCommand::PopLayer => {
                     scene.pop_layer();
                 }
+                RenderCommand::PushTransform(transform) => {
+                    transformed_scenes.push((Scene::new(), *transform));
+                    let transform = transformed_scenes.iter().fold(Affine::IDENTITY, |total, (_, transform)| total * *transform);
+                    window = transform.inverse().transform_rect_bbox(surface_window.to_kurbo()).into();
+                }
+                RenderCommand::PopTransform => {
+                    if let Some((transformed_scene, transform)) = transformed_scenes.pop() {
+                        let parent_scene = match transformed_scenes.last_mut() {
+                            Some((scene, _)) => scene,
+                            None => &mut self.scene,
+                        };
+                        parent_scene.append(&transformed_scene, Some(transform));
+                    }
+                    let transform = transformed_scenes.iter().fold(Affine::IDENTITY, |total, (_, transform)| total * *transform);
+                    window = transform.inverse().transform_rect_bbox(surface_window.to_kurbo()).into();
+                }
                 RenderCommand::FillBezPath(path, brush) => {
                     scene.fill(Fill::NonZero, Affine::IDENTITY, brush, None, &path);
                 }
+                RenderCommand::DrawShadow(rectangle, radius, std_dev, color) => {
+                    scene.draw_blurred_rounded_rect(
+                        Affine::IDENTITY,
+                        rectangle.to_kurbo(),
+                        *color,
+                        *radius as f64,
+                        *std_dev as f64,
+                    );
+                }
+                RenderCommand::DrawInsetShadow(clip, rectangle, radius, std_dev, color) => {
+                    // Fill the clip with the shadow color, then cut the blurred rectangle out of it.
+                    scene.push_layer(BlendMode::default(), 1.0, Affine::IDENTITY, clip);
+                    scene.fill(Fill::NonZero, Affine::IDENTITY, *color, None, clip);
+                    scene.push_layer(BlendMode::new(Mix::Normal, Compose::DestOut), 1.0, Affine::IDENTITY, clip);
+                    scene.draw_blurred_rounded_rect(
+                        Affine::IDENTITY,
+                        rectangle.to_kurbo(),
+                        Color::BLACK,
+                        *radius as f64,
+                        *std_dev as f64,
+                    );
+                    scene.pop_layer();
+                    scene.pop_layer();
+                }
                 _ => {}
             }
             
@@ -6,7 +6,7 @@ use crate::renderer::{Brush, RenderCommand};
 use crate::resource_manager::resource::Resource;
 use crate::resource_manager::ResourceManager;
 use peniko::kurbo::Affine;
-use peniko::{kurbo, Blob, Color, Fill};
+use peniko::{kurbo, BlendMode, Blob, Color, Compose, Fill, Mix};
 use std::num::NonZero;
 use softbuffer::Buffer;
 use std::num::NonZeroU32;
@@ -61,11 +61,14 @@ fn vello_draw_rect(scene: &mut RenderContext, rectangle: Rectangle, fill_color:
 }
 
 pub(crate) struct VelloCpuRenderer {
-    window: Arc<dyn Window>,
     render_context: RenderContext,
     pixmap: Pixmap,
-    surface: Surface,
+    /// The surface that frames are presented to. This is None when rendering off-screen.
+    surface: Option<Surface>,
     clear_color: Color,
+    /// Whether the current frame was cleared. A frame can be made of several render lists, like the user tree and the
+    /// dev tools, and only the first one clears it.
+    is_frame_cleared: bool,
 }
 
 impl VelloCpuRenderer {
@@ -83,32 +86,54 @@ impl VelloCpuRenderer {
             .expect("TODO: panic message");
 
         Self {
-            window,
             render_context,
             pixmap,
-            surface,
+            surface: Some(surface),
             clear_color: Color::WHITE,
+            is_frame_cleared: false,
         }
     }
+
+    /// Creates a renderer that draws into a pixmap without a window or surface.
+    pub(crate) fn new_headless(width: u16, height: u16) -> Self {
+        let width = width.max(1);
+        let height = height.max(1);
+
+        Self {
+            render_context: RenderContext::new(width, height),
+            pixmap: Pixmap::new(width, height),
+            surface: None,
+            clear_color: Color::WHITE,
+            is_frame_cleared: false,
+        }
+    }
+
+    /// The last frame that was submitted.
+    pub(crate) fn pixmap(&self) -> &Pixmap {
+        &self.pixmap
+    }
 }
 
 impl Renderer for VelloCpuRenderer {
     fn surface_width(&self) -> f32 {
-        self.window.surface_size().width as f32
+        self.pixmap.width() as f32
     }
 
     fn surface_height(&self) -> f32 {
-        self.window.surface_size().height as f32
+        self.pixmap.height() as f32
     }
 
     fn resize_surface(&mut self, width: f32, height: f32) {
         let width = width.max(1.0);
         let height = height.max(1.0);
-        self.surface
-            .resize(NonZeroU32::new(width as u32).unwrap(), NonZeroU32::new(height as u32).unwrap())
-            .expect("TODO: panic message");
+        if let Some(surface) = self.surface.as_mut() {
+            surface
+                .resize(NonZeroU32::new(width as u32).unwrap(), NonZeroU32::new(height as u32).unwrap())
+                .expect("TODO: panic message");
+        }
         self.pixmap = Pixmap::new(width as u16, height as u16);
         self.render_context = RenderContext::new(width as u16, height as u16);
+        self.is_frame_cleared = false;
     }
 
     fn surface_set_clear_color(&mut self, color: Color) {
@@ -125,6 +150,16 @@ impl Renderer for VelloCpuRenderer {
         self.render_context.set_paint(paint);
         self.render_context.set_fill_rule(Fill::NonZero);
         self.render_context.set_transform(Affine::IDENTITY);
+        if !self.is_frame_cleared {
+            self.render_context.fill_rect(&kurbo::Rect::new(0.0, 0.0, self.pixmap.width() as f64, self.pixmap.height() as f64));
+            self.is_frame_cleared = true;
+        }
+
+        // The transform of the current element, and the part of it that is visible in the window.
+        let surface_window = window;
+        let mut window = window;
+        let mut transform = Affine::IDENTITY;
+        let mut transforms: Vec<Affine> = Vec::new();
 
         SortedCommands::draw(&render_list, &render_list.overlay, &mut |command: &RenderCommand| {
             match command {
@@ -147,16 +182,17 @@ impl Renderer for VelloCpuRenderer {
                             let blob = Blob::new(data);
                             let vello_image = peniko::Image::new(blob, peniko::ImageFormat::Rgba8, image.width(), image.height());
 
+                            let current_transform = transform;
                             let mut transform = Affine::IDENTITY;
                             transform = transform.with_translation(kurbo::Vec2::new(rectangle.x as f64, rectangle.y as f64));
                             transform = transform.pre_scale_non_uniform(
                                 rectangle.width as f64 / image.width() as f64,
                                 rectangle.height as f64 / image.height() as f64,
                             );
-                            self.render_context.set_transform(transform);
+                            self.render_context.set_transform(current_transform * transform);
                             self.render_context.set_paint(PaintType::Image(vello_common::paint::Image::from_peniko_image(&vello_image)));
                             self.render_context.fill_rect(&kurbo::Rect::new(0.0, 0.0, image.width() as f64, image.height() as f64));
-                            self.render_context.reset_transform();
+                            self.render_context.set_transform(current_transform);
                         }
                     }
                 }
@@ -224,7 +260,7 @@ impl Renderer for VelloCpuRenderer {
                             }
 
                             self.render_context.set_paint(PaintType::from(text_render.override_brush.map(|b| b.color).unwrap_or_else(|| item.brush.color)));
-                            self.render_context.reset_transform();
+                            self.render_context.set_transform(transform);
 
                             let glyph_run_builder = self.render_context
                                 .glyph_run(&item.font)
@@ -253,15 +289,45 @@ impl Renderer for VelloCpuRenderer {
                     let clip_path = Some(peniko::kurbo::Rect::from_origin_size(peniko::kurbo::Point::new(rect.x as f64, rect.y as f64), peniko::kurbo::Size::new(rect.width as f64, rect.height as f64)).into_path(0.1));
                     self.render_context.push_layer(clip_path.as_ref(), None, None, None);
                 }
+                RenderCommand::PushOpacityLayer(opacity) => {
+                    self.render_context.push_layer(None, None, Some(*opacity), None);
+                }
                 RenderCommand::PopLayer => {
                     self.render_context.pop_layer();
                 }
+                RenderCommand::PushTransform(command_transform) => {
+                    transforms.push(transform);
+                    transform *= *command_transform;
+                    self.render_context.set_transform(transform);
+                    window = transform.inverse().transform_rect_bbox(surface_window.to_kurbo()).into();
+                }
+                RenderCommand::PopTransform => {
+                    transform = transforms.pop().unwrap_or(Affine::IDENTITY);
+                    self.render_context.set_transform(transform);
+                    window = transform.inverse().transform_rect_bbox(surface_window.to_kurbo()).into();
+                }
                 RenderCommand::FillBezPath(path, brush) => {
                     self.render_context.set_paint(brush_to_paint(&brush));
                     self.render_context.fill_path(&path);
                 }
+                RenderCommand::DrawShadow(rectangle, radius, std_dev, color) => {
+                    self.render_context.set_paint(PaintType::Solid(*color));
+                    self.render_context.fill_blurred_rounded_rect(&rectangle.to_kurbo(), *radius, *std_dev);
+                }
+                RenderCommand::DrawInsetShadow(clip, rectangle, radius, std_dev, color) => {
+                    // Fill the clip with the shadow color, then cut the blurred rectangle out of it.
+                    self.render_context.push_layer(Some(clip), None, None, None);
+                    self.render_context.set_paint(PaintType::Solid(*color));
+                    self.render_context.fill_path(clip);
+                    let cut_out = BlendMode::new(Mix::Normal, Compose::DestOut);
+                    self.render_context.push_layer(Some(clip), Some(cut_out), None, None);
+                    self.render_context.set_paint(PaintType::Solid(Color::BLACK));
+                    self.render_context.fill_blurred_rounded_rect(&rectangle.to_kurbo(), *radius, *std_dev);
+                    self.render_context.pop_layer();
+                    self.render_context.pop_layer();
+                }
                 RenderCommand::DrawTinyVg(rectangle, resource_identifier, override_color) => {
-                    draw_tiny_vg(&mut self.render_context, *rectangle, &resource_manager, resource_identifier.clone(), override_color);
+                    draw_tiny_vg(&mut self.render_context, transform, *rectangle, &resource_manager, resource_identifier.clone(), override_color);
                 }
                 _ => {}
             }
@@ -270,29 +336,32 @@ impl Renderer for VelloCpuRenderer {
 
     fn submit(&mut self, _resource_manager: Arc<ResourceManager>) {
         self.render_context.render_to_pixmap(&mut self.pixmap, RenderMode::OptimizeQuality);
-        let buffer = self.copy_pixmap_to_softbuffer(self.pixmap.width() as usize, self.pixmap.height() as usize);
-        buffer.present().expect("Failed to present buffer");
+        if let Some(surface) = self.surface.as_mut() {
+            let buffer = copy_pixmap_to_softbuffer(surface, &self.pixmap);
+            buffer.present().expect("Failed to present buffer");
+        }
         self.render_context.reset();
+        self.is_frame_cleared = false;
     }
 }
 
-impl VelloCpuRenderer {
-    fn copy_pixmap_to_softbuffer(&mut self, width: usize, height: usize) -> Buffer<Arc<dyn Window>, Arc<dyn Window>> {
-        let mut buffer = self.surface.buffer_mut().unwrap();
-
-        let pixmap = &self.pixmap.data_as_u8_slice();
+fn copy_pixmap_to_softbuffer<'a>(surface: &'a mut Surface, pixmap: &Pixmap) -> Buffer<'a, Arc<dyn Window>, Arc<dyn Window>> {
+    let width = pixmap.width() as usize;
+    let height = pixmap.height() as usize;
+    let mut buffer = surface.buffer_mut().unwrap();
 
-        for offset in 0..(width * height) {
-            let red = pixmap[4 * offset];
-            let green = pixmap[4 * offset + 1];
-            let blue = pixmap[4 * offset + 2];
-            let alpha = pixmap[4 * offset + 3];
+    let pixmap = pixmap.data_as_u8_slice();
 
-            buffer[offset] = rgba_to_encoded_u32(red as u32, green as u32, blue as u32, alpha as u32);
-        }
+    for offset in 0..(width * height) {
+        let red = pixmap[4 * offset];
+        let green = pixmap[4 * offset + 1];
+        let blue = pixmap[4 * offset + 2];
+        let alpha = pixmap[4 * offset + 3];
 
-        buffer
+        buffer[offset] = rgba_to_encoded_u32(red as u32, green as u32, blue as u32, alpha as u32);
     }
+
+    buffer
 }
 
 fn brush_to_paint(brush: &Brush) -> PaintType {
@@ -11,7 +11,7 @@ use std::sync::Arc;
 use peniko::kurbo::Shape;
 use vello_common::glyph::Glyph;
 use vello_common::paint::{Paint};
-use vello_common::peniko::Blob;
+use vello_common::peniko::{BlendMode, Blob, Compose, Mix};
 use vello_common::{kurbo, peniko};
 use vello_hybrid::RenderSize;
 use vello_hybrid::{RenderTargetConfig, Renderer};
@@ -21,6 +21,7 @@ use winit::window::Window;
 use crate::renderer::vello_hybrid::render_context::RenderContext;
 use crate::renderer::vello_hybrid::render_context::RenderSurface;
 use crate::renderer::vello_hybrid::tinyvg::draw_tiny_vg;
+use crate::renderer::shadow::blurred_rounded_rect_steps;
 use crate::renderer::Brush;
 use vello_hybrid::Scene;
 
@@ -135,7 +136,12 @@ impl CraftRenderer for VelloHybridRenderer<'_> {
     }
 
     fn prepare_render_list(&mut self, render_list: RenderList, resource_manager: Arc<ResourceManager>, window: Rectangle) {
-        
+        // The transform of the current element, and the part of it that is visible in the window.
+        let surface_window = window;
+        let mut window = window;
+        let mut transform = kurbo::Affine::IDENTITY;
+        let mut transforms: Vec<kurbo::Affine> = Vec::new();
+
         SortedCommands::draw(&render_list, &render_list.overlay, &mut |command: &RenderCommand| {
             let scene = &mut self.scene;
 
@@ -235,7 +241,7 @@ impl CraftRenderer for VelloHybridRenderer<'_> {
                             }
 
                             scene.set_paint(Paint::from(text_render.override_brush.map(|b| b.color).unwrap_or_else(|| item.brush.color)));
-                            scene.reset_transform();
+                            scene.set_transform(transform);
 
                             let glyph_run_builder = scene
                                 .glyph_run(&item.font)
@@ -261,19 +267,53 @@ impl CraftRenderer for VelloHybridRenderer<'_> {
                     }
                 }
                 RenderCommand::DrawTinyVg(rectangle, resource_identifier, override_color) => {
-                    draw_tiny_vg(scene, *rectangle, &resource_manager, resource_identifier.clone(), override_color);
+                    draw_tiny_vg(scene, transform, *rectangle, &resource_manager, resource_identifier.clone(), override_color);
                 }
                 RenderCommand::PushLayer(rect) => {
                     let clip_path = Some(peniko::kurbo::Rect::from_origin_size(peniko::kurbo::Point::new(rect.x as f64, rect.y as f64), peniko::kurbo::Size::new(rect.width as f64, rect.height as f64)).into_path(0.1));
                     scene.push_layer(clip_path.as_ref(), None, None, None);
                 }
+                RenderCommand::PushOpacityLayer(opacity) => {
+                    scene.push_layer(None, None, Some(*opacity), None);
+                }
                 RenderCommand::PopLayer => {
                     scene.pop_layer();
                 }
+                RenderCommand::PushTransform(command_transform) => {
+                    transforms.push(transform);
+                    transform *= *command_transform;
+                    scene.set_transform(transform);
+                    window = transform.inverse().transform_rect_bbox(surface_window.to_kurbo()).into();
+                }
+                RenderCommand::PopTransform => {
+                    transform = transforms.pop().unwrap_or(kurbo::Affine::IDENTITY);
+                    scene.set_transform(transform);
+                    window = transform.inverse().transform_rect_bbox(surface_window.to_kurbo()).into();
+                }
                 RenderCommand::FillBezPath(path, brush) => {
                     scene.set_paint(brush_to_paint(brush));
                     scene.fill_path(path);
                 }
+                // The hybrid renderer cannot blur yet, so the blur is approximated with stacked rounded rectangles.
+                RenderCommand::DrawShadow(rectangle, radius, std_dev, color) => {
+                    for step in blurred_rounded_rect_steps(*rectangle, *radius, *std_dev, *color) {
+                        scene.set_paint(Paint::from(step.color));
+                        scene.fill_path(&rounded_rect_path(step.rectangle, step.radius));
+                    }
+                }
+                RenderCommand::DrawInsetShadow(clip, rectangle, radius, std_dev, color) => {
+                    // Fill the clip with the shadow color, then cut the blurred rectangle out of it.
+                    scene.push_layer(Some(clip), None, None, None);
+                    scene.set_paint(Paint::from(*color));
+                    scene.fill_path(clip);
+                    scene.push_layer(Some(clip), Some(BlendMode::new(Mix::Normal, Compose::DestOut)), None, None);
+                    for step in blurred_rounded_rect_steps(*rectangle, *radius, *std_dev, Color::BLACK) {
+                        scene.set_paint(Paint::from(step.color));
+                        scene.fill_path(&rounded_rect_path(step.rectangle, step.radius));
+                    }
+                    scene.pop_layer();
+                    scene.pop_layer();
+                }
                 _ => {}
             }
         });
@@ -330,6 +370,10 @@ impl CraftRenderer for VelloHybridRenderer<'_> {
     }
 }
 
+fn rounded_rect_path(rectangle: Rectangle, radius: f32) -> kurbo::BezPath {
+    kurbo::RoundedRect::from_rect(rectangle.to_kurbo(), radius as f64).to_path(0.1)
+}
+
 fn brush_to_paint(brush: &Brush) -> Paint {
     match brush {
         Brush::Color(color) => {
@@ -37,7 +37,7 @@ pub(crate) fn draw_path(scene: &mut Scene, path: &Path, fill_style: &Style, line
     }
 }
 
-pub(crate) fn draw_tiny_vg(scene: &mut Scene, rectangle: Rectangle, resource_manager: &Arc<ResourceManager>, resource_identifier: ResourceIdentifier, override_color: &Option<Color>) {
+pub(crate) fn draw_tiny_vg(scene: &mut Scene, transform: Affine, rectangle: Rectangle, resource_manager: &Arc<ResourceManager>, resource_identifier: ResourceIdentifier, override_color: &Option<Color>) {
     let resource = resource_manager.resources.get(&resource_identifier);
     if resource.is_none() {
         return;
@@ -67,6 +67,7 @@ pub(crate) fn draw_tiny_vg(scene: &mut Scene, rectangle: Rectangle, resource_man
             rectangle.width as f64 / svg_width as f64,
             rectangle.height as f64 / svg_height as f64,
         );
+        let affine = transform * affine;
 
         for command in &tiny_vg.draw_commands {
             match command {
@@ -163,6 +164,6 @@ pub(crate) fn draw_tiny_vg(scene: &mut Scene, rectangle: Rectangle, resource_man
             }
         }
         
-        scene.reset_transform();
+        scene.set_transform(transform);
     }
 }
\ No newline at end of file
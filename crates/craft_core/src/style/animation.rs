@@ -0,0 +1,314 @@
+use crate::elements::ElementStyles;
+use crate::geometry::TrblRectangle;
+use crate::renderer::color::Color;
+use crate::style::style_flags::StyleFlags;
+use crate::style::{Style, Unit};
+use std::time::Duration;
+
+/// The style properties that can be transitioned and animated. Other properties jump to their new value.
+pub const ANIMATABLE_PROPERTIES: StyleFlags = StyleFlags::BACKGROUND
+    .union(StyleFlags::COLOR)
+    .union(StyleFlags::BORDER_COLOR)
+    .union(StyleFlags::WIDTH)
+    .union(StyleFlags::HEIGHT)
+    .union(StyleFlags::MIN_WIDTH)
+    .union(StyleFlags::MIN_HEIGHT)
+    .union(StyleFlags::MAX_WIDTH)
+    .union(StyleFlags::MAX_HEIGHT)
+    .union(StyleFlags::INSET)
+    .union(StyleFlags::MARGIN)
+    .union(StyleFlags::PADDING)
+    .union(StyleFlags::OPACITY);
+
+/// How the progress of a transition or animation maps to the progress of the animated values.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub enum Easing {
+    #[default]
+    Linear,
+    Ease,
+    EaseIn,
+    EaseOut,
+    EaseInOut,
+    /// A cubic bézier curve from (0, 0) to (1, 1) with the control points (x1, y1) and (x2, y2), like in CSS.
+    CubicBezier(f32, f32, f32, f32),
+}
+
+impl Easing {
+    /// Maps the linear progress `t`, between 0 and 1, to the eased progress.
+    pub fn apply(&self, t: f32) -> f32 {
+        let t = t.clamp(0.0, 1.0);
+        match *self {
+            Easing::Linear => t,
+            Easing::Ease => cubic_bezier(0.25, 0.1, 0.25, 1.0, t),
+            Easing::EaseIn => cubic_bezier(0.42, 0.0, 1.0, 1.0, t),
+            Easing::EaseOut => cubic_bezier(0.0, 0.0, 0.58, 1.0, t),
+            Easing::EaseInOut => cubic_bezier(0.42, 0.0, 0.58, 1.0, t),
+            Easing::CubicBezier(x1, y1, x2, y2) => cubic_bezier(x1, y1, x2, y2, t),
+        }
+    }
+}
+
+fn cubic_bezier(x1: f32, y1: f32, x2: f32, y2: f32, x: f32) -> f32 {
+    if x <= 0.0 || x >= 1.0 {
+        return x;
+    }
+
+    let bezier = |p1: f32, p2: f32, t: f32| {
+        let inverse = 1.0 - t;
+        3.0 * p1 * t * inverse * inverse + 3.0 * p2 * t * t * inverse + t * t * t
+    };
+
+    // The curve is monotonic in x, so we can search for the t that gives us x.
+    let (mut low, mut high) = (0.0, 1.0);
+    for _ in 0..32 {
+        let middle = (low + high) / 2.0;
+        if bezier(x1, x2, middle) < x {
+            low = middle;
+        } else {
+            high = middle;
+        }
+    }
+
+    bezier(y1, y2, (low + high) / 2.0)
+}
+
+/// Animates the changes of some style properties, for example when the element is hovered or pressed.
+///
+/// When one of the properties changes, the element moves from the value that is currently displayed to the new
+/// value over `duration`.
+#[derive(Clone, Copy, Debug)]
+pub struct Transition {
+    pub properties: StyleFlags,
+    pub duration: Duration,
+    pub easing: Easing,
+}
+
+impl Transition {
+    pub fn new(properties: StyleFlags, duration: Duration, easing: Easing) -> Self {
+        Transition {
+            properties,
+            duration,
+            easing,
+        }
+    }
+}
+
+/// How often a keyframe animation plays.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum AnimationIterations {
+    Count(u32),
+    Infinite,
+}
+
+/// The style of an element at one point of an [`Animation`].
+///
+/// Only the properties that are set on the keyframe are animated. Use the [`ElementStyles`] builders to set them.
+#[derive(Clone, Debug)]
+pub struct Keyframe {
+    offset: f32,
+    style: Style,
+}
+
+impl Keyframe {
+    /// A keyframe at `offset`, between 0 (the start of the animation) and 1 (the end of the animation).
+    pub fn new(offset: f32) -> Self {
+        Keyframe {
+            offset: offset.clamp(0.0, 1.0),
+            style: Style::default(),
+        }
+    }
+}
+
+impl ElementStyles for Keyframe {
+    fn styles_mut(&mut self) -> &mut Style {
+        &mut self.style
+    }
+}
+
+/// A keyframe animation that starts when the element is first displayed.
+///
+/// The element's own style is used where an animated property has no keyframe at the start or the end. Once the
+/// animation is finished, the element goes back to its own style.
+#[derive(Clone, Debug)]
+pub struct Animation {
+    keyframes: Vec<Keyframe>,
+    duration: Duration,
+    easing: Easing,
+    iterations: AnimationIterations,
+    alternate: bool,
+}
+
+impl Animation {
+    pub fn new(duration: Duration) -> Self {
+        Animation {
+            keyframes: Vec::new(),
+            duration,
+            easing: Easing::Linear,
+            iterations: AnimationIterations::Count(1),
+            alternate: false,
+        }
+    }
+
+    pub fn keyframe(mut self, keyframe: Keyframe) -> Self {
+        let index = self.keyframes.partition_point(|other| other.offset <= keyframe.offset);
+        self.keyframes.insert(index, keyframe);
+        self
+    }
+
+    /// Sets the easing that is applied between each pair of keyframes.
+    pub fn easing(mut self, easing: Easing) -> Self {
+        self.easing = easing;
+        self
+    }
+
+    pub fn iterations(mut self, iterations: AnimationIterations) -> Self {
+        self.iterations = iterations;
+        self
+    }
+
+    /// Plays every other iteration backwards.
+    pub fn alternate(mut self) -> Self {
+        self.alternate = true;
+        self
+    }
+
+    /// Returns `base` with the animated properties at `elapsed` applied, or `None` when the animation is finished.
+    pub(crate) fn sample(&self, base: &Style, elapsed: Duration) -> Option<Style> {
+        if self.duration.is_zero() {
+            return None;
+        }
+
+        let progress = elapsed.as_secs_f32() / self.duration.as_secs_f32();
+        let iteration = progress.floor() as u32;
+        if matches!(self.iterations, AnimationIterations::Count(count) if iteration >= count) {
+            return None;
+        }
+
+        let mut t = progress.fract();
+        if self.alternate && iteration % 2 == 1 {
+            t = 1.0 - t;
+        }
+
+        let properties = self
+            .keyframes
+            .iter()
+            .fold(StyleFlags::empty(), |properties, keyframe| properties | keyframe.style.dirty_flags)
+            .intersection(ANIMATABLE_PROPERTIES);
+
+        let mut keyframes: Vec<(f32, Style)> =
+            self.keyframes.iter().map(|keyframe| (keyframe.offset, Style::merge(base, &keyframe.style))).collect();
+        if keyframes.first().is_none_or(|(offset, _)| *offset > 0.0) {
+            keyframes.insert(0, (0.0, base.clone()));
+        }
+        if keyframes.last().is_some_and(|(offset, _)| *offset < 1.0) {
+            keyframes.push((1.0, base.clone()));
+        }
+
+        let end = keyframes.iter().position(|(offset, _)| *offset >= t).unwrap_or(keyframes.len() - 1).max(1);
+        let (start_offset, start_style) = &keyframes[end - 1];
+        let (end_offset, end_style) = &keyframes[end];
+        let segment_length = end_offset - start_offset;
+        let segment_t = if segment_length > 0.0 { (t - start_offset) / segment_length } else { 1.0 };
+
+        Some(interpolate(start_style, end_style, properties, self.easing.apply(segment_t)))
+    }
+}
+
+fn lerp(from: f32, to: f32, t: f32) -> f32 {
+    from + (to - from) * t
+}
+
+/// Units of different kinds, like `auto` and `100px`, cannot be interpolated and switch halfway through.
+fn lerp_unit(from: Unit, to: Unit, t: f32) -> Unit {
+    match (from, to) {
+        (Unit::Px(from), Unit::Px(to)) => Unit::Px(lerp(from, to, t)),
+        (Unit::Percentage(from), Unit::Percentage(to)) => Unit::Percentage(lerp(from, to, t)),
+        _ if t < 0.5 => from,
+        _ => to,
+    }
+}
+
+/// Colors are interpolated with premultiplied alpha, so that fading from transparent does not pass through black.
+fn lerp_color(from: Color, to: Color, t: f32) -> Color {
+    from.premultiply().lerp_rect(to.premultiply(), t).un_premultiply()
+}
+
+fn lerp_rectangle<T: Copy>(
+    from: TrblRectangle<T>,
+    to: TrblRectangle<T>,
+    t: f32,
+    lerp: impl Fn(T, T, f32) -> T,
+) -> TrblRectangle<T> {
+    TrblRectangle::new(
+        lerp(from.top, to.top, t),
+        lerp(from.right, to.right, t),
+        lerp(from.bottom, to.bottom, t),
+        lerp(from.left, to.left, t),
+    )
+}
+
+/// Returns `to` with the animatable `properties` set to the values `t` of the way from `from` to `to`.
+pub(crate) fn interpolate(from: &Style, to: &Style, properties: StyleFlags, t: f32) -> Style {
+    let mut style = to.clone();
+
+    if properties.contains(StyleFlags::BACKGROUND) {
+        *style.background_mut() = lerp_color(from.background(), to.background(), t);
+    }
+    if properties.contains(StyleFlags::COLOR) {
+        *style.color_mut() = lerp_color(from.color(), to.color(), t);
+    }
+    if properties.contains(StyleFlags::BORDER_COLOR) {
+        *style.border_color_mut() = lerp_rectangle(from.border_color(), to.border_color(), t, lerp_color);
+    }
+    if properties.contains(StyleFlags::WIDTH) {
+        *style.width_mut() = lerp_unit(from.width(), to.width(), t);
+    }
+    if properties.contains(StyleFlags::HEIGHT) {
+        *style.height_mut() = lerp_unit(from.height(), to.height(), t);
+    }
+    if properties.contains(StyleFlags::MIN_WIDTH) {
+        *style.min_width_mut() = lerp_unit(from.min_width(), to.min_width(), t);
+    }
+    if properties.contains(StyleFlags::MIN_HEIGHT) {
+        *style.min_height_mut() = lerp_unit(from.min_height(), to.min_height(), t);
+    }
+    if properties.contains(StyleFlags::MAX_WIDTH) {
+        *style.max_width_mut() = lerp_unit(from.max_width(), to.max_width(), t);
+    }
+    if properties.contains(StyleFlags::MAX_HEIGHT) {
+        *style.max_height_mut() = lerp_unit(from.max_height(), to.max_height(), t);
+    }
+    if properties.contains(StyleFlags::INSET) {
+        *style.inset_mut() = lerp_rectangle(from.inset(), to.inset(), t, lerp_unit);
+    }
+    if properties.contains(StyleFlags::MARGIN) {
+        *style.margin_mut() = lerp_rectangle(from.margin(), to.margin(), t, lerp_unit);
+    }
+    if properties.contains(StyleFlags::PADDING) {
+        *style.padding_mut() = lerp_rectangle(from.padding(), to.padding(), t, lerp_unit);
+    }
+    if properties.contains(StyleFlags::OPACITY) {
+        *style.opacity_mut() = lerp(from.opacity(), to.opacity(), t);
+    }
+
+    style
+}
+
+/// Whether any of the animatable `properties` has a different value in `a` and `b`.
+pub(crate) fn properties_differ(a: &Style, b: &Style, properties: StyleFlags) -> bool {
+    let differs = |property: StyleFlags, differs: bool| properties.contains(property) && differs;
+
+    differs(StyleFlags::BACKGROUND, a.background() != b.background())
+        || differs(StyleFlags::COLOR, a.color() != b.color())
+        || differs(StyleFlags::BORDER_COLOR, a.border_color() != b.border_color())
+        || differs(StyleFlags::WIDTH, a.width() != b.width())
+        || differs(StyleFlags::HEIGHT, a.height() != b.height())
+        || differs(StyleFlags::MIN_WIDTH, a.min_width() != b.min_width())
+        || differs(StyleFlags::MIN_HEIGHT, a.min_height() != b.min_height())
+        || differs(StyleFlags::MAX_WIDTH, a.max_width() != b.max_width())
+        || differs(StyleFlags::MAX_HEIGHT, a.max_height() != b.max_height())
+        || differs(StyleFlags::INSET, a.inset() != b.inset())
+        || differs(StyleFlags::MARGIN, a.margin() != b.margin())
+        || differs(StyleFlags::PADDING, a.padding() != b.padding())
+        || differs(StyleFlags::OPACITY, a.opacity() != b.opacity())
+}
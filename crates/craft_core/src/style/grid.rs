@@ -0,0 +1,350 @@
+use std::fmt;
+use std::sync::Arc;
+
+/// Why a grid template or track could not be created.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum GridError {
+    /// The string is not a valid track size or track, like `1fx`.
+    InvalidTrack(String),
+}
+
+impl fmt::Display for GridError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            GridError::InvalidTrack(track) => write!(f, "Invalid grid track: {track}"),
+        }
+    }
+}
+
+impl std::error::Error for GridError {}
+
+/// One bound of the size of a grid row or column.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum GridTrackSize {
+    Px(f32),
+    Percentage(f32),
+    /// A share of the free space. Only valid as the maximum of a track.
+    Fr(f32),
+    Auto,
+    MinContent,
+    MaxContent,
+}
+
+impl GridTrackSize {
+    fn scale(&self, scaling_factor: f32) -> Self {
+        match self {
+            GridTrackSize::Px(px) => GridTrackSize::Px(px * scaling_factor),
+            size => *size,
+        }
+    }
+}
+
+impl TryFrom<&str> for GridTrackSize {
+    type Error = GridError;
+
+    fn try_from(s: &str) -> Result<Self, Self::Error> {
+        let s = s.trim();
+        let parse = |value: &str| value.trim().parse::<f32>().map_err(|_| GridError::InvalidTrack(s.to_string()));
+
+        if s.eq_ignore_ascii_case("auto") {
+            Ok(GridTrackSize::Auto)
+        } else if s.eq_ignore_ascii_case("min-content") {
+            Ok(GridTrackSize::MinContent)
+        } else if s.eq_ignore_ascii_case("max-content") {
+            Ok(GridTrackSize::MaxContent)
+        } else if let Some(stripped) = s.strip_suffix("fr") {
+            parse(stripped).map(GridTrackSize::Fr)
+        } else if let Some(stripped) = s.strip_suffix("px") {
+            parse(stripped).map(GridTrackSize::Px)
+        } else if let Some(stripped) = s.strip_suffix('%') {
+            parse(stripped).map(GridTrackSize::Percentage)
+        } else {
+            Err(GridError::InvalidTrack(s.to_string()))
+        }
+    }
+}
+
+impl fmt::Display for GridTrackSize {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            GridTrackSize::Px(value) => write!(f, "{value}px"),
+            GridTrackSize::Percentage(value) => write!(f, "{value}%"),
+            GridTrackSize::Fr(value) => write!(f, "{value}fr"),
+            GridTrackSize::Auto => write!(f, "auto"),
+            GridTrackSize::MinContent => write!(f, "min-content"),
+            GridTrackSize::MaxContent => write!(f, "max-content"),
+        }
+    }
+}
+
+/// The size of a grid row or column, like `100px`, `1fr` or `minmax(100px, 1fr)`.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct GridTrack {
+    pub min: GridTrackSize,
+    pub max: GridTrackSize,
+}
+
+impl GridTrack {
+    /// A track of the given size. Like in CSS, `1fr` is short for `minmax(auto, 1fr)`.
+    pub fn new(size: GridTrackSize) -> Self {
+        match size {
+            GridTrackSize::Fr(_) => GridTrack::minmax(GridTrackSize::Auto, size),
+            size => GridTrack::minmax(size, size),
+        }
+    }
+
+    /// A track that is at least `min` and at most `max` large.
+    pub fn minmax(min: GridTrackSize, max: GridTrackSize) -> Self {
+        GridTrack { min, max }
+    }
+
+    fn scale(&self, scaling_factor: f32) -> Self {
+        GridTrack::minmax(self.min.scale(scaling_factor), self.max.scale(scaling_factor))
+    }
+}
+
+impl Default for GridTrack {
+    fn default() -> Self {
+        GridTrack::new(GridTrackSize::Auto)
+    }
+}
+
+impl From<GridTrackSize> for GridTrack {
+    fn from(size: GridTrackSize) -> Self {
+        GridTrack::new(size)
+    }
+}
+
+impl TryFrom<&str> for GridTrack {
+    type Error = GridError;
+
+    fn try_from(s: &str) -> Result<Self, Self::Error> {
+        let s = s.trim();
+        if let Some(arguments) = function_arguments(s, "minmax") {
+            let (min, max) = arguments.split_once(',').ok_or_else(|| GridError::InvalidTrack(s.to_string()))?;
+            Ok(GridTrack::minmax(min.try_into()?, max.try_into()?))
+        } else {
+            Ok(GridTrack::new(s.try_into()?))
+        }
+    }
+}
+
+impl fmt::Display for GridTrack {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if *self == GridTrack::new(self.max) {
+            write!(f, "{}", self.max)
+        } else {
+            write!(f, "minmax({}, {})", self.min, self.max)
+        }
+    }
+}
+
+/// The rows or columns of a grid, like `200px 1fr 1fr` or `repeat(3, minmax(100px, 1fr))`.
+///
+/// The tracks are shared between the clones of a template, so copying a style does not copy them.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct GridTemplate {
+    tracks: Arc<[GridTrack]>,
+}
+
+impl GridTemplate {
+    pub fn tracks(&self) -> &[GridTrack] {
+        &self.tracks
+    }
+
+    pub(crate) fn scale(&self, scaling_factor: f32) -> Self {
+        self.tracks.iter().map(|track| track.scale(scaling_factor)).collect()
+    }
+}
+
+impl FromIterator<GridTrack> for GridTemplate {
+    fn from_iter<I: IntoIterator<Item = GridTrack>>(tracks: I) -> Self {
+        GridTemplate {
+            tracks: tracks.into_iter().collect(),
+        }
+    }
+}
+
+impl From<&[GridTrack]> for GridTemplate {
+    fn from(tracks: &[GridTrack]) -> Self {
+        tracks.iter().copied().collect()
+    }
+}
+
+impl<const N: usize> From<[GridTrack; N]> for GridTemplate {
+    fn from(tracks: [GridTrack; N]) -> Self {
+        tracks.into_iter().collect()
+    }
+}
+
+impl From<Vec<GridTrack>> for GridTemplate {
+    fn from(tracks: Vec<GridTrack>) -> Self {
+        GridTemplate { tracks: tracks.into() }
+    }
+}
+
+impl TryFrom<&str> for GridTemplate {
+    type Error = GridError;
+
+    fn try_from(s: &str) -> Result<Self, Self::Error> {
+        let mut template = Vec::new();
+        for token in split_tracks(s) {
+            if let Some(arguments) = function_arguments(token, "repeat") {
+                let invalid_track = || GridError::InvalidTrack(token.to_string());
+                let (count, tracks) = arguments.split_once(',').ok_or_else(invalid_track)?;
+                let count: usize = count.trim().parse().map_err(|_| invalid_track())?;
+                let tracks = GridTemplate::try_from(tracks)?;
+                for _ in 0..count {
+                    template.extend_from_slice(tracks.tracks());
+                }
+            } else {
+                template.push(token.try_into()?);
+            }
+        }
+        Ok(template.into())
+    }
+}
+
+impl fmt::Display for GridTemplate {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let tracks: Vec<String> = self.tracks().iter().map(|track| track.to_string()).collect();
+        write!(f, "{}", tracks.join(" "))
+    }
+}
+
+/// Returns the arguments of `s` if it is a call of the CSS function `name`, like `minmax(100px, 1fr)`.
+fn function_arguments<'a>(s: &'a str, name: &str) -> Option<&'a str> {
+    s.trim().strip_prefix(name)?.trim_start().strip_prefix('(')?.strip_suffix(')')
+}
+
+/// Splits a track list at the whitespace that is not inside of parentheses.
+fn split_tracks(s: &str) -> Vec<&str> {
+    let mut tracks = Vec::new();
+    let mut depth = 0;
+    let mut start = None;
+
+    for (index, character) in s.char_indices() {
+        match character {
+            '(' => depth += 1,
+            ')' => depth -= 1,
+            character if character.is_whitespace() && depth == 0 => {
+                if let Some(track_start) = start.take() {
+                    tracks.push(&s[track_start..index]);
+                }
+                continue;
+            }
+            _ => {}
+        }
+        start.get_or_insert(index);
+    }
+    if let Some(track_start) = start {
+        tracks.push(&s[track_start..]);
+    }
+
+    tracks
+}
+
+/// The name of a grid area, which is shared between the clones of a style.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct GridAreaName(Arc<str>);
+
+impl GridAreaName {
+    pub fn new(name: &str) -> Self {
+        GridAreaName(name.into())
+    }
+
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+}
+
+/// A named area of a grid. The lines are 1-based and the end lines are exclusive, like in CSS.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct GridArea {
+    pub name: GridAreaName,
+    pub row_start: i16,
+    pub row_end: i16,
+    pub column_start: i16,
+    pub column_end: i16,
+}
+
+/// The named areas of a grid, like `grid-template-areas` in CSS.
+///
+/// Every string is a row and every whitespace separated name in a row is a cell. Cells with the name `.` do not
+/// belong to an area. An area covers the smallest rectangle around all of its cells.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct GridTemplateAreas {
+    areas: Arc<[GridArea]>,
+}
+
+impl GridTemplateAreas {
+    pub fn areas(&self) -> &[GridArea] {
+        &self.areas
+    }
+
+    pub fn find(&self, name: &str) -> Option<&GridArea> {
+        self.areas().iter().find(|area| area.name.as_str() == name)
+    }
+
+    /// The areas named in `rows`.
+    pub fn new(rows: &[&str]) -> Self {
+        let mut areas: Vec<GridArea> = Vec::new();
+
+        for (row, cells) in rows.iter().enumerate() {
+            for (column, name) in cells.split_whitespace().enumerate() {
+                if name == "." {
+                    continue;
+                }
+
+                let (row, column) = (row as i16 + 1, column as i16 + 1);
+                if let Some(area) = areas.iter_mut().find(|area| area.name.as_str() == name) {
+                    area.row_start = area.row_start.min(row);
+                    area.row_end = area.row_end.max(row + 1);
+                    area.column_start = area.column_start.min(column);
+                    area.column_end = area.column_end.max(column + 1);
+                    continue;
+                }
+
+                areas.push(GridArea {
+                    name: GridAreaName::new(name),
+                    row_start: row,
+                    row_end: row + 1,
+                    column_start: column,
+                    column_end: column + 1,
+                });
+            }
+        }
+
+        GridTemplateAreas { areas: areas.into() }
+    }
+}
+
+/// Where a grid item starts or ends in a row or column.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum GridPlacement {
+    /// Placed by the auto-placement algorithm.
+    #[default]
+    Auto,
+    /// Placed at the given 1-based line. Negative lines count from the end of the explicit grid.
+    Line(i16),
+    /// Spans the given number of tracks.
+    Span(u16),
+}
+
+/// Controls how the items without an explicit placement are placed in a grid.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum GridAutoFlow {
+    /// Fill each row in turn, adding new rows as necessary.
+    #[default]
+    Row,
+    /// Fill each column in turn, adding new columns as necessary.
+    Column,
+    /// Like `Row`, but fills holes earlier in the grid with smaller items that come later.
+    RowDense,
+    /// Like `Column`, but fills holes earlier in the grid with smaller items that come later.
+    ColumnDense,
+}
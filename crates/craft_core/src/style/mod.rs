@@ -1,5 +1,17 @@
+mod animation;
+mod grid;
+mod paint;
 pub(crate) mod style_flags;
 mod styles;
 mod taffy_conversions;
+mod transform;
 
-pub use styles::*;
\ No newline at end of file
+pub use animation::*;
+pub use grid::*;
+pub use paint::*;
+pub use style_flags::StyleFlags;
+pub use styles::*;
+pub use transform::*;
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,165 @@
+use crate::geometry::Rectangle;
+use crate::renderer::color::Color;
+use craft_logging::warn;
+use peniko::kurbo::Point;
+
+/// The maximum number of color stops in a [`Gradient`].
+pub const MAX_GRADIENT_STOPS: usize = 8;
+
+/// A shadow around or inside of the border box of an element, like `box-shadow` in CSS.
+///
+/// The shadow follows the `border_radius` of the element.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct BoxShadow {
+    pub offset_x: f32,
+    pub offset_y: f32,
+    /// The larger the blur radius, the softer the edges of the shadow.
+    pub blur_radius: f32,
+    /// Grows the shadow by this amount in every direction, or shrinks it when negative.
+    pub spread_radius: f32,
+    pub color: Color,
+    /// Draws the shadow inside of the element, above the background, instead of around it.
+    pub inset: bool,
+}
+
+impl BoxShadow {
+    pub fn new(offset_x: f32, offset_y: f32, blur_radius: f32, spread_radius: f32, color: Color) -> Self {
+        BoxShadow {
+            offset_x,
+            offset_y,
+            blur_radius,
+            spread_radius,
+            color,
+            inset: false,
+        }
+    }
+
+    /// Draws the shadow inside of the element instead of around it.
+    pub fn inset(mut self) -> Self {
+        self.inset = true;
+        self
+    }
+
+    pub(crate) fn scale(&self, scaling_factor: f32) -> Self {
+        BoxShadow {
+            offset_x: self.offset_x * scaling_factor,
+            offset_y: self.offset_y * scaling_factor,
+            blur_radius: self.blur_radius * scaling_factor,
+            spread_radius: self.spread_radius * scaling_factor,
+            ..*self
+        }
+    }
+}
+
+/// A color at an offset between 0 and 1 of a [`Gradient`].
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct ColorStop {
+    pub offset: f32,
+    pub color: Color,
+}
+
+impl From<(f32, Color)> for ColorStop {
+    fn from((offset, color): (f32, Color)) -> Self {
+        ColorStop { offset, color }
+    }
+}
+
+/// The shape of a [`Gradient`].
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum GradientKind {
+    /// A gradient along a line through the center of the element, like `linear-gradient` in CSS.
+    ///
+    /// The angle is in degrees: 0 goes upwards and 90 goes to the right.
+    Linear { angle: f32 },
+    /// A circle around the center of the element that reaches the farthest corner.
+    Radial,
+    /// A gradient around the center of the element, like `conic-gradient` in CSS.
+    ///
+    /// The angle in degrees that it starts at, clockwise from the top.
+    Sweep { start_angle: f32 },
+}
+
+/// A background that blends between colors, used in place of the flat background color.
+///
+/// A gradient holds at most [`MAX_GRADIENT_STOPS`] color stops, so that styles stay `Copy`.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Gradient {
+    kind: GradientKind,
+    length: u8,
+    stops: [ColorStop; MAX_GRADIENT_STOPS],
+}
+
+impl Gradient {
+    /// A gradient with the given stops. Only the first [`MAX_GRADIENT_STOPS`] stops are kept, and the rest are logged
+    /// and dropped.
+    pub fn new<T: Into<ColorStop> + Copy>(kind: GradientKind, stops: &[T]) -> Self {
+        if stops.len() > MAX_GRADIENT_STOPS {
+            warn!(
+                "A gradient can have at most {} color stops, dropping the last {}.",
+                MAX_GRADIENT_STOPS,
+                stops.len() - MAX_GRADIENT_STOPS
+            );
+        }
+        let stops = &stops[..stops.len().min(MAX_GRADIENT_STOPS)];
+
+        let mut gradient = Gradient {
+            kind,
+            length: stops.len() as u8,
+            stops: [ColorStop {
+                offset: 0.0,
+                color: Color::TRANSPARENT,
+            }; MAX_GRADIENT_STOPS],
+        };
+        for (stop, new_stop) in gradient.stops.iter_mut().zip(stops) {
+            *stop = (*new_stop).into();
+        }
+        gradient
+    }
+
+    pub fn linear<T: Into<ColorStop> + Copy>(angle: f32, stops: &[T]) -> Self {
+        Gradient::new(GradientKind::Linear { angle }, stops)
+    }
+
+    pub fn radial<T: Into<ColorStop> + Copy>(stops: &[T]) -> Self {
+        Gradient::new(GradientKind::Radial, stops)
+    }
+
+    pub fn sweep<T: Into<ColorStop> + Copy>(start_angle: f32, stops: &[T]) -> Self {
+        Gradient::new(GradientKind::Sweep { start_angle }, stops)
+    }
+
+    pub fn kind(&self) -> GradientKind {
+        self.kind
+    }
+
+    pub fn stops(&self) -> &[ColorStop] {
+        &self.stops[..self.length as usize]
+    }
+
+    /// Positions the gradient in the border box of an element.
+    pub(crate) fn to_peniko(self, rectangle: Rectangle) -> peniko::Gradient {
+        let center =
+            Point::new((rectangle.x + rectangle.width / 2.0) as f64, (rectangle.y + rectangle.height / 2.0) as f64);
+        let (half_width, half_height) = (rectangle.width as f64 / 2.0, rectangle.height as f64 / 2.0);
+
+        let gradient = match self.kind {
+            GradientKind::Linear { angle } => {
+                // Like in CSS, the line is long enough for the corners to get the colors of the first and last stops.
+                let (sin, cos) = (angle as f64).to_radians().sin_cos();
+                let half_length = half_width * sin.abs() + half_height * cos.abs();
+                let direction = Point::new(sin * half_length, -cos * half_length).to_vec2();
+                peniko::Gradient::new_linear(center - direction, center + direction)
+            }
+            GradientKind::Radial => peniko::Gradient::new_radial(center, half_width.hypot(half_height) as f32),
+            GradientKind::Sweep { start_angle } => {
+                // Peniko measures the angles clockwise from the positive x-axis in radians, CSS from the top.
+                let start = (start_angle - 90.0).to_radians();
+                peniko::Gradient::new_sweep(center, start, start + std::f32::consts::TAU)
+            }
+        };
+
+        let stops: Vec<peniko::ColorStop> =
+            self.stops().iter().map(|stop| peniko::ColorStop::from((stop.offset, stop.color))).collect();
+        gradient.with_stops(stops.as_slice())
+    }
+}
@@ -39,5 +39,17 @@ bitflags! {
         const BORDER_RADIUS = 1 << 33;
         const SCROLLBAR_COLOR = 1 << 34;
         const VISIBLE = 1 << 35;
+        const GRID_TEMPLATE_ROWS = 1 << 36;
+        const GRID_TEMPLATE_COLUMNS = 1 << 37;
+        const GRID_TEMPLATE_AREAS = 1 << 38;
+        const GRID_AUTO_FLOW = 1 << 39;
+        const GRID_ROW = 1 << 40;
+        const GRID_COLUMN = 1 << 41;
+        const GRID_AREA = 1 << 42;
+        const BOX_SHADOW = 1 << 43;
+        const OPACITY = 1 << 44;
+        const BACKGROUND_GRADIENT = 1 << 45;
+        const TRANSFORM = 1 << 46;
+        const TRANSFORM_ORIGIN = 1 << 47;
     }
 }
@@ -1,6 +1,9 @@
 use std::borrow::Cow;
 use crate::renderer::color::Color;
 use crate::style::style_flags::StyleFlags;
+use crate::style::{
+    BoxShadow, Gradient, GridAreaName, GridAutoFlow, GridPlacement, GridTemplate, GridTemplateAreas, Transform,
+};
 
 pub use taffy::BoxSizing;
 pub use taffy::Overflow;
@@ -11,7 +14,7 @@ use parley::{FontFamily, FontSettings, FontStack, GenericFamily, StyleProperty,
 use crate::geometry::TrblRectangle;
 use crate::text::text_context::ColorBrush;
 
-#[derive(Clone, Copy, Debug)]
+#[derive(Clone, Copy, Debug, PartialEq)]
 pub enum Unit {
     Px(f32),
     Percentage(f32),
@@ -38,6 +41,7 @@ impl Unit {
 pub enum Display {
     Flex,
     Block,
+    Grid,
     None,
 }
 
@@ -150,7 +154,7 @@ impl Default for FontStyle {
     }
 }
 
-#[derive(Clone, Copy, Debug)]
+#[derive(Clone, Debug)]
 pub struct Style {
     font_family_length: u8,
     font_family: [u8; 64],
@@ -177,9 +181,21 @@ pub struct Style {
     flex_grow: f32,
     flex_shrink: f32,
     flex_basis: Unit,
+    grid_template_rows: GridTemplate,
+    grid_template_columns: GridTemplate,
+    grid_template_areas: GridTemplateAreas,
+    grid_auto_flow: GridAutoFlow,
+    /// The start and end of the item in the rows of its grid.
+    grid_row: [GridPlacement; 2],
+    /// The start and end of the item in the columns of its grid.
+    grid_column: [GridPlacement; 2],
+    /// The named area of its grid that the item is placed in. Takes precedence over `grid_row` and `grid_column`.
+    grid_area: GridAreaName,
 
     color: Color,
     background: Color,
+    /// Drawn above the background color, when set.
+    background_gradient: Option<Gradient>,
     font_size: f32,
     font_weight: Weight,
     font_style: FontStyle,
@@ -189,6 +205,13 @@ pub struct Style {
     border_width: TrblRectangle<Unit>,
     border_radius: [(f32, f32); 4],
     scrollbar_color: ScrollbarColor,
+    box_shadow: Option<BoxShadow>,
+    /// The opacity of the element and its children, between 0 and 1.
+    opacity: f32,
+    /// Applied to the element and its children when they are drawn, without affecting the layout.
+    transform: Transform,
+    /// The point that the transform happens around, relative to the border box of the element.
+    transform_origin: [Unit; 2],
 
     /// The element is measured and occupies space, but is not drawn to the screen.
     visible: bool,
@@ -225,8 +248,16 @@ impl Default for Style {
             flex_grow: 0.0,
             flex_shrink: 1.0,
             flex_basis: Unit::Auto,
+            grid_template_rows: GridTemplate::default(),
+            grid_template_columns: GridTemplate::default(),
+            grid_template_areas: GridTemplateAreas::default(),
+            grid_auto_flow: GridAutoFlow::Row,
+            grid_row: [GridPlacement::Auto; 2],
+            grid_column: [GridPlacement::Auto; 2],
+            grid_area: GridAreaName::default(),
             color: Color::BLACK,
             background: Color::TRANSPARENT,
+            background_gradient: None,
             border_color: TrblRectangle::new_all(Color::BLACK),
             font_size: 16.0,
             font_weight: Default::default(),
@@ -237,6 +268,10 @@ impl Default for Style {
                 thumb_color: Color::from_rgb8(150, 150, 150),
                 track_color: Color::from_rgb8(100, 100, 100),
             },
+            box_shadow: None,
+            opacity: 1.0,
+            transform: Transform::default(),
+            transform_origin: [Unit::Percentage(50.0); 2],
             visible: true,
             dirty_flags: StyleFlags::empty(),
         }
@@ -485,6 +520,73 @@ impl Style {
         &mut self.flex_basis
     }
 
+    pub fn grid_template_rows(&self) -> &GridTemplate {
+        &self.grid_template_rows
+    }
+
+    pub fn grid_template_rows_mut(&mut self) -> &mut GridTemplate {
+        self.dirty_flags.insert(StyleFlags::GRID_TEMPLATE_ROWS);
+        &mut self.grid_template_rows
+    }
+
+    pub fn grid_template_columns(&self) -> &GridTemplate {
+        &self.grid_template_columns
+    }
+
+    pub fn grid_template_columns_mut(&mut self) -> &mut GridTemplate {
+        self.dirty_flags.insert(StyleFlags::GRID_TEMPLATE_COLUMNS);
+        &mut self.grid_template_columns
+    }
+
+    pub fn grid_template_areas(&self) -> &GridTemplateAreas {
+        &self.grid_template_areas
+    }
+
+    pub fn grid_template_areas_mut(&mut self) -> &mut GridTemplateAreas {
+        self.dirty_flags.insert(StyleFlags::GRID_TEMPLATE_AREAS);
+        &mut self.grid_template_areas
+    }
+
+    pub fn grid_auto_flow(&self) -> GridAutoFlow {
+        self.grid_auto_flow
+    }
+
+    pub fn grid_auto_flow_mut(&mut self) -> &mut GridAutoFlow {
+        self.dirty_flags.insert(StyleFlags::GRID_AUTO_FLOW);
+        &mut self.grid_auto_flow
+    }
+
+    pub fn grid_row(&self) -> [GridPlacement; 2] {
+        self.grid_row
+    }
+
+    pub fn grid_row_mut(&mut self) -> &mut [GridPlacement; 2] {
+        self.dirty_flags.insert(StyleFlags::GRID_ROW);
+        &mut self.grid_row
+    }
+
+    pub fn grid_column(&self) -> [GridPlacement; 2] {
+        self.grid_column
+    }
+
+    pub fn grid_column_mut(&mut self) -> &mut [GridPlacement; 2] {
+        self.dirty_flags.insert(StyleFlags::GRID_COLUMN);
+        &mut self.grid_column
+    }
+
+    pub fn grid_area(&self) -> Option<&str> {
+        if self.grid_area.is_empty() {
+            None
+        } else {
+            Some(self.grid_area.as_str())
+        }
+    }
+
+    pub fn grid_area_mut(&mut self) -> &mut GridAreaName {
+        self.dirty_flags.insert(StyleFlags::GRID_AREA);
+        &mut self.grid_area
+    }
+
     pub fn color(&self) -> Color {
         self.color
     }
@@ -575,6 +677,51 @@ impl Style {
         &mut self.scrollbar_color
     }
 
+    pub fn background_gradient(&self) -> Option<Gradient> {
+        self.background_gradient
+    }
+
+    pub fn background_gradient_mut(&mut self) -> &mut Option<Gradient> {
+        self.dirty_flags.insert(StyleFlags::BACKGROUND_GRADIENT);
+        &mut self.background_gradient
+    }
+
+    pub fn box_shadow(&self) -> Option<BoxShadow> {
+        self.box_shadow
+    }
+
+    pub fn box_shadow_mut(&mut self) -> &mut Option<BoxShadow> {
+        self.dirty_flags.insert(StyleFlags::BOX_SHADOW);
+        &mut self.box_shadow
+    }
+
+    pub fn opacity(&self) -> f32 {
+        self.opacity
+    }
+
+    pub fn opacity_mut(&mut self) -> &mut f32 {
+        self.dirty_flags.insert(StyleFlags::OPACITY);
+        &mut self.opacity
+    }
+
+    pub fn transform(&self) -> Transform {
+        self.transform
+    }
+
+    pub fn transform_mut(&mut self) -> &mut Transform {
+        self.dirty_flags.insert(StyleFlags::TRANSFORM);
+        &mut self.transform
+    }
+
+    pub fn transform_origin(&self) -> [Unit; 2] {
+        self.transform_origin
+    }
+
+    pub fn transform_origin_mut(&mut self) -> &mut [Unit; 2] {
+        self.dirty_flags.insert(StyleFlags::TRANSFORM_ORIGIN);
+        &mut self.transform_origin
+    }
+
     pub fn visible(&self) -> bool {
         self.visible
     }
@@ -596,11 +743,11 @@ impl Style {
         let new_dirty_flags = new.dirty_flags;
 
         if old_dirty_flags.is_empty() {
-            return *new;
+            return new.clone();
         }
 
         if new_dirty_flags.is_empty() {
-            return *old;
+            return old.clone();
         }
 
         let font_family_length = if new_dirty_flags.contains(StyleFlags::FONT_FAMILY_LENGTH) {
@@ -669,6 +816,34 @@ impl Style {
 
         let flex_basis = if new_dirty_flags.contains(StyleFlags::FLEX_BASIS) { new.flex_basis } else { old.flex_basis };
 
+        let grid_template_rows = if new_dirty_flags.contains(StyleFlags::GRID_TEMPLATE_ROWS) {
+            new.grid_template_rows.clone()
+        } else {
+            old.grid_template_rows.clone()
+        };
+
+        let grid_template_columns = if new_dirty_flags.contains(StyleFlags::GRID_TEMPLATE_COLUMNS) {
+            new.grid_template_columns.clone()
+        } else {
+            old.grid_template_columns.clone()
+        };
+
+        let grid_template_areas = if new_dirty_flags.contains(StyleFlags::GRID_TEMPLATE_AREAS) {
+            new.grid_template_areas.clone()
+        } else {
+            old.grid_template_areas.clone()
+        };
+
+        let grid_auto_flow =
+            if new_dirty_flags.contains(StyleFlags::GRID_AUTO_FLOW) { new.grid_auto_flow } else { old.grid_auto_flow };
+
+        let grid_row = if new_dirty_flags.contains(StyleFlags::GRID_ROW) { new.grid_row } else { old.grid_row };
+
+        let grid_column = if new_dirty_flags.contains(StyleFlags::GRID_COLUMN) { new.grid_column } else { old.grid_column };
+
+        let grid_area =
+            if new_dirty_flags.contains(StyleFlags::GRID_AREA) { new.grid_area.clone() } else { old.grid_area.clone() };
+
         let color = if new_dirty_flags.contains(StyleFlags::COLOR) { new.color } else { old.color };
 
         let background = if new_dirty_flags.contains(StyleFlags::BACKGROUND) { new.background } else { old.background };
@@ -697,6 +872,24 @@ impl Style {
             old.scrollbar_color
         };
 
+        let background_gradient = if new_dirty_flags.contains(StyleFlags::BACKGROUND_GRADIENT) {
+            new.background_gradient
+        } else {
+            old.background_gradient
+        };
+
+        let box_shadow = if new_dirty_flags.contains(StyleFlags::BOX_SHADOW) { new.box_shadow } else { old.box_shadow };
+
+        let opacity = if new_dirty_flags.contains(StyleFlags::OPACITY) { new.opacity } else { old.opacity };
+
+        let transform = if new_dirty_flags.contains(StyleFlags::TRANSFORM) { new.transform } else { old.transform };
+
+        let transform_origin = if new_dirty_flags.contains(StyleFlags::TRANSFORM_ORIGIN) {
+            new.transform_origin
+        } else {
+            old.transform_origin
+        };
+
         let visible = if new_dirty_flags.contains(StyleFlags::VISIBLE) { new.visible } else { old.visible };
 
         let dirty_flags = old_dirty_flags | new_dirty_flags;
@@ -727,8 +920,16 @@ impl Style {
             flex_grow,
             flex_shrink,
             flex_basis,
+            grid_template_rows,
+            grid_template_columns,
+            grid_template_areas,
+            grid_auto_flow,
+            grid_row,
+            grid_column,
+            grid_area,
             color,
             background,
+            background_gradient,
             font_size,
             font_weight,
             font_style,
@@ -737,6 +938,10 @@ impl Style {
             border_width,
             border_radius,
             scrollbar_color,
+            box_shadow,
+            opacity,
+            transform,
+            transform_origin,
             visible,
             dirty_flags,
         }
@@ -873,6 +1078,8 @@ impl Style {
         let flex_shrink = self.flex_shrink;
         
         let flex_basis = Self::scale_unit(&self.flex_basis, scaling_factor);
+        let grid_template_rows = self.grid_template_rows.scale(scaling_factor);
+        let grid_template_columns = self.grid_template_columns.scale(scaling_factor);
         let border_width = Self::scale_trlb_unit(&self.border_width, scaling_factor);
         let border_radius = self.border_radius;
         let border_radius = [
@@ -908,8 +1115,16 @@ impl Style {
             flex_grow,
             flex_shrink,
             flex_basis,
+            grid_template_rows,
+            grid_template_columns,
+            grid_template_areas: self.grid_template_areas.clone(),
+            grid_auto_flow: self.grid_auto_flow,
+            grid_row: self.grid_row,
+            grid_column: self.grid_column,
+            grid_area: self.grid_area.clone(),
             color: self.color,
             background: self.background,
+            background_gradient: self.background_gradient,
             // Don't scale the font_size, this is done externally.
             font_size: self.font_size,
             font_weight: self.font_weight,
@@ -919,6 +1134,13 @@ impl Style {
             border_width,
             border_radius,
             scrollbar_color: self.scrollbar_color,
+            box_shadow: self.box_shadow.map(|box_shadow| box_shadow.scale(scaling_factor)),
+            opacity: self.opacity,
+            transform: self.transform.scale_translation(scaling_factor),
+            transform_origin: [
+                Self::scale_unit(&self.transform_origin[0], scaling_factor),
+                Self::scale_unit(&self.transform_origin[1], scaling_factor),
+            ],
             visible: self.visible,
             dirty_flags: self.dirty_flags,
         }
@@ -1,4 +1,8 @@
-use crate::style::{AlignItems, Display, FlexDirection, JustifyContent, Style, Unit, Wrap};
+use crate::style::{
+    AlignItems, Display, FlexDirection, GridAutoFlow, GridPlacement, GridTemplate, GridTrackSize, JustifyContent, Style,
+    Unit, Wrap,
+};
+use taffy::style_helpers::{line, minmax};
 use taffy::{FlexWrap, Overflow};
 
 fn unit_to_taffy_dimension(unit: Unit) -> taffy::Dimension {
@@ -31,7 +35,63 @@ fn unit_to_taffy_length_percentage(unit: Unit) -> taffy::LengthPercentage {
     }
 }
 
+fn grid_track_size_to_taffy_min(size: GridTrackSize) -> taffy::MinTrackSizingFunction {
+    match size {
+        GridTrackSize::Px(px) => taffy::MinTrackSizingFunction::length(px),
+        GridTrackSize::Percentage(percentage) => taffy::MinTrackSizingFunction::percent(percentage / 100.0),
+        // A flexible minimum is invalid, so it is treated as auto like in CSS.
+        GridTrackSize::Fr(_) | GridTrackSize::Auto => taffy::MinTrackSizingFunction::auto(),
+        GridTrackSize::MinContent => taffy::MinTrackSizingFunction::min_content(),
+        GridTrackSize::MaxContent => taffy::MinTrackSizingFunction::max_content(),
+    }
+}
+
+fn grid_track_size_to_taffy_max(size: GridTrackSize) -> taffy::MaxTrackSizingFunction {
+    match size {
+        GridTrackSize::Px(px) => taffy::MaxTrackSizingFunction::length(px),
+        GridTrackSize::Percentage(percentage) => taffy::MaxTrackSizingFunction::percent(percentage / 100.0),
+        GridTrackSize::Fr(fr) => taffy::MaxTrackSizingFunction::fr(fr),
+        GridTrackSize::Auto => taffy::MaxTrackSizingFunction::auto(),
+        GridTrackSize::MinContent => taffy::MaxTrackSizingFunction::min_content(),
+        GridTrackSize::MaxContent => taffy::MaxTrackSizingFunction::max_content(),
+    }
+}
+
+fn grid_template_to_taffy(template: &GridTemplate) -> Vec<taffy::TrackSizingFunction> {
+    template
+        .tracks()
+        .iter()
+        .map(|track| minmax(grid_track_size_to_taffy_min(track.min), grid_track_size_to_taffy_max(track.max)))
+        .collect()
+}
+
+fn grid_placement_to_taffy(placement: [GridPlacement; 2]) -> taffy::Line<taffy::GridPlacement> {
+    let to_taffy = |placement: GridPlacement| match placement {
+        GridPlacement::Auto => taffy::GridPlacement::Auto,
+        GridPlacement::Line(index) => line(index),
+        GridPlacement::Span(span) => taffy::GridPlacement::Span(span),
+    };
+
+    taffy::Line {
+        start: to_taffy(placement[0]),
+        end: to_taffy(placement[1]),
+    }
+}
+
 impl Style {
+    /// Returns the rows and columns of the named area `name` in this grid container.
+    pub(crate) fn grid_area_lines(
+        &self,
+        name: &str,
+    ) -> Option<(taffy::Line<taffy::GridPlacement>, taffy::Line<taffy::GridPlacement>)> {
+        let area = self.grid_template_areas().find(name)?;
+
+        Some((
+            grid_placement_to_taffy([GridPlacement::Line(area.row_start), GridPlacement::Line(area.row_end)]),
+            grid_placement_to_taffy([GridPlacement::Line(area.column_start), GridPlacement::Line(area.column_end)]),
+        ))
+    }
+
     pub fn to_taffy_style(&self) -> taffy::Style {
         let style = self;
 
@@ -43,6 +103,7 @@ impl Style {
         let display = match style.display() {
             Display::Flex => taffy::Display::Flex,
             Display::Block => taffy::Display::Block,
+            Display::Grid => taffy::Display::Grid,
             Display::None => taffy::Display::None,
         };
 
@@ -139,6 +200,13 @@ impl Style {
 
         let scrollbar_width = style.scrollbar_width();
 
+        let grid_auto_flow = match style.grid_auto_flow() {
+            GridAutoFlow::Row => taffy::GridAutoFlow::Row,
+            GridAutoFlow::Column => taffy::GridAutoFlow::Column,
+            GridAutoFlow::RowDense => taffy::GridAutoFlow::RowDense,
+            GridAutoFlow::ColumnDense => taffy::GridAutoFlow::ColumnDense,
+        };
+
         let box_sizing = taffy::BoxSizing::BorderBox;
 
         taffy::Style {
@@ -165,6 +233,11 @@ impl Style {
                 y: overflow_y,
             },
             border,
+            grid_template_rows: grid_template_to_taffy(style.grid_template_rows()),
+            grid_template_columns: grid_template_to_taffy(style.grid_template_columns()),
+            grid_auto_flow,
+            grid_row: grid_placement_to_taffy(style.grid_row()),
+            grid_column: grid_placement_to_taffy(style.grid_column()),
             ..Default::default()
         }
     }
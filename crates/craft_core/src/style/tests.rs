@@ -0,0 +1,409 @@
+use crate::components::{Component, ComponentId, ComponentSpecification, Event, Props};
+use crate::elements::{Container, ElementStyles};
+use crate::events::PointerButton;
+use crate::geometry::{Point, Rectangle, Size};
+use crate::renderer::shadow::{blurred_rounded_rect_steps, ShadowStep};
+use crate::style::{
+    Animation, AnimationIterations, BoxShadow, Easing, Gradient, Keyframe, Style, StyleFlags, Transform,
+    MAX_GRADIENT_STOPS,
+};
+use crate::testing::TestHarness;
+use crate::{rgb, Color, WindowContext};
+use std::time::Duration;
+
+fn size(harness: &TestHarness, id: &str) -> (f32, f32) {
+    let bounds = harness.element_bounds(id).unwrap();
+    (bounds.width.round(), bounds.height.round())
+}
+
+#[test]
+fn easing_curves_start_and_end_at_the_keyframes() {
+    for easing in [Easing::Linear, Easing::Ease, Easing::EaseIn, Easing::EaseOut, Easing::EaseInOut] {
+        assert_eq!(easing.apply(0.0), 0.0);
+        assert_eq!(easing.apply(1.0), 1.0);
+    }
+
+    assert_eq!(Easing::Linear.apply(0.25), 0.25);
+    assert!((Easing::EaseInOut.apply(0.5) - 0.5).abs() < 1e-4);
+    assert!(Easing::EaseIn.apply(0.25) < 0.25);
+    assert!(Easing::EaseOut.apply(0.25) > 0.25);
+    // Progress outside of the animation is clamped.
+    assert_eq!(Easing::Linear.apply(1.5), 1.0);
+}
+
+#[test]
+fn transition_interpolates_between_the_normal_and_hovered_style() {
+    let mut harness = TestHarness::new(
+        Container::new()
+            .push(
+                Container::new()
+                    .id("box")
+                    .width(100)
+                    .height(50)
+                    .hovered()
+                    .width(200)
+                    .height(100)
+                    .normal()
+                    .transition(StyleFlags::WIDTH, Duration::from_millis(100), Easing::Linear),
+            )
+            .component(),
+        (),
+        Size::new(400.0, 300.0),
+    );
+    assert!(!harness.is_animating());
+
+    harness.pointer_moved(Point::new(10.0, 10.0));
+    assert_eq!(size(&harness, "box"), (100.0, 100.0), "Only the width is transitioned.");
+    assert!(harness.is_animating());
+
+    harness.advance_time(Duration::from_millis(50));
+    assert_eq!(size(&harness, "box"), (150.0, 100.0));
+
+    harness.advance_time(Duration::from_millis(50));
+    assert_eq!(size(&harness, "box"), (200.0, 100.0));
+    assert!(!harness.is_animating());
+
+    // Leaving halfway through the way back starts a new transition from the displayed width.
+    harness.pointer_moved(Point::new(300.0, 50.0));
+    harness.advance_time(Duration::from_millis(50));
+    assert_eq!(size(&harness, "box").0, 150.0);
+    harness.pointer_moved(Point::new(10.0, 10.0));
+    harness.advance_time(Duration::from_millis(50));
+    assert_eq!(size(&harness, "box").0, 175.0);
+    harness.advance_time(Duration::from_millis(50));
+    assert_eq!(size(&harness, "box").0, 200.0);
+}
+
+#[test]
+fn keyframe_animation_loops_while_it_is_displayed() {
+    let animation = Animation::new(Duration::from_millis(100))
+        .keyframe(Keyframe::new(1.0).height(50))
+        .keyframe(Keyframe::new(0.0).height(10))
+        .iterations(AnimationIterations::Infinite)
+        .alternate();
+    let mut harness = TestHarness::new(
+        Container::new().push(Container::new().id("box").width(10).height(100).animation(animation)).component(),
+        (),
+        Size::new(400.0, 300.0),
+    );
+
+    assert_eq!(size(&harness, "box"), (10.0, 10.0));
+    assert!(harness.is_animating());
+
+    harness.advance_time(Duration::from_millis(25));
+    assert_eq!(size(&harness, "box"), (10.0, 20.0));
+
+    // Every other iteration plays backwards.
+    harness.advance_time(Duration::from_millis(100));
+    assert_eq!(size(&harness, "box"), (10.0, 40.0));
+
+    harness.advance_time(Duration::from_millis(75));
+    assert_eq!(size(&harness, "box"), (10.0, 10.0));
+    assert!(harness.is_animating());
+}
+
+#[test]
+fn finished_animation_returns_to_the_element_style() {
+    let animation = Animation::new(Duration::from_millis(100)).keyframe(Keyframe::new(0.5).width(300));
+    let mut harness = TestHarness::new(
+        Container::new().push(Container::new().id("box").width(100).height(10).animation(animation)).component(),
+        (),
+        Size::new(400.0, 300.0),
+    );
+
+    // Without keyframes at the start and the end, the animation starts and ends at the element's own style.
+    harness.advance_time(Duration::from_millis(25));
+    assert_eq!(size(&harness, "box").0, 200.0);
+    harness.advance_time(Duration::from_millis(25));
+    assert_eq!(size(&harness, "box").0, 300.0);
+
+    harness.advance_time(Duration::from_millis(50));
+    assert_eq!(size(&harness, "box").0, 100.0);
+    assert!(!harness.is_animating());
+}
+
+#[test]
+fn animation_interpolates_colors_with_premultiplied_alpha() {
+    let animation = Animation::new(Duration::from_millis(100))
+        .keyframe(Keyframe::new(0.0).background(Color::TRANSPARENT))
+        .keyframe(Keyframe::new(1.0).background(rgb(255, 0, 0)));
+
+    let style = animation.sample(&Style::default(), Duration::from_millis(50)).unwrap();
+    let [red, green, blue, alpha] = style.background().to_rgba8().to_u8_array();
+    assert_eq!((red, green, blue), (255, 0, 0));
+    assert!(alpha.abs_diff(128) <= 1);
+
+    assert!(animation.sample(&Style::default(), Duration::from_millis(100)).is_none());
+}
+
+#[test]
+fn linear_gradients_follow_the_css_angles() {
+    let rectangle = Rectangle::new(0.0, 0.0, 100.0, 50.0);
+    let endpoints = |angle: f32| {
+        let gradient = Gradient::linear(angle, &[(0.0, Color::BLACK), (1.0, Color::WHITE)]).to_peniko(rectangle);
+        match gradient.kind {
+            peniko::GradientKind::Linear { start, end } => (start.x.round(), start.y.round(), end.x.round(), end.y.round()),
+            kind => panic!("Expected a linear gradient, got {kind:?}"),
+        }
+    };
+
+    assert_eq!(endpoints(0.0), (50.0, 50.0, 50.0, 0.0));
+    assert_eq!(endpoints(90.0), (0.0, 25.0, 100.0, 25.0));
+    assert_eq!(endpoints(180.0), (50.0, 0.0, 50.0, 50.0));
+}
+
+#[test]
+fn gradients_keep_the_first_stops_that_fit() {
+    let stops: Vec<(f32, Color)> = (0..=MAX_GRADIENT_STOPS).map(|stop| (stop as f32 / 10.0, Color::BLACK)).collect();
+    let gradient = Gradient::radial(&stops);
+
+    assert_eq!(gradient.stops().len(), MAX_GRADIENT_STOPS);
+    assert_eq!(gradient.stops().last().unwrap().offset, (MAX_GRADIENT_STOPS - 1) as f32 / 10.0);
+}
+
+#[test]
+fn shadows_and_opacity_are_drawn_around_the_element() {
+    let mut harness = TestHarness::new(
+        Container::new()
+            .push(
+                Container::new()
+                    .width(20)
+                    .height(10)
+                    .margin(10, 0, 0, 10)
+                    .border_radius(4, 4, 4, 4)
+                    .opacity(0.5)
+                    .box_shadow(BoxShadow::new(2.0, 3.0, 4.0, 1.0, Color::BLACK)),
+            )
+            .push(
+                Container::new().width(20).height(10).box_shadow(BoxShadow::new(0.0, 0.0, 2.0, 0.0, Color::BLACK).inset()),
+            )
+            .component(),
+        (),
+        Size::new(100.0, 100.0),
+    );
+
+    let snapshot = harness.render_list_snapshot();
+    // The spread grows the shadow and its corner radius, and the blur radius is twice the standard deviation.
+    assert!(
+        snapshot.contains("opacity_layer 0.5\n  shadow x=11 y=12 w=22 h=12 radius=5 std_dev=2 #000000ff\n"),
+        "{snapshot}"
+    );
+    assert!(snapshot.contains("inset_shadow x=30 y=0 w=20 h=10 radius=0 std_dev=1 #000000ff\n"), "{snapshot}");
+}
+
+#[test]
+fn shadows_use_the_radius_of_each_corner() {
+    let mut harness = TestHarness::new(
+        Container::new()
+            .width(20)
+            .height(10)
+            .border_radius(4, 0, 2, 0)
+            .box_shadow(BoxShadow::new(0.0, 0.0, 0.0, 0.0, Color::BLACK))
+            .component(),
+        (),
+        Size::new(100.0, 100.0),
+    );
+
+    let snapshot = harness.render_list_snapshot();
+    // Every corner is drawn with its own radius, clipped to the quarter of the shadow around it.
+    assert!(
+        snapshot.contains(concat!(
+            "layer x=-1.5 y=-1.5 w=11.5 h=6.5\n  shadow x=0 y=0 w=20 h=10 radius=4 std_dev=0.5 #000000ff\n",
+            "layer x=10 y=-1.5 w=11.5 h=6.5\n  shadow x=0 y=0 w=20 h=10 radius=0 std_dev=0.5 #000000ff\n",
+            "layer x=10 y=5 w=11.5 h=6.5\n  shadow x=0 y=0 w=20 h=10 radius=2 std_dev=0.5 #000000ff\n",
+            "layer x=-1.5 y=5 w=11.5 h=6.5\n  shadow x=0 y=0 w=20 h=10 radius=0 std_dev=0.5 #000000ff\n",
+        )),
+        "{snapshot}"
+    );
+}
+
+#[test]
+fn hybrid_renderer_approximates_blurred_shadows() {
+    let rectangle = Rectangle::new(10.0, 10.0, 40.0, 20.0);
+    let color = Color::from_rgba8(0, 0, 0, 128);
+    let bounds = |step: &ShadowStep| (step.rectangle.x, step.rectangle.y, step.rectangle.width, step.rectangle.height);
+
+    // Shadows without a blur keep their hard edges.
+    let steps = blurred_rounded_rect_steps(rectangle, 4.0, 0.5, color);
+    assert_eq!(steps.len(), 1);
+    assert_eq!((bounds(&steps[0]), steps[0].radius, steps[0].color), ((10.0, 10.0, 40.0, 20.0), 4.0, color));
+
+    // Blurred shadows fade from 1.5 standard deviations outside of the edge to 1.5 inside of it.
+    let steps = blurred_rounded_rect_steps(rectangle, 4.0, 2.0, color);
+    assert_eq!(steps.len(), 6);
+    assert_eq!(bounds(&steps[0]), (7.5, 7.5, 45.0, 25.0));
+    assert_eq!(steps[0].radius, 6.5);
+    assert_eq!(bounds(&steps[5]), (12.5, 12.5, 35.0, 15.0));
+    assert_eq!(steps[5].radius, 1.5);
+
+    // The alpha adds up to the alpha of the shadow where all of the steps overlap, and rises linearly towards it.
+    let alpha = |steps: &[ShadowStep]| 1.0 - steps.iter().map(|step| 1.0 - step.color.components[3]).product::<f32>();
+    let shadow_alpha = color.components[3];
+    assert!((alpha(&steps) - shadow_alpha).abs() < 1e-5);
+    assert!((alpha(&steps[..3]) - shadow_alpha / 2.0).abs() < 1e-5);
+}
+
+#[test]
+fn opacity_is_animatable() {
+    let animation = Animation::new(Duration::from_millis(100)).keyframe(Keyframe::new(1.0).opacity(0.0));
+    let mut style = Style::default();
+    *style.opacity_mut() = 0.8;
+
+    assert_eq!(animation.sample(&style, Duration::from_millis(25)).unwrap().opacity(), 0.6);
+}
+
+#[cfg(feature = "vello_cpu_renderer")]
+#[test]
+fn opacity_gradients_and_shadows_are_rendered() {
+    let mut harness = TestHarness::new(
+        Container::new()
+            .width(140)
+            .height(40)
+            .background(Color::WHITE)
+            .push(Container::new().width(20).height(20).background(rgb(255, 0, 0)).opacity(0.5))
+            .push(
+                Container::new()
+                    .width(40)
+                    .height(20)
+                    .background_gradient(Gradient::linear(90.0, &[(0.0, Color::BLACK), (1.0, Color::WHITE)])),
+            )
+            .push(
+                Container::new()
+                    .width(20)
+                    .height(20)
+                    .margin(0, 0, 0, 10)
+                    .background(rgb(0, 0, 255))
+                    .box_shadow(BoxShadow::new(0.0, 10.0, 0.0, 0.0, Color::BLACK)),
+            )
+            .push(
+                Container::new().width(30).height(20).box_shadow(BoxShadow::new(0.0, 0.0, 0.0, 4.0, Color::BLACK).inset()),
+            )
+            .component(),
+        (),
+        Size::new(140.0, 40.0),
+    );
+
+    let image = harness.render_to_image();
+    let [red, green, blue, _] = image.pixel(10, 10);
+    assert_eq!(red, 255);
+    assert!(green.abs_diff(128) <= 1 && blue.abs_diff(128) <= 1, "{:?}", image.pixel(10, 10));
+
+    let (left, right) = (image.pixel(22, 10)[0], image.pixel(58, 10)[0]);
+    assert!(left < 30 && right > 225, "{left} {right}");
+
+    assert_eq!(image.pixel(80, 10), [0, 0, 255, 255]);
+    // The shadow is offset below the element.
+    assert_eq!(image.pixel(80, 25), [0, 0, 0, 255]);
+    assert_eq!(image.pixel(80, 35), [255, 255, 255, 255]);
+
+    // The inset shadow covers the edges of the element and leaves its middle alone.
+    assert_eq!(image.pixel(91, 10), [0, 0, 0, 255]);
+    assert_eq!(image.pixel(105, 10), [255, 255, 255, 255]);
+}
+
+#[test]
+fn transforms_do_not_affect_the_layout() {
+    let mut harness = TestHarness::new(
+        Container::new()
+            .push(
+                Container::new().id("box").width(40).height(20).transform(Transform::new().translate(100.0, 0.0).rotate(90.0)),
+            )
+            .push(Container::new().id("next").width(10).height(10))
+            .component(),
+        (),
+        Size::new(200.0, 200.0),
+    );
+
+    let bounds = |id: &str| {
+        let bounds = harness.element_bounds(id).unwrap();
+        (bounds.x, bounds.y, bounds.width, bounds.height)
+    };
+    assert_eq!(bounds("box"), (0.0, 0.0, 40.0, 20.0));
+    assert_eq!(bounds("next"), (40.0, 0.0, 10.0, 10.0));
+
+    // The rotation happens around the center of the element.
+    let snapshot = harness.render_list_snapshot();
+    assert!(snapshot.contains("transform 0,1,-1,0,130,-10\n  "), "{snapshot}");
+}
+
+#[derive(Default)]
+struct Clicks {
+    count: u32,
+}
+
+impl Component for Clicks {
+    type GlobalState = ();
+    type Props = Transform;
+    type Message = ();
+
+    fn view(
+        &self,
+        _global_state: &Self::GlobalState,
+        props: &Self::Props,
+        _children: Vec<ComponentSpecification>,
+        _id: ComponentId,
+        _window: &WindowContext,
+    ) -> ComponentSpecification {
+        Container::new()
+            .width("100%")
+            .height("100%")
+            .push(Container::new().width(100).height(20).transform(*props).on_pointer_button(
+                |state: &mut Clicks, _: &mut (), _: &mut Event, pointer_button: &PointerButton| {
+                    if pointer_button.clicked() {
+                        state.count += 1;
+                    }
+                },
+            ))
+            .component()
+    }
+}
+
+fn clicks_at(transform: Transform, position: Point) -> u32 {
+    let mut harness = TestHarness::new(Clicks::component().props(Props::new(transform)), (), Size::new(200.0, 200.0));
+    harness.click(position);
+    harness.component_state::<Clicks>().unwrap().count
+}
+
+#[test]
+fn transformed_elements_are_hit_where_they_are_drawn() {
+    let translated = Transform::new().translate(100.0, 50.0);
+    assert_eq!(clicks_at(translated, Point::new(10.0, 10.0)), 0);
+    assert_eq!(clicks_at(translated, Point::new(110.0, 60.0)), 1);
+
+    // Rotated around its center at (50, 10), the element covers x = 40..60 and y = -40..60.
+    let rotated = Transform::new().rotate(90.0);
+    assert_eq!(clicks_at(rotated, Point::new(90.0, 10.0)), 0);
+    assert_eq!(clicks_at(rotated, Point::new(50.0, 50.0)), 1);
+
+    let scaled = Transform::new().scale(0.5, 0.5);
+    assert_eq!(clicks_at(scaled, Point::new(10.0, 10.0)), 0);
+    assert_eq!(clicks_at(scaled, Point::new(30.0, 10.0)), 1);
+}
+
+#[cfg(feature = "vello_cpu_renderer")]
+#[test]
+fn transformed_elements_are_rendered() {
+    let mut harness = TestHarness::new(
+        Container::new()
+            .width(40)
+            .height(40)
+            .background(Color::WHITE)
+            .push(
+                Container::new()
+                    .width(10)
+                    .height(10)
+                    .background(rgb(255, 0, 0))
+                    .transform(Transform::new().translate(20.0, 20.0).scale(2.0, 1.0)),
+            )
+            .component(),
+        (),
+        Size::new(40.0, 40.0),
+    );
+
+    let image = harness.render_to_image();
+    assert_eq!(image.pixel(5, 5), [255, 255, 255, 255]);
+    // Scaled around its center, the element covers x = 15..35 and y = 20..30.
+    assert_eq!(image.pixel(18, 25), [255, 0, 0, 255]);
+    assert_eq!(image.pixel(32, 25), [255, 0, 0, 255]);
+    assert_eq!(image.pixel(25, 15), [255, 255, 255, 255]);
+}
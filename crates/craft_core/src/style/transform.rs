@@ -0,0 +1,69 @@
+use peniko::kurbo::{Affine, Vec2};
+
+/// A 2D transformation of an element and its children, like `transform` in CSS.
+///
+/// The transformations are applied in the order they are added, just like the functions of a CSS transform. They
+/// happen around the `transform_origin` of the element and do not affect the layout.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct Transform {
+    affine: Affine,
+}
+
+impl Transform {
+    pub fn new() -> Self {
+        Transform::default()
+    }
+
+    /// A transform with the matrix `[a c e; b d f; 0 0 1]`, like `matrix(a, b, c, d, e, f)` in CSS.
+    pub fn matrix(a: f64, b: f64, c: f64, d: f64, e: f64, f: f64) -> Self {
+        Transform {
+            affine: Affine::new([a, b, c, d, e, f]),
+        }
+    }
+
+    pub fn translate(self, x: f64, y: f64) -> Self {
+        self.then(Affine::translate(Vec2::new(x, y)))
+    }
+
+    /// Rotates clockwise by `degrees`.
+    pub fn rotate(self, degrees: f64) -> Self {
+        self.then(Affine::rotate(degrees.to_radians()))
+    }
+
+    pub fn scale(self, x: f64, y: f64) -> Self {
+        self.then(Affine::scale_non_uniform(x, y))
+    }
+
+    /// Skews along the x- and y-axis by the given angles in degrees.
+    pub fn skew(self, x_degrees: f64, y_degrees: f64) -> Self {
+        self.then(Affine::skew(x_degrees.to_radians().tan(), y_degrees.to_radians().tan()))
+    }
+
+    pub fn is_identity(&self) -> bool {
+        self.affine == Affine::IDENTITY
+    }
+
+    pub fn to_affine(self) -> Affine {
+        self.affine
+    }
+
+    fn then(self, affine: Affine) -> Self {
+        // Like in CSS, the last transformation is applied to the element first.
+        Transform {
+            affine: self.affine * affine,
+        }
+    }
+
+    /// Translations are in pixels, so they have to be scaled along with the layout.
+    pub(crate) fn scale_translation(self, scaling_factor: f32) -> Self {
+        let [a, b, c, d, e, f] = self.affine.as_coeffs();
+        let scaling_factor = scaling_factor as f64;
+        Transform::matrix(a, b, c, d, e * scaling_factor, f * scaling_factor)
+    }
+}
+
+impl From<Affine> for Transform {
+    fn from(affine: Affine) -> Self {
+        Transform { affine }
+    }
+}
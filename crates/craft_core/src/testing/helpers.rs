@@ -0,0 +1,153 @@
+//! Helpers that the tests share.
+
+use crate::accessibility::{Node, NodeId, Role, Toggled, TreeUpdate};
+use crate::components::{Component, ComponentId, ComponentSpecification, Event, Props};
+use crate::elements::{Container, ElementStyles, Slider, Switch, Text, TextInput};
+use crate::events::PointerButton;
+use crate::geometry::{Point, Size};
+use crate::style::Overflow;
+use crate::testing::TestHarness;
+use crate::WindowContext;
+use winit::event::{ElementState, MouseButton};
+
+/// Mounts the component `C` with `props` into a window of 400x300 logical pixels.
+pub(crate) fn mount<C: Component>(props: C::Props) -> TestHarness {
+    TestHarness::new(C::component().props(Props::new(props)), (), Size::new(400.0, 300.0))
+}
+
+/// The state of the first component of type `C`.
+pub(crate) fn state<C: Component>(harness: &TestHarness) -> &C {
+    harness.component_state::<C>().unwrap()
+}
+
+/// A form with a text input, a switch, a slider, a button and a scrolled list, for the focus and the accessibility.
+#[derive(Default)]
+pub(crate) struct Form {
+    pub(crate) presses: u32,
+    pub(crate) name: String,
+    pub(crate) subscribed: bool,
+    pub(crate) volume: f64,
+}
+
+impl Component for Form {
+    type GlobalState = ();
+    type Props = ();
+    type Message = ();
+
+    fn view(
+        &self,
+        _global_state: &Self::GlobalState,
+        _props: &Self::Props,
+        _children: Vec<ComponentSpecification>,
+        _id: ComponentId,
+        _window: &WindowContext,
+    ) -> ComponentSpecification {
+        Container::new()
+            .id("root")
+            .width("100%")
+            .height("100%")
+            .push(Text::new("Name").id("label"))
+            .push(TextInput::new(&self.name).id("name").on_text_input_changed(
+                |state: &mut Form, _: &mut (), _: &mut Event, name: &str| {
+                    state.name = name.to_string();
+                },
+            ))
+            .push(Switch::new(20.0).id("subscribed").on_switch_toggled(
+                |state: &mut Form, _: &mut (), _: &mut Event, subscribed: bool| {
+                    state.subscribed = subscribed;
+                },
+            ))
+            .push(Slider::new(16.0).id("volume").on_slider_value_changed(
+                |state: &mut Form, _: &mut (), _: &mut Event, volume: f64| {
+                    state.volume = volume;
+                },
+            ))
+            .push(
+                Container::new()
+                    .id("submit")
+                    .width(100)
+                    .height(40)
+                    .accessibility_label("Submit")
+                    .accessibility_role(Role::Button)
+                    .on_pointer_button(|state: &mut Form, _: &mut (), _: &mut Event, pointer_button: &PointerButton| {
+                        if pointer_button.clicked() {
+                            state.presses += 1;
+                        }
+                    }),
+            )
+            .push(
+                Container::new()
+                    .id("list")
+                    .width(100)
+                    .height(50)
+                    .overflow(Overflow::Scroll)
+                    .push(Container::new().width(100).height(200)),
+            )
+            .component()
+    }
+}
+
+/// The accessibility node of the element with the id `id`.
+pub(crate) fn accessibility_node<'a>(harness: &TestHarness, tree: &'a TreeUpdate, id: &str) -> &'a Node {
+    let node_id = NodeId(harness.find_element_by_id(id).unwrap().component_id());
+    tree.nodes.iter().find(|(id, _)| *id == node_id).map(|(_, node)| node).unwrap()
+}
+
+/// Whether the checkbox, switch or radio button with the id `id` is checked, as assistive technologies see it.
+pub(crate) fn toggled(harness: &TestHarness, id: &str) -> Option<Toggled> {
+    accessibility_node(harness, &harness.accessibility_tree(), id).toggled()
+}
+
+/// The id of the element that has the focus.
+pub(crate) fn focused_id(harness: &TestHarness) -> Option<String> {
+    harness.focused_element().and_then(|element| element.get_id().clone())
+}
+
+/// Clicks the element with the id `id` with the right mouse button.
+pub(crate) fn right_click(harness: &mut TestHarness, id: &str) {
+    let bounds = harness.element_bounds(id).unwrap();
+    let position = Point::new(bounds.x + 10.0, bounds.y + 10.0);
+    harness.pointer_moved(position);
+    harness.pointer_button(position, MouseButton::Right, ElementState::Pressed);
+    harness.pointer_button(position, MouseButton::Right, ElementState::Released);
+}
+
+/// Moves the pointer over the center of the element with the id `id`.
+pub(crate) fn hover_element(harness: &mut TestHarness, id: &str) {
+    let bounds = harness.element_bounds(id).unwrap();
+    harness.pointer_moved(Point::new(bounds.x + bounds.width / 2.0, bounds.y + bounds.height / 2.0));
+}
+
+/// A row that is turned on and off by clicks. Its id tells whether it is on, so tests can tell whether it kept its
+/// state.
+#[derive(Default)]
+pub(crate) struct ToggleRow {
+    is_on: bool,
+}
+
+impl Component for ToggleRow {
+    type GlobalState = ();
+    type Props = usize;
+    type Message = ();
+
+    fn view(
+        &self,
+        _global_state: &Self::GlobalState,
+        index: &Self::Props,
+        _children: Vec<ComponentSpecification>,
+        _id: ComponentId,
+        _window: &WindowContext,
+    ) -> ComponentSpecification {
+        let id = if self.is_on { format!("on-{index}") } else { format!("row-{index}") };
+        Container::new()
+            .id(&id)
+            .width(100)
+            .height(20)
+            .on_pointer_button(|state: &mut ToggleRow, _: &mut (), _: &mut Event, pointer_button: &PointerButton| {
+                if pointer_button.clicked() {
+                    state.is_on = !state.is_on;
+                }
+            })
+            .component()
+    }
+}
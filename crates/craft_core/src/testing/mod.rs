@@ -0,0 +1,649 @@
+//! A headless harness for driving a component tree in tests.
+//!
+//! The harness mounts a [`ComponentSpecification`] into an in-memory reactive tree, lays it out against a fixed
+//! window size, and lets you inject synthetic input events. No window, GPU, or display server is involved.
+//!
+//! ```no_run
+//! use craft_core::elements::{Container, ElementStyles};
+//! use craft_core::geometry::Size;
+//! use craft_core::testing::TestHarness;
+//!
+//! let mut harness = TestHarness::new(Container::new().id("root").width(100).height(50).component(), (), Size::new(800.0, 600.0));
+//! harness.click_element("root");
+//! assert!(harness.find_element_by_id("root").is_some());
+//! ```
+
+#[cfg(test)]
+pub(crate) mod helpers;
+mod rendered_image;
+mod snapshot;
+#[cfg(test)]
+mod tests;
+
+pub use rendered_image::{ImageDiff, RenderedImage, UPDATE_GOLDEN_IMAGES_ENV};
+pub use snapshot::{assert_snapshot, UPDATE_SNAPSHOTS_ENV};
+
+use crate::accessibility::{build_tree_update, dispatch_action_request, ActionRequest, TreeUpdate};
+use crate::app_message::AppMessage;
+use crate::components::component::{ComponentId, ComponentSpecification};
+use crate::components::Event;
+use crate::elements::element::Element;
+use crate::events::event_dispatch::{dispatch_event, dispatch_file_drag};
+use crate::events::internal::InternalMessage;
+use crate::events::resource_event::ResourceEvent;
+use crate::events::{
+    CraftMessage, EventDispatchType, FileDragEvent, KeyboardInput, Message, MouseWheel, PointerButton, PointerMoved,
+};
+use crate::geometry::{Point, Rectangle, Size};
+use crate::reactive::animation::animate;
+use crate::reactive::element_id::reset_unique_element_id;
+use crate::reactive::tree::ComponentTreeNode;
+use crate::resource_manager::resource_type::ResourceType;
+use crate::resource_manager::{ResourceIdentifier, ResourceManager};
+use crate::text::text_context::TextContext;
+use crate::testing::snapshot::RecordingRenderer;
+use crate::{
+    draw_reactive_tree, layout, style_root_element, update_reactive_tree, CraftRuntime, GlobalState, ReactiveTree,
+    WindowContext, WindowId, WindowOptions,
+};
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+use tokio::sync::mpsc::{channel, Receiver};
+use winit::dpi::PhysicalPosition;
+use winit::event::{
+    ButtonSource, ElementState, FingerId, Ime, KeyEvent, Modifiers, MouseButton, MouseScrollDelta, PointerSource,
+    TouchPhase,
+};
+use winit::keyboard::{Key, KeyLocation, ModifiersState, NativeKeyCode, PhysicalKey, SmolStr};
+
+#[cfg(feature = "vello_cpu_renderer")]
+use {crate::renderer::renderer::Renderer, crate::renderer::vello_cpu::VelloCpuRenderer, crate::Color};
+
+/// Drives a component tree without a window.
+///
+/// Every injected event is dispatched the same way the windowed app dispatches it, and is followed by a new frame,
+/// just like the window would request a redraw. Positions are in physical pixels, while the window size is in
+/// logical pixels, matching winit.
+pub struct TestHarness {
+    app: ComponentSpecification,
+    global_state: GlobalState,
+    runtime: CraftRuntime,
+    text_context: Option<TextContext>,
+    resource_manager: Arc<ResourceManager>,
+    resource_receiver: Receiver<AppMessage>,
+    resources_collected: HashMap<ResourceIdentifier, bool>,
+    reload_fonts: bool,
+    scale_factor: f64,
+    mouse_position: Option<Point>,
+    /// The fingers that touch the screen, in the order they touched it. The first one is the primary pointer.
+    touches: Vec<FingerId>,
+    pub(crate) reactive_tree: ReactiveTree,
+    window_context: WindowContext,
+    /// The windows that the components opened and did not close yet. Their trees are not built.
+    open_windows: Vec<(WindowId, WindowOptions)>,
+    /// The components that opened the open windows.
+    window_openers: HashMap<WindowId, ComponentId>,
+    /// The time that frames are drawn at. It only moves forward in `advance_time`, so animations are deterministic.
+    now: Instant,
+    is_animating: bool,
+}
+
+impl TestHarness {
+    /// Mounts `application` with the provided global state into a window of `window_size` logical pixels.
+    pub fn new<GlobalStateType: Send + 'static>(
+        application: ComponentSpecification,
+        global_state: GlobalStateType,
+        window_size: Size<f32>,
+    ) -> Self {
+        reset_unique_element_id();
+
+        let (app_sender, resource_receiver) = channel::<AppMessage>(100);
+
+        let now = Instant::now();
+        let mut reactive_tree = ReactiveTree::default();
+        reactive_tree.user_state.storage.insert(0, Box::new(()));
+        reactive_tree.event_time = Some(now);
+
+        let mut window_context = WindowContext::new();
+        window_context.window_size = window_size;
+
+        let mut harness = Self {
+            app: application,
+            global_state: Box::new(global_state),
+            runtime: CraftRuntime::new(),
+            text_context: None,
+            resource_manager: Arc::new(ResourceManager::new(app_sender)),
+            resource_receiver,
+            resources_collected: Default::default(),
+            reload_fonts: false,
+            scale_factor: 1.0,
+            mouse_position: None,
+            touches: Vec::new(),
+            reactive_tree,
+            window_context,
+            open_windows: Vec::new(),
+            window_openers: HashMap::new(),
+            now,
+            is_animating: false,
+        };
+        harness.redraw();
+
+        harness
+    }
+
+    /// Changes the scale factor and draws a new frame.
+    pub fn set_scale_factor(&mut self, scale_factor: f64) {
+        self.scale_factor = scale_factor;
+        self.redraw();
+    }
+
+    /// Sets the longest time between two clicks that still counts them as a double click, like
+    /// [`CraftOptions::double_click_interval`](crate::CraftOptions::double_click_interval).
+    pub fn set_double_click_interval(&mut self, interval: Duration) {
+        self.reactive_tree.double_click_interval = Some(interval);
+    }
+
+    /// Resizes the window to `window_size` logical pixels and draws a new frame.
+    pub fn resize(&mut self, window_size: Size<f32>) {
+        self.window_context.window_size = window_size;
+        self.redraw();
+    }
+
+    /// Rebuilds the reactive tree from the current state and lays it out.
+    pub fn redraw(&mut self) {
+        self.rebuild();
+
+        let text_context = self.text_context.get_or_insert_with(TextContext::new);
+        let root = self.reactive_tree.element_tree.as_mut().unwrap();
+        let root_size = self.window_context.window_size;
+        style_root_element(root, root_size);
+        self.is_animating = animate(root.as_mut(), &mut self.reactive_tree.element_state, self.now);
+
+        layout(
+            &mut self.reactive_tree.element_state,
+            root_size.width,
+            root_size.height,
+            text_context,
+            root.as_mut(),
+            Point::new(0.0, 0.0),
+            self.resource_manager.clone(),
+            self.scale_factor,
+            self.mouse_position,
+        );
+    }
+
+    /// Moves the clock that transitions, animations and click counts use forward by `duration`, then draws a new frame.
+    pub fn advance_time(&mut self, duration: Duration) {
+        self.now += duration;
+        self.reactive_tree.event_time = Some(self.now);
+        self.redraw();
+    }
+
+    /// Whether a transition, animation or timer was running in the last frame, in which case the window would keep
+    /// drawing new frames.
+    pub fn is_animating(&self) -> bool {
+        self.is_animating
+    }
+
+    /// The window size in physical pixels.
+    fn viewport_size(&self) -> Size<f32> {
+        let window_size = self.window_context.window_size;
+        Size::new(
+            (window_size.width as f64 * self.scale_factor).round() as f32,
+            (window_size.height as f64 * self.scale_factor).round() as f32,
+        )
+    }
+
+    /// Rebuilds the reactive tree from the current state without laying it out.
+    ///
+    /// Elements may only be laid out once, so every layout has to start from a freshly built tree.
+    fn rebuild(&mut self) {
+        self.process_resource_events();
+
+        let text_context = self.text_context.get_or_insert_with(TextContext::new);
+
+        let old_element_ids = self.reactive_tree.element_ids.clone();
+        let old_component_ids = self.reactive_tree.component_ids.clone();
+        self.runtime.borrow_tokio_runtime().block_on(update_reactive_tree(
+            self.app.clone(),
+            &mut self.reactive_tree,
+            &mut self.global_state,
+            self.resource_manager.clone(),
+            &mut self.reload_fonts,
+            text_context,
+            self.scale_factor,
+            &mut self.resources_collected,
+            &mut self.window_context,
+        ));
+
+        for request in self.window_context.requested_windows.drain(..) {
+            if let Some(opener_id) = request.opener {
+                self.window_openers.insert(request.id, opener_id);
+            }
+            self.open_windows.push((request.id, request.options));
+        }
+        for window_id in self.window_context.requested_window_closes.drain(..) {
+            self.open_windows.retain(|(id, _)| *id != window_id);
+            self.window_openers.remove(&window_id);
+        }
+
+        // There is no window to apply the other requests to, so we only reset them.
+        self.window_context.reset();
+
+        self.reactive_tree.user_state.remove_unused_state(&old_component_ids, &self.reactive_tree.component_ids);
+        self.reactive_tree.element_state.remove_unused_state(&old_element_ids, &self.reactive_tree.element_ids);
+    }
+
+    /// Renders the current frame on the CPU and returns the pixels.
+    ///
+    /// The image is `window_size * scale_factor` pixels large and uses a white background, like the window does.
+    #[cfg(feature = "vello_cpu_renderer")]
+    pub fn render_to_image(&mut self) -> RenderedImage {
+        self.rebuild();
+
+        let viewport_size = self.viewport_size();
+
+        let mut renderer = VelloCpuRenderer::new_headless(viewport_size.width as u16, viewport_size.height as u16);
+        renderer.surface_set_clear_color(Color::WHITE);
+
+        let text_context = self.text_context.get_or_insert_with(TextContext::new);
+        self.is_animating = self.runtime.borrow_tokio_runtime().block_on(draw_reactive_tree(
+            &mut self.reactive_tree,
+            self.resource_manager.clone(),
+            &mut renderer,
+            viewport_size,
+            Point::new(0.0, 0.0),
+            text_context,
+            self.scale_factor,
+            self.mouse_position,
+            None,
+            self.now,
+        ));
+        renderer.submit(self.resource_manager.clone());
+
+        let pixmap = renderer.pixmap();
+        RenderedImage::from_premultiplied(pixmap.width() as u32, pixmap.height() as u32, pixmap.data_as_u8_slice())
+    }
+
+    /// Lays out the current frame and serializes the render list it produces, see [`RenderList::to_snapshot`].
+    ///
+    /// Commands outside of the window are culled the same way the renderers cull them.
+    ///
+    /// [`RenderList::to_snapshot`]: crate::renderer::RenderList::to_snapshot
+    pub fn render_list_snapshot(&mut self) -> String {
+        self.rebuild();
+
+        let viewport_size = self.viewport_size();
+        let mut renderer = RecordingRenderer::new(viewport_size.width, viewport_size.height);
+
+        let text_context = self.text_context.get_or_insert_with(TextContext::new);
+        self.is_animating = self.runtime.borrow_tokio_runtime().block_on(draw_reactive_tree(
+            &mut self.reactive_tree,
+            self.resource_manager.clone(),
+            &mut renderer,
+            viewport_size,
+            Point::new(0.0, 0.0),
+            text_context,
+            self.scale_factor,
+            self.mouse_position,
+            None,
+            self.now,
+        ));
+
+        renderer.render_list.expect("The render list was not prepared").to_snapshot()
+    }
+
+    /// Waits up to `timeout` for the resources that are still loading, such as images, then draws a new frame.
+    ///
+    /// Resources that fail to load never arrive, so this gives up once the timeout has passed.
+    pub fn wait_for_resources(&mut self, timeout: Duration) {
+        let deadline = Instant::now() + timeout;
+
+        loop {
+            let is_loading = self
+                .resources_collected
+                .keys()
+                .any(|resource_identifier| self.resource_manager.resources.get(resource_identifier).is_none());
+            let remaining = deadline.saturating_duration_since(Instant::now());
+            if !is_loading || remaining.is_zero() {
+                break;
+            }
+
+            let receiver = &mut self.resource_receiver;
+            match self.runtime.borrow_tokio_runtime().block_on(tokio::time::timeout(remaining, receiver.recv())) {
+                Ok(Some(app_message)) => self.on_resource_message(app_message),
+                Ok(None) | Err(_) => break,
+            }
+        }
+
+        self.redraw();
+    }
+
+    /// Dispatches a message through the tree as if it came from the window, then draws a new frame.
+    pub fn dispatch(&mut self, message: CraftMessage) {
+        let is_style = matches!(message, CraftMessage::PointerMovedEvent(_) | CraftMessage::PointerButtonEvent(_));
+
+        dispatch_event(
+            &Message::CraftMessage(message),
+            EventDispatchType::Bubbling,
+            &mut self.resource_manager,
+            self.mouse_position,
+            &mut self.reactive_tree,
+            &mut self.global_state,
+            &mut self.text_context,
+            &mut self.window_context,
+            is_style,
+        );
+
+        self.redraw();
+    }
+
+    /// Closes a window that a component opened as if the user closed it, then tells the component and draws a new frame.
+    pub fn close_window(&mut self, window_id: WindowId) {
+        self.open_windows.retain(|(id, _)| *id != window_id);
+        let Some(opener_id) = self.window_openers.remove(&window_id) else {
+            return;
+        };
+
+        dispatch_event(
+            &Message::CraftMessage(CraftMessage::WindowClosed(window_id)),
+            EventDispatchType::Direct(opener_id),
+            &mut self.resource_manager,
+            self.mouse_position,
+            &mut self.reactive_tree,
+            &mut self.global_state,
+            &mut self.text_context,
+            &mut self.window_context,
+            false,
+        );
+
+        self.redraw();
+    }
+
+    /// Dispatches a file drag of the operating system, as if files were dragged over or dropped on the window.
+    pub fn file_drag(&mut self, file_drag_event: FileDragEvent) {
+        dispatch_file_drag(
+            file_drag_event,
+            &mut self.resource_manager,
+            &mut self.reactive_tree,
+            &mut self.global_state,
+            &mut self.text_context,
+            &mut self.window_context,
+        );
+
+        self.redraw();
+    }
+
+    /// Moves the mouse to `position`.
+    pub fn pointer_moved(&mut self, position: Point) {
+        self.set_mouse_position(position);
+
+        let physical_position = PhysicalPosition::new(position.x as f64, position.y as f64);
+        let pointer_moved = PointerMoved::new(None, physical_position, PointerSource::Mouse, true);
+        self.dispatch(CraftMessage::PointerMovedEvent(pointer_moved));
+    }
+
+    /// Presses or releases `button` at `position`.
+    pub fn pointer_button(&mut self, position: Point, button: MouseButton, state: ElementState) {
+        self.set_mouse_position(position);
+
+        let physical_position = PhysicalPosition::new(position.x as f64, position.y as f64);
+        let pointer_button = PointerButton::new(None, state, physical_position, ButtonSource::Mouse(button), true);
+        self.dispatch(CraftMessage::PointerButtonEvent(pointer_button));
+    }
+
+    /// Touches the screen with the finger `finger_id` at `position`, or lifts the finger.
+    pub fn touch(&mut self, finger_id: FingerId, position: Point, state: ElementState) {
+        if state == ElementState::Pressed && !self.touches.contains(&finger_id) {
+            self.touches.push(finger_id);
+        }
+        let primary = self.touches.first() == Some(&finger_id);
+        if state == ElementState::Released {
+            self.touches.retain(|touch| *touch != finger_id);
+        }
+        if primary {
+            self.set_mouse_position(position);
+        }
+
+        let physical_position = PhysicalPosition::new(position.x as f64, position.y as f64);
+        let button = ButtonSource::Touch { finger_id, force: None };
+        let pointer_button = PointerButton::new(None, state, physical_position, button, primary);
+        self.dispatch(CraftMessage::PointerButtonEvent(pointer_button));
+    }
+
+    /// Moves the finger `finger_id`, which touches the screen, to `position`.
+    pub fn touch_moved(&mut self, finger_id: FingerId, position: Point) {
+        let primary = self.touches.first() == Some(&finger_id);
+        if primary {
+            self.set_mouse_position(position);
+        }
+
+        let physical_position = PhysicalPosition::new(position.x as f64, position.y as f64);
+        let source = PointerSource::Touch { finger_id, force: None };
+        let pointer_moved = PointerMoved::new(None, physical_position, source, primary);
+        self.dispatch(CraftMessage::PointerMovedEvent(pointer_moved));
+    }
+
+    /// Moves the mouse to `position` and clicks the left mouse button.
+    pub fn click(&mut self, position: Point) {
+        self.pointer_moved(position);
+        self.pointer_button(position, MouseButton::Left, ElementState::Pressed);
+        self.pointer_button(position, MouseButton::Left, ElementState::Released);
+    }
+
+    /// Clicks the center of the element with the given `id`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if no element has the given `id`.
+    pub fn click_element(&mut self, id: &str) {
+        let bounds = self.element_bounds(id).unwrap_or_else(|| panic!("No element with the id `{id}` was found."));
+        self.click(Point::new(bounds.x + bounds.width / 2.0, bounds.y + bounds.height / 2.0));
+    }
+
+    /// Moves the mouse to `position` and scrolls down by `delta_y` pixels with the mouse wheel, or up when negative.
+    pub fn scroll(&mut self, position: Point, delta_y: f32) {
+        self.pointer_moved(position);
+
+        // The wheel moves the content, so scrolling down is a negative delta.
+        let delta = MouseScrollDelta::PixelDelta(PhysicalPosition::new(0.0, -delta_y as f64));
+        self.dispatch(CraftMessage::MouseWheelEvent(MouseWheel::new(None, delta, TouchPhase::Moved)));
+    }
+
+    /// Sends a raw keyboard event.
+    pub fn keyboard_input(&mut self, event: KeyEvent) {
+        self.dispatch(CraftMessage::KeyboardInputEvent(KeyboardInput::new(None, event, false)));
+    }
+
+    /// Presses and releases `key`.
+    pub fn key_press(&mut self, key: Key) {
+        self.keyboard_input(key_event(key.clone(), ElementState::Pressed));
+        self.keyboard_input(key_event(key, ElementState::Released));
+    }
+
+    /// Presses and releases a key for every character in `text`.
+    pub fn type_text(&mut self, text: &str) {
+        for character in text.chars() {
+            self.key_press(Key::Character(SmolStr::new(character.to_string())));
+        }
+    }
+
+    /// Changes the modifier keys that are held down.
+    pub fn modifiers_changed(&mut self, modifiers: ModifiersState) {
+        self.dispatch(CraftMessage::ModifiersChangedEvent(Modifiers::from(modifiers)));
+    }
+
+    /// Sends an input method event.
+    pub fn ime(&mut self, ime: Ime) {
+        self.dispatch(CraftMessage::ImeEvent(ime));
+    }
+
+    /// Awaits every future in the update queue and delivers the results to their components.
+    ///
+    /// This keeps going until the update queue is empty, so futures queued while handling a result are awaited too.
+    pub fn run_pending_updates(&mut self) {
+        while !self.reactive_tree.update_queue.is_empty() {
+            let entries: Vec<_> = self.reactive_tree.update_queue.drain(..).collect();
+
+            for entry in entries {
+                let future = if let Some(future) = entry.update_result {
+                    future
+                } else {
+                    continue;
+                };
+                let result = self.runtime.borrow_tokio_runtime().block_on(future);
+
+                // The component may have been unmounted while the future was pending.
+                let state = if let Some(state) = self.reactive_tree.user_state.storage.get_mut(&entry.source_component) {
+                    state.as_mut()
+                } else {
+                    continue;
+                };
+
+                let mut event = Event::with_window_context(self.window_context.clone());
+                (entry.update_function)(
+                    state,
+                    &mut self.global_state,
+                    entry.props,
+                    &mut event,
+                    &Message::UserMessage(result),
+                );
+                event.window.set_window_opener(entry.source_component);
+                self.window_context = event.window;
+            }
+
+            self.redraw();
+        }
+    }
+
+    /// Builds the accessibility tree of the current frame, exactly like the one sent to assistive technologies.
+    pub fn accessibility_tree(&self) -> TreeUpdate {
+        let root = self.reactive_tree.element_tree.as_ref().expect("The tree has not been built");
+        build_tree_update(root.as_ref(), &self.reactive_tree.element_state, self.reactive_tree.focus)
+    }
+
+    /// Performs an action as if an assistive technology requested it, then draws a new frame.
+    pub fn accessibility_action(&mut self, request: ActionRequest) {
+        dispatch_action_request(
+            request,
+            &mut self.resource_manager,
+            &mut self.reactive_tree,
+            &mut self.global_state,
+            &mut self.text_context,
+            &mut self.window_context,
+        );
+
+        self.redraw();
+    }
+
+    /// Finds the first element with the given `id` in pre-order.
+    pub fn find_element_by_id(&self, id: &str) -> Option<&dyn Element> {
+        let root = self.reactive_tree.element_tree.as_ref()?;
+        root.as_ref().pre_order_iter().find(|element| element.get_id().as_deref() == Some(id))
+    }
+
+    /// Returns the border rectangle of the element with the given `id` after layout.
+    pub fn element_bounds(&self, id: &str) -> Option<Rectangle> {
+        self.find_element_by_id(id)
+            .map(|element| element.element_data().layout_item.computed_box_transformed.border_rectangle())
+    }
+
+    /// Returns the element that has the keyboard focus.
+    pub fn focused_element(&self) -> Option<&dyn Element> {
+        let focus = self.reactive_tree.focus?;
+        let root = self.reactive_tree.element_tree.as_ref()?;
+        root.as_ref().pre_order_iter().find(|element| element.component_id() == focus)
+    }
+
+    /// Returns the state of the first mounted component of type `T` in pre-order.
+    pub fn component_state<T: 'static>(&self) -> Option<&T> {
+        let mut to_visit: Vec<&ComponentTreeNode> = vec![self.reactive_tree.component_tree.as_ref()?];
+
+        while let Some(node) = to_visit.pop() {
+            // Only components have user state, so elements are skipped here.
+            if let Some(state) = self.component_state_by_id::<T>(node.id) {
+                return Some(state);
+            }
+            to_visit.extend(node.children.iter().rev());
+        }
+
+        None
+    }
+
+    /// Returns the state of the component with the given component id.
+    pub fn component_state_by_id<T: 'static>(&self, id: ComponentId) -> Option<&T> {
+        self.reactive_tree.user_state.storage.get(&id).and_then(|state| state.downcast_ref::<T>())
+    }
+
+    pub fn global_state<T: 'static>(&self) -> Option<&T> {
+        self.global_state.downcast_ref::<T>()
+    }
+
+    pub fn window_context(&self) -> &WindowContext {
+        &self.window_context
+    }
+
+    /// The windows that were opened with [`WindowContext::open_window`] and are still open, in the order they were
+    /// opened.
+    pub fn open_windows(&self) -> &[(WindowId, WindowOptions)] {
+        &self.open_windows
+    }
+
+    pub fn root_element(&self) -> Option<&dyn Element> {
+        self.reactive_tree.element_tree.as_deref()
+    }
+
+    fn set_mouse_position(&mut self, position: Point) {
+        self.mouse_position = Some(position);
+        self.window_context.mouse_position =
+            Some(Point::new(position.x / self.scale_factor as f32, position.y / self.scale_factor as f32));
+    }
+
+    /// Stores the resources that finished loading in the background.
+    fn process_resource_events(&mut self) {
+        while let Ok(app_message) = self.resource_receiver.try_recv() {
+            self.on_resource_message(app_message);
+        }
+    }
+
+    fn on_resource_message(&mut self, app_message: AppMessage) {
+        if let InternalMessage::ResourceEvent(ResourceEvent::Loaded(resource_identifier, resource_type, resource)) =
+            app_message.data
+        {
+            if resource_type == ResourceType::Font {
+                self.reload_fonts = true;
+            }
+            self.resource_manager.resources.insert(resource_identifier, Arc::new(resource));
+        }
+    }
+}
+
+fn key_event(key: Key, state: ElementState) -> KeyEvent {
+    // Winit only reports the produced text when a key is pressed.
+    let text = match (&key, state) {
+        (Key::Character(character), ElementState::Pressed) => Some(character.clone()),
+        _ => None,
+    };
+
+    KeyEvent {
+        physical_key: PhysicalKey::Unidentified(NativeKeyCode::Unidentified),
+        logical_key: key.clone(),
+        text: text.clone(),
+        location: KeyLocation::Standard,
+        state,
+        repeat: false,
+        text_with_all_modifiers: text,
+        key_without_modifiers: key,
+    }
+}
+
+/// `golden/button.png` -> `golden/button.<suffix>.png`
+fn sibling_path(path: &Path, suffix: &str) -> PathBuf {
+    let stem = path.file_stem().unwrap_or_default().to_string_lossy();
+    match path.extension() {
+        Some(extension) => path.with_file_name(format!("{stem}.{suffix}.{}", extension.to_string_lossy())),
+        None => path.with_file_name(format!("{stem}.{suffix}")),
+    }
+}
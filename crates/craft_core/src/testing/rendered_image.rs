@@ -0,0 +1,191 @@
+use crate::testing::sibling_path;
+use image::{ImageFormat, RgbaImage};
+use std::fmt;
+use std::io::Cursor;
+use std::path::Path;
+
+/// Set this environment variable to overwrite the reference images in [`RenderedImage::assert_matches_png`].
+pub const UPDATE_GOLDEN_IMAGES_ENV: &str = "CRAFT_UPDATE_GOLDEN_IMAGES";
+
+/// An image with unpremultiplied RGBA8 pixels stored in row-major order.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct RenderedImage {
+    pub width: u32,
+    pub height: u32,
+    pub data: Vec<u8>,
+}
+
+/// The result of comparing two images.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct ImageDiff {
+    /// True if the images do not have the same width and height.
+    /// None of the other fields are meaningful when this is set.
+    pub size_mismatch: bool,
+    /// The number of pixels that differ by more than the tolerance in at least one channel.
+    pub differing_pixels: u64,
+    /// The largest difference in a single channel across all pixels.
+    pub max_channel_difference: u8,
+    pub total_pixels: u64,
+}
+
+impl ImageDiff {
+    pub fn is_match(&self) -> bool {
+        !self.size_mismatch && self.differing_pixels == 0
+    }
+}
+
+impl fmt::Display for ImageDiff {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.size_mismatch {
+            return write!(f, "the image sizes do not match");
+        }
+        write!(
+            f,
+            "{} of {} pixels differ, max channel difference: {}",
+            self.differing_pixels, self.total_pixels, self.max_channel_difference
+        )
+    }
+}
+
+impl RenderedImage {
+    pub fn new(width: u32, height: u32, data: Vec<u8>) -> Self {
+        assert_eq!(data.len(), width as usize * height as usize * 4, "Expected 4 bytes per pixel");
+        Self { width, height, data }
+    }
+
+    /// Creates an image from premultiplied RGBA8 pixels, like the ones the renderers produce.
+    pub fn from_premultiplied(width: u32, height: u32, premultiplied: &[u8]) -> Self {
+        let mut data = premultiplied.to_vec();
+        for pixel in data.chunks_exact_mut(4) {
+            let alpha = pixel[3];
+            if alpha != 0 && alpha != 255 {
+                for channel in &mut pixel[..3] {
+                    *channel = ((*channel as u32 * 255 + alpha as u32 / 2) / alpha as u32).min(255) as u8;
+                }
+            }
+        }
+
+        Self::new(width, height, data)
+    }
+
+    /// Returns the RGBA value of the pixel at `x` and `y`.
+    pub fn pixel(&self, x: u32, y: u32) -> [u8; 4] {
+        let offset = (y as usize * self.width as usize + x as usize) * 4;
+        [self.data[offset], self.data[offset + 1], self.data[offset + 2], self.data[offset + 3]]
+    }
+
+    pub fn from_png(bytes: &[u8]) -> Result<Self, image::ImageError> {
+        let image = image::load_from_memory_with_format(bytes, ImageFormat::Png)?.into_rgba8();
+        Ok(Self::new(image.width(), image.height(), image.into_raw()))
+    }
+
+    pub fn load_png(path: impl AsRef<Path>) -> Result<Self, image::ImageError> {
+        let bytes = std::fs::read(path).map_err(image::ImageError::IoError)?;
+        Self::from_png(&bytes)
+    }
+
+    pub fn to_png(&self) -> Result<Vec<u8>, image::ImageError> {
+        let mut bytes = Vec::new();
+        self.to_rgba_image().write_to(&mut Cursor::new(&mut bytes), ImageFormat::Png)?;
+        Ok(bytes)
+    }
+
+    pub fn save_png(&self, path: impl AsRef<Path>) -> Result<(), image::ImageError> {
+        self.to_rgba_image().save_with_format(path, ImageFormat::Png)
+    }
+
+    /// Compares every channel of every pixel against `reference`.
+    ///
+    /// Channels that differ by `tolerance` or less are treated as equal, which absorbs small anti-aliasing changes.
+    pub fn compare(&self, reference: &RenderedImage, tolerance: u8) -> ImageDiff {
+        let total_pixels = self.width as u64 * self.height as u64;
+        if self.width != reference.width || self.height != reference.height {
+            return ImageDiff {
+                size_mismatch: true,
+                total_pixels,
+                ..Default::default()
+            };
+        }
+
+        let mut diff = ImageDiff {
+            total_pixels,
+            ..Default::default()
+        };
+        for (actual, expected) in self.data.chunks_exact(4).zip(reference.data.chunks_exact(4)) {
+            let max_difference = actual.iter().zip(expected).map(|(a, e)| a.abs_diff(*e)).max().unwrap_or(0);
+            diff.max_channel_difference = diff.max_channel_difference.max(max_difference);
+            if max_difference > tolerance {
+                diff.differing_pixels += 1;
+            }
+        }
+
+        diff
+    }
+
+    /// Produces an image that highlights the pixels that differ from `reference` in red.
+    pub fn diff_image(&self, reference: &RenderedImage, tolerance: u8) -> RenderedImage {
+        let mut data = Vec::with_capacity(self.data.len());
+        for (index, actual) in self.data.chunks_exact(4).enumerate() {
+            let expected = reference.data.get(index * 4..index * 4 + 4);
+            let differs = self.width != reference.width || self.height != reference.height
+                || expected.is_none_or(|expected| actual.iter().zip(expected).any(|(a, e)| a.abs_diff(*e) > tolerance));
+            if differs {
+                data.extend_from_slice(&[255, 0, 0, 255]);
+            } else {
+                // Fade the matching pixels, so the differences stand out.
+                data.extend(actual[..3].iter().map(|channel| 191 + channel / 4));
+                data.push(255);
+            }
+        }
+
+        RenderedImage::new(self.width, self.height, data)
+    }
+
+    /// Asserts that this image matches the PNG at `path`.
+    ///
+    /// If the `CRAFT_UPDATE_GOLDEN_IMAGES` environment variable is set, the reference is written instead. A missing
+    /// reference fails the assertion, so a deleted or misspelled reference does not pass silently. On a mismatch
+    /// `<name>.actual.png` and `<name>.diff.png` are written next to the reference for inspection.
+    pub fn assert_matches_png(&self, path: impl AsRef<Path>, tolerance: u8) {
+        let path = path.as_ref();
+
+        if std::env::var_os(UPDATE_GOLDEN_IMAGES_ENV).is_some() {
+            if let Some(parent) = path.parent() {
+                std::fs::create_dir_all(parent).expect("Failed to create the reference image directory");
+            }
+            self.save_png(path).expect("Failed to write the reference image");
+            return;
+        }
+
+        if !path.exists() {
+            let actual_path = sibling_path(path, "actual");
+            if let Some(parent) = path.parent() {
+                std::fs::create_dir_all(parent).expect("Failed to create the reference image directory");
+            }
+            self.save_png(&actual_path).expect("Failed to write the actual image");
+            panic!(
+                "The reference image {} does not exist. Set {UPDATE_GOLDEN_IMAGES_ENV} to write it. See {}.",
+                path.display(),
+                actual_path.display()
+            );
+        }
+
+        let reference = RenderedImage::load_png(path).expect("Failed to read the reference image");
+        let diff = self.compare(&reference, tolerance);
+        if diff.is_match() {
+            return;
+        }
+
+        let actual_path = sibling_path(path, "actual");
+        self.save_png(&actual_path).expect("Failed to write the actual image");
+        if !diff.size_mismatch {
+            self.diff_image(&reference, tolerance).save_png(sibling_path(path, "diff")).expect("Failed to write the diff image");
+        }
+
+        panic!("The rendered image does not match {}: {}. See {}.", path.display(), diff, actual_path.display());
+    }
+
+    fn to_rgba_image(&self) -> RgbaImage {
+        RgbaImage::from_raw(self.width, self.height, self.data.clone()).unwrap()
+    }
+}
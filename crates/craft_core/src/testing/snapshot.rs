@@ -0,0 +1,125 @@
+use crate::geometry::Rectangle;
+use crate::renderer::color::Color;
+use crate::renderer::renderer::{RenderList, Renderer};
+use crate::resource_manager::ResourceManager;
+use crate::testing::sibling_path;
+use std::path::Path;
+use std::sync::Arc;
+
+/// Set this environment variable to overwrite the reference snapshots in [`assert_snapshot`].
+pub const UPDATE_SNAPSHOTS_ENV: &str = "CRAFT_UPDATE_SNAPSHOTS";
+
+/// Keeps the sorted and culled render list instead of drawing it.
+pub(super) struct RecordingRenderer {
+    width: f32,
+    height: f32,
+    pub(super) render_list: Option<RenderList>,
+}
+
+impl RecordingRenderer {
+    pub(super) fn new(width: f32, height: f32) -> Self {
+        Self {
+            width,
+            height,
+            render_list: None,
+        }
+    }
+}
+
+impl Renderer for RecordingRenderer {
+    fn surface_width(&self) -> f32 {
+        self.width
+    }
+
+    fn surface_height(&self) -> f32 {
+        self.height
+    }
+
+    fn resize_surface(&mut self, width: f32, height: f32) {
+        self.width = width;
+        self.height = height;
+    }
+
+    fn surface_set_clear_color(&mut self, _color: Color) {}
+
+    fn prepare_render_list(
+        &mut self,
+        render_list: RenderList,
+        _resource_manager: Arc<ResourceManager>,
+        _window: Rectangle,
+    ) {
+        self.render_list = Some(render_list);
+    }
+
+    fn submit(&mut self, _resource_manager: Arc<ResourceManager>) {}
+}
+
+/// Asserts that `actual` matches the snapshot stored at `path`.
+///
+/// If the `CRAFT_UPDATE_SNAPSHOTS` environment variable is set, the snapshot is written instead. A missing snapshot
+/// fails the assertion, so a deleted or misspelled snapshot does not pass silently. On a mismatch or a missing snapshot
+/// `<name>.actual.<extension>` is written next to the snapshot, and the differing lines are printed on a mismatch.
+pub fn assert_snapshot(path: impl AsRef<Path>, actual: &str) {
+    let path = path.as_ref();
+
+    if std::env::var_os(UPDATE_SNAPSHOTS_ENV).is_some() {
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent).expect("Failed to create the snapshot directory");
+        }
+        std::fs::write(path, actual).expect("Failed to write the snapshot");
+        return;
+    }
+
+    if !path.exists() {
+        let actual_path = sibling_path(path, "actual");
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent).expect("Failed to create the snapshot directory");
+        }
+        std::fs::write(&actual_path, actual).expect("Failed to write the actual snapshot");
+        panic!(
+            "The snapshot {} does not exist. Set {UPDATE_SNAPSHOTS_ENV} to write it. See {}.",
+            path.display(),
+            actual_path.display()
+        );
+    }
+
+    let expected = std::fs::read_to_string(path).expect("Failed to read the snapshot");
+    // Snapshots checked out on Windows may have CRLF line endings.
+    let expected = expected.replace("\r\n", "\n");
+    if expected == actual {
+        return;
+    }
+
+    let actual_path = sibling_path(path, "actual");
+    std::fs::write(&actual_path, actual).expect("Failed to write the actual snapshot");
+
+    panic!(
+        "The snapshot does not match {}. See {}.\n{}",
+        path.display(),
+        actual_path.display(),
+        line_diff(&expected, actual)
+    );
+}
+
+/// Lists the lines that differ between `expected` and `actual`, prefixed with their line number.
+fn line_diff(expected: &str, actual: &str) -> String {
+    let expected_lines: Vec<&str> = expected.lines().collect();
+    let actual_lines: Vec<&str> = actual.lines().collect();
+
+    let mut diff = String::new();
+    for index in 0..expected_lines.len().max(actual_lines.len()) {
+        let expected_line = expected_lines.get(index);
+        let actual_line = actual_lines.get(index);
+        if expected_line == actual_line {
+            continue;
+        }
+        if let Some(expected_line) = expected_line {
+            diff.push_str(&format!("{:>4} - {}\n", index + 1, expected_line));
+        }
+        if let Some(actual_line) = actual_line {
+            diff.push_str(&format!("{:>4} + {}\n", index + 1, actual_line));
+        }
+    }
+
+    diff
+}
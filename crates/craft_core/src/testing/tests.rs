@@ -0,0 +1,273 @@
+use crate::components::{Component, ComponentId, ComponentSpecification, Event};
+use crate::elements::{Container, ElementStyles, Text};
+use crate::events::PointerButton;
+use crate::geometry::{Point, Rectangle, Size};
+use crate::renderer::renderer::Renderer;
+use crate::renderer::{Brush, RenderList};
+use crate::resource_manager::ResourceIdentifier;
+use crate::testing::helpers::state;
+use crate::testing::snapshot::RecordingRenderer;
+use crate::testing::{assert_snapshot, RenderedImage, TestHarness};
+use crate::{rgb, Color, WindowContext, WindowId, WindowOptions};
+use peniko::kurbo::{Rect, Shape};
+use std::path::PathBuf;
+
+#[derive(Default)]
+struct Counter {
+    count: i64,
+}
+
+impl Component for Counter {
+    type GlobalState = ();
+    type Props = ();
+    type Message = ();
+
+    fn view(
+        &self,
+        _global_state: &Self::GlobalState,
+        _props: &Self::Props,
+        _children: Vec<ComponentSpecification>,
+        _id: ComponentId,
+        _window: &WindowContext,
+    ) -> ComponentSpecification {
+        Container::new()
+            .id("root")
+            .width("100%")
+            .height("100%")
+            .push(
+                Container::new()
+                    .id("increment")
+                    .width(100)
+                    .height(40)
+                    .margin(10, 0, 0, 20)
+                    .on_pointer_button(|state: &mut Counter, _: &mut (), _: &mut Event, pointer_button: &PointerButton| {
+                        if pointer_button.clicked() {
+                            state.count += 1;
+                        }
+                    }),
+            )
+            .component()
+    }
+}
+
+#[test]
+fn harness_layout_uses_window_size() {
+    let harness = TestHarness::new(Counter::component(), (), Size::new(400.0, 300.0));
+
+    let root = harness.element_bounds("root").unwrap();
+    assert_eq!((root.width, root.height), (400.0, 300.0));
+
+    let increment = harness.element_bounds("increment").unwrap();
+    assert_eq!((increment.x, increment.y, increment.width, increment.height), (20.0, 10.0, 100.0, 40.0));
+
+    assert!(harness.find_element_by_id("missing").is_none());
+}
+
+#[test]
+fn harness_click_updates_component_state() {
+    let mut harness = TestHarness::new(Counter::component(), (), Size::new(400.0, 300.0));
+
+    harness.click_element("increment");
+    harness.click_element("increment");
+    assert_eq!(state::<Counter>(&harness).count, 2);
+
+    // Clicking outside the button does nothing.
+    harness.click(Point::new(300.0, 200.0));
+    assert_eq!(state::<Counter>(&harness).count, 2);
+}
+
+#[test]
+fn rendered_image_compare_counts_pixels_over_tolerance() {
+    let reference = RenderedImage::new(2, 1, vec![10, 10, 10, 255, 200, 200, 200, 255]);
+    let actual = RenderedImage::new(2, 1, vec![12, 10, 10, 255, 100, 200, 200, 255]);
+
+    let diff = actual.compare(&reference, 2);
+    assert_eq!(diff.differing_pixels, 1);
+    assert_eq!(diff.max_channel_difference, 100);
+    assert!(!diff.is_match());
+
+    assert!(actual.compare(&actual, 0).is_match());
+    assert!(actual.compare(&RenderedImage::new(1, 1, vec![0; 4]), 0).size_mismatch);
+
+    let round_trip = RenderedImage::from_png(&actual.to_png().unwrap()).unwrap();
+    assert_eq!(round_trip, actual);
+}
+
+#[test]
+fn missing_reference_image_fails() {
+    let image = RenderedImage::new(1, 1, vec![255, 0, 0, 255]);
+    let directory = std::env::temp_dir().join(format!("craft_golden_image_test_{}", std::process::id()));
+    let path = directory.join("red.png");
+    let _ = std::fs::remove_dir_all(&directory);
+
+    let result = std::panic::catch_unwind(|| image.assert_matches_png(&path, 0));
+    assert!(result.is_err());
+    assert!(!path.exists());
+    std::fs::rename(directory.join("red.actual.png"), &path).unwrap();
+    image.assert_matches_png(&path, 0);
+
+    let _ = std::fs::remove_dir_all(&directory);
+}
+
+#[cfg(feature = "vello_cpu_renderer")]
+#[test]
+fn harness_render_to_image_draws_backgrounds() {
+    let mut harness = TestHarness::new(
+        Container::new().push(Container::new().width(20).height(10).margin(5, 0, 0, 5).background(rgb(255, 0, 0))).component(),
+        (),
+        Size::new(40.0, 30.0),
+    );
+
+    let image = harness.render_to_image();
+    assert_eq!((image.width, image.height), (40, 30));
+    assert_eq!(image.pixel(10, 10), [255, 0, 0, 255]);
+    assert_eq!(image.pixel(35, 25), [255, 255, 255, 255]);
+
+    harness.set_scale_factor(2.0);
+    let image = harness.render_to_image();
+    assert_eq!((image.width, image.height), (80, 60));
+    assert_eq!(image.pixel(20, 20), [255, 0, 0, 255]);
+}
+
+#[test]
+fn render_list_snapshot_nests_layers_and_overlays() {
+    let mut render_list = RenderList::new();
+    render_list.push_layer(Rectangle::new(0.0, 0.0, 50.0, 50.0));
+    render_list.draw_rect(Rectangle::new(1.0, 2.0, 3.0, 4.0), Color::BLACK);
+    render_list.pop_layer();
+    render_list.start_overlay();
+    render_list.draw_image(Rectangle::new(0.0, 0.0, 10.5, 10.0), ResourceIdentifier::File(PathBuf::from("cat.png")));
+    render_list.end_overlay();
+    render_list.draw_tiny_vg(Rectangle::new(0.0, 90.0, 10.0, 10.0), ResourceIdentifier::Bytes(&[1, 2, 3]), Some(rgb(255, 0, 0)));
+    render_list.fill_bez_path(Rect::new(0.0, 0.0, 1.0 / 3.0, 2.0).to_path(0.1), Brush::Color(Color::WHITE));
+    // Below the window, so it is culled.
+    render_list.draw_rect_outline(Rectangle::new(0.0, 200.0, 10.0, 10.0), Color::BLACK);
+
+    let mut renderer = RecordingRenderer::new(100.0, 100.0);
+    renderer.sort_and_cull_render_list(&mut render_list);
+
+    assert_eq!(
+        render_list.to_snapshot(),
+        "\
+layer x=0 y=0 w=50 h=50
+  rect x=1 y=2 w=3 h=4 #000000ff
+tinyvg x=0 y=90 w=10 h=10 bytes=3 color=#ff0000ff
+path #ffffffff
+  M0,0 L0.33,0 L0.33,2 L0,2 Z
+overlay
+  image x=0 y=0 w=10.5 h=10 file=\"cat.png\"
+"
+    );
+}
+
+#[test]
+fn harness_render_list_snapshot_matches_file() {
+    let mut harness = TestHarness::new(
+        Container::new().push(Container::new().width(20).height(10).margin(5, 0, 0, 5).background(rgb(255, 0, 0))).component(),
+        (),
+        Size::new(40.0, 30.0),
+    );
+    let snapshot = harness.render_list_snapshot();
+    assert!(snapshot.contains("rect x=5 y=5 w=20 h=10 #ff0000ff\n"), "{snapshot}");
+
+    let directory = std::env::temp_dir().join(format!("craft_snapshot_test_{}", std::process::id()));
+    let path = directory.join("background.txt");
+    let _ = std::fs::remove_dir_all(&directory);
+
+    // A missing snapshot fails instead of being written, unless the snapshots are updated.
+    let result = std::panic::catch_unwind(|| assert_snapshot(&path, &snapshot));
+    assert!(result.is_err());
+    assert!(!path.exists());
+    std::fs::rename(directory.join("background.actual.txt"), &path).unwrap();
+    assert_snapshot(&path, &snapshot);
+
+    harness.set_scale_factor(2.0);
+    let scaled_snapshot = harness.render_list_snapshot();
+    let result = std::panic::catch_unwind(|| assert_snapshot(&path, &scaled_snapshot));
+    assert!(result.is_err());
+    assert_eq!(std::fs::read_to_string(directory.join("background.actual.txt")).unwrap(), scaled_snapshot);
+
+    let _ = std::fs::remove_dir_all(&directory);
+}
+
+#[derive(Default)]
+struct Inspector {
+    window: Option<WindowId>,
+}
+
+impl Component for Inspector {
+    type GlobalState = ();
+    type Props = ();
+    type Message = ();
+
+    fn view(
+        &self,
+        _global_state: &Self::GlobalState,
+        _props: &Self::Props,
+        _children: Vec<ComponentSpecification>,
+        _id: ComponentId,
+        window: &WindowContext,
+    ) -> ComponentSpecification {
+        assert_eq!(window.window_id(), WindowId::PRIMARY);
+
+        Container::new()
+            .id("toggle")
+            .width(100)
+            .height(40)
+            .on_pointer_button(|state: &mut Inspector, _: &mut (), event: &mut Event, pointer_button: &PointerButton| {
+                if !pointer_button.clicked() {
+                    return;
+                }
+                match state.window.take() {
+                    Some(window) => event.window.close_window(window),
+                    None => {
+                        let options = WindowOptions::basic("Inspector").size(300.0, 200.0);
+                        state.window = Some(event.window.open_window(Text::new("Details").component(), options));
+                    }
+                }
+            })
+            .component()
+    }
+
+    fn on_window_closed(&mut self, _global_state: &mut (), _props: &(), _event: &mut Event, window: WindowId) {
+        if self.window == Some(window) {
+            self.window = None;
+        }
+    }
+}
+
+#[test]
+fn components_open_and_close_windows() {
+    let mut harness = TestHarness::new(Inspector::component(), (), Size::new(400.0, 300.0));
+    assert!(harness.open_windows().is_empty());
+
+    harness.click_element("toggle");
+    let window = state::<Inspector>(&harness).window.unwrap();
+    assert_ne!(window, WindowId::PRIMARY);
+    let [(id, options)] = harness.open_windows() else {
+        panic!("expected one open window");
+    };
+    assert_eq!(*id, window);
+    assert_eq!(options.title, "Inspector");
+    let size = options.size.unwrap();
+    assert_eq!((size.width, size.height), (300.0, 200.0));
+
+    harness.click_element("toggle");
+    assert!(harness.open_windows().is_empty());
+}
+
+#[test]
+fn the_opener_is_told_when_the_user_closes_a_window() {
+    let mut harness = TestHarness::new(Inspector::component(), (), Size::new(400.0, 300.0));
+    harness.click_element("toggle");
+    let window = state::<Inspector>(&harness).window.unwrap();
+
+    harness.close_window(window);
+    assert!(harness.open_windows().is_empty());
+    assert_eq!(state::<Inspector>(&harness).window, None);
+
+    // The next click opens a new window instead of closing the one that is gone.
+    harness.click_element("toggle");
+    assert_eq!(harness.open_windows().len(), 1);
+    assert_ne!(state::<Inspector>(&harness).window, Some(window));
+}